@@ -0,0 +1,117 @@
+//! Benchmark des règles sur une collection de 500 requêtes
+//!
+//! `cargo bench --bench rules` mesure une analyse complète, puis compare le coût des
+//! motifs de regex des règles compilés à chaque requête (ancien fonctionnement) et
+//! compilés une seule fois dans des statiques `LazyLock`. Sans dépendance : le
+//! harness de test standard n'est pas utilisé (`harness = false`).
+
+use postman_linter_core::rules::security::hardcoded_secrets::SECRET_PATTERNS;
+use postman_linter_core::rules::testing::{
+    test_body_content_validation::BODY_PATTERNS, test_http_status_mandatory::STATUS_PATTERNS,
+    test_response_time_mandatory::RESPONSE_TIME_PATTERNS, test_schema_validation_recommended::SCHEMA_PATTERNS,
+};
+use postman_linter_core::{run_linter, utils, LintConfig};
+use regex::Regex;
+use serde_json::{json, Value};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const REQUESTS: usize = 500;
+const ITERATIONS: u32 = 5;
+
+/// Collection de 500 requêtes réparties en 10 folders, avec scripts de test
+fn collection() -> Value {
+    let folders: Vec<Value> = (0..10)
+        .map(|folder| {
+            let items: Vec<Value> = (0..REQUESTS / 10)
+                .map(|i| {
+                    json!({
+                        "name": format!("GET Resource {}-{}", folder, i),
+                        "request": {
+                            "method": "GET",
+                            "url": format!("{{{{base_url}}}}/folders/{}/resources/{}?page=1", folder, i),
+                            "header": [{ "key": "Authorization", "value": "Bearer {{token}}" }]
+                        },
+                        "event": [{
+                            "listen": "test",
+                            "script": { "exec": [
+                                "pm.test(\"Status code is 200\", () => pm.response.to.have.status(200));",
+                                "pm.test(\"Response time is acceptable\", () => pm.expect(pm.response.responseTime).to.be.below(500));",
+                                "const body = pm.response.json();",
+                                "pm.test(\"Body has id\", () => pm.expect(body).to.have.property('id'));",
+                                "pm.environment.set('last_id', body.id);"
+                            ] }
+                        }]
+                    })
+                })
+                .collect();
+            json!({ "name": format!("Folder {}", folder), "item": items })
+        })
+        .collect();
+    json!({ "info": { "name": "Benchmark" }, "item": folders })
+}
+
+/// Durée moyenne d'une exécution
+fn measure(mut run: impl FnMut()) -> Duration {
+    run();
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        run();
+    }
+    start.elapsed() / ITERATIONS
+}
+
+fn main() {
+    let collection = collection();
+    let config = LintConfig { local_only: true, ..Default::default() };
+    let lint = measure(|| {
+        black_box(run_linter(black_box(&collection), &config));
+    });
+    println!("run_linter, {} requests: {:.2} ms", REQUESTS, lint.as_secs_f64() * 1000.0);
+
+    // Temps passé dans les règles, les plus lentes d'abord
+    let profiled = LintConfig { profile: Some(true), ..config.clone() };
+    if let Some(profile) = run_linter(&collection, &profiled).profile {
+        println!("  rules: {:.2} ms", profile.total_ms);
+        for rule in profile.rules.iter().take(5) {
+            println!("  {:<40} {:>9.2} ms", rule.rule_id, rule.duration_ms);
+        }
+    }
+
+    // Motifs de regex des règles de tests et de secrets, appliqués à chaque requête
+    let patterns: Vec<&str> = SECRET_PATTERNS
+        .iter()
+        .map(|(pattern, _, _)| *pattern)
+        .chain(STATUS_PATTERNS.iter().copied())
+        .chain(RESPONSE_TIME_PATTERNS.iter().copied())
+        .chain(BODY_PATTERNS.iter().copied())
+        .chain(SCHEMA_PATTERNS.iter().copied())
+        .collect();
+    let requests: Vec<String> = collection["item"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .flat_map(|folder| folder["item"].as_array().unwrap().iter().map(|item| item.to_string()))
+        .collect();
+    let count_matches = |regexes: &[Regex], text: &str| regexes.iter().filter(|re| re.is_match(text)).count();
+
+    let per_request = measure(|| {
+        for request in &requests {
+            black_box(count_matches(&utils::build_regexes(&patterns), request));
+        }
+    });
+    let compiled = utils::build_regexes(&patterns);
+    let precompiled = measure(|| {
+        for request in &requests {
+            black_box(count_matches(&compiled, request));
+        }
+    });
+    println!(
+        "{} patterns x {} requests: compiled per request {:.2} ms, precompiled {:.2} ms ({:.0}x faster)",
+        patterns.len(),
+        requests.len(),
+        per_request.as_secs_f64() * 1000.0,
+        precompiled.as_secs_f64() * 1000.0,
+        per_request.as_secs_f64() / precompiled.as_secs_f64().max(f64::EPSILON)
+    );
+}
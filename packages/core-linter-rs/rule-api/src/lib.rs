@@ -0,0 +1,514 @@
+//! API d'écriture de règles pour le linter Postman
+//!
+//! Ce crate expose le strict nécessaire pour implémenter une règle hors du moteur :
+//! le trait [`Rule`], le type [`LintIssue`] et son [`IssueBuilder`], ainsi qu'un
+//! parcours de collection ([`walk`]) fournissant un [`ItemContext`] par item.
+//!
+//! Les règles externes sont compilées dans un build personnalisé de la CLI et
+//! enregistrées dans le registre du moteur (`postman_linter_core::registry`), ou
+//! livrées en plugin chargé dynamiquement (voir [`plugin`]).
+
+pub mod plugin;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Version de l'API de règles, incrémentée à chaque changement incompatible
+pub const API_VERSION: u32 = 3;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct LintIssue {
+    pub rule_id: String,
+    pub severity: String,
+    pub message: String,
+    /// Forme du message dans le catalogue du moteur (ex: `required-folders.missing`),
+    /// pour regrouper ou re-rendre les issues sans analyser le texte
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<String>,
+    /// Valeurs insérées dans le message (nom de requête, seuil...), par paramètre
+    #[serde(default, skip_serializing_if = "serde_json::Map::is_empty")]
+    pub params: serde_json::Map<String, Value>,
+    pub path: String,
+    /// `path` au format JSON Pointer (RFC 6901, ex: `/item/0/item/2/request/url`),
+    /// renseigné par le moteur pour les outils JSON standards
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pointer: Option<String>,
+    /// Ligne (à partir de 1) du nœud dans le JSON d'origine, renseignée par le moteur
+    /// quand la source est fournie (`LintConfig::source_map`)
+    pub line: Option<u32>,
+    /// Colonne (à partir de 1, en caractères) associée à `line`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub column: Option<u32>,
+    pub fix: Option<FixAction>,
+    /// Effort de correction estimé (renseigné par le moteur)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub effort: Option<Effort>,
+    /// Points de score regagnés en corrigeant cette issue (renseigné par le moteur)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score_impact: Option<f64>,
+    /// Issues liées (ex: conséquence en cascade d'une autre issue), pour les regrouper
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related: Vec<IssueRef>,
+}
+
+/// Référence vers une autre issue du même résultat
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IssueRef {
+    pub rule_id: String,
+    pub path: String,
+}
+
+/// Correction proposée par une règle, sérialisée avec son type dans `type`
+/// (ex: `{"type": "rename_request", "suggested_name": "GET List Users"}`)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FixAction {
+    /// Renommer la requête
+    RenameRequest { suggested_name: String },
+    /// Ajouter un test (ou un helper pre-request) au script de test
+    AddTest { test_code: String },
+    /// Ajouter un test de temps de réponse
+    AddResponseTimeTest { suggested_code: String },
+    /// Ajouter une validation de schéma (suggestion manuelle)
+    AddSchemaValidation { suggested_code: String },
+    /// Remplacer la description d'un `pm.test`
+    #[serde(alias = "fix_test_description_uri")]
+    UpdateTestDescription { old_description: String, new_description: String },
+    /// Ajuster le seuil d'une assertion de temps de réponse
+    #[serde(alias = "update_threshold")]
+    AdjustThreshold {
+        #[serde(default)]
+        current_threshold: u64,
+        #[serde(alias = "new_threshold")]
+        suggested_threshold: u64,
+        /// p95 observé dans le rapport Newman, si disponible
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        observed_p95: Option<u64>,
+    },
+    /// Ne conserver que les exemples de réponse aux indices donnés
+    PruneExamples { keep_indices: Vec<usize> },
+    /// Créer ou mettre à jour un header
+    SetHeader { header: String, value: String },
+    /// Désactiver un header
+    DisableHeader { header: String },
+    /// Remplacer une variable mal formée par sa forme normalisée (`{{base_url}}`)
+    NormalizePlaceholder { original: String, normalized: String },
+    /// Remplacer (ou ajouter) la table des endpoints dans la description d'un folder
+    RegenerateEndpointTable { table: String },
+    /// Compléter l'Overview de la collection avec un squelette (sections, métadonnées)
+    GenerateOverview { content: String },
+    /// Remplacer l'URL du schéma de la collection (`info.schema`)
+    UpgradeSchema { schema: String },
+    /// Remplacer une valeur codée en dur par une variable (suggestion manuelle)
+    UseEnvironmentVariable { field: String, suggested_variable: String },
+    /// Remplacer un secret codé en dur par une variable (suggestion manuelle)
+    ReplaceSecret { secret_type: String, suggested_variable: String },
+    /// Fix d'un type non standard (règle externe ou JS), conservé tel quel
+    #[serde(untagged)]
+    Custom(Value),
+}
+
+impl FixAction {
+    /// Type du fix, tel que sérialisé dans `type`
+    pub fn fix_type(&self) -> &str {
+        match self {
+            FixAction::RenameRequest { .. } => "rename_request",
+            FixAction::AddTest { .. } => "add_test",
+            FixAction::AddResponseTimeTest { .. } => "add_response_time_test",
+            FixAction::AddSchemaValidation { .. } => "add_schema_validation",
+            FixAction::UpdateTestDescription { .. } => "update_test_description",
+            FixAction::AdjustThreshold { .. } => "adjust_threshold",
+            FixAction::PruneExamples { .. } => "prune_examples",
+            FixAction::SetHeader { .. } => "set_header",
+            FixAction::DisableHeader { .. } => "disable_header",
+            FixAction::NormalizePlaceholder { .. } => "normalize_placeholder",
+            FixAction::RegenerateEndpointTable { .. } => "regenerate_endpoint_table",
+            FixAction::GenerateOverview { .. } => "generate_overview",
+            FixAction::UpgradeSchema { .. } => "upgrade_schema",
+            FixAction::UseEnvironmentVariable { .. } => "use_environment_variable",
+            FixAction::ReplaceSecret { .. } => "replace_secret",
+            FixAction::Custom(value) => value["type"].as_str().unwrap_or(""),
+        }
+    }
+}
+
+/// Effort de correction d'une issue
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "kebab-case")]
+pub enum Effort {
+    /// Corrigée automatiquement par `--fix`
+    AutoFixable,
+    /// Correction manuelle rapide (renommage, ajout d'une ligne de test...)
+    QuickManual,
+    /// Correction demandant une réflexion ou une coordination (auth, schémas, secrets...)
+    Significant,
+}
+
+impl LintIssue {
+    /// Démarre la construction d'une issue pour la règle donnée
+    pub fn builder(rule_id: &str) -> IssueBuilder {
+        IssueBuilder::new(rule_id)
+    }
+}
+
+/// Construction pas à pas d'une [`LintIssue`] (sévérité "warning" par défaut)
+#[derive(Debug, Clone)]
+pub struct IssueBuilder {
+    issue: LintIssue,
+}
+
+impl IssueBuilder {
+    pub fn new(rule_id: &str) -> Self {
+        IssueBuilder {
+            issue: LintIssue {
+                rule_id: rule_id.to_string(),
+                severity: "warning".to_string(),
+                message: String::new(),
+                path: String::new(),
+                line: None,
+                fix: None,
+                effort: None,
+                score_impact: None,
+                related: Vec::new(),
+                message_id: None,
+                params: Default::default(),
+                pointer: None,
+                column: None,
+            },
+        }
+    }
+
+    /// "error", "warning", "info" ou "hint"
+    pub fn severity(mut self, severity: &str) -> Self {
+        self.issue.severity = severity.to_string();
+        self
+    }
+
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.issue.message = message.into();
+        self
+    }
+
+    /// Identifiant stable du message, propre à la règle (ex: `acme-rule.missing-header`)
+    pub fn message_id(mut self, message_id: &str) -> Self {
+        self.issue.message_id = Some(message_id.to_string());
+        self
+    }
+
+    /// Valeur d'un paramètre du message
+    pub fn param(mut self, name: &str, value: impl Into<Value>) -> Self {
+        self.issue.params.insert(name.to_string(), value.into());
+        self
+    }
+
+    pub fn path(mut self, path: impl Into<String>) -> Self {
+        self.issue.path = path.into();
+        self
+    }
+
+    pub fn line(mut self, line: u32) -> Self {
+        self.issue.line = Some(line);
+        self
+    }
+
+    pub fn column(mut self, column: u32) -> Self {
+        self.issue.column = Some(column);
+        self
+    }
+
+    /// Fix applicable par le moteur (ex: `{"type": "add_test", "test_code": "..."}`)
+    pub fn fix(mut self, fix: FixAction) -> Self {
+        self.issue.fix = Some(fix);
+        self
+    }
+
+    /// Lie l'issue à une autre issue (règle + path)
+    pub fn related(mut self, rule_id: &str, path: &str) -> Self {
+        self.issue.related.push(IssueRef {
+            rule_id: rule_id.to_string(),
+            path: path.to_string(),
+        });
+        self
+    }
+
+    pub fn build(self) -> LintIssue {
+        self.issue
+    }
+}
+
+/// Contexte d'un item pendant le parcours de la collection
+#[derive(Debug)]
+pub struct ItemContext<'a> {
+    /// Item Postman brut (requête ou folder)
+    pub item: &'a Value,
+    /// Chemin de l'item, ex: `/item[0]/item[2]`
+    pub path: String,
+    /// Nom de l'item (`Item-N` s'il n'en a pas)
+    pub name: String,
+    /// Noms des folders parents, du plus haut au plus proche
+    pub folders: &'a [String],
+    /// Scripts de test de l'item, joints par "\n"
+    pub test_script: String,
+    /// Scripts pre-request de l'item, joints par "\n"
+    pub prerequest_script: String,
+    /// Scripts de test hérités (collection puis folders parents)
+    pub inherited_test_scripts: &'a [String],
+}
+
+impl ItemContext<'_> {
+    pub fn is_request(&self) -> bool {
+        self.item.get("request").is_some()
+    }
+
+    pub fn is_folder(&self) -> bool {
+        self.item["item"].is_array()
+    }
+
+    /// Méthode HTTP en majuscules ("GET" par défaut)
+    pub fn method(&self) -> String {
+        self.item["request"]["method"].as_str().unwrap_or("GET").to_uppercase()
+    }
+
+    /// URL brute de la requête (chaîne ou `url.raw`)
+    pub fn url(&self) -> String {
+        let url = &self.item["request"]["url"];
+        url.as_str().or_else(|| url["raw"].as_str()).unwrap_or("").to_string()
+    }
+}
+
+/// Joint les scripts d'un type d'événement ("test" ou "prerequest")
+pub fn event_script(item: &Value, listen: &str) -> String {
+    item["event"]
+        .as_array()
+        .map(|events| {
+            events
+                .iter()
+                .filter(|event| event["listen"] == listen)
+                .filter_map(|event| event["script"]["exec"].as_array())
+                .map(|exec| exec.iter().filter_map(|line| line.as_str()).collect::<Vec<&str>>().join("\n"))
+                .collect::<Vec<String>>()
+                .join("\n")
+        })
+        .unwrap_or_default()
+}
+
+/// Nom d'un item ("Item-N", N à partir de 1, s'il n'est pas nommé)
+pub fn item_name(item: &Value, index: usize) -> String {
+    item["name"].as_str().map(str::to_string).unwrap_or_else(|| format!("Item-{}", index + 1))
+}
+
+/// Visiteur de [`traverse`] : l'état hérité des folders parents est tenu par le
+/// visiteur, empilé par `enter_folder` et dépilé par `leave_folder`
+pub trait Visitor<'a> {
+    /// Appelé pour chaque item ; `index` est sa position dans son parent, `depth` vaut
+    /// 0 au premier niveau
+    fn visit(&mut self, item: &'a Value, path: &str, index: usize, depth: usize);
+
+    /// Après `visit` d'un folder, avant ses enfants
+    fn enter_folder(&mut self, _folder: &'a Value, _path: &str, _index: usize) {}
+
+    /// Après les enfants du folder
+    fn leave_folder(&mut self) {}
+}
+
+/// Parcours en profondeur des items de la collection (un folder avant ses enfants),
+/// commun à [`walk`] et aux parcours du moteur
+pub fn traverse<'a>(collection: &'a Value, visitor: &mut impl Visitor<'a>) {
+    if let Some(items) = collection["item"].as_array() {
+        traverse_level(items, "", 0, visitor);
+    }
+}
+
+fn traverse_level<'a>(items: &'a [Value], parent_path: &str, depth: usize, visitor: &mut impl Visitor<'a>) {
+    for (index, item) in items.iter().enumerate() {
+        let path = format!("{}/item[{}]", parent_path, index);
+        visitor.visit(item, &path, index, depth);
+        if let Some(sub_items) = item["item"].as_array() {
+            visitor.enter_folder(item, &path, index);
+            traverse_level(sub_items, &path, depth + 1, visitor);
+            visitor.leave_folder();
+        }
+    }
+}
+
+/// Parcourt tous les items de la collection (profondeur d'abord, dans l'ordre)
+pub fn walk(collection: &Value, visit: &mut dyn FnMut(&ItemContext)) {
+    let mut walker = ContextWalker { folders: Vec::new(), inherited: Vec::new(), pushed: Vec::new(), visit };
+    let collection_tests = event_script(collection, "test");
+    if !collection_tests.is_empty() {
+        walker.inherited.push(collection_tests);
+    }
+    traverse(collection, &mut walker);
+}
+
+/// Piles des noms et scripts de test des folders parents, partagées par tous les items
+struct ContextWalker<'v> {
+    folders: Vec<String>,
+    inherited: Vec<String>,
+    /// Par folder parent : son script de test (non vide) a-t-il été empilé
+    pushed: Vec<bool>,
+    visit: &'v mut dyn FnMut(&ItemContext),
+}
+
+impl<'a> Visitor<'a> for ContextWalker<'_> {
+    fn visit(&mut self, item: &'a Value, path: &str, index: usize, _depth: usize) {
+        let context = ItemContext {
+            item,
+            path: path.to_string(),
+            name: item_name(item, index),
+            folders: &self.folders,
+            test_script: event_script(item, "test"),
+            prerequest_script: event_script(item, "prerequest"),
+            inherited_test_scripts: &self.inherited,
+        };
+        (self.visit)(&context);
+    }
+
+    // Les scripts du folder s'appliquent à tous ses descendants
+    fn enter_folder(&mut self, folder: &'a Value, _path: &str, index: usize) {
+        self.folders.push(item_name(folder, index));
+        let tests = event_script(folder, "test");
+        self.pushed.push(!tests.is_empty());
+        if !tests.is_empty() {
+            self.inherited.push(tests);
+        }
+    }
+
+    fn leave_folder(&mut self) {
+        self.folders.pop();
+        if self.pushed.pop() == Some(true) {
+            self.inherited.pop();
+        }
+    }
+}
+
+/// Règle de lint
+///
+/// Une règle implémente au minimum `id` et `check_item` ; `check` parcourt la
+/// collection et appelle `check_item` pour chaque item. Les règles ayant besoin
+/// d'une vue globale (doublons, comparaisons entre requêtes) redéfinissent `check`.
+pub trait Rule: Send + Sync {
+    /// Identifiant unique, en kebab-case et préfixé par l'équipe (ex: "acme-no-legacy-host")
+    fn id(&self) -> &str;
+
+    /// Sévérité par défaut des issues produites
+    fn severity(&self) -> &str {
+        "warning"
+    }
+
+    /// Description courte affichée dans la documentation des règles
+    fn description(&self) -> &str {
+        ""
+    }
+
+    /// Vérifie un item ; `options` contient les `rule_options` de la règle (ou null)
+    fn check_item(&self, _item: &ItemContext, _options: &Value) -> Vec<LintIssue> {
+        Vec::new()
+    }
+
+    /// Vérifie la collection entière
+    fn check(&self, collection: &Value, options: &Value) -> Vec<LintIssue> {
+        let mut issues = Vec::new();
+        walk(collection, &mut |item| issues.extend(self.check_item(item, options)));
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    struct NoLegacyHost;
+
+    impl Rule for NoLegacyHost {
+        fn id(&self) -> &str {
+            "acme-no-legacy-host"
+        }
+
+        fn check_item(&self, item: &ItemContext, _options: &Value) -> Vec<LintIssue> {
+            if item.is_request() && item.url().contains("legacy.acme.com") {
+                vec![LintIssue::builder(self.id())
+                    .severity(self.severity())
+                    .message(format!("Request \"{}\" targets the legacy host", item.name))
+                    .path(&item.path)
+                    .build()]
+            } else {
+                Vec::new()
+            }
+        }
+    }
+
+    #[test]
+    fn test_rule_check_walks_items() {
+        let collection = json!({
+            "item": [{
+                "name": "Users",
+                "item": [
+                    { "name": "GET Users", "request": { "method": "GET", "url": "https://legacy.acme.com/users" } },
+                    { "name": "GET Orders", "request": { "method": "GET", "url": "https://api.acme.com/orders" } }
+                ]
+            }]
+        });
+
+        let issues = NoLegacyHost.check(&collection, &Value::Null);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].rule_id, "acme-no-legacy-host");
+        assert_eq!(issues[0].path, "/item[0]/item[0]");
+        assert_eq!(issues[0].severity, "warning");
+    }
+
+    #[test]
+    fn test_walk_context() {
+        let collection = json!({
+            "event": [{ "listen": "test", "script": { "exec": ["pm.test('global', () => {});"] } }],
+            "item": [{
+                "name": "Users",
+                "event": [{ "listen": "test", "script": { "exec": ["pm.response.to.have.status(200);"] } }],
+                "item": [{ "request": { "method": "post", "url": { "raw": "{{base_url}}/users" } } }]
+            }]
+        });
+
+        let mut contexts = Vec::new();
+        walk(&collection, &mut |item| {
+            contexts.push((item.path.clone(), item.name.clone(), item.folders.to_vec(), item.inherited_test_scripts.len()))
+        });
+
+        assert_eq!(contexts.len(), 2);
+        assert_eq!(contexts[0], ("/item[0]".to_string(), "Users".to_string(), vec![], 1));
+        assert_eq!(contexts[1], ("/item[0]/item[0]".to_string(), "Item-1".to_string(), vec!["Users".to_string()], 2));
+    }
+
+    #[test]
+    fn test_issue_builder() {
+        let issue = IssueBuilder::new("acme-rule")
+            .severity("error")
+            .message("Broken")
+            .path("/item[1]")
+            .fix(FixAction::AddTest { test_code: "pm.test('ok', () => {});".to_string() })
+            .related("hardcoded-secrets", "/item[1]/request/header[0]")
+            .build();
+
+        assert_eq!(issue.severity, "error");
+        assert_eq!(issue.path, "/item[1]");
+        assert_eq!(issue.related[0].rule_id, "hardcoded-secrets");
+        assert_eq!(issue.fix.unwrap().fix_type(), "add_test");
+    }
+
+    #[test]
+    fn test_fix_action_serde() {
+        let fix = FixAction::SetHeader { header: "Content-Type".to_string(), value: "application/json".to_string() };
+        let json = serde_json::to_value(&fix).unwrap();
+        assert_eq!(json, json!({ "type": "set_header", "header": "Content-Type", "value": "application/json" }));
+        assert_eq!(serde_json::from_value::<FixAction>(json).unwrap(), fix);
+
+        // Ancien nom de type et de champ
+        let legacy: FixAction = serde_json::from_value(json!({ "type": "update_threshold", "new_threshold": 500 })).unwrap();
+        assert_eq!(legacy, FixAction::AdjustThreshold { current_threshold: 0, suggested_threshold: 500, observed_p95: None });
+
+        // Type inconnu ou payload incomplet : conservé tel quel
+        let custom: FixAction = serde_json::from_value(json!({ "type": "acme_fix", "x": 1 })).unwrap();
+        assert_eq!(custom.fix_type(), "acme_fix");
+        assert_eq!(serde_json::to_value(&custom).unwrap(), json!({ "type": "acme_fix", "x": 1 }));
+    }
+}
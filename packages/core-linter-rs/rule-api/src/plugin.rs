@@ -0,0 +1,233 @@
+//! Plugins : règles chargées dynamiquement, sans recompiler la CLI ni le WASM
+//!
+//! Un crate de règles compilé en `cdylib` devient un plugin en appelant
+//! [`export_plugin!`](crate::export_plugin) avec ses règles. Le même code donne une
+//! bibliothèque native (`.so` / `.dylib` / `.dll`, chargée par `--plugin` sur la CLI)
+//! ou un module `wasm32-unknown-unknown` (chargé par `loadPlugin` dans le navigateur).
+//!
+//! L'interface est en C et n'échange que des chaînes UTF-8 terminées par un zéro,
+//! contenant du JSON ; elle ne dépend donc pas de la version du compilateur :
+//!
+//! | Fonction exportée | Rôle |
+//! |---|---|
+//! | `linterman_plugin_abi_version() -> u32` | [`PLUGIN_ABI_VERSION`] |
+//! | `linterman_plugin_rules() -> *mut c_char` | tableau de [`PluginRuleInfo`] |
+//! | `linterman_plugin_check(rule_id, collection, options) -> *mut c_char` | issues de la règle, ou `{"error": "..."}` |
+//! | `linterman_plugin_alloc(len) -> *mut u8` | tampon pour les arguments (hôte WASM) |
+//! | `linterman_plugin_free(ptr)` | libère une chaîne retournée ou allouée |
+
+use crate::{LintIssue, Rule};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::ffi::{c_char, CStr, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// Version de l'interface des plugins, incrémentée à chaque changement incompatible
+pub const PLUGIN_ABI_VERSION: u32 = 1;
+
+/// Description d'une règle exposée par un plugin
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PluginRuleInfo {
+    pub id: String,
+    pub severity: String,
+    #[serde(default)]
+    pub description: String,
+}
+
+/// Règles du plugin, en JSON
+#[doc(hidden)]
+pub fn rules_json(rules: &[&dyn Rule]) -> String {
+    let infos: Vec<PluginRuleInfo> = rules
+        .iter()
+        .map(|rule| PluginRuleInfo {
+            id: rule.id().to_string(),
+            severity: rule.severity().to_string(),
+            description: rule.description().to_string(),
+        })
+        .collect();
+    serde_json::to_string(&infos).unwrap_or_else(|_| "[]".to_string())
+}
+
+/// Exécute une règle du plugin sur une collection JSON ; une erreur (JSON invalide,
+/// règle inconnue, panic) est retournée sous la forme `{"error": "..."}`
+#[doc(hidden)]
+pub fn check_json(rules: &[&dyn Rule], rule_id: &str, collection: &str, options: &str) -> String {
+    let outcome = catch_unwind(AssertUnwindSafe(|| -> Result<Vec<LintIssue>, String> {
+        let rule = rules
+            .iter()
+            .find(|rule| rule.id() == rule_id)
+            .ok_or_else(|| format!("Unknown rule '{}'", rule_id))?;
+        let collection: Value = serde_json::from_str(collection).map_err(|e| format!("Invalid collection: {}", e))?;
+        let options: Value = serde_json::from_str(options).unwrap_or(Value::Null);
+        Ok(rule.check(&collection, &options))
+    }));
+
+    let error = match outcome {
+        Ok(Ok(issues)) => return serde_json::to_string(&issues).unwrap_or_else(|_| "[]".to_string()),
+        Ok(Err(error)) => error,
+        Err(panic) => panic
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| panic.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "rule panicked".to_string()),
+    };
+    serde_json::json!({ "error": error }).to_string()
+}
+
+/// Transfère une chaîne à l'hôte, qui la rend avec `linterman_plugin_free`
+#[doc(hidden)]
+pub fn into_raw(s: String) -> *mut c_char {
+    CString::new(s.replace('\0', "")).unwrap_or_default().into_raw()
+}
+
+/// Lit une chaîne passée par l'hôte (vide si le pointeur est nul ou l'UTF-8 invalide)
+///
+/// # Safety
+/// `ptr` est nul ou pointe vers une chaîne terminée par un zéro.
+#[doc(hidden)]
+pub unsafe fn from_ptr<'a>(ptr: *const c_char) -> &'a str {
+    if ptr.is_null() {
+        return "";
+    }
+    CStr::from_ptr(ptr).to_str().unwrap_or_default()
+}
+
+/// Tampon de `len` octets dont le dernier est le zéro final : l'hôte y écrit
+/// exactement `len - 1` octets sans zéro, puis le libère avec [`free`]
+#[doc(hidden)]
+pub fn alloc(len: usize) -> *mut u8 {
+    let mut buffer = vec![1u8; len.max(1)];
+    let last = buffer.len() - 1;
+    buffer[last] = 0;
+    // Restitué par `free` via CString::from_raw (la longueur est retrouvée par le zéro final)
+    CString::from_vec_with_nul(buffer).expect("un seul zéro, en fin de tampon").into_raw() as *mut u8
+}
+
+/// # Safety
+/// `ptr` est nul ou provient de [`into_raw`] / [`alloc`], et n'a pas déjà été libéré.
+#[doc(hidden)]
+pub unsafe fn free(ptr: *mut c_char) {
+    if !ptr.is_null() {
+        drop(CString::from_raw(ptr));
+    }
+}
+
+/// Exporte les règles d'un crate `cdylib` comme plugin
+///
+/// ```ignore
+/// linterman_rule_api::export_plugin!(NoLegacyHost, OwnerTagRequired);
+/// ```
+///
+/// Chaque règle est une valeur (struct unitaire ou expression constante).
+#[macro_export]
+macro_rules! export_plugin {
+    ($($rule:expr),+ $(,)?) => {
+        fn __linterman_plugin_rules() -> ::std::vec::Vec<&'static dyn $crate::Rule> {
+            ::std::vec![$(&$rule as &'static dyn $crate::Rule),+]
+        }
+
+        #[no_mangle]
+        pub extern "C" fn linterman_plugin_abi_version() -> u32 {
+            $crate::plugin::PLUGIN_ABI_VERSION
+        }
+
+        #[no_mangle]
+        pub extern "C" fn linterman_plugin_rules() -> *mut ::std::ffi::c_char {
+            $crate::plugin::into_raw($crate::plugin::rules_json(&__linterman_plugin_rules()))
+        }
+
+        /// # Safety
+        /// Arguments : chaînes terminées par un zéro (ou pointeurs nuls).
+        #[no_mangle]
+        pub unsafe extern "C" fn linterman_plugin_check(
+            rule_id: *const ::std::ffi::c_char,
+            collection: *const ::std::ffi::c_char,
+            options: *const ::std::ffi::c_char,
+        ) -> *mut ::std::ffi::c_char {
+            $crate::plugin::into_raw($crate::plugin::check_json(
+                &__linterman_plugin_rules(),
+                $crate::plugin::from_ptr(rule_id),
+                $crate::plugin::from_ptr(collection),
+                $crate::plugin::from_ptr(options),
+            ))
+        }
+
+        #[no_mangle]
+        pub extern "C" fn linterman_plugin_alloc(len: usize) -> *mut u8 {
+            $crate::plugin::alloc(len)
+        }
+
+        /// # Safety
+        /// `ptr` provient de `linterman_plugin_rules`, `linterman_plugin_check` ou
+        /// `linterman_plugin_alloc`.
+        #[no_mangle]
+        pub unsafe extern "C" fn linterman_plugin_free(ptr: *mut ::std::ffi::c_char) {
+            $crate::plugin::free(ptr)
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ItemContext;
+
+    struct OwnerTag;
+
+    impl Rule for OwnerTag {
+        fn id(&self) -> &str {
+            "acme-owner-tag"
+        }
+
+        fn description(&self) -> &str {
+            "Requests carry an [owner:] tag"
+        }
+
+        fn check_item(&self, item: &ItemContext, _options: &Value) -> Vec<LintIssue> {
+            if item.name.contains("panic") {
+                panic!("unexpected item");
+            }
+            if item.is_request() && !item.name.contains("[owner:") {
+                vec![LintIssue::builder(self.id()).message("No owner tag").path(&item.path).build()]
+            } else {
+                Vec::new()
+            }
+        }
+    }
+
+    #[test]
+    fn test_rules_and_check_json() {
+        let rules: [&dyn Rule; 1] = [&OwnerTag];
+        let infos: Vec<PluginRuleInfo> = serde_json::from_str(&rules_json(&rules)).unwrap();
+        assert_eq!(infos[0].id, "acme-owner-tag");
+        assert_eq!(infos[0].severity, "warning");
+
+        let collection = r#"{ "item": [{ "name": "GET Users", "request": { "url": "/users" } }] }"#;
+        let issues: Vec<LintIssue> = serde_json::from_str(&check_json(&rules, "acme-owner-tag", collection, "null")).unwrap();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].path, "/item[0]");
+
+        let unknown: Value = serde_json::from_str(&check_json(&rules, "other", collection, "null")).unwrap();
+        assert_eq!(unknown["error"], "Unknown rule 'other'");
+        let panicked: Value =
+            serde_json::from_str(&check_json(&rules, "acme-owner-tag", r#"{ "item": [{ "name": "panic" }] }"#, "null"))
+                .unwrap();
+        assert_eq!(panicked["error"], "unexpected item");
+    }
+
+    #[test]
+    fn test_raw_strings_round_trip() {
+        let raw = into_raw("[\"ok\"]".to_string());
+        assert_eq!(unsafe { from_ptr(raw) }, "[\"ok\"]");
+        unsafe { free(raw) };
+
+        // Tampon écrit par l'hôte puis relu comme chaîne
+        let buffer = alloc(4);
+        unsafe {
+            std::ptr::copy_nonoverlapping(b"abc".as_ptr(), buffer, 3);
+            assert_eq!(from_ptr(buffer as *const c_char), "abc");
+            free(buffer as *mut c_char);
+        }
+        assert_eq!(unsafe { from_ptr(std::ptr::null()) }, "");
+    }
+}
@@ -0,0 +1,162 @@
+//! Baseline : issues existantes acceptées lors de l'adoption du linter
+//!
+//! Sur une collection ancienne, la baseline enregistre l'empreinte de chaque issue
+//! présente (`compare::fingerprint` : règle + identifiant de message + path,
+//! indépendante de la locale). Les analyses suivantes ne signalent que les issues
+//! absentes de la baseline ; après un nettoyage, la baseline est régénérée pour ne plus couvrir les issues corrigées.
+//! Les empreintes en double sont comptées : une deuxième occurrence d'une issue
+//! déjà connue est nouvelle.
+
+use crate::compare::fingerprint;
+use crate::LintResult;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Version du format de fichier (v2 : empreintes sur l'identifiant de message et le path)
+pub const BASELINE_VERSION: u32 = 2;
+
+/// Issue acceptée ; règle et message sont conservés pour la relecture du fichier
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct BaselineEntry {
+    pub fingerprint: String,
+    pub rule_id: String,
+    pub message: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Baseline {
+    pub version: u32,
+    /// Version du moteur ayant produit la baseline
+    #[serde(default)]
+    pub engine_version: String,
+    pub issues: Vec<BaselineEntry>,
+}
+
+/// Bilan de l'application d'une baseline
+#[derive(Serialize, Debug, Default, PartialEq)]
+pub struct BaselineReport {
+    /// Issues connues, retirées du résultat
+    pub baselined: usize,
+    /// Issues de la baseline disparues (baseline à régénérer)
+    pub fixed: usize,
+}
+
+impl Baseline {
+    /// Baseline couvrant toutes les issues d'un résultat (triée pour des diffs lisibles)
+    pub fn from_result(result: &LintResult) -> Self {
+        let mut issues: Vec<BaselineEntry> = result
+            .issues
+            .iter()
+            .map(|issue| BaselineEntry {
+                fingerprint: fingerprint(issue),
+                rule_id: issue.rule_id.clone(),
+                message: issue.message.clone(),
+            })
+            .collect();
+        issues.sort_by(|a, b| (&a.rule_id, &a.message).cmp(&(&b.rule_id, &b.message)));
+
+        Baseline {
+            version: BASELINE_VERSION,
+            engine_version: result.engine_version.clone(),
+            issues,
+        }
+    }
+
+    /// Retire du résultat les issues couvertes par la baseline ; les compteurs par
+    /// sévérité des stats sont recalculés sur les issues restantes (le quality gate ne
+    /// voit que les nouvelles issues), le score reste calculé sur toutes les issues
+    pub fn apply(&self, result: &mut LintResult) -> BaselineReport {
+        let mut remaining: HashMap<&str, usize> = HashMap::new();
+        for entry in &self.issues {
+            *remaining.entry(entry.fingerprint.as_str()).or_default() += 1;
+        }
+
+        let before = result.issues.len();
+        result.issues.retain(|issue| match remaining.get_mut(fingerprint(issue).as_str()) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                false
+            }
+            _ => true,
+        });
+
+        let count = |severity: &str| result.issues.iter().filter(|issue| issue.severity == severity).count() as u32;
+        result.stats.errors = count("error");
+        result.stats.warnings = count("warning");
+        result.stats.infos = count("info");
+        result.stats.hints = count("hint");
+        result.regroup();
+
+        BaselineReport {
+            baselined: before - result.issues.len(),
+            fixed: remaining.values().sum(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::Locale;
+    use crate::{run_linter, LintConfig};
+    use serde_json::{json, Value};
+
+    fn lint(collection: &Value) -> LintResult {
+        let config = LintConfig {
+            rules: Some(vec!["test-http-status-mandatory".to_string()]),
+            ..Default::default()
+        };
+        run_linter(collection, &config)
+    }
+
+    fn request(name: &str) -> Value {
+        json!({ "name": name, "request": { "method": "GET", "url": "https://api.example.com/users" } })
+    }
+
+    fn tested(name: &str) -> Value {
+        let mut request = request(name);
+        request["event"] = json!([{ "listen": "test", "script": { "exec": ["pm.response.to.have.status(200);"] } }]);
+        request
+    }
+
+    #[test]
+    fn test_only_new_issues_remain() {
+        let baseline = Baseline::from_result(&lint(&json!({ "item": [request("GET Users"), request("GET Orders")] })));
+        assert_eq!(baseline.issues.len(), 2);
+
+        // Une requête corrigée, une nouvelle ajoutée
+        let mut result = lint(&json!({ "item": [tested("GET Users"), request("GET Orders"), request("GET Invoices")] }));
+        let report = baseline.apply(&mut result);
+
+        assert_eq!(report, BaselineReport { baselined: 1, fixed: 1 });
+        assert_eq!(result.issues.len(), 1);
+        assert!(result.issues[0].message.contains("GET Invoices"));
+        assert_eq!(result.stats.errors, 1);
+    }
+
+    #[test]
+    fn test_locale_does_not_change_fingerprints() {
+        let collection = json!({ "item": [request("GET Users"), request("GET Orders")] });
+        let baseline = Baseline::from_result(&lint(&collection));
+
+        let config = LintConfig {
+            rules: Some(vec!["test-http-status-mandatory".to_string()]),
+            locale: Some(Locale::Fr),
+            ..Default::default()
+        };
+        let mut result = run_linter(&collection, &config);
+        assert_ne!(result.issues[0].message, baseline.issues[0].message);
+
+        assert_eq!(baseline.apply(&mut result), BaselineReport { baselined: 2, fixed: 0 });
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_file_round_trip() {
+        let baseline = Baseline::from_result(&lint(&json!({ "item": [request("GET Users")] })));
+        let json = serde_json::to_string(&baseline).unwrap();
+
+        assert_eq!(serde_json::from_str::<Baseline>(&json).unwrap(), baseline);
+        assert_eq!(baseline.issues[0].rule_id, "test-http-status-mandatory");
+    }
+}
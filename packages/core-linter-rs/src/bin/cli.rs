@@ -1,26 +1,154 @@
 use std::env;
 use std::fs;
 use std::io::{self, Read};
-use postman_linter_core::{run_linter, LintConfig};
+use std::path::Path;
+use postman_linter_core::envelope::{self, Envelope};
+use postman_linter_core::i18n::Locale;
+use postman_linter_core::incremental::lint_diff;
+use postman_linter_core::graph::{self, GraphFormat};
+use postman_linter_core::grouping::GroupBy;
+use postman_linter_core::ignore::IgnorePattern;
+use postman_linter_core::maturity::Maturity;
+use postman_linter_core::merge::merge_collections;
+use postman_linter_core::normalize::{normalize, NormalizeOptions};
+use postman_linter_core::ordering::SortBy;
+use postman_linter_core::plugins;
+use postman_linter_core::project_config::{self, ProjectConfig};
+use postman_linter_core::rules::security::internal_hosts_disclosure::InternalHostsDisclosureConfig;
+use postman_linter_core::sanitize::sanitize;
+use postman_linter_core::scaffold::scaffold_tests;
+use postman_linter_core::snippets::SnippetLibrary;
+use postman_linter_core::starter::new_collection;
+use postman_linter_core::visibility::Visibility;
+use postman_linter_core::registry::RuleRegistry;
+use postman_linter_core::rules::{list_rules, Category};
+use postman_linter_core::baseline::{Baseline, BASELINE_VERSION};
+use postman_linter_core::compare::compare_results;
+use postman_linter_core::suppression::Suppression;
+use postman_linter_core::usage_stats::UsageStats;
+use postman_linter_core::summary::{summarize, FailOn, QualityGate};
+use postman_linter_core::shard::{lint_shard, merge_results, Shard};
+use postman_linter_core::source_map;
+use postman_linter_core::workspace::{lint_export, summarize_workspace, WorkspaceExport};
+use postman_linter_core::{run_linter_with_registry, LintConfig, LintResult, LintStats};
 use serde::Deserialize;
 use std::collections::HashMap;
 
 /// Structure pour parser le fichier de config exporté depuis l'IHM
 #[derive(Deserialize)]
 struct ExportedConfig {
+    #[allow(dead_code)] // Champ présent dans l'export, non exploité par le CLI
     version: String,
     #[serde(rename = "enabledRules")]
     enabled_rules: Vec<String>,
+    /// Règles exclues (prioritaires sur enabledRules)
+    #[serde(rename = "disabledRules", default)]
+    disabled_rules: Option<Vec<String>>,
     #[serde(rename = "customTemplates")]
     custom_templates: Option<HashMap<String, String>>,
+    /// Snippets de tests maison (status_test, response_time_test, schema_test)
+    snippets: Option<HashMap<String, String>>,
+    /// Issues masquées volontairement (règle + préfixe de path + raison)
+    #[serde(default)]
+    suppressions: Option<Vec<Suppression>>,
+    /// Items exclus par motif de path ou de noms
+    #[serde(default)]
+    ignore: Option<Vec<IgnorePattern>>,
+    /// Niveau de maturité visé
+    #[serde(rename = "maturityTarget", default)]
+    maturity_target: Option<Maturity>,
+    /// Réglages de la commande normalize
+    #[serde(default)]
+    normalize: Option<NormalizeOptions>,
+    /// Seuils du quality gate
+    #[serde(rename = "qualityGate", default)]
+    quality_gate: Option<QualityGate>,
+    /// Options propres à chaque règle, indexées par rule_id
+    #[serde(rename = "ruleOptions", default)]
+    rule_options: Option<HashMap<String, serde_json::Value>>,
+    /// Pénalité maximale par catégorie
+    #[serde(rename = "categoryCaps", default)]
+    category_caps: Option<HashMap<Category, f64>>,
+    /// Ordre des issues
+    #[serde(rename = "sortBy", default)]
+    sort_by: Option<SortBy>,
+    /// Fusion des issues en double entre règles (active par défaut)
+    #[serde(default)]
+    deduplicate: Option<bool>,
+    /// Regroupement des issues dans le résultat
+    #[serde(rename = "groupBy", default)]
+    group_by: Option<GroupBy>,
+    /// Profilage des règles
+    #[serde(default)]
+    profile: Option<bool>,
+    /// Positions des issues dans le JSON d'origine
+    #[serde(rename = "sourceMap", default)]
+    source_map: Option<bool>,
 }
 
 fn print_usage() {
-    eprintln!("Usage: postman-linter [OPTIONS] [COLLECTION_FILE]");
+    eprintln!("Usage: postman-linter [OPTIONS] [COLLECTION_FILE...]");
+    eprintln!("       postman-linter scaffold [OPTIONS] [COLLECTION_FILE]");
+    eprintln!("       postman-linter sanitize [OPTIONS] [COLLECTION_FILE]");
+    eprintln!("       postman-linter normalize [OPTIONS] [COLLECTION_FILE]");
+    eprintln!("       postman-linter baseline [OPTIONS] [COLLECTION_FILE]");
+    eprintln!("       postman-linter new --name <NAME> [OPTIONS]");
+    eprintln!("       postman-linter merge [OPTIONS] OURS_FILE THEIRS_FILE");
+    eprintln!("       postman-linter merge-results SHARD_RESULT...");
+    eprintln!();
+    eprintln!("Commands:");
+    eprintln!("  scaffold           Inject missing standard tests into every request and print the collection");
+    eprintln!("  sanitize           Print a shareable copy with secrets, PII and internal hosts redacted");
+    eprintln!("  normalize          Print the collection with headers, query params and variables sorted and");
+    eprintln!("                     volatile fields stripped, for readable git diffs");
+    eprintln!("  baseline           Print a baseline recording every current issue (regenerate after cleanup)");
+    eprintln!("  new                Print a lint-clean starter collection (overview, example folder, standard tests)");
+    eprintln!("  merge              Merge two versions of a collection by item id/name, print the merged");
+    eprintln!("                     collection and lint it; exits with 1 on merge conflicts");
+    eprintln!("  merge-results      Combine the JSON results of every --shard job into one result");
+    eprintln!();
+    eprintln!("Several collection files are linted as a workspace: one result with per-collection");
+    eprintln!("results, cross-collection issues and an overall score weighted by request count.");
+    eprintln!("Workspace exports / backups are split automatically; each collection is checked");
+    eprintln!("against its matching environment.");
     eprintln!();
     eprintln!("Options:");
-    eprintln!("  --config <FILE>    Load rules configuration from JSON file");
+    eprintln!("  --config <FILE>    Load rules configuration (exported JSON, or .toml/.yaml/.json project file)");
+    eprintln!("                     Without --config, .linterman.toml/.yaml/.json is searched upward");
+    eprintln!("                     from the working directory");
     eprintln!("  --rules <RULES>    Comma-separated list of rule IDs to enable");
+    eprintln!("  --disable <RULES>  Comma-separated list of rule IDs to skip (all other rules run)");
+    eprintln!("  --locale <LANG>    Language of issue messages, suggested code comments and number/duration");
+    eprintln!("                     formats (fr, en); messages are in English without it");
+    eprintln!("  --visibility <V>   Collection visibility profile (internal, public)");
+    eprintln!("  --newman <FILE>    Newman JSON report used to suggest realistic response time thresholds");
+    eprintln!("  --diff <FILE>      Compare with a previous JSON result (new/resolved/persisting issues)");
+    eprintln!("  --since <FILE>     Report only issues introduced since this previous collection version;");
+    eprintln!("                     exits with 1 if any");
+    eprintln!("  --baseline <FILE>  Report only issues missing from the baseline; exits with 1 if any");
+    eprintln!("  --plugin <FILE>    Load the rules of a native plugin library (.so, .dylib, .dll); repeatable");
+    eprintln!("  --script-rule <FILE>  Load a rule written as a Rhai script (.rhai, id = file name); repeatable,");
+    eprintln!("                     requires a CLI built with --features rhai-rules");
+    eprintln!("  --name <NAME>      Name of the collection generated by `new`");
+    eprintln!("  --show-suppressed  Include issues hidden by suppressions in the output");
+    eprintln!("  --profile          Record per-rule execution time and issue counts in the result");
+    eprintln!("  --source-map       Add the line and column of each issue in the collection file");
+    eprintln!("  --stats-file <FILE>  Add this run's per-rule issue counts to a local usage statistics file");
+    eprintln!("                     (created if missing; rule ids and counts only, nothing is sent)");
+    eprintln!("  --max-issues-per-rule <N>  Keep at most N issues per rule in the output (stats keep true counts)");
+    eprintln!("  --group-by <KEY>   Add issues grouped by rule or path (item) to the result");
+    eprintln!("  --sort-by <ORDER>  Issue order: path-first (default), severity-first, rule-first");
+    eprintln!("  --shard <I/N>      Lint only shard I of N (top-level items split deterministically)");
+    eprintln!("  --format <FORMAT>  Output format: json (full result, default), json-v1 (reports without the");
+    eprintln!("                     versioned envelope), summary, or mermaid/dot (folder and request tree");
+    eprintln!("                     of the collection, for documentation sites)");
+    eprintln!("  --graph-variables  With mermaid/dot: add the variable flow (request setting → requests reading)");
+    eprintln!("  --min-score <N>    Quality gate: minimum score required");
+    eprintln!("  --max-warnings <N> Quality gate: maximum number of warnings");
+    eprintln!("  --fail-on <SEV>    Quality gate: fail on any issue of this severity or above (error, warning, info)");
+    eprintln!("                     With a quality gate (options or qualityGate in the config), the CLI exits");
+    eprintln!("                     with 1 when it fails");
+    eprintln!("  --list-rules       Print the built-in rules (id, category, severity, description, options) as JSON");
     eprintln!("  --help             Show this help message");
     eprintln!();
     eprintln!("Examples:");
@@ -28,14 +156,170 @@ fn print_usage() {
     eprintln!("  postman-linter collection.json");
     eprintln!("  postman-linter --config linterman-rules-config.json collection.json");
     eprintln!("  postman-linter --rules test-http-status-mandatory,hardcoded-secrets collection.json");
+    eprintln!("  postman-linter --newman newman-report.json collection.json");
+    eprintln!("  postman-linter --diff previous-result.json collection.json");
+    eprintln!("  postman-linter --since main/collection.json collection.json");
+    eprintln!("  postman-linter baseline collection.json > linterman-baseline.json");
+    eprintln!("  postman-linter --baseline linterman-baseline.json collection.json");
+    eprintln!("  postman-linter --plugin ./libacme_rules.so collection.json");
+    eprintln!("  postman-linter --format summary --min-score 80 collection.json");
+    eprintln!("  postman-linter --fail-on error --max-warnings 10 --min-score 80 collection.json");
+    eprintln!("  postman-linter --shard 2/5 collection.json > shard-2.json");
+    eprintln!("  postman-linter merge-results shard-*.json");
+    eprintln!("  postman-linter --format summary collections/*.json");
+    eprintln!("  postman-linter --format mermaid --graph-variables collection.json > docs/collection.mmd");
+    eprintln!("  postman-linter workspace-backup.json");
+    eprintln!("  postman-linter scaffold --locale en collection.json > scaffolded.json");
+    eprintln!("  postman-linter sanitize collection.json > shareable.json");
+    eprintln!("  postman-linter normalize collection.json > collection.normalized.json");
+    eprintln!("  postman-linter new --name \"Orders API\" > orders-api.postman_collection.json");
+    eprintln!("  postman-linter merge main/collection.json feature/collection.json > merged.json");
+}
+
+/// Règles externes compilées dans ce build de la CLI, puis règles des plugins et
+/// des scripts Rhai
+///
+/// Build personnalisé : déclarer le crate de règles en dépendance optionnelle
+/// derrière une feature (voir Cargo.toml), puis l'enregistrer ci-dessous.
+fn custom_rules(plugin_files: &[String], script_files: &[String]) -> RuleRegistry {
+    let mut registry = RuleRegistry::new();
+    // #[cfg(feature = "acme-rules")]
+    // registry.register(acme_linter_rules::NoLegacyHost).expect("duplicate rule id");
+    for path in plugin_files {
+        let ids = plugins::load(Path::new(path), &mut registry).unwrap_or_else(|e| {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        });
+        eprintln!("🧩 Loaded plugin {}: {}", path, ids.join(", "));
+    }
+    #[cfg(feature = "rhai-rules")]
+    for path in script_files {
+        let id = postman_linter_core::script_rules::load(Path::new(path), &mut registry).unwrap_or_else(|e| {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        });
+        eprintln!("📜 Loaded script rule {}: {}", path, id);
+    }
+    #[cfg(not(feature = "rhai-rules"))]
+    if !script_files.is_empty() {
+        eprintln!("Error: --script-rule requires a CLI built with `--features rhai-rules`");
+        std::process::exit(1);
+    }
+    registry
+}
+
+/// Lit et parse une collection (fichier, ou stdin si `path` est absent)
+fn read_collection(path: Option<&str>) -> serde_json::Value {
+    parse_collection(&read_source(path), path)
+}
+
+/// Texte d'une collection (fichier, ou stdin si `path` est absent)
+fn read_source(path: Option<&str>) -> String {
+    if let Some(file_path) = path {
+        fs::read_to_string(file_path)
+            .unwrap_or_else(|e| {
+                eprintln!("Error reading collection file '{}': {}", file_path, e);
+                std::process::exit(1);
+            })
+    } else {
+        let mut buffer = String::new();
+        io::stdin().read_to_string(&mut buffer)
+            .expect("Failed to read from stdin");
+        buffer
+    }
+}
+
+fn parse_collection(collection_json: &str, path: Option<&str>) -> serde_json::Value {
+    serde_json::from_str(collection_json)
+        .unwrap_or_else(|e| {
+            eprintln!("Error parsing collection JSON{}: {}", path.map(|p| format!(" '{}'", p)).unwrap_or_default(), e);
+            std::process::exit(1);
+        })
+}
+
+/// Imprime un rapport JSON dans l'enveloppe versionnée, ou nu avec `--format json-v1`
+fn print_report<T: serde::Serialize>(report: &T, legacy_json: bool) {
+    let json = if legacy_json {
+        serde_json::to_string_pretty(report)
+    } else {
+        serde_json::to_string_pretty(&Envelope::new(report))
+    };
+    println!("{}", json.unwrap());
+}
+
+/// Sous-commande merge-results : recombine les sorties JSON des shards
+fn merge_command(args: &[String]) {
+    let legacy_json = args.windows(2).any(|pair| pair[0] == "--format" && pair[1] == "json-v1");
+    let files: Vec<&String> = args
+        .iter()
+        .enumerate()
+        .filter(|(i, arg)| *arg != "--format" && (*i == 0 || args[i - 1] != "--format"))
+        .map(|(_, arg)| arg)
+        .collect();
+    if files.is_empty() {
+        eprintln!("Error: merge-results requires the JSON result of every shard");
+        std::process::exit(1);
+    }
+    
+    let results: Vec<LintResult> = files
+        .iter()
+        .map(|path| {
+            let json = fs::read_to_string(path).unwrap_or_else(|e| {
+                eprintln!("Error reading shard result '{}': {}", path, e);
+                std::process::exit(1);
+            });
+            envelope::parse(&json).unwrap_or_else(|e| {
+                eprintln!("Error parsing shard result '{}': {}", path, e);
+                std::process::exit(1);
+            })
+        })
+        .collect();
+    
+    let merged = merge_results(&results).unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    });
+    eprintln!("✅ Merged {} shards: score {}, {} issues", results.len(), merged.score, merged.issues.len());
+    print_report(&merged, legacy_json);
 }
 
 fn main() {
     let args: Vec<String> = env::args().collect();
     
+    if args.get(1).map(String::as_str) == Some("merge-results") {
+        merge_command(&args[2..]);
+        return;
+    }
+    
     let mut config_file: Option<String> = None;
     let mut rules_arg: Option<String> = None;
-    let mut collection_file: Option<String> = None;
+    let mut disable_arg: Option<String> = None;
+    let mut collection_files: Vec<String> = Vec::new();
+    let mut command: Option<String> = None;
+    let mut locale: Option<Locale> = None;
+    let mut newman_file: Option<String> = None;
+    let mut visibility: Option<Visibility> = None;
+    let mut diff_file: Option<String> = None;
+    let mut baseline_file: Option<String> = None;
+    let mut stats_file: Option<String> = None;
+    let mut since_file: Option<String> = None;
+    let mut collection_name: Option<String> = None;
+    let mut plugin_files: Vec<String> = Vec::new();
+    let mut script_files: Vec<String> = Vec::new();
+    let mut show_suppressed = false;
+    let mut profile_rules = false;
+    let mut source_map = false;
+    let mut summary_format = false;
+    let mut legacy_json = false;
+    let mut graph_format: Option<GraphFormat> = None;
+    let mut graph_variables = false;
+    let mut min_score: Option<u32> = None;
+    let mut max_warnings: Option<u32> = None;
+    let mut fail_on: Option<FailOn> = None;
+    let mut max_issues_per_rule: Option<usize> = None;
+    let mut sort_by: Option<SortBy> = None;
+    let mut group_by: Option<GroupBy> = None;
+    let mut shard: Option<Shard> = None;
     
     // Parse arguments
     let mut i = 1;
@@ -45,6 +329,10 @@ fn main() {
                 print_usage();
                 return;
             }
+            "--list-rules" => {
+                println!("{}", serde_json::to_string_pretty(&list_rules()).unwrap());
+                return;
+            }
             "--config" | "-c" => {
                 if i + 1 < args.len() {
                     config_file = Some(args[i + 1].clone());
@@ -63,8 +351,239 @@ fn main() {
                     std::process::exit(1);
                 }
             }
+            "--disable" => {
+                if i + 1 < args.len() {
+                    disable_arg = Some(args[i + 1].clone());
+                    i += 2;
+                } else {
+                    eprintln!("Error: --disable requires a comma-separated list");
+                    std::process::exit(1);
+                }
+            }
+            "--locale" | "-l" => {
+                if i + 1 < args.len() {
+                    locale = Some(Locale::parse(&args[i + 1]).unwrap_or_else(|| {
+                        eprintln!("Error: unsupported locale '{}' (expected fr or en)", args[i + 1]);
+                        std::process::exit(1);
+                    }));
+                    i += 2;
+                } else {
+                    eprintln!("Error: --locale requires a language code");
+                    std::process::exit(1);
+                }
+            }
+            "--visibility" => {
+                if i + 1 < args.len() {
+                    visibility = Some(Visibility::parse(&args[i + 1]).unwrap_or_else(|| {
+                        eprintln!("Error: unsupported visibility '{}' (expected internal or public)", args[i + 1]);
+                        std::process::exit(1);
+                    }));
+                    i += 2;
+                } else {
+                    eprintln!("Error: --visibility requires a value");
+                    std::process::exit(1);
+                }
+            }
+            "--newman" => {
+                if i + 1 < args.len() {
+                    newman_file = Some(args[i + 1].clone());
+                    i += 2;
+                } else {
+                    eprintln!("Error: --newman requires a file path");
+                    std::process::exit(1);
+                }
+            }
+            "--format" => {
+                if i + 1 < args.len() {
+                    match args[i + 1].as_str() {
+                        "json" => summary_format = false,
+                        "json-v1" => {
+                            summary_format = false;
+                            legacy_json = true;
+                        }
+                        "summary" => summary_format = true,
+                        other => {
+                            graph_format = Some(GraphFormat::parse(other).unwrap_or_else(|| {
+                                eprintln!("Error: unsupported format '{}' (expected json, json-v1, summary, mermaid or dot)", other);
+                                std::process::exit(1);
+                            }));
+                        }
+                    }
+                    i += 2;
+                } else {
+                    eprintln!("Error: --format requires a value");
+                    std::process::exit(1);
+                }
+            }
+            "--min-score" => {
+                if i + 1 < args.len() {
+                    min_score = Some(args[i + 1].parse().unwrap_or_else(|_| {
+                        eprintln!("Error: --min-score expects a number between 0 and 100, got '{}'", args[i + 1]);
+                        std::process::exit(1);
+                    }));
+                    i += 2;
+                } else {
+                    eprintln!("Error: --min-score requires a value");
+                    std::process::exit(1);
+                }
+            }
+            "--max-warnings" => {
+                if i + 1 < args.len() {
+                    max_warnings = Some(args[i + 1].parse().unwrap_or_else(|_| {
+                        eprintln!("Error: --max-warnings expects a number, got '{}'", args[i + 1]);
+                        std::process::exit(1);
+                    }));
+                    i += 2;
+                } else {
+                    eprintln!("Error: --max-warnings requires a value");
+                    std::process::exit(1);
+                }
+            }
+            "--fail-on" => {
+                if i + 1 < args.len() {
+                    fail_on = Some(FailOn::parse(&args[i + 1]).unwrap_or_else(|| {
+                        eprintln!("Error: unsupported severity '{}' (expected error, warning or info)", args[i + 1]);
+                        std::process::exit(1);
+                    }));
+                    i += 2;
+                } else {
+                    eprintln!("Error: --fail-on requires a severity");
+                    std::process::exit(1);
+                }
+            }
+            "--max-issues-per-rule" => {
+                if i + 1 < args.len() {
+                    max_issues_per_rule = Some(args[i + 1].parse().unwrap_or_else(|_| {
+                        eprintln!("Error: --max-issues-per-rule expects a number, got '{}'", args[i + 1]);
+                        std::process::exit(1);
+                    }));
+                    i += 2;
+                } else {
+                    eprintln!("Error: --max-issues-per-rule requires a value");
+                    std::process::exit(1);
+                }
+            }
+            "--group-by" => {
+                if i + 1 < args.len() {
+                    group_by = Some(GroupBy::parse(&args[i + 1]).unwrap_or_else(|| {
+                        eprintln!("Error: unsupported grouping '{}' (expected rule or path)", args[i + 1]);
+                        std::process::exit(1);
+                    }));
+                    i += 2;
+                } else {
+                    eprintln!("Error: --group-by requires a value");
+                    std::process::exit(1);
+                }
+            }
+            "--sort-by" => {
+                if i + 1 < args.len() {
+                    sort_by = Some(SortBy::parse(&args[i + 1]).unwrap_or_else(|| {
+                        eprintln!("Error: unsupported order '{}' (expected path-first, severity-first or rule-first)", args[i + 1]);
+                        std::process::exit(1);
+                    }));
+                    i += 2;
+                } else {
+                    eprintln!("Error: --sort-by requires a value");
+                    std::process::exit(1);
+                }
+            }
+            "--shard" => {
+                if i + 1 < args.len() {
+                    shard = Some(Shard::parse(&args[i + 1]).unwrap_or_else(|e| {
+                        eprintln!("Error: {}", e);
+                        std::process::exit(1);
+                    }));
+                    i += 2;
+                } else {
+                    eprintln!("Error: --shard requires a value (e.g. 2/5)");
+                    std::process::exit(1);
+                }
+            }
+            "--graph-variables" => {
+                graph_variables = true;
+                i += 1;
+            }
+            "--show-suppressed" => {
+                show_suppressed = true;
+                i += 1;
+            }
+            "--profile" => {
+                profile_rules = true;
+                i += 1;
+            }
+            "--source-map" => {
+                source_map = true;
+                i += 1;
+            }
+            "--diff" => {
+                if i + 1 < args.len() {
+                    diff_file = Some(args[i + 1].clone());
+                    i += 2;
+                } else {
+                    eprintln!("Error: --diff requires a file path");
+                    std::process::exit(1);
+                }
+            }
+            "--baseline" => {
+                if i + 1 < args.len() {
+                    baseline_file = Some(args[i + 1].clone());
+                    i += 2;
+                } else {
+                    eprintln!("Error: --baseline requires a file path");
+                    std::process::exit(1);
+                }
+            }
+            "--stats-file" => {
+                if i + 1 < args.len() {
+                    stats_file = Some(args[i + 1].clone());
+                    i += 2;
+                } else {
+                    eprintln!("Error: --stats-file requires a file path");
+                    std::process::exit(1);
+                }
+            }
+            "--since" => {
+                if i + 1 < args.len() {
+                    since_file = Some(args[i + 1].clone());
+                    i += 2;
+                } else {
+                    eprintln!("Error: --since requires a file path");
+                    std::process::exit(1);
+                }
+            }
+            "--plugin" => {
+                if i + 1 < args.len() {
+                    plugin_files.push(args[i + 1].clone());
+                    i += 2;
+                } else {
+                    eprintln!("Error: --plugin requires a file path");
+                    std::process::exit(1);
+                }
+            }
+            "--script-rule" => {
+                if i + 1 < args.len() {
+                    script_files.push(args[i + 1].clone());
+                    i += 2;
+                } else {
+                    eprintln!("Error: --script-rule requires a file path");
+                    std::process::exit(1);
+                }
+            }
+            "--name" => {
+                if i + 1 < args.len() {
+                    collection_name = Some(args[i + 1].clone());
+                    i += 2;
+                } else {
+                    eprintln!("Error: --name requires a value");
+                    std::process::exit(1);
+                }
+            }
+            "scaffold" | "sanitize" | "normalize" | "baseline" | "new" | "merge" if command.is_none() && collection_files.is_empty() => {
+                command = Some(args[i].clone());
+                i += 1;
+            }
             arg if !arg.starts_with('-') => {
-                collection_file = Some(arg.to_string());
+                collection_files.push(arg.to_string());
                 i += 1;
             }
             _ => {
@@ -75,29 +594,71 @@ fn main() {
         }
     }
     
-    // Lire la collection (depuis fichier ou stdin)
-    let collection_json = if let Some(file_path) = collection_file {
-        fs::read_to_string(&file_path)
-            .unwrap_or_else(|e| {
-                eprintln!("Error reading collection file '{}': {}", file_path, e);
-                std::process::exit(1);
-            })
+    // Lire les collections (depuis les fichiers ou stdin) ; les exports de workspace
+    // sont découpés en collections et environnements
+    let mut export = WorkspaceExport::default();
+    // Texte de chaque fichier, pour positionner les issues (`--source-map`)
+    let mut sources: HashMap<String, String> = HashMap::new();
+    let mut contains_export = false;
+    let new_command = command.as_deref() == Some("new");
+    if new_command && !collection_files.is_empty() {
+        eprintln!("Error: new does not read a collection");
+        std::process::exit(1);
+    }
+    if new_command {
+        // Pas de collection à lire : elle est générée
+    } else if collection_files.is_empty() {
+        let source = read_source(None);
+        contains_export |= export.add("stdin", parse_collection(&source, None));
+        sources.insert("stdin".to_string(), source);
     } else {
-        let mut buffer = String::new();
-        io::stdin().read_to_string(&mut buffer)
-            .expect("Failed to read from stdin");
-        buffer
-    };
+        for path in &collection_files {
+            let source = read_source(Some(path));
+            contains_export |= export.add(path, parse_collection(&source, Some(path)));
+            sources.insert(path.clone(), source);
+        }
+    }
+    for source in &export.unsupported {
+        eprintln!("⚠️  {}: collection format v1 not supported, skipped (re-export it as Collection v2.1)", source);
+    }
     
-    // Parser la collection
-    let collection: serde_json::Value = serde_json::from_str(&collection_json)
-        .unwrap_or_else(|e| {
-            eprintln!("Error parsing collection JSON: {}", e);
-            std::process::exit(1);
-        });
+    // merge : exactement deux versions d'une même collection
+    let merge_mode = command.as_deref() == Some("merge");
+    if merge_mode && (export.collections.len() != 2 || contains_export) {
+        eprintln!("Error: merge requires two collection files (ours, then theirs)");
+        std::process::exit(1);
+    }
+    
+    // Plusieurs collections ou export : mode workspace, incompatible avec les modes mono-collection
+    let workspace_mode = (collection_files.len() > 1 && !merge_mode) || contains_export;
+    if workspace_mode && (command.is_some() || shard.is_some() || diff_file.is_some() || baseline_file.is_some() || since_file.is_some() || graph_format.is_some()) {
+        eprintln!("Error: scaffold, sanitize, normalize, baseline, --shard, --diff, --baseline, --since and --format mermaid/dot accept a single collection file");
+        std::process::exit(1);
+    }
     
     // Construire la configuration
     let mut rules: Option<Vec<String>> = None;
+    let mut disabled_rules: Option<Vec<String>> = None;
+    let mut snippets: Option<HashMap<String, String>> = None;
+    let mut suppressions: Option<Vec<Suppression>> = None;
+    let mut ignore: Option<Vec<IgnorePattern>> = None;
+    let mut maturity_target: Option<Maturity> = None;
+    let mut normalize_options: Option<NormalizeOptions> = None;
+    let mut quality_gate: Option<QualityGate> = None;
+    let mut rule_options: Option<HashMap<String, serde_json::Value>> = None;
+    let mut category_caps: Option<HashMap<Category, f64>> = None;
+    let mut exported_sort_by: Option<SortBy> = None;
+    let mut deduplicate: Option<bool> = None;
+    let mut exported_group_by: Option<GroupBy> = None;
+    let mut exported_profile: Option<bool> = None;
+    let mut exported_source_map: Option<bool> = None;
+    let mut project: Option<ProjectConfig> = None;
+    
+    // Sans --config : fichier du projet dans le répertoire courant ou ses parents
+    let config_file = config_file.or_else(|| {
+        let found = project_config::discover(&env::current_dir().ok()?)?;
+        Some(found.to_string_lossy().into_owned())
+    });
     
     // Charger depuis le fichier de config si spécifié
     if let Some(config_path) = config_file {
@@ -107,38 +668,441 @@ fn main() {
                 std::process::exit(1);
             });
         
-        let exported_config: ExportedConfig = serde_json::from_str(&config_json)
-            .unwrap_or_else(|e| {
-                eprintln!("Error parsing config file: {}", e);
-                std::process::exit(1);
+        // Configuration exportée depuis l'IHM (JSON avec `enabledRules`) ou fichier du projet
+        let exported_config = serde_json::from_str::<serde_json::Value>(&config_json)
+            .ok()
+            .filter(|value| value.get("enabledRules").is_some())
+            .map(|value| {
+                serde_json::from_value::<ExportedConfig>(value).unwrap_or_else(|e| {
+                    eprintln!("Error parsing config file: {}", e);
+                    std::process::exit(1);
+                })
             });
         
-        rules = Some(exported_config.enabled_rules);
-        
-        // Note: custom_templates is ignored in the open-source CLI
-        // Template customization is a SaaS-only feature
-        if exported_config.custom_templates.is_some() {
-            eprintln!("ℹ️  Note: custom_templates ignored (SaaS-only feature)");
+        if let Some(exported_config) = exported_config {
+            rules = Some(exported_config.enabled_rules);
+            disabled_rules = exported_config.disabled_rules;
+            snippets = exported_config.snippets;
+            suppressions = exported_config.suppressions;
+            ignore = exported_config.ignore;
+            maturity_target = exported_config.maturity_target;
+            normalize_options = exported_config.normalize;
+            quality_gate = exported_config.quality_gate;
+            rule_options = exported_config.rule_options;
+            category_caps = exported_config.category_caps;
+            exported_sort_by = exported_config.sort_by;
+            deduplicate = exported_config.deduplicate;
+            exported_group_by = exported_config.group_by;
+            exported_profile = exported_config.profile;
+            exported_source_map = exported_config.source_map;
+            
+            // Note: custom_templates is ignored in the open-source CLI
+            // Template customization is a SaaS-only feature
+            if exported_config.custom_templates.is_some() {
+                eprintln!("ℹ️  Note: custom_templates ignored (SaaS-only feature)");
+            }
+            
+            eprintln!("✅ Loaded config: {} rules enabled", rules.as_ref().map(|r| r.len()).unwrap_or(0));
+        } else {
+            let format = project_config::ConfigFormat::from_path(Path::new(&config_path))
+                .unwrap_or(project_config::ConfigFormat::Json);
+            let loaded = project_config::parse(&config_json, format).unwrap_or_else(|e| {
+                eprintln!("Error parsing config file '{}': {}", config_path, e);
+                std::process::exit(1);
+            });
+            
+            eprintln!(
+                "✅ Loaded config {}: {} rules enabled",
+                config_path,
+                loaded.rules.as_ref().map(|r| r.len().to_string()).unwrap_or_else(|| "all".to_string())
+            );
+            project = Some(loaded);
         }
-        
-        eprintln!("✅ Loaded config: {} rules enabled", rules.as_ref().map(|r| r.len()).unwrap_or(0));
     }
     
-    // Override avec --rules si spécifié
-    if let Some(rules_str) = rules_arg {
-        rules = Some(rules_str.split(',').map(|s| s.trim().to_string()).collect());
+    // Overrides du quality gate (qui détermine alors le code de sortie)
+    if let Some(min_score) = min_score {
+        quality_gate.get_or_insert_with(QualityGate::unset).min_score = Some(min_score);
     }
+    if let Some(max_warnings) = max_warnings {
+        quality_gate.get_or_insert_with(QualityGate::unset).max_warnings = Some(max_warnings);
+    }
+    if let Some(fail_on) = fail_on {
+        quality_gate.get_or_insert_with(QualityGate::unset).fail_on = Some(fail_on);
+    }
+    
+    // Charger le rapport Newman si spécifié
+    let newman_report = newman_file.map(|report_path| {
+        let report_json = fs::read_to_string(&report_path)
+            .unwrap_or_else(|e| {
+                eprintln!("Error reading Newman report '{}': {}", report_path, e);
+                std::process::exit(1);
+            });
+        serde_json::from_str::<serde_json::Value>(&report_json)
+            .unwrap_or_else(|e| {
+                eprintln!("Error parsing Newman report: {}", e);
+                std::process::exit(1);
+            })
+    });
     
-    let config = LintConfig {
+    let mut config = LintConfig {
         local_only: true,
         rules,
+        disabled_rules,
         fix: None,
         custom_templates: None, // SaaS-only feature
+        rule_options,
+        locale,
+        snippets,
+        newman_report,
+        visibility,
+        limits: None,
+        suppressions,
+        strict_fix: None,
+        quality_gate,
+        max_issues_per_rule,
+        sort_by: exported_sort_by,
+        deduplicate,
+        group_by: exported_group_by,
+        profile: exported_profile,
+        source_map: exported_source_map,
+        severity_overrides: None,
+        category_caps,
+        ignore,
+        maturity_target,
+        normalize: normalize_options,
     };
+    if let Some(project) = &project {
+        project.apply(&mut config);
+    }
+    
+    // Override avec --group-by si spécifié
+    if let Some(group_by) = group_by {
+        config.group_by = Some(group_by);
+    }
+    
+    // Override avec --profile si spécifié
+    if profile_rules {
+        config.profile = Some(true);
+    }
     
-    // Exécuter le linter
-    let result = run_linter(&collection, &config);
+    // Override avec --source-map si spécifié ; sans positions, inutile de garder les textes
+    if source_map {
+        config.source_map = Some(true);
+    }
+    if !config.source_map.unwrap_or(false) {
+        sources.clear();
+    }
     
-    // Afficher le résultat en JSON
-    println!("{}", serde_json::to_string_pretty(&result).unwrap());
+    // Override avec --sort-by si spécifié
+    if let Some(sort_by) = sort_by {
+        config.sort_by = Some(sort_by);
+    }
+    
+    // Override avec --rules si spécifié
+    if let Some(rules_str) = rules_arg {
+        config.rules = Some(rules_str.split(',').map(|s| s.trim().to_string()).collect());
+    }
+    if let Some(disable_str) = disable_arg {
+        config.disabled_rules = Some(disable_str.split(',').map(|s| s.trim().to_string()).collect());
+    }
+    
+    let registry = custom_rules(&plugin_files, &script_files);
+    
+    // Mode workspace : un seul résultat pour toutes les collections
+    if workspace_mode {
+        let mut workspace = lint_export(&export, &config, &registry);
+        // Collections lues chacune dans son fichier (pas les entrées d'un export)
+        for collection in &mut workspace.collections {
+            if let Some(source) = sources.get(&collection.source) {
+                source_map::annotate(&mut collection.result, source);
+            }
+        }
+        // Même configuration pour toutes les collections : avertissements affichés une fois
+        for warning in workspace.collections.first().map(|c| c.result.config_warnings.as_slice()).unwrap_or_default() {
+            eprintln!("⚠️  {}", warning);
+        }
+        if let Some(stats_path) = &stats_file {
+            record_usage(stats_path, workspace.collections.iter().map(|collection| &collection.result));
+        }
+        if !show_suppressed {
+            for collection in &mut workspace.collections {
+                collection.result.suppressed.clear();
+            }
+        }
+        eprintln!(
+            "✅ Workspace: {} collections, score {}, {} cross-collection issues",
+            workspace.collections.len(),
+            workspace.score,
+            workspace.issues.len()
+        );
+        if summary_format {
+            let summary = summarize_workspace(&workspace, &config.quality_gate.clone().unwrap_or_else(QualityGate::unset));
+            print_report(&summary, legacy_json);
+        } else {
+            print_report(&workspace, legacy_json);
+        }
+        if quality_gate_failed(config.quality_gate.as_ref(), workspace.score, &workspace.stats) {
+            std::process::exit(1);
+        }
+        return;
+    }
+    
+    // Mode new : collection de départ sans issue
+    if new_command {
+        let Some(name) = collection_name else {
+            eprintln!("Error: new requires --name");
+            std::process::exit(1);
+        };
+        let collection = new_collection(&name, &config);
+        let result = run_linter_with_registry(&collection, &config, &registry);
+        eprintln!("✅ New collection \"{}\": score {}, {} issues", name, result.score, result.issues.len());
+        println!("{}", serde_json::to_string_pretty(&collection).unwrap());
+        return;
+    }
+    
+    // Mode merge : fusion des deux versions, conflits puis analyse du résultat
+    if merge_mode {
+        let outcome = merge_collections(&export.collections[0].1, &export.collections[1].1);
+        for conflict in &outcome.conflicts {
+            eprintln!("{} ({})", conflict.message, conflict.path);
+        }
+        let result = run_linter_with_registry(&outcome.collection, &config, &registry);
+        eprintln!(
+            "✅ Merge: {} conflicts, merged collection score {}, {} issues",
+            outcome.conflicts.len(),
+            result.score,
+            result.issues.len()
+        );
+        println!("{}", serde_json::to_string_pretty(&outcome.collection).unwrap());
+        if !outcome.conflicts.is_empty() {
+            std::process::exit(1);
+        }
+        return;
+    }
+    let (collection_source, mut collection) = export.collections.remove(0);
+    
+    // Mode scaffold : injecter les tests manquants et afficher la collection
+    if command.as_deref() == Some("scaffold") {
+        let library = SnippetLibrary::new(config.locale.unwrap_or_default(), config.snippets.as_ref());
+        let report = scaffold_tests(&mut collection, &library);
+        eprintln!(
+            "✅ Scaffold: {} tests added to {} requests",
+            report.tests_added, report.requests_updated
+        );
+        println!("{}", serde_json::to_string_pretty(&collection).unwrap());
+        return;
+    }
+    
+    // Mode sanitize : produire une copie partageable et le rapport des remplacements
+    if command.as_deref() == Some("sanitize") {
+        let options: InternalHostsDisclosureConfig = config.options_for("internal-hosts-disclosure");
+        let report = sanitize(&mut collection, &options.internal_suffixes);
+        for redaction in &report.redactions {
+            eprintln!("🔏 {} → {} ({})", redaction.kind, redaction.replacement, redaction.path);
+        }
+        eprintln!("✅ Sanitize: {} values redacted", report.redactions.len());
+        println!("{}", serde_json::to_string_pretty(&collection).unwrap());
+        return;
+    }
+    
+    // Mode normalize : tris et champs volatils retirés, pour des diffs git lisibles
+    if command.as_deref() == Some("normalize") {
+        let report = normalize(&mut collection, &config.normalize.clone().unwrap_or_default());
+        eprintln!(
+            "✅ Normalize: {} arrays sorted, {} volatile fields stripped",
+            report.arrays_sorted, report.fields_stripped
+        );
+        println!("{}", serde_json::to_string_pretty(&collection).unwrap());
+        return;
+    }
+    
+    // Graphe de la collection (Mermaid / DOT) : pas d'analyse
+    if let Some(format) = graph_format {
+        print!("{}", graph::render(&collection, format, graph_variables));
+        return;
+    }
+    
+    // Mode incrémental : issues introduites depuis la version précédente
+    if let Some(since_path) = since_file {
+        let previous = read_collection(Some(&since_path));
+        let diff = lint_diff(&previous, &collection, &config, &registry);
+        eprintln!(
+            "🔀 {} changed items, {} new issues (score {} → {})",
+            diff.changed_items.len(),
+            diff.issues.len(),
+            diff.score_before,
+            diff.score_after
+        );
+        print_report(&diff, legacy_json);
+        if !diff.issues.is_empty() {
+            std::process::exit(1);
+        }
+        return;
+    }
+    
+    // Exécuter le linter ; la troncature par règle attend le filtrage par la baseline
+    let max_issues_per_rule = config.max_issues_per_rule.take();
+    let mut result = match shard {
+        Some(shard) => lint_shard(&collection, &config, &registry, shard),
+        None => run_linter_with_registry(&collection, &config, &registry),
+    };
+    if let Some(source) = sources.get(&collection_source) {
+        source_map::annotate(&mut result, source);
+    }
+    
+    for warning in &result.config_warnings {
+        eprintln!("⚠️  {}", warning);
+    }
+    
+    // Règles les plus lentes (détail complet dans `profile`)
+    if let Some(profile) = &result.profile {
+        eprintln!("⏱️  Rules ran in {:.1} ms; slowest:", profile.total_ms);
+        for rule in profile.rules.iter().take(5) {
+            eprintln!("   {:>8.1} ms  {} ({} issues)", rule.duration_ms, rule.rule_id, rule.issues);
+        }
+    }
+    
+    // Statistiques d'usage locales, avant le filtrage par la baseline
+    if let Some(stats_path) = &stats_file {
+        record_usage(stats_path, [&result]);
+    }
+    
+    // Mode baseline : enregistrer toutes les issues actuelles
+    if command.as_deref() == Some("baseline") {
+        let baseline = Baseline::from_result(&result);
+        eprintln!("✅ Baseline: {} issues recorded", baseline.issues.len());
+        println!("{}", serde_json::to_string_pretty(&baseline).unwrap());
+        return;
+    }
+    
+    // Ne conserver que les issues absentes de la baseline
+    let mut new_issues_found = false;
+    if let Some(baseline_path) = &baseline_file {
+        let baseline_json = fs::read_to_string(baseline_path).unwrap_or_else(|e| {
+            eprintln!("Error reading baseline '{}': {}", baseline_path, e);
+            std::process::exit(1);
+        });
+        let baseline: Baseline = serde_json::from_str(&baseline_json).unwrap_or_else(|e| {
+            eprintln!("Error parsing baseline '{}': {}", baseline_path, e);
+            std::process::exit(1);
+        });
+        if baseline.version != BASELINE_VERSION {
+            eprintln!(
+                "⚠️  Baseline '{}' uses format v{} (current: v{}): regenerate it with `postman-linter baseline`",
+                baseline_path, baseline.version, BASELINE_VERSION
+            );
+        }
+        let report = baseline.apply(&mut result);
+        eprintln!(
+            "📋 Baseline: {} new issues, {} known issues hidden",
+            result.issues.len(),
+            report.baselined
+        );
+        if report.fixed > 0 {
+            eprintln!("ℹ️  {} baseline issues are fixed: regenerate it with `postman-linter baseline`", report.fixed);
+        }
+        new_issues_found = !result.issues.is_empty();
+    }
+    
+    // Issues omises par --max-issues-per-rule
+    if let Some(max) = max_issues_per_rule {
+        result.truncate_per_rule(max);
+    }
+    let omitted: usize = result.truncated.values().sum();
+    if omitted > 0 {
+        eprintln!("ℹ️  {} issues omitted from the output (--max-issues-per-rule)", omitted);
+    }
+    
+    // Issues masquées : détaillées seulement sur demande
+    if !result.suppressed.is_empty() && !show_suppressed {
+        eprintln!(
+            "ℹ️  {} issues suppressed (use --show-suppressed to list them)",
+            result.suppressed.len()
+        );
+        result.suppressed.clear();
+    }
+    
+    // Comparer avec un résultat précédent
+    if let Some(diff_path) = diff_file {
+        let previous_json = fs::read_to_string(&diff_path).unwrap_or_else(|e| {
+            eprintln!("Error reading previous result '{}': {}", diff_path, e);
+            std::process::exit(1);
+        });
+        let previous: LintResult = envelope::parse(&previous_json).unwrap_or_else(|e| {
+            eprintln!("Error parsing previous result: {}", e);
+            std::process::exit(1);
+        });
+        
+        let comparison = compare_results(&previous, &result);
+        eprintln!(
+            "📈 Score: {} → {} ({:+}) | {} new, {} resolved, {} persisting",
+            comparison.score_before,
+            comparison.score_after,
+            comparison.score_delta,
+            comparison.new_issues.len(),
+            comparison.resolved_issues.len(),
+            comparison.persisting_issues.len()
+        );
+        if comparison.ruleset_changed {
+            eprintln!(
+                "⚠️  Rules changed since the previous result (engine {} → {}): some differences come from the rules, not the collection",
+                if previous.engine_version.is_empty() { "unknown" } else { &previous.engine_version },
+                result.engine_version
+            );
+        }
+        print_report(&comparison, legacy_json);
+    } else if summary_format {
+        // Résumé seul (score, stats, issues par règle, verdict du quality gate)
+        let summary = summarize(&result, &config.quality_gate.clone().unwrap_or_else(QualityGate::unset));
+        print_report(&summary, legacy_json);
+    } else {
+        // Afficher le résultat en JSON
+        print_report(&result, legacy_json);
+    }
+    
+    // Avec --baseline, seules les nouvelles issues font échouer la CI
+    let gate_failed = quality_gate_failed(config.quality_gate.as_ref(), result.score, &result.stats);
+    if new_issues_found || gate_failed {
+        std::process::exit(1);
+    }
+}
+
+/// Ajoute des analyses au fichier de statistiques d'usage (`--stats-file`) ; un fichier
+/// illisible n'est jamais écrasé et ne fait pas échouer l'analyse
+fn record_usage<'a>(path: &str, results: impl IntoIterator<Item = &'a LintResult>) {
+    let mut stats = match fs::read_to_string(path) {
+        Ok(content) => match serde_json::from_str::<UsageStats>(&content) {
+            Ok(stats) => stats,
+            Err(e) => {
+                eprintln!("⚠️  Usage stats file '{}' not updated: {}", path, e);
+                return;
+            }
+        },
+        Err(e) if e.kind() == io::ErrorKind::NotFound => UsageStats::default(),
+        Err(e) => {
+            eprintln!("⚠️  Usage stats file '{}' not updated: {}", path, e);
+            return;
+        }
+    };
+    for result in results {
+        stats.record(result);
+    }
+    match fs::write(path, serde_json::to_string_pretty(&stats).unwrap()) {
+        Ok(()) => eprintln!("📊 Usage stats: {} runs recorded in {}", stats.runs, path),
+        Err(e) => eprintln!("⚠️  Usage stats file '{}' not updated: {}", path, e),
+    }
+}
+
+/// Évalue le quality gate configuré (config ou options) ; sans gate, l'analyse ne
+/// fait jamais échouer la CI
+fn quality_gate_failed(gate: Option<&QualityGate>, score: u32, stats: &LintStats) -> bool {
+    let Some(gate) = gate else {
+        return false;
+    };
+    let verdict = gate.evaluate(score, stats);
+    if !verdict.passed {
+        eprintln!("❌ Quality gate failed: {}", verdict.failures.join(", "));
+    }
+    !verdict.passed
 }
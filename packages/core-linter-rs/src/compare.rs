@@ -0,0 +1,176 @@
+//! Comparaison de deux résultats d'analyse
+//!
+//! Classe les issues en nouvelles / résolues / persistantes en les appariant par
+//! empreinte, et calcule l'évolution du score et des stats. Utilisé par
+//! `--diff` dans la CLI et par les pages de tendance du SaaS.
+
+use crate::{utils, LintIssue, LintResult, LintStats};
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Empreinte stable d'une issue : règle + identifiant de message + path. Le texte
+/// rendu n'en fait pas partie : changer de locale ou faire varier un compteur
+/// (paramètres du message) ne crée pas de fausses nouvelles issues. Les issues sans
+/// `message_id` (règles externes) retombent sur leur message.
+pub fn fingerprint(issue: &LintIssue) -> String {
+    let message = issue.message_id.as_deref().unwrap_or(&issue.message);
+    utils::stable_hash(
+        issue.rule_id.bytes().chain([0]).chain(message.bytes()).chain([0]).chain(issue.path.bytes()),
+    )
+}
+
+#[derive(Serialize, Debug, Default, PartialEq)]
+pub struct StatsDelta {
+    pub total_requests: i64,
+    pub total_tests: i64,
+    pub total_folders: i64,
+    pub errors: i64,
+    pub warnings: i64,
+    pub infos: i64,
+    pub hints: i64,
+    pub test_coverage_percent: f64,
+}
+
+#[derive(Serialize, Debug)]
+pub struct ResultComparison {
+    pub score_before: u32,
+    pub score_after: u32,
+    pub score_delta: i64,
+    pub stats_delta: StatsDelta,
+    /// Issues apparues depuis `before`
+    pub new_issues: Vec<LintIssue>,
+    /// Issues de `before` disparues dans `after`
+    pub resolved_issues: Vec<LintIssue>,
+    /// Issues présentes dans les deux résultats (version `after`)
+    pub persisting_issues: Vec<LintIssue>,
+    /// Moteur ou jeu de règles différent entre les deux résultats : une partie des
+    /// issues nouvelles ou résolues vient des règles, pas de la collection
+    pub ruleset_changed: bool,
+}
+
+/// Compare deux résultats ; les empreintes en double sont appariées une à une
+pub fn compare_results(before: &LintResult, after: &LintResult) -> ResultComparison {
+    let mut remaining: HashMap<String, Vec<&LintIssue>> = HashMap::new();
+    for issue in &before.issues {
+        remaining.entry(fingerprint(issue)).or_default().push(issue);
+    }
+
+    let mut new_issues = Vec::new();
+    let mut persisting_issues = Vec::new();
+    for issue in &after.issues {
+        match remaining.get_mut(&fingerprint(issue)).and_then(|matches| matches.pop()) {
+            Some(_) => persisting_issues.push(issue.clone()),
+            None => new_issues.push(issue.clone()),
+        }
+    }
+
+    // Conserver l'ordre d'origine des issues résolues
+    let mut resolved_counts: HashMap<String, usize> =
+        remaining.iter().map(|(fp, matches)| (fp.clone(), matches.len())).collect();
+    let resolved_issues = before
+        .issues
+        .iter()
+        .filter(|issue| match resolved_counts.get_mut(&fingerprint(issue)) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                true
+            }
+            _ => false,
+        })
+        .cloned()
+        .collect();
+
+    ResultComparison {
+        score_before: before.score,
+        score_after: after.score,
+        score_delta: after.score as i64 - before.score as i64,
+        stats_delta: stats_delta(&before.stats, &after.stats),
+        new_issues,
+        resolved_issues,
+        persisting_issues,
+        ruleset_changed: before.engine_version != after.engine_version || before.ruleset_hash != after.ruleset_hash,
+    }
+}
+
+fn stats_delta(before: &LintStats, after: &LintStats) -> StatsDelta {
+    let delta = |b: u32, a: u32| a as i64 - b as i64;
+    StatsDelta {
+        total_requests: delta(before.total_requests, after.total_requests),
+        total_tests: delta(before.total_tests, after.total_tests),
+        total_folders: delta(before.total_folders, after.total_folders),
+        errors: delta(before.errors, after.errors),
+        warnings: delta(before.warnings, after.warnings),
+        infos: delta(before.infos, after.infos),
+        hints: delta(before.hints, after.hints),
+        test_coverage_percent: ((after.test_coverage_percent - before.test_coverage_percent) * 10.0).round() / 10.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{run_linter, LintConfig};
+    use serde_json::json;
+
+    fn config() -> LintConfig {
+        LintConfig {
+            rules: Some(vec!["test-http-status-mandatory".to_string(), "test-response-time-mandatory".to_string()]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_new_resolved_and_persisting() {
+        let before = run_linter(
+            &json!({
+                "info": { "name": "Test" },
+                "item": [{ "name": "GET Users", "request": { "method": "GET", "url": "https://api.example.com/users" } }]
+            }),
+            &config(),
+        );
+        let after = run_linter(
+            &json!({
+                "info": { "name": "Test" },
+                "item": [
+                    {
+                        "name": "GET Users",
+                        "request": { "method": "GET", "url": "https://api.example.com/users" },
+                        "event": [{ "listen": "test", "script": { "exec": ["pm.response.to.have.status(200);"] } }]
+                    },
+                    { "name": "GET Orders", "request": { "method": "GET", "url": "https://api.example.com/orders" } }
+                ]
+            }),
+            &config(),
+        );
+
+        let comparison = compare_results(&before, &after);
+
+        // GET Users : statut résolu, temps de réponse persistant
+        assert_eq!(comparison.resolved_issues.len(), 1);
+        assert_eq!(comparison.resolved_issues[0].rule_id, "test-http-status-mandatory");
+        assert_eq!(comparison.persisting_issues.len(), 1);
+        assert_eq!(comparison.persisting_issues[0].path, "/item[0]");
+        assert_eq!(comparison.new_issues.len(), 2);
+        assert_eq!(comparison.stats_delta.total_requests, 1);
+        assert_eq!(comparison.score_delta, after.score as i64 - before.score as i64);
+        assert!(!comparison.ruleset_changed);
+
+        let reconfigured = run_linter(&json!({ "info": { "name": "Test" }, "item": [] }), &LintConfig::default());
+        assert!(compare_results(&before, &reconfigured).ruleset_changed);
+    }
+
+    #[test]
+    fn test_fingerprint_is_stable() {
+        let issue = LintIssue::builder("hardcoded-secrets").message("Secret found").path("/item[0]").build();
+        let moved = LintIssue::builder("hardcoded-secrets").message("Secret found").path("/item[3]").build();
+        assert_ne!(fingerprint(&issue), fingerprint(&moved));
+        assert_eq!(fingerprint(&issue).len(), 16);
+        assert_ne!(fingerprint(&issue), fingerprint(&LintIssue::builder("other").message("Secret found").path("/item[0]").build()));
+
+        // Avec un identifiant de message, le texte rendu et les paramètres n'entrent pas en compte
+        let counted = |count: u64, message: &str| {
+            LintIssue::builder("test-coverage-minimum").message_id("test-coverage-minimum").param("count", count).message(message).build()
+        };
+        assert_eq!(fingerprint(&counted(3, "3 requests")), fingerprint(&counted(5, "5 requêtes")));
+    }
+}
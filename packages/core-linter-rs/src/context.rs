@@ -0,0 +1,240 @@
+//! Contexte d'exécution partagé par les règles
+//!
+//! Regroupe ce qui est calculé une seule fois par analyse (modèle typé, index des scripts
+//! et URLs, bibliothèque de snippets, timings Newman, formatage des nombres) pour éviter que
+//! chaque règle ne refasse les mêmes extractions pendant son parcours de la collection.
+
+use crate::model::{Collection, Item, TypedItem};
+use crate::newman::ResponseTimings;
+use crate::snippets::SnippetLibrary;
+use crate::utils;
+use regex::Regex;
+use serde_json::Value;
+use std::collections::{BTreeSet, HashMap};
+use std::sync::LazyLock;
+
+/// URL d'une requête, extraite une seule fois
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct RequestUrl {
+    /// URL brute (`utils::get_request_url`)
+    pub raw: String,
+    /// Hôte sans schéma ni port (`utils::get_url_host`)
+    pub host: String,
+    /// Path sans hôte, query string ni fragment (`utils::get_url_path`)
+    pub path: String,
+}
+
+/// Scripts d'un item (requête, folder, ou collection pour le path "")
+#[derive(Debug, Default, Clone)]
+pub struct ItemScripts {
+    /// Scripts de test de l'item, joints par "\n"
+    pub test: String,
+    /// Scripts pre-request de l'item, joints par "\n"
+    pub prerequest: String,
+    /// Variables écrites : (scope, nom), ex: ("environment", "token")
+    pub set_variables: Vec<(String, String)>,
+    /// APIs pm appelées, ex: "pm.response.json", "pm.environment.set"
+    pub pm_apis: BTreeSet<String>,
+    /// URL de la requête (requêtes uniquement)
+    pub url: Option<RequestUrl>,
+}
+
+/// Variable écrite par un script : (scope, nom)
+static SET_VARIABLE: LazyLock<Regex> = LazyLock::new(|| {
+    utils::build_regex(r#"pm\.(environment|collectionVariables|globals|variables)\.set\(\s*['"]([^'"]+)['"]"#).unwrap()
+});
+
+/// Appel d'une API pm, ex: `pm.response.json(`
+static PM_API: LazyLock<Regex> = LazyLock::new(|| utils::build_regex(r"\b(pm(?:\.[A-Za-z_$][\w$]*)+)\s*\(").unwrap());
+
+/// Index des scripts de tous les items, par path (`/item[0]/item[1]`)
+#[derive(Debug, Default, Clone)]
+pub struct ScriptIndex {
+    items: HashMap<String, ItemScripts>,
+    empty: ItemScripts,
+}
+
+impl ScriptIndex {
+    /// Parcourt la collection une seule fois et indexe les scripts de chaque item
+    pub fn build(collection: &Value) -> Self {
+        let mut index = ScriptIndex::default();
+        index.insert(String::new(), collection);
+        if let Some(items) = collection["item"].as_array() {
+            index.index_items(items, "");
+        }
+        index
+    }
+
+    /// Scripts de l'item au path donné (vides si l'item est inconnu)
+    pub fn get(&self, path: &str) -> &ItemScripts {
+        self.items.get(path).unwrap_or(&self.empty)
+    }
+
+    /// Parcours des items (voir `utils::walk_items`), les scripts des folders parents
+    /// étant lus dans l'index au lieu d'être extraits à nouveau
+    pub fn walk(&self, collection: &Value, visit: impl FnMut(&utils::ItemContext)) {
+        utils::walk_items_with(
+            collection,
+            |_, path| {
+                let scripts = self.get(path);
+                (scripts.test.clone(), scripts.prerequest.clone())
+            },
+            visit,
+        );
+    }
+
+    fn index_items(&mut self, items: &[Value], parent_path: &str) {
+        for (index, item) in items.iter().enumerate() {
+            let current_path = if parent_path.is_empty() {
+                format!("/item[{}]", index)
+            } else {
+                format!("{}/item[{}]", parent_path, index)
+            };
+
+            self.insert(current_path.clone(), item);
+
+            // Si c'est un folder, récurser
+            if let Some(sub_items) = item["item"].as_array() {
+                self.index_items(sub_items, &current_path);
+            }
+        }
+    }
+
+    fn insert(&mut self, path: String, item: &Value) {
+        let test = utils::extract_test_scripts(item).join("\n");
+        let prerequest = utils::extract_prerequest_scripts(item).join("\n");
+
+        let mut set_variables = Vec::new();
+        let mut pm_apis = BTreeSet::new();
+        for script in [&prerequest, &test] {
+            set_variables.extend(
+                SET_VARIABLE
+                    .captures_iter(script)
+                    .map(|caps| (caps[1].to_string(), caps[2].to_string())),
+            );
+            pm_apis.extend(PM_API.captures_iter(script).map(|caps| caps[1].to_string()));
+        }
+
+        self.items.insert(
+            path,
+            ItemScripts {
+                test,
+                prerequest,
+                set_variables,
+                pm_apis,
+                url: utils::is_request(item).then(|| RequestUrl {
+                    raw: utils::get_request_url(item),
+                    host: utils::get_url_host(item),
+                    path: utils::get_url_path(item),
+                }),
+            },
+        );
+    }
+}
+
+/// Contexte transmis aux règles
+#[derive(Debug, Default)]
+pub struct LintContext {
+    /// Collection désérialisée une seule fois dans le modèle typé
+    pub model: Collection,
+    pub scripts: ScriptIndex,
+    pub snippets: SnippetLibrary,
+    pub timings: ResponseTimings,
+}
+
+impl LintContext {
+    pub fn new(collection: &Value) -> Self {
+        Self::with_scripts(ScriptIndex::build(collection)).with_model(Collection::from_value(collection))
+    }
+
+    /// Contexte réutilisant un index de scripts déjà construit
+    pub fn with_scripts(scripts: ScriptIndex) -> Self {
+        LintContext {
+            scripts,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_model(mut self, model: Collection) -> Self {
+        self.model = model;
+        self
+    }
+
+    pub fn with_snippets(mut self, snippets: SnippetLibrary) -> Self {
+        self.snippets = snippets;
+        self
+    }
+
+    pub fn with_timings(mut self, timings: ResponseTimings) -> Self {
+        self.timings = timings;
+        self
+    }
+
+    /// Parcours partagé des items (voir `ScriptIndex::walk`), chaque item étant
+    /// accompagné de son équivalent dans le modèle typé et de ses folders parents
+    pub fn walk(&self, collection: &Value, mut visit: impl FnMut(&utils::ItemContext, &TypedItem)) {
+        // Item que le modèle n'a pas pu lire (tableau d'items mal formé) : vu vide
+        let empty = Item::default();
+        let mut folders: Vec<&Item> = Vec::new();
+        self.scripts.walk(collection, |item| {
+            folders.truncate(item.depth);
+            let siblings = folders.last().map_or(self.model.item.as_slice(), |folder| folder.children());
+            let typed = siblings.get(item.index).unwrap_or(&empty);
+            visit(item, &TypedItem { item: typed, folders: &folders });
+            if item.has_children() {
+                folders.push(typed);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_script_index() {
+        let collection = json!({
+            "event": [{ "listen": "test", "script": { "exec": ["pm.test('global', () => {});"] } }],
+            "item": [{
+                "name": "Users",
+                "item": [{
+                    "name": "POST Login",
+                    "request": { "method": "POST", "url": "{{base_url}}/login" },
+                    "event": [
+                        { "listen": "prerequest", "script": { "exec": ["pm.variables.set('start', Date.now());"] } },
+                        { "listen": "test", "script": { "exec": ["const body = pm.response.json();", "pm.environment.set('token', body.token);"] } }
+                    ]
+                }]
+            }]
+        });
+
+        let index = ScriptIndex::build(&collection);
+
+        let login = index.get("/item[0]/item[0]");
+        assert!(login.test.contains("pm.response.json()"));
+        assert!(login.prerequest.contains("Date.now()"));
+        assert_eq!(
+            login.set_variables,
+            vec![
+                ("variables".to_string(), "start".to_string()),
+                ("environment".to_string(), "token".to_string())
+            ]
+        );
+        assert!(login.pm_apis.contains("pm.response.json"));
+        assert!(login.pm_apis.contains("pm.environment.set"));
+
+        assert_eq!(login.url.as_ref().map(|url| url.path.as_str()), Some("/login"));
+        assert_eq!(login.url.as_ref().map(|url| url.host.as_str()), Some("{{base_url}}"));
+        assert!(index.get("/item[0]").url.is_none());
+
+        assert!(index.get("").test.contains("global"));
+        assert!(index.get("/item[9]").test.is_empty());
+
+        // Parcours : scripts des folders parents lus dans l'index
+        let mut parents = Vec::new();
+        index.walk(&collection, |item| parents.push(item.parent_test_scripts.len()));
+        assert_eq!(parents, vec![0, 1]);
+    }
+}
@@ -0,0 +1,164 @@
+//! Matrice de couverture des tests
+//!
+//! Indique, pour chaque requête, quels types de tests obligatoires sont présents
+//! (statut, temps de réponse, contenu du body, schéma), en tenant compte des tests
+//! hérités des folders parents et de la collection, ainsi que les taux agrégés.
+
+use crate::rules::testing::{
+    test_body_content_validation, test_http_status_mandatory, test_response_time_mandatory,
+    test_schema_validation_recommended,
+};
+use crate::utils;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::LazyLock;
+
+/// Types de tests présents pour une requête
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RequestCoverage {
+    pub path: String,
+    pub name: String,
+    pub status: bool,
+    pub response_time: bool,
+    pub body: bool,
+    pub schema: bool,
+}
+
+/// Matrice de couverture : détail par requête + pourcentages agrégés
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct CoverageMatrix {
+    pub requests: Vec<RequestCoverage>,
+    pub status_percent: f64,
+    pub response_time_percent: f64,
+    pub body_percent: f64,
+    pub schema_percent: f64,
+}
+
+struct Detectors {
+    status: Regex,
+    response_time: Regex,
+    body: Regex,
+    schema: Regex,
+}
+
+static DETECTORS: LazyLock<Detectors> = LazyLock::new(|| Detectors {
+    status: utils::build_regex(&test_http_status_mandatory::STATUS_PATTERNS.join("|")).unwrap(),
+    response_time: utils::build_regex(&test_response_time_mandatory::RESPONSE_TIME_PATTERNS.join("|")).unwrap(),
+    body: utils::build_regex(&test_body_content_validation::BODY_PATTERNS.join("|")).unwrap(),
+    schema: utils::build_regex(&test_schema_validation_recommended::SCHEMA_PATTERNS.join("|")).unwrap(),
+});
+
+/// Calcule la matrice de couverture de la collection
+pub fn compute(collection: &Value) -> CoverageMatrix {
+    let mut requests = Vec::new();
+    let collection_scripts = utils::extract_test_scripts(collection);
+    if let Some(items) = collection["item"].as_array() {
+        collect_items(items, &mut requests, "", &collection_scripts);
+    }
+
+    from_requests(requests)
+}
+
+/// Calcule les taux agrégés à partir du détail par requête
+pub fn from_requests(requests: Vec<RequestCoverage>) -> CoverageMatrix {
+    let percent = |covered: fn(&RequestCoverage) -> bool| {
+        if requests.is_empty() {
+            return 0.0;
+        }
+        let count = requests.iter().filter(|r| covered(r)).count();
+        // Arrondi à 0.1% pour la sortie JSON
+        (count as f64 / requests.len() as f64 * 1000.0).round() / 10.0
+    };
+
+    CoverageMatrix {
+        status_percent: percent(|r| r.status),
+        response_time_percent: percent(|r| r.response_time),
+        body_percent: percent(|r| r.body),
+        schema_percent: percent(|r| r.schema),
+        requests,
+    }
+}
+
+fn collect_items(items: &[Value], requests: &mut Vec<RequestCoverage>, parent_path: &str, parent_scripts: &[String]) {
+    for (index, item) in items.iter().enumerate() {
+        let default_name = format!("Item-{}", index + 1);
+        let item_name = item["name"].as_str().unwrap_or(&default_name);
+        let current_path = if parent_path.is_empty() {
+            format!("/item[{}]", index)
+        } else {
+            format!("{}/item[{}]", parent_path, index)
+        };
+
+        // Scripts hérités + scripts propres à l'item
+        let mut scripts = parent_scripts.to_vec();
+        scripts.extend(utils::extract_test_scripts(item));
+
+        if item.get("request").is_some() {
+            let covered = |regex: &Regex| scripts.iter().any(|script| regex.is_match(script));
+
+            requests.push(RequestCoverage {
+                path: current_path.clone(),
+                name: item_name.to_string(),
+                status: covered(&DETECTORS.status),
+                response_time: covered(&DETECTORS.response_time),
+                body: covered(&DETECTORS.body),
+                schema: covered(&DETECTORS.schema),
+            });
+        }
+
+        // Si c'est un folder, récurser avec ses scripts
+        if let Some(sub_items) = item["item"].as_array() {
+            collect_items(sub_items, requests, &current_path, &scripts);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_matrix_per_request_and_aggregates() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "Users",
+                "event": [{
+                    "listen": "test",
+                    "script": { "exec": ["pm.test('Status', () => pm.response.to.have.status(200));"] }
+                }],
+                "item": [
+                    {
+                        "name": "GET Users",
+                        "request": { "method": "GET", "url": "{{base_url}}/users" },
+                        "event": [{
+                            "listen": "test",
+                            "script": { "exec": ["pm.response.to.have.jsonSchema(schema);"] }
+                        }]
+                    },
+                    {
+                        "name": "GET User",
+                        "request": { "method": "GET", "url": "{{base_url}}/users/1" }
+                    }
+                ]
+            }]
+        });
+
+        let matrix = compute(&collection);
+
+        assert_eq!(matrix.requests.len(), 2);
+        let first = &matrix.requests[0];
+        assert_eq!(first.path, "/item[0]/item[0]");
+        assert!(first.status && first.schema && first.body);
+        assert!(!first.response_time);
+        // Le statut est hérité du folder
+        assert!(matrix.requests[1].status);
+        assert!(!matrix.requests[1].schema);
+
+        assert_eq!(matrix.status_percent, 100.0);
+        assert_eq!(matrix.schema_percent, 50.0);
+        assert_eq!(matrix.response_time_percent, 0.0);
+    }
+}
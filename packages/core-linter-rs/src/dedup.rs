@@ -0,0 +1,105 @@
+//! Déduplication des issues entre règles
+//!
+//! Certaines règles décrivent le même problème sous des formes différentes : une
+//! section de l'Overview aussi exigée comme métadonnée, une requête sans aucune
+//! validation du body signalée à la fois par `test-body-content-validation` et par
+//! `test-schema-validation-recommended`... Chaque forme de message concernée porte
+//! une signature de problème ; sur un même path, les issues de même signature (et de
+//! même valeur de paramètre) sont fusionnées et seule la plus grave est conservée.
+//! Désactivable avec `LintConfig::deduplicate`.
+
+use crate::LintIssue;
+use std::collections::HashMap;
+
+/// Formes de message (`message_id`) → signature du problème et paramètre qui
+/// l'identifie (comparé sans tenir compte de la casse) ; à gravité égale, la forme
+/// listée en premier l'emporte
+const SIGNATURES: &[(&str, &str, &str)] = &[
+    ("collection-overview-template.missing-section", "overview-entry-missing", "section"),
+    ("collection-overview-template.missing-metadata", "overview-entry-missing", "metadata"),
+    ("test-body-content-validation.missing", "response-body-unvalidated", "request"),
+    ("test-schema-validation-recommended.missing", "response-body-unvalidated", "request"),
+];
+
+/// Rang d'une sévérité, la plus grave d'abord
+fn severity_rank(severity: &str) -> usize {
+    match severity {
+        "error" => 0,
+        "warning" => 1,
+        "info" => 2,
+        _ => 3,
+    }
+}
+
+type Key = (String, &'static str, String);
+
+/// Clé de déduplication d'une issue (path, signature et valeur du paramètre) et
+/// priorité de l'issue pour cette clé (la plus petite l'emporte)
+fn key(issue: &LintIssue) -> Option<(Key, (usize, usize))> {
+    let message_id = issue.message_id.as_deref()?;
+    let (position, (_, signature, param)) = SIGNATURES.iter().enumerate().find(|(_, (id, _, _))| *id == message_id)?;
+    let value = issue.params.get(*param)?.as_str()?.trim().to_lowercase();
+    Some(((issue.path.clone(), *signature, value), (severity_rank(&issue.severity), position)))
+}
+
+/// Ne conserve que l'issue prioritaire de chaque problème ; retourne le nombre
+/// d'issues retirées
+pub fn apply(issues: &mut Vec<LintIssue>) -> usize {
+    let mut winners: HashMap<Key, ((usize, usize), usize)> = HashMap::new();
+    for (index, issue) in issues.iter().enumerate() {
+        let Some((key, priority)) = key(issue) else {
+            continue;
+        };
+        let winner = winners.entry(key).or_insert((priority, index));
+        if priority < winner.0 {
+            *winner = (priority, index);
+        }
+    }
+
+    let before = issues.len();
+    let mut index = 0;
+    issues.retain(|issue| {
+        let keep = key(issue).is_none_or(|(key, _)| winners[&key].1 == index);
+        index += 1;
+        keep
+    });
+    before - issues.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(message_id: &str, severity: &str, param: (&str, &str)) -> LintIssue {
+        LintIssue::builder(message_id.split('.').next().unwrap())
+            .severity(severity)
+            .path("/item[0]")
+            .message_id(message_id)
+            .param(param.0, param.1)
+            .build()
+    }
+
+    #[test]
+    fn test_most_severe_issue_wins() {
+        let mut issues = vec![
+            issue("test-schema-validation-recommended.missing", "info", ("request", "GET Users")),
+            issue("test-body-content-validation.missing", "warning", ("request", "GET Users")),
+            issue("test-http-status-mandatory.missing", "error", ("request", "GET Users")),
+        ];
+        assert_eq!(apply(&mut issues), 1);
+        let rules: Vec<&str> = issues.iter().map(|issue| issue.rule_id.as_str()).collect();
+        assert_eq!(rules, vec!["test-body-content-validation", "test-http-status-mandatory"]);
+    }
+
+    #[test]
+    fn test_param_value_distinguishes_problems() {
+        let mut issues = vec![
+            issue("collection-overview-template.missing-section", "error", ("section", "Référent")),
+            issue("collection-overview-template.missing-metadata", "error", ("metadata", "référent")),
+            issue("collection-overview-template.missing-metadata", "error", ("metadata", "Version de collection")),
+        ];
+        assert_eq!(apply(&mut issues), 1);
+        assert_eq!(issues[0].message_id.as_deref(), Some("collection-overview-template.missing-section"));
+        assert_eq!(issues.len(), 2);
+    }
+}
@@ -0,0 +1,61 @@
+//! Enveloppe versionnée des sorties JSON de la CLI
+//!
+//! Les rapports (résultat, workspace, résumé, comparaisons) sont imprimés sous la
+//! forme `{ "format_version": 2, "result": { ... } }` : un parseur en aval vérifie la
+//! version au lieu de deviner la forme du document, et les ajouts de champs restent
+//! compatibles. `--format json-v1` imprime le document nu, comme avant l'enveloppe.
+//! Les fichiers relus par la CLI (`--diff`, `merge-results`) sont acceptés sous
+//! les deux formes.
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Version du format de sortie JSON (1 = document nu, sans enveloppe)
+pub const FORMAT_VERSION: u32 = 2;
+
+/// Sortie JSON versionnée
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Envelope<T> {
+    pub format_version: u32,
+    pub result: T,
+}
+
+impl<T> Envelope<T> {
+    /// Enveloppe un document dans la version courante du format
+    pub fn new(result: T) -> Self {
+        Envelope {
+            format_version: FORMAT_VERSION,
+            result,
+        }
+    }
+}
+
+/// Lit un document JSON produit par la CLI, enveloppé ou nu (`json-v1`)
+pub fn parse<T: DeserializeOwned>(json: &str) -> Result<T, serde_json::Error> {
+    let mut value: Value = serde_json::from_str(json)?;
+    if value.get("format_version").is_some_and(Value::is_u64) && value.get("result").is_some() {
+        value = value["result"].take();
+    }
+    serde_json::from_value(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_envelope_shape() {
+        let envelope = serde_json::to_value(Envelope::new(json!({ "score": 80 }))).unwrap();
+        assert_eq!(envelope, json!({ "format_version": 2, "result": { "score": 80 } }));
+    }
+
+    #[test]
+    fn test_parse_both_versions() {
+        let wrapped: Value = parse(r#"{ "format_version": 2, "result": { "score": 80 } }"#).unwrap();
+        let bare: Value = parse(r#"{ "score": 80 }"#).unwrap();
+        assert_eq!(wrapped, bare);
+        assert!(parse::<Value>("{ \"score\": ").is_err());
+    }
+}
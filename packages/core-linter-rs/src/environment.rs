@@ -0,0 +1,111 @@
+//! Génération d'un template d'environnement Postman
+//!
+//! Les fixes introduisent des variables (`{{base_url}}`, `{{api_key}}`, `location`...)
+//! qui n'existent pas encore côté utilisateur. Ce module les recense et produit un
+//! environnement Postman importable directement, avec des valeurs vides.
+
+use crate::utils;
+use regex::Regex;
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::sync::LazyLock;
+
+static PLACEHOLDER: LazyLock<Regex> = LazyLock::new(|| utils::build_regex(r"\{\{([A-Za-z0-9_.\-]+)\}\}").unwrap());
+
+static SCRIPT_VARIABLE: LazyLock<Regex> = LazyLock::new(|| {
+    utils::build_regex(r#"pm\.(?:environment|collectionVariables|globals|variables)\.(?:get|set)\(\s*['"]([^'"]+)['"]"#)
+        .unwrap()
+});
+
+/// Nom de variable à typer `secret` dans le template
+static SECRET_NAME: LazyLock<Regex> = LazyLock::new(|| {
+    utils::build_regex(r"(?i)token|password|passwd|secret|api[_-]?key|credential|private").unwrap()
+});
+
+/// Variables référencées par une collection : placeholders `{{var}}` et
+/// appels `pm.environment/collectionVariables/globals/variables.get|set('var')`
+pub fn referenced_variables(collection: &Value) -> BTreeSet<String> {
+
+    let content = serde_json::to_string(collection).unwrap_or_default();
+    // Les scripts sont sérialisés avec des guillemets échappés
+    let content = content.replace("\\\"", "\"");
+
+    PLACEHOLDER
+        .captures_iter(&content)
+        .chain(SCRIPT_VARIABLE.captures_iter(&content))
+        .map(|caps| caps[1].to_string())
+        .collect()
+}
+
+/// Variables présentes dans la collection corrigée mais absentes de l'originale
+pub fn introduced_variables(original: &Value, fixed: &Value) -> BTreeSet<String> {
+    let before = referenced_variables(original);
+    referenced_variables(fixed)
+        .into_iter()
+        .filter(|name| !before.contains(name))
+        .collect()
+}
+
+/// Construit un environnement Postman contenant les variables données (valeurs vides)
+pub fn environment_template(collection_name: &str, variables: &BTreeSet<String>) -> Value {
+    let values: Vec<Value> = variables
+        .iter()
+        .map(|name| {
+            let var_type = if SECRET_NAME.is_match(name) { "secret" } else { "default" };
+            serde_json::json!({
+                "key": name,
+                "value": "",
+                "type": var_type,
+                "enabled": true,
+            })
+        })
+        .collect();
+
+    serde_json::json!({
+        "name": format!("{} - Environment", collection_name),
+        "values": values,
+        "_postman_variable_scope": "environment",
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_introduced_variables() {
+        let original = json!({
+            "item": [{ "name": "GET Users", "request": { "url": "{{base_url}}/users" } }]
+        });
+        let fixed = json!({
+            "item": [{
+                "name": "GET Users",
+                "request": {
+                    "url": "{{base_url}}/users",
+                    "header": [{ "key": "X-Api-Key", "value": "{{api_key}}" }]
+                },
+                "event": [{
+                    "listen": "prerequest",
+                    "script": { "exec": ["pm.environment.set('location', pm.request.url.getPath());"] }
+                }]
+            }]
+        });
+
+        let introduced = introduced_variables(&original, &fixed);
+        assert_eq!(introduced.into_iter().collect::<Vec<_>>(), vec!["api_key", "location"]);
+    }
+
+    #[test]
+    fn test_environment_template() {
+        let variables: BTreeSet<String> = ["api_key", "location"].iter().map(|s| s.to_string()).collect();
+
+        let environment = environment_template("My API", &variables);
+
+        assert_eq!(environment["name"], "My API - Environment");
+        assert_eq!(environment["values"][0]["key"], "api_key");
+        assert_eq!(environment["values"][0]["type"], "secret");
+        assert_eq!(environment["values"][1]["type"], "default");
+        assert_eq!(environment["_postman_variable_scope"], "environment");
+    }
+}
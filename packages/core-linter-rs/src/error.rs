@@ -0,0 +1,93 @@
+//! Erreurs de la bibliothèque
+//!
+//! `LintError` est l'erreur des points d'entrée Rust qui lisent du JSON, valident
+//! une configuration ou corrigent une collection (`lint_json`, `LintConfig::from_json`,
+//! `LintConfig::validate`, `Linter`).
+//! Les bindings WASM la convertissent en `WasmError` (`{ code, detail, line, column }`
+//! côté JavaScript).
+
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LintError {
+    /// JSON invalide, tronqué ou de forme inattendue ; `input` nomme l'entrée
+    /// ("collection", "config"...) et la position (à partir de 1) est connue pour
+    /// les erreurs de syntaxe
+    Parse {
+        input: String,
+        message: String,
+        line: Option<usize>,
+        column: Option<usize>,
+    },
+    /// Configuration lisible mais incohérente (sévérité ou option invalide)
+    InvalidConfig(String),
+    /// Règle inconnue référencée par la configuration
+    UnknownRule(String),
+    /// Corrections automatiques refusées (mode strict, fix de type inconnu)
+    FixFailed(String),
+    /// Handle de session fermé ou jamais ouvert (bindings WASM)
+    UnknownHandle(u32),
+}
+
+impl LintError {
+    /// Erreur de parsing JSON de l'entrée `input`, avec sa position
+    pub fn parse(input: &str, error: &serde_json::Error) -> Self {
+        // serde_json suffixe son message de la position, reportée à part
+        let message = error.to_string();
+        let position = format!(" at line {} column {}", error.line(), error.column());
+        let message = message.strip_suffix(&position).unwrap_or(&message);
+
+        LintError::Parse {
+            input: input.to_string(),
+            message: message.to_string(),
+            line: (error.line() > 0).then_some(error.line()),
+            column: (error.line() > 0).then_some(error.column()),
+        }
+    }
+}
+
+impl fmt::Display for LintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LintError::Parse { input, message, line, column } => {
+                write!(f, "Failed to parse {}: {}", input, message)?;
+                if let (Some(line), Some(column)) = (line, column) {
+                    write!(f, " (line {}, column {})", line, column)?;
+                }
+                Ok(())
+            }
+            LintError::InvalidConfig(detail) => write!(f, "Invalid config: {}", detail),
+            LintError::UnknownRule(rule_id) => write!(f, "Unknown rule '{}'", rule_id),
+            LintError::FixFailed(detail) => write!(f, "{}", detail),
+            LintError::UnknownHandle(handle) => {
+                write!(f, "Unknown collection handle {} (closed or never opened)", handle)
+            }
+        }
+    }
+}
+
+impl std::error::Error for LintError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    #[test]
+    fn test_parse_error_location() {
+        let error = serde_json::from_str::<Value>("{\n  \"info\": {\n    \"name\": \"Te").unwrap_err();
+        let lint_error = LintError::parse("collection", &error);
+
+        assert!(matches!(lint_error, LintError::Parse { line: Some(3), .. }));
+        assert_eq!(
+            lint_error.to_string(),
+            "Failed to parse collection: EOF while parsing a string (line 3, column 15)"
+        );
+    }
+
+    #[test]
+    fn test_error_trait() {
+        let error: Box<dyn std::error::Error> = Box::new(LintError::UnknownRule("test-http-statuss".to_string()));
+        assert_eq!(error.to_string(), "Unknown rule 'test-http-statuss'");
+    }
+}
@@ -1,251 +1,550 @@
-use crate::LintIssue;
-use serde_json::Value;
+//! Module de correction automatique des collections Postman
+//! 
+//! Ce module applique les corrections suggérées par les règles de linting
+//! pour générer une collection corrigée automatiquement.
 
-/// Module de correction automatique des collections Postman
-/// 
-/// Ce module applique les corrections suggérées par les règles de linting
-/// pour générer une collection corrigée automatiquement.
+use crate::i18n::Locale;
+use crate::pointer;
+use crate::snippets;
+use crate::{FixAction, LintIssue};
+use crate::utils;
+use regex::Regex;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::sync::{LazyLock, OnceLock};
 
 /// Applique toutes les corrections possibles à une collection
 pub fn apply_fixes(collection: &mut Value, issues: &[LintIssue]) -> usize {
-    let mut fixes_applied = 0;
+    apply_fixes_with_locale(collection, issues, Locale::default())
+}
+
+/// Applique les corrections en commentant le code injecté dans la langue demandée
+pub fn apply_fixes_with_locale(collection: &mut Value, issues: &[LintIssue], locale: Locale) -> usize {
+    apply_fixes_checked(collection, issues, locale).applied
+}
+
+/// Résultat de l'application des corrections
+#[derive(Debug, Default)]
+pub struct FixReport {
+    pub applied: usize,
+    /// Fixes dont le type n'est enregistré dans aucun handler
+    pub unknown: Vec<UnknownFix>,
+}
+
+/// Fix proposé par une règle avec un type inconnu du registre
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnknownFix {
+    pub rule_id: String,
+    pub path: String,
+    pub fix_type: String,
+}
+
+impl std::fmt::Display for UnknownFix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown fix type '{}' emitted by rule '{}' at {}", self.fix_type, self.rule_id, self.path)
+    }
+}
+
+/// Applique les corrections et relève les types de fix non enregistrés
+/// (utilisé par le mode strict pour les remonter en erreur)
+pub fn apply_fixes_checked(collection: &mut Value, issues: &[LintIssue], locale: Locale) -> FixReport {
+    let registry = FixRegistry::builtin();
+    let mut report = FixReport::default();
     
     for issue in issues {
         if let Some(fix) = &issue.fix {
-            if apply_single_fix(collection, &issue.path, fix) {
-                fixes_applied += 1;
+            match registry.apply(collection, &issue.path, fix, locale) {
+                Ok(true) => report.applied += 1,
+                Ok(false) => {}
+                Err(fix_type) => report.unknown.push(UnknownFix {
+                    rule_id: issue.rule_id.clone(),
+                    path: issue.path.clone(),
+                    fix_type,
+                }),
             }
         }
     }
     
-    fixes_applied
+    report
 }
 
-/// Applique une correction unique
-fn apply_single_fix(collection: &mut Value, path: &str, fix: &Value) -> bool {
-    let fix_type = fix["type"].as_str().unwrap_or("");
-    
-    match fix_type {
-        "rename_request" => apply_rename_request(collection, path, fix),
-        "add_test" | "add_response_time_test" => apply_add_test(collection, path, fix),
-        "update_test_description" | "fix_test_description_uri" => apply_update_test_description(collection, path, fix),
-        "update_threshold" | "adjust_threshold" => apply_update_threshold(collection, path, fix),
-        _ => false,
+/// Handler appliquant un type de fix à l'item désigné par `path`
+pub type FixHandler = fn(&mut Value, &str, &FixAction, Locale) -> bool;
+
+/// Registre des handlers de fix, indexés par type.
+/// Toute règle proposant un fix doit utiliser un type enregistré ici : soit avec
+/// un handler (fix automatique), soit comme suggestion manuelle.
+#[derive(Default)]
+pub struct FixRegistry {
+    handlers: BTreeMap<String, Option<FixHandler>>,
+}
+
+impl FixRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registre des fixes intégrés, partagé par tout le moteur
+    pub fn builtin() -> &'static FixRegistry {
+        static BUILTIN: OnceLock<FixRegistry> = OnceLock::new();
+        BUILTIN.get_or_init(|| {
+            let mut registry = FixRegistry::new();
+            registry.register("rename_request", |collection, path, fix, _| apply_rename_request(collection, path, fix));
+            registry.register("add_test", apply_add_test);
+            registry.register("add_response_time_test", apply_add_test);
+            registry.register("update_test_description", apply_update_test_description);
+            registry.register("adjust_threshold", |collection, path, fix, _| apply_update_threshold(collection, path, fix));
+            registry.register("prune_examples", |collection, path, fix, _| apply_prune_examples(collection, path, fix));
+            registry.register("set_header", |collection, path, fix, _| apply_set_header(collection, path, fix));
+            registry.register("disable_header", |collection, path, fix, _| apply_disable_header(collection, path, fix));
+            registry.register("normalize_placeholder", |collection, path, fix, _| apply_normalize_placeholder(collection, path, fix));
+            registry.register("regenerate_endpoint_table", |collection, path, fix, _| apply_regenerate_endpoint_table(collection, path, fix));
+            registry.register("generate_overview", |collection, _, fix, _| apply_generate_overview(collection, fix));
+            registry.register("upgrade_schema", |collection, _, fix, _| apply_upgrade_schema(collection, fix));
+            // Suggestions appliquées à la main (code ou variable à créer)
+            registry.register_manual("add_schema_validation");
+            registry.register_manual("use_environment_variable");
+            registry.register_manual("replace_secret");
+            registry
+        })
+    }
+
+    /// Enregistre (ou remplace) le handler d'un type de fix
+    pub fn register(&mut self, fix_type: &str, handler: FixHandler) {
+        self.handlers.insert(fix_type.to_string(), Some(handler));
+    }
+
+    /// Déclare un type de fix proposé comme suggestion, sans application automatique
+    pub fn register_manual(&mut self, fix_type: &str) {
+        self.handlers.insert(fix_type.to_string(), None);
+    }
+
+    pub fn is_registered(&self, fix_type: &str) -> bool {
+        self.handlers.contains_key(fix_type)
+    }
+
+    /// Le type de fix possède-t-il un handler automatique ?
+    pub fn is_auto_fixable(&self, fix_type: &str) -> bool {
+        matches!(self.handlers.get(fix_type), Some(Some(_)))
+    }
+
+    /// Types de fix appliqués automatiquement
+    pub fn auto_fix_types(&self) -> Vec<&str> {
+        self.handlers
+            .iter()
+            .filter(|(_, handler)| handler.is_some())
+            .map(|(fix_type, _)| fix_type.as_str())
+            .collect()
+    }
+
+    /// Applique un fix ; `Err(type)` si le type n'est pas enregistré
+    pub fn apply(&self, collection: &mut Value, path: &str, fix: &FixAction, locale: Locale) -> Result<bool, String> {
+        let fix_type = fix.fix_type();
+        match self.handlers.get(fix_type) {
+            Some(Some(handler)) => Ok(handler(collection, path, fix, locale)),
+            Some(None) => Ok(false),
+            None => Err(fix_type.to_string()),
+        }
     }
 }
 
+/// Le fix peut-il être appliqué automatiquement ?
+pub fn is_auto_fixable(fix: &FixAction) -> bool {
+    FixRegistry::builtin().is_auto_fixable(fix.fix_type())
+}
+
+/// Applique une correction unique à l'item désigné par `path`
+pub fn apply_single_fix(collection: &mut Value, path: &str, fix: &FixAction, locale: Locale) -> bool {
+    FixRegistry::builtin().apply(collection, path, fix, locale).unwrap_or(false)
+}
+
 /// Correction : Renommer une requête
-fn apply_rename_request(collection: &mut Value, path: &str, fix: &Value) -> bool {
-    if let Some(suggested_name) = fix["suggested_name"].as_str() {
-        if let Some(item) = get_item_by_path_mut(collection, path) {
-            item["name"] = Value::String(suggested_name.to_string());
-            return true;
-        }
+fn apply_rename_request(collection: &mut Value, path: &str, fix: &FixAction) -> bool {
+    let FixAction::RenameRequest { suggested_name } = fix else {
+        return false;
+    };
+    
+    if let Some(item) = get_item_by_path_mut(collection, path) {
+        item["name"] = Value::String(suggested_name.to_string());
+        return true;
     }
     false
 }
 
 /// Correction : Ajouter un test
-fn apply_add_test(collection: &mut Value, path: &str, fix: &Value) -> bool {
-    let test_code = fix["test_code"].as_str()
-        .or_else(|| fix["suggested_code"].as_str());
+fn apply_add_test(collection: &mut Value, path: &str, fix: &FixAction, locale: Locale) -> bool {
+    let (FixAction::AddTest { test_code } | FixAction::AddResponseTimeTest { suggested_code: test_code }) = fix else {
+        return false;
+    };
     
-    if let Some(test_code) = test_code {
-        if let Some(item) = get_item_by_path_mut(collection, path) {
-            // Créer ou récupérer le tableau d'events
-            if !item["event"].is_array() {
-                item["event"] = Value::Array(vec![]);
-            }
-            
-            let events = item["event"].as_array_mut().unwrap();
-            
-            // Si le test utilise la variable 'location', ajouter le prerequest
-            if test_code.contains("location") {
-                let has_prerequest = events.iter().any(|e| e["listen"] == "prerequest");
-                if !has_prerequest {
-                    events.push(serde_json::json!({
-                        "listen": "prerequest",
-                        "script": {
-                            "exec": [
-                                "// Définir la variable location pour les tests",
-                                "pm.environment.set('location', pm.request.url.getPath());"
-                            ],
-                            "type": "text/javascript"
-                        }
-                    }));
-                }
-            }
-            
-            // Chercher un event "test" existant
-            let mut test_event_found = false;
-            for event in events.iter_mut() {
-                if event["listen"] == "test" {
-                    // Vérifier si le test existe déjà
-                    if let Some(exec) = event["script"]["exec"].as_array_mut() {
-                        let test_exists = exec.iter().any(|line| {
-                            if let Some(line_str) = line.as_str() {
-                                // Vérifier si le test est similaire (même pattern)
-                                line_str.contains("Status code") && test_code.contains("Status code")
-                                || line_str.contains("responseTime") && test_code.contains("responseTime")
-                                || line_str.contains("response time") && test_code.contains("response time")
-                            } else {
-                                false
-                            }
-                        });
-                        
-                        // Ajouter seulement si le test n'existe pas déjà
-                        if !test_exists {
-                            exec.push(Value::String(test_code.to_string()));
-                        }
-                    }
-                    test_event_found = true;
-                    break;
-                }
-            }
-            
-            // Si pas d'event "test", en créer un
-            if !test_event_found {
+    if let Some(item) = get_item_by_path_mut(collection, path) {
+        // Créer ou récupérer le tableau d'events
+        if !item["event"].is_array() {
+            item["event"] = Value::Array(vec![]);
+        }
+        
+        let events = item["event"].as_array_mut().unwrap();
+        
+        // Si le test utilise la variable 'location', ajouter le prerequest
+        if test_code.contains("location") {
+            let has_prerequest = events.iter().any(|e| e["listen"] == "prerequest");
+            if !has_prerequest {
                 events.push(serde_json::json!({
-                    "listen": "test",
+                    "listen": "prerequest",
                     "script": {
-                        "exec": [test_code],
+                        "exec": snippets::location_prerequest(locale),
                         "type": "text/javascript"
                     }
                 }));
             }
-            
-            return true;
         }
+        
+        // Chercher un event "test" existant
+        let mut test_event_found = false;
+        for event in events.iter_mut() {
+            if event["listen"] == "test" {
+                // Vérifier si le test existe déjà
+                if let Some(exec) = event["script"]["exec"].as_array_mut() {
+                    let test_exists = exec.iter().any(|line| {
+                        if let Some(line_str) = line.as_str() {
+                            // Vérifier si le test est similaire (même pattern)
+                            line_str.contains("Status code") && test_code.contains("Status code")
+                            || line_str.contains("responseTime") && test_code.contains("responseTime")
+                            || line_str.contains("response time") && test_code.contains("response time")
+                        } else {
+                            false
+                        }
+                    });
+                    
+                    // Ajouter seulement si le test n'existe pas déjà
+                    if !test_exists {
+                        exec.push(Value::String(test_code.to_string()));
+                    }
+                }
+                test_event_found = true;
+                break;
+            }
+        }
+        
+        // Si pas d'event "test", en créer un
+        if !test_event_found {
+            events.push(serde_json::json!({
+                "listen": "test",
+                "script": {
+                    "exec": [test_code],
+                    "type": "text/javascript"
+                }
+            }));
+        }
+        
+        return true;
     }
     false
 }
 
 /// Correction : Mettre à jour la description d'un test
-fn apply_update_test_description(collection: &mut Value, path: &str, fix: &Value) -> bool {
-    if let Some(old_desc) = fix["old_description"].as_str() {
-        if let Some(new_desc) = fix["new_description"].as_str() {
-            if let Some(item) = get_item_by_path_mut(collection, path) {
-                // Si la nouvelle description utilise 'location', ajouter le prerequest
-                if new_desc.contains("location") {
-                    if !item["event"].is_array() {
-                        item["event"] = Value::Array(vec![]);
-                    }
-                    let events = item["event"].as_array_mut().unwrap();
-                    let has_prerequest = events.iter().any(|e| e["listen"] == "prerequest");
-                    if !has_prerequest {
-                        events.push(serde_json::json!({
-                            "listen": "prerequest",
-                            "script": {
-                                "exec": [
-                                    "// Définir la variable location pour les tests",
-                                    "pm.environment.set('location', pm.request.url.getPath());"
-                                ],
-                                "type": "text/javascript"
-                            }
-                        }));
+fn apply_update_test_description(collection: &mut Value, path: &str, fix: &FixAction, locale: Locale) -> bool {
+    let FixAction::UpdateTestDescription { old_description: old_desc, new_description: new_desc } = fix else {
+        return false;
+    };
+    
+    if let Some(item) = get_item_by_path_mut(collection, path) {
+        // Si la nouvelle description utilise 'location', ajouter le prerequest
+        if new_desc.contains("location") {
+            if !item["event"].is_array() {
+                item["event"] = Value::Array(vec![]);
+            }
+            let events = item["event"].as_array_mut().unwrap();
+            let has_prerequest = events.iter().any(|e| e["listen"] == "prerequest");
+            if !has_prerequest {
+                events.push(serde_json::json!({
+                    "listen": "prerequest",
+                    "script": {
+                        "exec": snippets::location_prerequest(locale),
+                        "type": "text/javascript"
                     }
-                }
-                
-                if let Some(events) = item["event"].as_array_mut() {
-                    for event in events {
-                        if event["listen"] == "test" {
-                            if let Some(exec) = event["script"]["exec"].as_array_mut() {
-                                for line in exec.iter_mut() {
-                                    if let Some(line_str) = line.as_str() {
-                                        // Remplacer "old_desc" par new_desc dans pm.test()
-                                        if line_str.contains(&format!("\"{}\"", old_desc)) || 
-                                           line_str.contains(&format!("'{}'", old_desc)) {
-                                            let new_line = line_str
-                                                .replace(&format!("\"{}\"", old_desc), new_desc)
-                                                .replace(&format!("'{}'", old_desc), new_desc);
-                                            *line = Value::String(new_line);
-                                        }
-                                    }
+                }));
+            }
+        }
+        
+        if let Some(events) = item["event"].as_array_mut() {
+            for event in events {
+                if event["listen"] == "test" {
+                    if let Some(exec) = event["script"]["exec"].as_array_mut() {
+                        for line in exec.iter_mut() {
+                            if let Some(line_str) = line.as_str() {
+                                // Remplacer "old_desc" par new_desc dans pm.test()
+                                if line_str.contains(&format!("\"{}\"", old_desc)) || 
+                                   line_str.contains(&format!("'{}'", old_desc)) {
+                                    let new_line = line_str
+                                        .replace(&format!("\"{}\"", old_desc), new_desc)
+                                        .replace(&format!("'{}'", old_desc), new_desc);
+                                    *line = Value::String(new_line);
                                 }
                             }
                         }
                     }
                 }
-                return true;
             }
         }
+        return true;
     }
     false
 }
 
+/// Seuil d'une assertion de temps de réponse (`.below(n)`)
+static BELOW: LazyLock<Regex> = LazyLock::new(|| utils::build_regex(r"\.below\((\d+)\)").unwrap());
+
 /// Correction : Mettre à jour un seuil de temps de réponse
-fn apply_update_threshold(collection: &mut Value, path: &str, fix: &Value) -> bool {
-    let new_threshold = fix["new_threshold"].as_i64()
-        .or_else(|| fix["suggested_threshold"].as_i64());
+///
+/// Remplace le seuil relevé par la règle (`current_threshold`) par le seuil suggéré ;
+/// un fix sans seuil relevé (ancien format) remplace tout seuil supérieur à la
+/// suggestion. Échoue si aucune ligne n'a été réécrite.
+fn apply_update_threshold(collection: &mut Value, path: &str, fix: &FixAction) -> bool {
+    let FixAction::AdjustThreshold { current_threshold, suggested_threshold, .. } = *fix else {
+        return false;
+    };
+    let outdated = |threshold: u64| match current_threshold {
+        0 => threshold > suggested_threshold,
+        current => threshold == current,
+    };
+
+    let Some(events) = get_item_by_path_mut(collection, path).and_then(|item| item["event"].as_array_mut()) else {
+        return false;
+    };
+    let mut rewritten = false;
+    for event in events.iter_mut().filter(|event| event["listen"] == "test") {
+        let Some(exec) = event["script"]["exec"].as_array_mut() else {
+            continue;
+        };
+        for line in exec.iter_mut() {
+            let Some(line_str) = line.as_str().filter(|line| line.contains("responseTime")) else {
+                continue;
+            };
+            let new_line = BELOW.replace_all(line_str, |caps: &regex::Captures| match caps[1].parse::<u64>() {
+                Ok(threshold) if outdated(threshold) => format!(".below({})", suggested_threshold),
+                _ => caps[0].to_string(),
+            });
+            if new_line != line_str {
+                *line = Value::String(new_line.into_owned());
+                rewritten = true;
+            }
+        }
+    }
+    rewritten
+}
+
+/// Correction : Ne conserver que certains exemples de réponse
+fn apply_prune_examples(collection: &mut Value, path: &str, fix: &FixAction) -> bool {
+    let FixAction::PruneExamples { keep_indices: keep } = fix else {
+        return false;
+    };
     
-    if let Some(new_threshold) = new_threshold {
-        if let Some(item) = get_item_by_path_mut(collection, path) {
-            if let Some(events) = item["event"].as_array_mut() {
-                for event in events {
-                    if event["listen"] == "test" {
-                        if let Some(exec) = event["script"]["exec"].as_array_mut() {
-                            for line in exec.iter_mut() {
-                                if let Some(line_str) = line.as_str() {
-                                    // Remplacer les seuils >2000 par 2000
-                                    if line_str.contains("responseTime") && line_str.contains("below") {
-                                        // Regex pour trouver le nombre
-                                        let re = regex::Regex::new(r"\.below\((\d+)\)").unwrap();
-                                        if let Some(caps) = re.captures(line_str) {
-                                            if let Some(threshold_str) = caps.get(1) {
-                                                if let Ok(threshold) = threshold_str.as_str().parse::<i64>() {
-                                                    if threshold > 2000 {
-                                                        let new_line = line_str.replace(
-                                                            &format!(".below({})", threshold),
-                                                            &format!(".below({})", new_threshold)
-                                                        );
-                                                        *line = Value::String(new_line);
-                                                    }
-                                                }
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
+    if let Some(item) = get_item_by_path_mut(collection, path) {
+        if let Some(responses) = item["response"].as_array_mut() {
+            let before = responses.len();
+            let mut index = 0;
+            responses.retain(|_| {
+                let kept = keep.contains(&index);
+                index += 1;
+                kept
+            });
+            // Aucun exemple retiré : le fix n'a rien changé
+            return responses.len() < before;
+        }
+    }
+    false
+}
+
+/// Correction : Définir la valeur d'un header de requête (créé s'il n'existe pas)
+fn apply_set_header(collection: &mut Value, path: &str, fix: &FixAction) -> bool {
+    let FixAction::SetHeader { header: header_name, value: header_value } = fix else {
+        return false;
+    };
+    
+    // Le path peut pointer vers /request/header : on remonte à l'item
+    let item_path = path.trim_end_matches("/request/header");
+    if let Some(item) = get_item_by_path_mut(collection, item_path) {
+        if !item["request"].is_object() {
+            return false;
+        }
+        if !item["request"]["header"].is_array() {
+            item["request"]["header"] = Value::Array(vec![]);
+        }
+        
+        let headers = item["request"]["header"].as_array_mut().unwrap();
+        let existing = headers.iter_mut().find(|h| {
+            h["key"].as_str().map(|k| k.eq_ignore_ascii_case(header_name)).unwrap_or(false)
+        });
+        
+        match existing {
+            Some(header) => header["value"] = Value::String(header_value.to_string()),
+            None => headers.push(serde_json::json!({
+                "key": header_name,
+                "value": header_value,
+            })),
+        }
+        return true;
+    }
+    false
+}
+
+/// Correction : Désactiver un header de requête (conservé mais `disabled: true`)
+fn apply_disable_header(collection: &mut Value, path: &str, fix: &FixAction) -> bool {
+    let FixAction::DisableHeader { header: header_name } = fix else {
+        return false;
+    };
+    
+    let item_path = path.trim_end_matches("/request/header");
+    if let Some(item) = get_item_by_path_mut(collection, item_path) {
+        if let Some(headers) = item["request"]["header"].as_array_mut() {
+            let mut disabled = false;
+            for header in headers.iter_mut() {
+                if header["key"].as_str().map(|k| k.eq_ignore_ascii_case(header_name)).unwrap_or(false) {
+                    header["disabled"] = Value::Bool(true);
+                    disabled = true;
                 }
-                return true;
             }
+            return disabled;
         }
     }
     false
 }
 
-/// Récupère un item par son path (mutable)
-fn get_item_by_path_mut<'a>(collection: &'a mut Value, path: &str) -> Option<&'a mut Value> {
-    let parts: Vec<&str> = path.split('/').filter(|p| !p.is_empty()).collect();
-    let mut current = collection;
+/// Correction : Remplacer une variable mal formée dans toute la requête (URL, headers, body, auth)
+fn apply_normalize_placeholder(collection: &mut Value, path: &str, fix: &FixAction) -> bool {
+    let FixAction::NormalizePlaceholder { original, normalized } = fix else {
+        return false;
+    };
     
-    for part in parts {
-        if part.starts_with("item[") && part.ends_with(']') {
-            let index_str = &part[5..part.len() - 1];
-            if let Ok(index) = index_str.parse::<usize>() {
-                if let Some(items) = current["item"].as_array_mut() {
-                    if index < items.len() {
-                        current = &mut items[index];
-                    } else {
-                        return None;
-                    }
-                } else {
-                    return None;
-                }
-            } else {
-                return None;
+    fn replace_strings(value: &mut Value, original: &str, normalized: &str) -> bool {
+        match value {
+            Value::String(s) if s.contains(original) => {
+                *s = s.replace(original, normalized);
+                true
             }
+            Value::Array(values) => values.iter_mut().fold(false, |changed, v| replace_strings(v, original, normalized) | changed),
+            Value::Object(map) => map.values_mut().fold(false, |changed, v| replace_strings(v, original, normalized) | changed),
+            _ => false,
+        }
+    }
+    
+    match get_item_by_path_mut(collection, path) {
+        Some(item) if item["request"].is_object() => replace_strings(&mut item["request"], original, normalized),
+        _ => false,
+    }
+}
+
+/// Ligne de table d'endpoints (`| GET | ...`)
+static METHOD_ROW: LazyLock<Regex> =
+    LazyLock::new(|| utils::build_regex(r"(?i)^\s*\|\s*`?(GET|POST|PUT|PATCH|DELETE|HEAD|OPTIONS)`?\s*\|").unwrap());
+
+/// Correction : Remplacer la table des endpoints de la description d'un folder
+/// (ajoutée sous un titre "Endpoints" si la description n'en contient pas)
+fn apply_regenerate_endpoint_table(collection: &mut Value, path: &str, fix: &FixAction) -> bool {
+    let FixAction::RegenerateEndpointTable { table } = fix else {
+        return false;
+    };
+    
+    let Some(item) = get_item_by_path_mut(collection, path) else {
+        return false;
+    };
+    // Description : chaîne ou objet { content, type }
+    let description = if item["description"].is_object() {
+        &mut item["description"]["content"]
+    } else {
+        &mut item["description"]
+    };
+    let current = description.as_str().unwrap_or("").to_string();
+    
+    // Table existante : bloc de lignes `|` contenant au moins une ligne de méthode HTTP
+    let lines: Vec<&str> = current.lines().collect();
+    let mut start = 0;
+    while start < lines.len() {
+        if !lines[start].trim_start().starts_with('|') {
+            start += 1;
+            continue;
+        }
+        let mut end = start;
+        while end < lines.len() && lines[end].trim_start().starts_with('|') {
+            end += 1;
         }
+        if lines[start..end].iter().any(|line| METHOD_ROW.is_match(line)) {
+            let mut updated: Vec<&str> = lines[..start].to_vec();
+            updated.push(table);
+            updated.extend(&lines[end..]);
+            *description = Value::String(updated.join("\n"));
+            return true;
+        }
+        start = end;
     }
     
+    let updated = if current.trim().is_empty() {
+        format!("## Endpoints\n\n{}", table)
+    } else {
+        format!("{}\n\n## Endpoints\n\n{}", current.trim_end(), table)
+    };
+    *description = Value::String(updated);
+    true
+}
+
+/// Correction : Ajouter le squelette de l'Overview à la description de la collection
+/// (une seule fois, même si plusieurs issues portent le même fix)
+fn apply_generate_overview(collection: &mut Value, fix: &FixAction) -> bool {
+    let FixAction::GenerateOverview { content } = fix else {
+        return false;
+    };
+    
+    if !collection["info"].is_object() {
+        collection["info"] = serde_json::json!({});
+    }
+    let description = if collection["info"]["description"].is_object() {
+        &mut collection["info"]["description"]["content"]
+    } else {
+        &mut collection["info"]["description"]
+    };
+    let current = description.as_str().unwrap_or("");
+    if current.contains(content.as_str()) {
+        return false;
+    }
+    
+    let updated = if current.trim().is_empty() {
+        content.to_string()
+    } else {
+        format!("{}\n\n{}", current.trim_end(), content)
+    };
+    *description = Value::String(updated);
+    true
+}
+
+/// Correction : Remplacer l'URL du schéma de la collection
+fn apply_upgrade_schema(collection: &mut Value, fix: &FixAction) -> bool {
+    let FixAction::UpgradeSchema { schema } = fix else {
+        return false;
+    };
+    
+    if !collection["info"].is_object() || collection["info"]["schema"] == schema.as_str() {
+        return false;
+    }
+    collection["info"]["schema"] = Value::String(schema.clone());
+    true
+}
+
+/// Récupère un item par son path (mutable) ; accepte aussi un JSON Pointer
+/// (`LintIssue::pointer`)
+fn get_item_by_path_mut<'a>(collection: &'a mut Value, path: &str) -> Option<&'a mut Value> {
+    let mut current = collection;
+    for index in pointer::item_indices(path)? {
+        current = current["item"].as_array_mut()?.get_mut(index)?;
+    }
     Some(current)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::context::LintContext;
+    use crate::rules::performance::response_time_threshold;
     use serde_json::json;
 
     #[test]
@@ -259,17 +558,13 @@ mod tests {
             }]
         });
 
-        let issues = vec![LintIssue {
-            rule_id: "request-naming-convention".to_string(),
-            severity: "warning".to_string(),
-            message: "Test".to_string(),
-            path: "/item[0]".to_string(),
-            line: None,
-            fix: Some(json!({
-                "type": "rename_request",
-                "suggested_name": "GET Users List"
-            })),
-        }];
+        let issues = vec![LintIssue::builder("request-naming-convention")
+            .message("Test")
+            .path("/item[0]")
+            .fix(FixAction::RenameRequest {
+                suggested_name: "GET Users List".to_string(),
+            })
+            .build()];
 
         let fixes_applied = apply_fixes(&mut collection, &issues);
         
@@ -277,6 +572,21 @@ mod tests {
         assert_eq!(collection["item"][0]["name"], "GET Users List");
     }
 
+    #[test]
+    fn test_fix_resolves_json_pointer_paths() {
+        let mut collection = json!({
+            "item": [{ "name": "Users", "item": [{ "name": "Users List", "request": { "method": "GET" } }] }]
+        });
+        let issue = LintIssue::builder("request-naming-convention")
+            .path("/item/0/item/0/request")
+            .fix(FixAction::RenameRequest { suggested_name: "GET Users List".to_string() })
+            .build();
+
+        assert_eq!(apply_fixes(&mut collection, &[issue]), 1);
+        assert_eq!(collection["item"][0]["item"][0]["name"], "GET Users List");
+        assert!(get_item_by_path_mut(&mut collection, "/item/3").is_none());
+    }
+
     #[test]
     fn test_add_test() {
         let mut collection = json!({
@@ -288,17 +598,14 @@ mod tests {
             }]
         });
 
-        let issues = vec![LintIssue {
-            rule_id: "test-http-status-mandatory".to_string(),
-            severity: "error".to_string(),
-            message: "Test".to_string(),
-            path: "/item[0]".to_string(),
-            line: None,
-            fix: Some(json!({
-                "type": "add_test",
-                "test_code": "pm.test('Status code is 200', function() { pm.response.to.have.status(200); });"
-            })),
-        }];
+        let issues = vec![LintIssue::builder("test-http-status-mandatory")
+            .severity("error")
+            .message("Test")
+            .path("/item[0]")
+            .fix(FixAction::AddTest {
+                test_code: "pm.test('Status code is 200', function() { pm.response.to.have.status(200); });".to_string(),
+            })
+            .build()];
 
         let fixes_applied = apply_fixes(&mut collection, &issues);
         
@@ -306,4 +613,250 @@ mod tests {
         assert!(collection["item"][0]["event"].is_array());
         assert_eq!(collection["item"][0]["event"][0]["listen"], "test");
     }
+
+    #[test]
+    fn test_add_test_prerequest_follows_locale() {
+        let mut collection = json!({
+            "item": [{
+                "name": "GET Users",
+                "request": { "method": "GET" }
+            }]
+        });
+
+        let issues = vec![LintIssue::builder("test-http-status-mandatory")
+            .severity("error")
+            .message("Test")
+            .path("/item[0]")
+            .fix(FixAction::AddTest {
+                test_code: "pm.test(location + ' - Status code is 2xx', function() { pm.response.to.be.success; });".to_string(),
+            })
+            .build()];
+
+        apply_fixes_with_locale(&mut collection, &issues, Locale::En);
+
+        let prerequest = &collection["item"][0]["event"][0];
+        assert_eq!(prerequest["listen"], "prerequest");
+        assert_eq!(prerequest["script"]["exec"][0], "// Define the location variable for tests");
+    }
+
+    #[test]
+    fn test_prune_examples() {
+        let mut collection = json!({
+            "item": [{
+                "name": "GET Users",
+                "request": { "method": "GET" },
+                "response": [
+                    { "name": "OK", "code": 200 },
+                    { "name": "OK again", "code": 200 },
+                    { "name": "Not found", "code": 404 }
+                ]
+            }]
+        });
+
+        let issues = vec![LintIssue::builder("example-count-limits")
+            .message("Test")
+            .path("/item[0]")
+            .fix(FixAction::PruneExamples { keep_indices: vec![0, 2] })
+            .build()];
+
+        let fixes_applied = apply_fixes(&mut collection, &issues);
+
+        assert_eq!(fixes_applied, 1);
+        let responses = collection["item"][0]["response"].as_array().unwrap();
+        assert_eq!(responses.len(), 2);
+        assert_eq!(responses[1]["code"], 404);
+
+        // Tous les exemples conservés : rien n'est compté comme appliqué
+        let mut keep_all = issues.clone();
+        keep_all[0].fix = Some(FixAction::PruneExamples { keep_indices: vec![0, 1] });
+        assert_eq!(apply_fixes(&mut collection, &keep_all), 0);
+    }
+
+    #[test]
+    fn test_set_header() {
+        let mut collection = json!({
+            "item": [{
+                "name": "POST Login",
+                "request": {
+                    "method": "POST",
+                    "header": [{ "key": "content-type", "value": "application/json" }],
+                    "body": { "mode": "urlencoded", "urlencoded": [] }
+                }
+            }]
+        });
+
+        let issues = vec![LintIssue::builder("body-mode-matches-content-type")
+            .message("Test")
+            .path("/item[0]/request/header")
+            .fix(FixAction::SetHeader {
+                header: "Content-Type".to_string(),
+                value: "application/x-www-form-urlencoded".to_string(),
+            })
+            .build()];
+
+        let fixes_applied = apply_fixes(&mut collection, &issues);
+
+        assert_eq!(fixes_applied, 1);
+        let headers = collection["item"][0]["request"]["header"].as_array().unwrap();
+        assert_eq!(headers.len(), 1);
+        assert_eq!(headers[0]["value"], "application/x-www-form-urlencoded");
+    }
+
+    #[test]
+    fn test_adjust_threshold_rewrites_the_reported_assertion() {
+        let mut collection = json!({
+            "item": [{
+                "name": "GET Users",
+                "request": { "method": "GET", "url": "https://api.example.com/users" },
+                "event": [{ "listen": "test", "script": { "exec": [
+                    "pm.expect(pm.response.responseTime).to.be.below(1500);",
+                    "pm.expect(pm.response.responseTime).to.be.below(300);"
+                ] } }]
+            }]
+        });
+        let config = response_time_threshold::ResponseTimeThresholdConfig { max_ms: 500 };
+        let issues = response_time_threshold::check_with_config(&collection, &LintContext::new(&collection), &config);
+        assert_eq!(issues.len(), 1);
+
+        assert_eq!(apply_fixes(&mut collection, &issues), 1);
+        let exec = &collection["item"][0]["event"][0]["script"]["exec"];
+        assert_eq!(exec[0], "pm.expect(pm.response.responseTime).to.be.below(500);");
+        assert_eq!(exec[1], "pm.expect(pm.response.responseTime).to.be.below(300);");
+        // Seuil déjà corrigé : rien à réécrire, le fix n'est pas compté
+        assert_eq!(apply_fixes(&mut collection, &issues), 0);
+    }
+
+    #[test]
+    fn test_disable_header() {
+        let mut collection = json!({
+            "item": [{
+                "name": "GET Users",
+                "request": {
+                    "method": "GET",
+                    "header": [{ "key": "X-Debug", "value": "true" }, { "key": "Accept", "value": "*/*" }]
+                }
+            }]
+        });
+
+        let fix = FixAction::DisableHeader { header: "x-debug".to_string() };
+
+        assert!(apply_single_fix(&mut collection, "/item[0]/request/header", &fix, Locale::Fr));
+        let headers = collection["item"][0]["request"]["header"].as_array().unwrap();
+        assert_eq!(headers[0]["disabled"], true);
+        assert!(headers[1].get("disabled").is_none());
+    }
+
+    #[test]
+    fn test_regenerate_endpoint_table() {
+        let mut collection = json!({
+            "item": [
+                { "name": "Users", "description": "Users API\n\n| Method | Path |\n|---|---|\n| POST | /users |\n\nOwner: team", "item": [] },
+                { "name": "Orders", "description": { "content": "Orders API", "type": "text/markdown" }, "item": [] }
+            ]
+        });
+        let fix = FixAction::RegenerateEndpointTable {
+            table: "| Method | Path |\n|--------|------|\n| GET | /users |".to_string(),
+        };
+
+        assert!(apply_single_fix(&mut collection, "/item[0]", &fix, Locale::Fr));
+        assert!(apply_single_fix(&mut collection, "/item[1]", &fix, Locale::Fr));
+        assert_eq!(
+            collection["item"][0]["description"],
+            "Users API\n\n| Method | Path |\n|--------|------|\n| GET | /users |\n\nOwner: team"
+        );
+        assert_eq!(
+            collection["item"][1]["description"]["content"],
+            "Orders API\n\n## Endpoints\n\n| Method | Path |\n|--------|------|\n| GET | /users |"
+        );
+    }
+
+    #[test]
+    fn test_upgrade_schema() {
+        let mut collection = json!({
+            "info": { "name": "Test", "schema": "https://schema.getpostman.com/json/collection/v2.0.0/collection.json" },
+            "item": []
+        });
+        let fix = FixAction::UpgradeSchema {
+            schema: "https://schema.getpostman.com/json/collection/v2.1.0/collection.json".to_string(),
+        };
+
+        assert!(apply_single_fix(&mut collection, "", &fix, Locale::Fr));
+        assert!(!apply_single_fix(&mut collection, "", &fix, Locale::Fr));
+        assert_eq!(collection["info"]["schema"], "https://schema.getpostman.com/json/collection/v2.1.0/collection.json");
+    }
+
+    #[test]
+    fn test_normalize_placeholder() {
+        let mut collection = json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "GET Users",
+                "request": {
+                    "method": "GET",
+                    "url": { "raw": "{base_url}}/users", "host": ["{base_url}}"] },
+                    "header": [{ "key": "X-Base", "value": "{base_url}}" }]
+                }
+            }]
+        });
+
+        let issues = crate::rules::best_practices::placeholder_syntax::check(&collection);
+        let fixes_applied = apply_fixes(&mut collection, &issues);
+
+        assert_eq!(fixes_applied, 1);
+        assert_eq!(collection["item"][0]["request"]["url"]["raw"], "{{base_url}}/users");
+        assert_eq!(collection["item"][0]["request"]["url"]["host"][0], "{{base_url}}");
+        assert_eq!(collection["item"][0]["request"]["header"][0]["value"], "{{base_url}}");
+    }
+
+    #[test]
+    fn test_unknown_fix_type_reported() {
+        let mut collection = json!({ "item": [{ "name": "GET Users", "request": { "method": "GET" } }] });
+        let issues = vec![
+            LintIssue::builder("custom-rule")
+                .path("/item[0]")
+                .fix(FixAction::Custom(json!({ "type": "rewrite_everything" })))
+                .build(),
+            LintIssue::builder("request-naming-convention")
+                .path("/item[0]")
+                .fix(FixAction::RenameRequest { suggested_name: "GET List Users".to_string() })
+                .build(),
+        ];
+
+        let report = apply_fixes_checked(&mut collection, &issues, Locale::default());
+
+        assert_eq!(report.applied, 1);
+        assert_eq!(
+            report.unknown,
+            vec![UnknownFix {
+                rule_id: "custom-rule".to_string(),
+                path: "/item[0]".to_string(),
+                fix_type: "rewrite_everything".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_builtin_rule_fixes_are_registered() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "users",
+                "request": {
+                    "method": "GET",
+                    "url": "https://api.example.com/users",
+                    "header": [{ "key": "X-Debug", "value": "true" }],
+                    "body": { "mode": "raw", "raw": "{}" }
+                },
+                "event": [{ "listen": "test", "script": { "exec": ["pm.test('ok', () => {});"] } }]
+            }]
+        });
+
+        let result = crate::run_linter(&collection, &crate::LintConfig::default());
+        let registry = FixRegistry::builtin();
+
+        assert!(result.issues.iter().any(|issue| issue.fix.is_some()));
+        for fix in result.issues.iter().filter_map(|issue| issue.fix.as_ref()) {
+            assert!(registry.is_registered(fix.fix_type()), "{:?}", fix);
+        }
+    }
 }
@@ -0,0 +1,219 @@
+//! Export du graphe de la collection (`--format mermaid|dot`)
+//!
+//! Arbre collection → folders → requêtes, pour afficher une vue d'architecture dans
+//! un site de documentation. En option, le flux des variables : un arc pointillé
+//! relie la requête qui écrit une variable (`pm.environment.set('token', ...)`,
+//! `pm.collectionVariables.set`...) à chaque requête qui la lit (`{{token}}` dans la
+//! requête ou `pm.*.get('token')` dans ses scripts).
+
+use crate::rule_api::{walk, ItemContext};
+use crate::utils;
+use regex::Regex;
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::sync::LazyLock;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphFormat {
+    Mermaid,
+    Dot,
+}
+
+impl GraphFormat {
+    pub fn parse(format: &str) -> Option<Self> {
+        match format {
+            "mermaid" => Some(GraphFormat::Mermaid),
+            "dot" => Some(GraphFormat::Dot),
+            _ => None,
+        }
+    }
+}
+
+enum NodeKind {
+    Collection,
+    Folder,
+    Request,
+}
+
+struct Node {
+    kind: NodeKind,
+    label: String,
+}
+
+/// Graphe de la collection : nœuds (indice 0 = collection), arbre et flux des variables
+struct Graph {
+    nodes: Vec<Node>,
+    tree: Vec<(usize, usize)>,
+    /// (écrivain, lecteur, variable)
+    variables: BTreeSet<(usize, usize, String)>,
+}
+
+/// Rend le graphe de la collection au format demandé
+pub fn render(collection: &Value, format: GraphFormat, include_variables: bool) -> String {
+    let graph = build(collection, include_variables);
+    match format {
+        GraphFormat::Mermaid => render_mermaid(&graph),
+        GraphFormat::Dot => render_dot(&graph),
+    }
+}
+
+static SET_VARIABLE: LazyLock<Regex> = LazyLock::new(|| {
+    utils::build_regex(r#"pm\.(?:environment|collectionVariables|globals|variables)\.set\(\s*['"]([^'"]+)['"]"#).unwrap()
+});
+
+static GET_VARIABLE: LazyLock<Regex> = LazyLock::new(|| {
+    utils::build_regex(r#"pm\.(?:environment|collectionVariables|globals|variables)\.get\(\s*['"]([^'"]+)['"]"#).unwrap()
+});
+
+static PLACEHOLDER: LazyLock<Regex> = LazyLock::new(|| utils::build_regex(r"\{\{([^{}\s]+)\}\}").unwrap());
+
+fn build(collection: &Value, include_variables: bool) -> Graph {
+
+    let name = collection["info"]["name"].as_str().unwrap_or("Collection");
+    let mut graph = Graph {
+        nodes: vec![Node { kind: NodeKind::Collection, label: name.to_string() }],
+        tree: Vec::new(),
+        variables: BTreeSet::new(),
+    };
+    // Nœud de chaque item par path, pour rattacher les enfants
+    let mut node_paths = vec![String::new()];
+    // (nœud, variables écrites, variables lues) par requête
+    let mut usages: Vec<(usize, BTreeSet<String>, BTreeSet<String>)> = Vec::new();
+
+    walk(collection, &mut |item: &ItemContext| {
+        let node = graph.nodes.len();
+        let parent_path = item.path.rsplit_once('/').map(|(parent, _)| parent).unwrap_or_default();
+        let parent = node_paths.iter().rposition(|path| path == parent_path).unwrap_or(0);
+        let (kind, label) = if item.is_request() {
+            (NodeKind::Request, format!("{} {}", item.method(), item.name))
+        } else {
+            (NodeKind::Folder, item.name.clone())
+        };
+        graph.nodes.push(Node { kind, label });
+        graph.tree.push((parent, node));
+        node_paths.push(item.path.clone());
+
+        if include_variables && item.is_request() {
+            let scripts = format!("{}\n{}", item.prerequest_script, item.test_script);
+            let captures = |pattern: &regex::Regex, text: &str| -> BTreeSet<String> {
+                pattern.captures_iter(text).map(|caps| caps[1].to_string()).collect()
+            };
+            let mut reads = captures(&GET_VARIABLE, &scripts);
+            reads.extend(captures(&PLACEHOLDER, &item.item["request"].to_string()));
+            usages.push((node, captures(&SET_VARIABLE, &scripts), reads));
+        }
+    });
+
+    for (writer, sets, _) in &usages {
+        for (reader, _, reads) in &usages {
+            if writer == reader {
+                continue;
+            }
+            for variable in sets.intersection(reads) {
+                graph.variables.insert((*writer, *reader, variable.clone()));
+            }
+        }
+    }
+    graph
+}
+
+fn render_mermaid(graph: &Graph) -> String {
+    let escape = |label: &str| label.replace('"', "#quot;");
+    let mut lines = vec!["flowchart LR".to_string()];
+    for (index, node) in graph.nodes.iter().enumerate() {
+        let label = escape(&node.label);
+        lines.push(match node.kind {
+            NodeKind::Collection => format!("    n{}[[\"{}\"]]", index, label),
+            NodeKind::Folder => format!("    n{}[\"📁 {}\"]", index, label),
+            NodeKind::Request => format!("    n{}(\"{}\")", index, label),
+        });
+    }
+    for (parent, child) in &graph.tree {
+        lines.push(format!("    n{} --> n{}", parent, child));
+    }
+    for (writer, reader, variable) in &graph.variables {
+        lines.push(format!("    n{} -. \"{}\" .-> n{}", writer, escape(variable), reader));
+    }
+    lines.join("\n") + "\n"
+}
+
+fn render_dot(graph: &Graph) -> String {
+    let escape = |label: &str| label.replace('\\', "\\\\").replace('"', "\\\"");
+    let mut lines = vec![
+        "digraph collection {".to_string(),
+        "    rankdir=LR;".to_string(),
+        "    node [fontname=\"Helvetica\"];".to_string(),
+    ];
+    for (index, node) in graph.nodes.iter().enumerate() {
+        let shape = match node.kind {
+            NodeKind::Collection => "shape=box, style=bold",
+            NodeKind::Folder => "shape=folder",
+            NodeKind::Request => "shape=box, style=rounded",
+        };
+        lines.push(format!("    n{} [label=\"{}\", {}];", index, escape(&node.label), shape));
+    }
+    for (parent, child) in &graph.tree {
+        lines.push(format!("    n{} -> n{};", parent, child));
+    }
+    for (writer, reader, variable) in &graph.variables {
+        lines.push(format!("    n{} -> n{} [label=\"{}\", style=dashed];", writer, reader, escape(variable)));
+    }
+    lines.push("}".to_string());
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn collection() -> Value {
+        json!({
+            "info": { "name": "Shop \"v2\"" },
+            "item": [
+                { "name": "Auth", "item": [{
+                    "name": "Login",
+                    "event": [{ "listen": "test", "script": { "exec": ["pm.environment.set('token', pm.response.json().token);"] } }],
+                    "request": { "method": "POST", "url": "{{base_url}}/login" }
+                }] },
+                { "name": "GET Orders", "request": {
+                    "method": "GET",
+                    "url": "{{base_url}}/orders",
+                    "header": [{ "key": "Authorization", "value": "Bearer {{token}}" }]
+                } }
+            ]
+        })
+    }
+
+    #[test]
+    fn test_mermaid_tree() {
+        let mermaid = render(&collection(), GraphFormat::Mermaid, false);
+        assert_eq!(
+            mermaid,
+            [
+                "flowchart LR",
+                "    n0[[\"Shop #quot;v2#quot;\"]]",
+                "    n1[\"📁 Auth\"]",
+                "    n2(\"POST Login\")",
+                "    n3(\"GET GET Orders\")",
+                "    n0 --> n1",
+                "    n1 --> n2",
+                "    n0 --> n3",
+                ""
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn test_dot_variable_flow() {
+        let dot = render(&collection(), GraphFormat::Dot, true);
+        assert!(dot.starts_with("digraph collection {"));
+        assert!(dot.contains("    n0 [label=\"Shop \\\"v2\\\"\", shape=box, style=bold];"));
+        assert!(dot.contains("    n1 -> n2;"));
+        assert!(dot.contains("    n2 -> n3 [label=\"token\", style=dashed];"));
+        // base_url n'est écrite par aucune requête
+        assert_eq!(dot.matches("dashed").count(), 1);
+        assert_eq!(GraphFormat::parse("svg"), None);
+    }
+}
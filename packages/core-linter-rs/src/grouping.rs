@@ -0,0 +1,169 @@
+//! Regroupement des issues par règle ou par item
+//!
+//! Les rapports (tableaux de bord, commentaires de merge request) présentent les
+//! issues par règle ou par requête plutôt qu'en liste plate. `group_by_rule` et
+//! `group_by_path` produisent ces résumés imbriqués (règle → items, item → règles),
+//! qui référencent les issues par leur index dans `LintResult::issues`. Avec
+//! `LintConfig::group_by`, le regroupement est aussi inclus dans le résultat sérialisé.
+
+use crate::ordering::compare_paths;
+use crate::relations::item_path;
+use crate::{LintIssue, LintResult};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Critère de regroupement
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum GroupBy {
+    /// Par règle, puis par item
+    Rule,
+    /// Par item (requête, folder ou `""` pour la collection), puis par règle
+    Path,
+}
+
+impl GroupBy {
+    /// Parse un critère ("rule", "path")
+    pub fn parse(value: &str) -> Option<GroupBy> {
+        match value.to_lowercase().as_str() {
+            "rule" => Some(GroupBy::Rule),
+            "path" => Some(GroupBy::Path),
+            _ => None,
+        }
+    }
+}
+
+/// Issues d'une règle ou d'un item
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct IssueGroup {
+    /// rule_id ou path de l'item
+    pub key: String,
+    pub count: usize,
+    /// Nombre d'issues par sévérité
+    pub severities: BTreeMap<String, usize>,
+    /// Sous-groupes : items d'une règle, ou règles d'un item
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub groups: Vec<IssueGroup>,
+    /// Index des issues dans `LintResult::issues` (sous-groupes uniquement)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub issues: Vec<usize>,
+}
+
+/// Regroupement inclus dans le résultat (`LintConfig::group_by`)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct IssueGroups {
+    pub by: GroupBy,
+    pub groups: Vec<IssueGroup>,
+}
+
+impl LintResult {
+    /// Issues par règle (la plus fréquente d'abord), puis par item
+    pub fn group_by_rule(&self) -> Vec<IssueGroup> {
+        let mut groups = group(&self.issues, |issue| issue.rule_id.as_str(), |issue| item_path(&issue.path));
+        groups.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.key.cmp(&b.key)));
+        groups
+    }
+
+    /// Issues par item (dans l'ordre de la collection), puis par règle
+    pub fn group_by_path(&self) -> Vec<IssueGroup> {
+        let mut groups = group(&self.issues, |issue| item_path(&issue.path), |issue| issue.rule_id.as_str());
+        groups.sort_by(|a, b| compare_paths(&a.key, &b.key));
+        groups
+    }
+
+    /// Regroupement selon le critère demandé
+    pub fn group_issues(&self, by: GroupBy) -> IssueGroups {
+        let groups = match by {
+            GroupBy::Rule => self.group_by_rule(),
+            GroupBy::Path => self.group_by_path(),
+        };
+        IssueGroups { by, groups }
+    }
+
+    /// Recalcule `groups` après une modification de la liste d'issues (baseline, shard)
+    pub(crate) fn regroup(&mut self) {
+        if let Some(by) = self.groups.as_ref().map(|groups| groups.by) {
+            self.groups = Some(self.group_issues(by));
+        }
+    }
+}
+
+/// Groupes de premier niveau par `outer`, sous-groupes par `inner` (ordre de première
+/// apparition pour les sous-groupes)
+fn group<'a>(
+    issues: &'a [LintIssue],
+    outer: impl Fn(&'a LintIssue) -> &'a str,
+    inner: impl Fn(&'a LintIssue) -> &'a str,
+) -> Vec<IssueGroup> {
+    let mut groups: Vec<IssueGroup> = Vec::new();
+    for (index, issue) in issues.iter().enumerate() {
+        let group = find_or_insert(&mut groups, outer(issue));
+        count(group, issue);
+        let subgroup = find_or_insert(&mut group.groups, inner(issue));
+        count(subgroup, issue);
+        subgroup.issues.push(index);
+    }
+    groups
+}
+
+fn find_or_insert<'g>(groups: &'g mut Vec<IssueGroup>, key: &str) -> &'g mut IssueGroup {
+    let position = match groups.iter().position(|group| group.key == key) {
+        Some(position) => position,
+        None => {
+            groups.push(IssueGroup { key: key.to_string(), ..Default::default() });
+            groups.len() - 1
+        }
+    };
+    &mut groups[position]
+}
+
+fn count(group: &mut IssueGroup, issue: &LintIssue) {
+    group.count += 1;
+    *group.severities.entry(issue.severity.clone()).or_default() += 1;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{run_linter, LintConfig};
+    use serde_json::json;
+
+    fn result() -> crate::LintResult {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": (0..11)
+                .map(|i| json!({ "name": format!("GET Item {}", i), "request": { "method": "GET", "url": format!("https://api.example.com/items/{}", i) } }))
+                .collect::<Vec<_>>()
+        });
+        let config = LintConfig {
+            local_only: true,
+            rules: Some(vec!["test-http-status-mandatory".to_string(), "environment-variables-usage".to_string()]),
+            ..Default::default()
+        };
+        run_linter(&collection, &config)
+    }
+
+    #[test]
+    fn test_group_by_rule() {
+        let result = result();
+        let groups = result.group_by_rule();
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].count, 11);
+        assert_eq!(groups[0].groups.len(), 11);
+        assert_eq!(groups.iter().map(|group| group.count).sum::<usize>(), result.issues.len());
+
+        let status = groups.iter().find(|group| group.key == "test-http-status-mandatory").unwrap();
+        assert_eq!(status.severities["error"], 11);
+        let first = &status.groups[0];
+        assert_eq!(result.issues[first.issues[0]].rule_id, "test-http-status-mandatory");
+    }
+
+    #[test]
+    fn test_group_by_path_in_collection_order() {
+        let result = result();
+        let groups = result.group_by_path();
+        let keys: Vec<&str> = groups.iter().take(3).map(|group| group.key.as_str()).collect();
+        assert_eq!(keys, vec!["/item[0]", "/item[1]", "/item[2]"]);
+        assert_eq!(groups.last().unwrap().key, "/item[10]");
+        assert_eq!(groups[0].groups.len(), 2);
+    }
+}
@@ -0,0 +1,198 @@
+//! Garde-fous de ressources
+//!
+//! Une regex pathologique sur un script de plusieurs Mo peut bloquer le module WASM
+//! (et l'onglet du navigateur). Avant d'exécuter les règles, on mesure la taille des
+//! entrées : les règles dont l'entrée dépasse la limite configurée sont ignorées et
+//! signalées ("rule skipped: input too large") au lieu de geler le moteur.
+
+use crate::rules::RuleInput;
+use crate::utils;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Limites de taille des entrées (en octets)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct ResourceLimits {
+    /// Au-delà, aucune règle n'est exécutée
+    pub max_collection_bytes: usize,
+    /// Taille maximale des scripts d'un item (test + pre-request)
+    pub max_script_bytes: usize,
+    /// Taille maximale d'une requête sérialisée
+    pub max_request_bytes: usize,
+}
+
+impl Default for ResourceLimits {
+    fn default() -> Self {
+        ResourceLimits {
+            max_collection_bytes: 20 * 1024 * 1024,
+            max_script_bytes: 512 * 1024,
+            max_request_bytes: 2 * 1024 * 1024,
+        }
+    }
+}
+
+/// Règle non exécutée et raison
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SkippedRule {
+    pub rule_id: String,
+    pub reason: String,
+}
+
+/// Mesures de la collection, calculées une fois avant l'exécution des règles
+pub struct ResourceGuard {
+    collection_too_large: Option<String>,
+    script_too_large: Option<String>,
+    request_too_large: Option<String>,
+}
+
+impl ResourceGuard {
+    pub fn new(collection: &Value, limits: &ResourceLimits) -> Self {
+        let collection_bytes = serde_json::to_string(collection).map(|s| s.len()).unwrap_or(0);
+        let collection_too_large = (collection_bytes > limits.max_collection_bytes).then(|| {
+            format!(
+                "rule skipped: input too large (collection is {} bytes, max_collection_bytes = {})",
+                collection_bytes, limits.max_collection_bytes
+            )
+        });
+
+        let mut largest_script = (0, String::from("/"));
+        let mut largest_request = (0, String::from("/"));
+        if collection_too_large.is_none() {
+            largest_script.0 = script_bytes(collection);
+            if let Some(items) = collection["item"].as_array() {
+                measure_items(items, &mut largest_script, &mut largest_request, "");
+            }
+        }
+
+        ResourceGuard {
+            collection_too_large,
+            script_too_large: (largest_script.0 > limits.max_script_bytes).then(|| {
+                format!(
+                    "rule skipped: input too large (scripts of {} are {} bytes, max_script_bytes = {})",
+                    largest_script.1, largest_script.0, limits.max_script_bytes
+                )
+            }),
+            request_too_large: (largest_request.0 > limits.max_request_bytes).then(|| {
+                format!(
+                    "rule skipped: input too large (request {} is {} bytes, max_request_bytes = {})",
+                    largest_request.1, largest_request.0, limits.max_request_bytes
+                )
+            }),
+        }
+    }
+
+    /// Raison pour laquelle une règle ne doit pas être exécutée, le cas échéant
+    pub fn skip_reason(&self, rule_id: &str) -> Option<&str> {
+        if let Some(reason) = &self.collection_too_large {
+            return Some(reason);
+        }
+        // Les règles externes ne déclarent pas leurs entrées : seule la taille totale compte
+        let inputs = crate::rules::find(rule_id).map(|rule| rule.inputs()).unwrap_or_default();
+        if inputs.contains(&RuleInput::Scripts) {
+            if let Some(reason) = &self.script_too_large {
+                return Some(reason);
+            }
+        }
+        if inputs.contains(&RuleInput::RequestContent) {
+            if let Some(reason) = &self.request_too_large {
+                return Some(reason);
+            }
+        }
+        None
+    }
+
+    /// Vrai si les scripts peuvent être analysés sans risque (matrice de couverture...)
+    pub fn scripts_within_limits(&self) -> bool {
+        self.collection_too_large.is_none() && self.script_too_large.is_none()
+    }
+}
+
+fn script_bytes(item: &Value) -> usize {
+    utils::extract_test_scripts(item)
+        .iter()
+        .chain(utils::extract_prerequest_scripts(item).iter())
+        .map(|script| script.len())
+        .sum()
+}
+
+fn measure_items(
+    items: &[Value],
+    largest_script: &mut (usize, String),
+    largest_request: &mut (usize, String),
+    parent_path: &str,
+) {
+    for (index, item) in items.iter().enumerate() {
+        let current_path = if parent_path.is_empty() {
+            format!("/item[{}]", index)
+        } else {
+            format!("{}/item[{}]", parent_path, index)
+        };
+
+        let scripts = script_bytes(item);
+        if scripts > largest_script.0 {
+            *largest_script = (scripts, current_path.clone());
+        }
+
+        if let Some(request) = item.get("request") {
+            let request_bytes = serde_json::to_string(request).map(|s| s.len()).unwrap_or(0);
+            if request_bytes > largest_request.0 {
+                *largest_request = (request_bytes, current_path.clone());
+            }
+        }
+
+        // Si c'est un folder, récurser
+        if let Some(sub_items) = item["item"].as_array() {
+            measure_items(sub_items, largest_script, largest_request, &current_path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn collection_with_script(script: &str) -> Value {
+        json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "GET Users",
+                "request": { "method": "GET", "url": "{{base_url}}/users" },
+                "event": [{ "listen": "test", "script": { "exec": [script] } }]
+            }]
+        })
+    }
+
+    #[test]
+    fn test_large_script_skips_script_rules_only() {
+        let collection = collection_with_script(&"a".repeat(2048));
+        let limits = ResourceLimits { max_script_bytes: 1024, ..Default::default() };
+
+        let guard = ResourceGuard::new(&collection, &limits);
+
+        let reason = guard.skip_reason("test-http-status-mandatory").unwrap();
+        assert!(reason.starts_with("rule skipped: input too large"));
+        assert!(reason.contains("/item[0]"));
+        assert!(guard.skip_reason("request-naming-convention").is_none());
+        assert!(!guard.scripts_within_limits());
+    }
+
+    #[test]
+    fn test_oversized_collection_skips_everything() {
+        let collection = collection_with_script("pm.test('ok', () => {});");
+        let limits = ResourceLimits { max_collection_bytes: 10, ..Default::default() };
+
+        let guard = ResourceGuard::new(&collection, &limits);
+
+        assert!(guard.skip_reason("request-naming-convention").is_some());
+    }
+
+    #[test]
+    fn test_default_limits_allow_regular_collections() {
+        let guard = ResourceGuard::new(&collection_with_script("pm.test('ok', () => {});"), &ResourceLimits::default());
+
+        assert!(guard.skip_reason("hardcoded-secrets").is_none());
+        assert!(guard.scripts_within_limits());
+    }
+}
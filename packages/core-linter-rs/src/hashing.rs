@@ -0,0 +1,75 @@
+//! Empreintes de contenu par item
+//!
+//! `hash_items` retourne un hash stable du contenu de chaque item (requête ou
+//! folder, sous-arbre compris), indexé par path. Comparer deux séries de hashes
+//! suffit pour savoir quelles requêtes ont changé entre deux uploads, sans
+//! comparer les JSON complets côté client.
+
+use crate::utils;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Hash du contenu de chaque item, par path (`/item[0]/item[2]`)
+pub fn hash_items(collection: &Value) -> BTreeMap<String, String> {
+    let mut hashes = BTreeMap::new();
+    if let Some(items) = collection["item"].as_array() {
+        hash_level(items, &mut hashes, "");
+    }
+    hashes
+}
+
+/// Hash stable d'une valeur JSON : les clés des objets sont sérialisées triées
+/// (`serde_json::Map` sans `preserve_order`), l'ordre d'origine n'influe donc pas
+pub fn content_hash(value: &Value) -> String {
+    utils::stable_hash(value.to_string().into_bytes())
+}
+
+fn hash_level(items: &[Value], hashes: &mut BTreeMap<String, String>, parent_path: &str) {
+    for (index, item) in items.iter().enumerate() {
+        let current_path = format!("{}/item[{}]", parent_path, index);
+        hashes.insert(current_path.clone(), content_hash(item));
+
+        // Si c'est un folder, récurser
+        if let Some(sub_items) = item["item"].as_array() {
+            hash_level(sub_items, hashes, &current_path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_only_changed_items_change_hash() {
+        let before = json!({
+            "item": [{
+                "name": "Users",
+                "item": [
+                    { "name": "GET Users", "request": { "method": "GET", "url": "{{base_url}}/users" } },
+                    { "name": "POST Users", "request": { "method": "POST", "url": "{{base_url}}/users" } }
+                ]
+            }]
+        });
+        let mut after = before.clone();
+        after["item"][0]["item"][1]["request"]["url"] = json!("{{base_url}}/v2/users");
+
+        let (old, new) = (hash_items(&before), hash_items(&after));
+
+        assert_eq!(old.len(), 3);
+        assert_eq!(old["/item[0]/item[0]"], new["/item[0]/item[0]"]);
+        assert_ne!(old["/item[0]/item[1]"], new["/item[0]/item[1]"]);
+        // Le folder englobe ses enfants
+        assert_ne!(old["/item[0]"], new["/item[0]"]);
+    }
+
+    #[test]
+    fn test_hash_ignores_key_order() {
+        let a: Value = serde_json::from_str(r#"{"name": "GET Users", "request": {"method": "GET", "url": "/users"}}"#).unwrap();
+        let b: Value = serde_json::from_str(r#"{"request": {"url": "/users", "method": "GET"}, "name": "GET Users"}"#).unwrap();
+
+        assert_eq!(content_hash(&a), content_hash(&b));
+        assert_eq!(content_hash(&a).len(), 16);
+    }
+}
@@ -0,0 +1,708 @@
+//! Catalogue de traductions
+//!
+//! Centralise les textes dépendant de la langue (commentaires des snippets de code
+//! injectés par les fixes, messages des règles) ainsi que le formatage des nombres,
+//! pourcentages et durées affichés dans les messages : les paramètres restent des
+//! valeurs brutes (nombres compris) et ne sont formatés qu'au rendu.
+//!
+//! Chaque forme de message est déclarée dans `MESSAGES` (identifiant + gabarit par
+//! langue). Les règles émettent l'identifiant et les paramètres de leurs messages,
+//! dont le texte est rendu ici : en anglais à la construction de l'issue, puis dans
+//! la langue demandée par le moteur. Ajouter une langue revient à ajouter une
+//! colonne au catalogue.
+
+use crate::rule_api::IssueBuilder;
+use crate::LintIssue;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Langue de sortie des rapports et du code suggéré
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    #[default]
+    Fr,
+    En,
+}
+
+impl Locale {
+    /// Parse un code de langue ("fr", "en", "en-US"...)
+    pub fn parse(code: &str) -> Option<Locale> {
+        let lang = code.split(['-', '_']).next().unwrap_or("").to_lowercase();
+        match lang.as_str() {
+            "fr" => Some(Locale::Fr),
+            "en" => Some(Locale::En),
+            _ => None,
+        }
+    }
+}
+
+/// Retourne le texte associé à une clé du catalogue dans la langue demandée
+pub fn t(locale: Locale, key: &str) -> &'static str {
+    match (key, locale) {
+        // Commentaires des snippets de code
+        ("snippet.location_comment", Locale::Fr) => "// Définir la variable location pour les tests",
+        ("snippet.location_comment", Locale::En) => "// Define the location variable for tests",
+        ("snippet.schema_comment", Locale::Fr) => "// Définir le schéma JSON attendu",
+        ("snippet.schema_comment", Locale::En) => "// Define the expected JSON schema",
+        ("snippet.schema_properties_comment", Locale::Fr) => "// Définir les propriétés attendues",
+        ("snippet.schema_properties_comment", Locale::En) => "// Define the expected properties",
+        ("snippet.schema_test_comment", Locale::Fr) => "// Test de validation de schéma",
+        ("snippet.schema_test_comment", Locale::En) => "// Schema validation test",
+        _ => "",
+    }
+}
+
+/// Forme d'un message de règle : identifiant stable et gabarit par langue.
+/// Les paramètres s'écrivent `{nom}`, ou `{nom:format}` pour un nombre à afficher
+/// comme pourcentage (`percent`, `percent.1` pour une décimale) ou comme durée en
+/// millisecondes (`duration`) ; `{{...}}` reste littéral (variables Postman).
+#[derive(Debug, Clone, Copy)]
+pub struct Message {
+    pub id: &'static str,
+    pub en: &'static str,
+    pub fr: &'static str,
+}
+
+impl Message {
+    pub fn template(&self, locale: Locale) -> &'static str {
+        match locale {
+            Locale::Fr => self.fr,
+            Locale::En => self.en,
+        }
+    }
+}
+
+const fn msg(id: &'static str, en: &'static str, fr: &'static str) -> Message {
+    Message { id, en, fr }
+}
+
+/// Catalogue des messages des règles intégrées
+pub const MESSAGES: &[Message] = &[
+    // Testing
+    msg(
+        "test-http-status-mandatory.missing",
+        r#"Request '{request}' does not test the HTTP status code"#,
+        r#"La requête '{request}' ne teste pas le code de statut HTTP"#,
+    ),
+    msg(
+        "test-response-time-mandatory.missing",
+        r#"⏱️ Request "{request}" is missing response time test"#,
+        r#"⏱️ La requête "{request}" n'a pas de test de temps de réponse"#,
+    ),
+    msg(
+        "test-body-content-validation.missing",
+        r#"⚠️ Request "{request}" should validate response content (body, properties, schema)"#,
+        r#"⚠️ La requête "{request}" devrait valider le contenu de la réponse (body, propriétés, schéma)"#,
+    ),
+    msg(
+        "test-schema-validation-recommended.missing",
+        r#"🛡️ Request "{request}" should use JSON schema validation to improve test robustness"#,
+        r#"🛡️ La requête "{request}" devrait valider la réponse avec un schéma JSON pour fiabiliser ses tests"#,
+    ),
+    msg(
+        "undefined-helper-calls.undefined",
+        r#"🧩 Script of "{item}" calls {helper}() which is not defined in its scripts or in any parent folder or collection script"#,
+        r#"🧩 Le script de "{item}" appelle {helper}(), définie ni dans ses scripts ni dans ceux de ses folders parents ou de la collection"#,
+    ),
+    msg(
+        "test-description-with-uri.location-variable",
+        r#"🎯 Test "{test}" in "{request}" should include a path segment (ex: "{segment}") or use the location/requestName variable"#,
+        r#"🎯 Le test "{test}" de "{request}" devrait inclure un segment du chemin (ex : "{segment}") ou utiliser la variable location/requestName"#,
+    ),
+    msg(
+        "test-description-with-uri.path-variable",
+        r#"🎯 Test "{test}" in "{request}" should include a path segment (ex: "{segment}") or use the {variables} variable"#,
+        r#"🎯 Le test "{test}" de "{request}" devrait inclure un segment du chemin (ex : "{segment}") ou utiliser la variable {variables}"#,
+    ),
+    // Documentation
+    msg(
+        "collection-overview-template.missing-section",
+        r#"❌ Missing documentation section: "{section}""#,
+        r#"❌ Section de documentation manquante : "{section}""#,
+    ),
+    msg(
+        "collection-overview-template.missing-metadata",
+        r#"📋 Missing metadata: "{metadata}" is not in the documentation"#,
+        r#"📋 Métadonnée manquante : "{metadata}" non présente dans la documentation"#,
+    ),
+    msg(
+        "collection-overview-template.incomplete-metadata",
+        r#"📋 Incomplete metadata: "{metadata}" is present but empty"#,
+        r#"📋 Métadonnée incomplète : "{metadata}" est présente mais vide"#,
+    ),
+    msg(
+        "collection-overview-template.description-too-short",
+        r#"📝 Collection description too short (minimum {min} characters required)"#,
+        r#"📝 Description de collection trop courte (minimum {min} caractères requis)"#,
+    ),
+    msg(
+        "request-examples-required.no-examples",
+        r#"📋 Request "{request}" has no response examples"#,
+        r#"📋 La requête "{request}" n'a pas d'exemple de réponse"#,
+    ),
+    msg(
+        "request-examples-required.missing-name",
+        r#"🏷️ Example #{number} for "{request}" is missing name"#,
+        r#"🏷️ L'exemple n°{number} de "{request}" n'a pas de nom"#,
+    ),
+    msg(
+        "request-examples-required.missing-content",
+        r#"📄 Example #{number} for "{request}" is missing content"#,
+        r#"📄 L'exemple n°{number} de "{request}" n'a pas de contenu"#,
+    ),
+    msg(
+        "request-examples-required.undocumented-params",
+        r#"📝 Request "{request}" has undocumented parameters: {params}"#,
+        r#"📝 La requête "{request}" a des paramètres non documentés : {params}"#,
+    ),
+    msg(
+        "example-count-limits.too-many",
+        r#"📚 Request "{request}" has {count} saved examples (maximum {max} recommended, keep one per status code)"#,
+        r#"📚 La requête "{request}" a {count} exemples enregistrés (maximum {max} recommandé, gardez-en un par code de statut)"#,
+    ),
+    msg(
+        "example-header-consistency.html-error-page",
+        r#"📄 Example "{example}" of request "{request}" is saved as a success but its body is an HTML error page"#,
+        r#"📄 L'exemple "{example}" de la requête "{request}" est enregistré comme un succès mais son body est une page d'erreur HTML"#,
+    ),
+    msg(
+        "example-header-consistency.content-type",
+        r#"📄 Example "{example}" of request "{request}" has a JSON body but declares Content-Type "{content_type}" (expected application/json)"#,
+        r#"📄 L'exemple "{example}" de la requête "{request}" a un body JSON mais déclare le Content-Type "{content_type}" (application/json attendu)"#,
+    ),
+    msg(
+        "example-url-hygiene.absolute-host",
+        r#"🔗 Example "{example}" of request "{request}" shows {example_host} while the request uses {request_host}"#,
+        r#"🔗 L'exemple "{example}" de la requête "{request}" affiche {example_host} alors que la requête utilise {request_host}"#,
+    ),
+    msg(
+        "error-body-convention.missing-fields",
+        r#"🧾 Error example "{example}" ({code}) of request "{request}" misses {fields} (expected error envelope: {envelope})"#,
+        r#"🧾 L'exemple d'erreur "{example}" ({code}) de la requête "{request}" ne contient pas {fields} (enveloppe d'erreur attendue : {envelope})"#,
+    ),
+    msg(
+        "error-body-convention.no-body",
+        r#"🧾 Error example "{example}" ({code}) of request "{request}" has no body (expected error envelope: {envelope})"#,
+        r#"🧾 L'exemple d'erreur "{example}" ({code}) de la requête "{request}" n'a pas de body (enveloppe d'erreur attendue : {envelope})"#,
+    ),
+    msg(
+        "error-body-convention.not-json-object",
+        r#"🧾 Error example "{example}" ({code}) of request "{request}" is not a JSON object (expected error envelope: {envelope})"#,
+        r#"🧾 L'exemple d'erreur "{example}" ({code}) de la requête "{request}" n'est pas un objet JSON (enveloppe d'erreur attendue : {envelope})"#,
+    ),
+    msg(
+        "deprecation-metadata.missing",
+        r#"🌅 Deprecated item "{item}" does not state its sunset date and replacement; add "Deprecated: <YYYY-MM-DD> → <replacement path>" to its description"#,
+        r#"🌅 L'item déprécié "{item}" n'indique ni sa date de retrait ni son remplaçant ; ajoutez "Deprecated: <YYYY-MM-DD> → <replacement path>" à sa description"#,
+    ),
+    msg(
+        "deprecation-metadata.invalid-date",
+        r#"🌅 Deprecated item "{item}" has an invalid sunset date "{date}" (expected YYYY-MM-DD); add "Deprecated: <YYYY-MM-DD> → <replacement path>" to its description"#,
+        r#"🌅 L'item déprécié "{item}" a une date de retrait invalide "{date}" (YYYY-MM-DD attendu) ; ajoutez "Deprecated: <YYYY-MM-DD> → <replacement path>" à sa description"#,
+    ),
+    msg(
+        "folder-summary-accuracy.not-listed",
+        r#"📑 Folder "{folder}" description does not list its {count} endpoints (method + path table)"#,
+        r#"📑 La description du folder "{folder}" ne liste pas ses {count} endpoints (tableau méthode + chemin)"#,
+    ),
+    msg(
+        "folder-summary-accuracy.out-of-date",
+        r#"📑 Folder "{folder}" endpoint table is out of date (undocumented: {added}; no longer in the folder: {removed})"#,
+        r#"📑 Le tableau des endpoints du folder "{folder}" n'est plus à jour (non documentés : {added} ; absents du folder : {removed})"#,
+    ),
+    msg(
+        "folder-summary-accuracy.undocumented",
+        r#"📑 Folder "{folder}" endpoint table is out of date (undocumented: {added})"#,
+        r#"📑 Le tableau des endpoints du folder "{folder}" n'est plus à jour (non documentés : {added})"#,
+    ),
+    msg(
+        "folder-summary-accuracy.removed",
+        r#"📑 Folder "{folder}" endpoint table is out of date (no longer in the folder: {removed})"#,
+        r#"📑 Le tableau des endpoints du folder "{folder}" n'est plus à jour (absents du folder : {removed})"#,
+    ),
+    // Structure
+    msg(
+        "request-naming-convention.method-prefix",
+        r#"📝 Request "{request}" should start with the HTTP method (ex: "{method} {request}")"#,
+        r#"📝 La requête "{request}" devrait commencer par la méthode HTTP (ex : "{method} {request}")"#,
+    ),
+    msg(
+        "allowed-methods.not-allowed",
+        r#"🚫 Request "{request}" uses {method} which is not an allowed method (allowed: {allowed})"#,
+        r#"🚫 La requête "{request}" utilise {method}, qui ne fait pas partie des méthodes autorisées ({allowed})"#,
+    ),
+    msg(
+        "no-body-on-get.body",
+        r#"📦 Request "{request}" sends a body with {method} (some gateways drop it silently)"#,
+        r#"📦 La requête "{request}" envoie un body avec {method} (certaines gateways l'ignorent silencieusement)"#,
+    ),
+    msg(
+        "body-mode-matches-content-type.mismatch",
+        r#"🧾 Request "{request}" sends a {mode} body with Content-Type "{content_type}" (expected "{expected}")"#,
+        r#"🧾 La requête "{request}" envoie un body {mode} avec le Content-Type "{content_type}" ("{expected}" attendu)"#,
+    ),
+    msg(
+        "name-matches-resource.mismatch",
+        r#"🔤 Request "{request}" refers to {words} but its URL path "{url_path}" does not (renamed after a copy?)"#,
+        r#"🔤 La requête "{request}" mentionne {words} mais pas son chemin d'URL "{url_path}" (renommée après une copie ?)"#,
+    ),
+    msg(
+        "method-url-semantics.get-on-action",
+        r#"🔀 Request "{request}" uses GET on an action path "{url_path}" (a state-changing verb usually calls for POST/PUT/DELETE)"#,
+        r#"🔀 La requête "{request}" utilise GET sur un chemin d'action "{url_path}" (un verbe qui modifie l'état appelle plutôt POST/PUT/DELETE)"#,
+    ),
+    msg(
+        "method-url-semantics.post-without-body",
+        r#"🔀 Request "{request}" uses POST without body on a read-only path "{url_path}" (consider GET)"#,
+        r#"🔀 La requête "{request}" utilise POST sans body sur un chemin en lecture seule "{url_path}" (envisager GET)"#,
+    ),
+    msg(
+        "disabled-items.too-many",
+        r#"💤 {count} disabled items found (max {max}): delete them or re-enable their tests"#,
+        r#"💤 {count} items désactivés trouvés (max {max}) : supprimez-les ou réactivez leurs tests"#,
+    ),
+    msg(
+        "disabled-items.name-marked",
+        r#"💤 "{item}" is disabled (name marked as disabled)"#,
+        r#"💤 "{item}" est désactivé (nom marqué comme désactivé)"#,
+    ),
+    msg(
+        "disabled-items.tests-commented",
+        r#"💤 "{item}" is disabled (all tests commented out)"#,
+        r#"💤 "{item}" est désactivé (tous ses tests sont commentés)"#,
+    ),
+    msg(
+        "locale-header-consistency.invalid",
+        r#"🌐 Request "{request}" sends an invalid Accept-Language value ({values}); use BCP-47 tags like "fr-FR""#,
+        r#"🌐 La requête "{request}" envoie une valeur Accept-Language invalide ({values}) ; utilisez des tags BCP-47 comme "fr-FR""#,
+    ),
+    msg(
+        "locale-header-consistency.inconsistent",
+        r#"🌐 Request "{request}" sends Accept-Language "{value}" while most requests send "{dominant}"; drive it from a variable such as {{accept_language}}"#,
+        r#"🌐 La requête "{request}" envoie Accept-Language "{value}" alors que la plupart des requêtes envoient "{dominant}" ; pilotez-le par une variable comme {{accept_language}}"#,
+    ),
+    msg(
+        "no-personal-markers.request",
+        r#"🏷️ Request "{name}" contains personal markers ({markers}); use a name describing the API behaviour"#,
+        r#"🏷️ La requête "{name}" contient des marqueurs personnels ({markers}) ; utilisez un nom qui décrit le comportement de l'API"#,
+    ),
+    msg(
+        "no-personal-markers.folder",
+        r#"🏷️ Folder "{name}" contains personal markers ({markers}); use a name describing the API behaviour"#,
+        r#"🏷️ Le folder "{name}" contient des marqueurs personnels ({markers}) ; utilisez un nom qui décrit le comportement de l'API"#,
+    ),
+    msg(
+        "no-personal-markers.request-ticket-only",
+        r#"🏷️ Request "{name}" is only named after a ticket; add a descriptive name or a description"#,
+        r#"🏷️ La requête "{name}" ne porte que le nom d'un ticket ; donnez-lui un nom descriptif ou une description"#,
+    ),
+    msg(
+        "no-personal-markers.folder-ticket-only",
+        r#"🏷️ Folder "{name}" is only named after a ticket; add a descriptive name or a description"#,
+        r#"🏷️ Le folder "{name}" ne porte que le nom d'un ticket ; donnez-lui un nom descriptif ou une description"#,
+    ),
+    msg(
+        "required-folders.missing",
+        r#"📁 Required folder "{folder}" is missing at the root of the collection"#,
+        r#"📁 Le folder obligatoire "{folder}" est absent de la racine de la collection"#,
+    ),
+    msg(
+        "required-folders.too-few-requests",
+        r#"📁 Folder "{folder}" contains {count} requests (at least {min} required)"#,
+        r#"📁 Le folder "{folder}" contient {count} requêtes (au moins {min} requises)"#,
+    ),
+    msg(
+        "schema-version-current.insecure-url",
+        r#"🗂️ Collection schema URL "{schema}" uses http instead of https"#,
+        r#"🗂️ L'URL du schéma de la collection "{schema}" utilise http au lieu de https"#,
+    ),
+    msg(
+        "schema-version-current.legacy-auth",
+        r#"🗂️ Collection uses the v2.0.0 schema ("{schema}") and {count} auth blocks in the v2.0 layout; convert them to key/value lists before upgrading to v2.1.0"#,
+        r#"🗂️ La collection utilise le schéma v2.0.0 ("{schema}") et {count} blocs d'auth au format v2.0 ; convertissez-les en listes clé/valeur avant de passer en v2.1.0"#,
+    ),
+    msg(
+        "schema-version-current.outdated",
+        r#"🗂️ Collection uses the v2.0.0 schema ("{schema}"), upgrade to v2.1.0"#,
+        r#"🗂️ La collection utilise le schéma v2.0.0 ("{schema}"), passez en v2.1.0"#,
+    ),
+    // Flow
+    msg(
+        "unreachable-requests.unreachable",
+        r#"🔀 Request "{request}" can never run: no setNextRequest flow reaches it"#,
+        r#"🔀 La requête "{request}" ne peut jamais s'exécuter : aucun enchaînement setNextRequest n'y mène"#,
+    ),
+    // Best practices
+    msg(
+        "environment-variables-usage.hardcoded-url",
+        r#"🔧 Request "{request}" should use an environment variable for the URL (ex: {{base_url}})"#,
+        r#"🔧 La requête "{request}" devrait utiliser une variable d'environnement pour l'URL (ex : {{base_url}})"#,
+    ),
+    msg(
+        "test-coverage-minimum.insufficient",
+        r#"📊 Insufficient test coverage: {coverage:percent.1} ({tested}/{total} requests tested). Recommended minimum: {minimum:percent}"#,
+        r#"📊 Couverture de tests insuffisante : {coverage:percent.1} ({tested}/{total} requêtes testées). Minimum recommandé : {minimum:percent}"#,
+    ),
+    msg(
+        "placeholder-syntax.malformed",
+        r#"🧩 Request "{request}" uses malformed variable "{found}" (Postman will not resolve it, use "{expected}")"#,
+        r#"🧩 La requête "{request}" utilise la variable mal formée "{found}" (Postman ne la résoudra pas, utilisez "{expected}")"#,
+    ),
+    msg(
+        "deterministic-test-data.random",
+        r#"🎲 "{request}" generates non-reproducible test data with Math.random()/_.random() in its pre-request script (use pm.variables.replaceIn('{{$randomInt}}') or a seeded helper)"#,
+        r#"🎲 "{request}" génère des données de test non reproductibles avec Math.random()/_.random() dans son script de pré-requête (utilisez pm.variables.replaceIn('{{$randomInt}}') ou un générateur à graine)"#,
+    ),
+    msg(
+        "token-refresh-pattern.every-request",
+        r#"🔄 "{request}" fetches a new token before every request; store its expiry and only refresh when it has expired"#,
+        r#"🔄 "{request}" récupère un nouveau token avant chaque requête ; stockez son expiration et ne le renouvelez qu'une fois expiré"#,
+    ),
+    msg(
+        "variable-scope-appropriate.secret-in-shared-scope",
+        r#"🗂️ "{request}" stores secret "{variable}" in pm.{scope} (exported with the collection); use the environment scope"#,
+        r#"🗂️ "{request}" stocke le secret "{variable}" dans pm.{scope} (exporté avec la collection) ; utilisez le scope environment"#,
+    ),
+    msg(
+        "variable-scope-appropriate.local-only",
+        r#"🗂️ "{request}" sets "{variable}" in pm.{scope} but only reads it within the same request; use pm.variables"#,
+        r#"🗂️ "{request}" définit "{variable}" dans pm.{scope} mais ne la lit que dans la même requête ; utilisez pm.variables"#,
+    ),
+    msg(
+        "variable-scope-appropriate.shared-via-variables",
+        r#"🗂️ "{request}" shares "{variable}" with other requests through pm.variables; use pm.collectionVariables"#,
+        r#"🗂️ "{request}" partage "{variable}" avec d'autres requêtes via pm.variables ; utilisez pm.collectionVariables"#,
+    ),
+    msg(
+        "variable-cleanup.environment",
+        r#"🧹 Environment variable "{variable}" set by "{item}" is never unset; add a final cleanup calling pm.environment.unset("{variable}") so runs on a shared environment do not leak state"#,
+        r#"🧹 La variable d'environnement "{variable}" définie par "{item}" n'est jamais retirée ; ajoutez un nettoyage final appelant pm.environment.unset("{variable}") pour que les exécutions sur un environnement partagé ne se transmettent pas leur état"#,
+    ),
+    msg(
+        "variable-cleanup.globals",
+        r#"🧹 Global variable "{variable}" set by "{item}" is never unset; add a final cleanup calling pm.globals.unset("{variable}") so runs on a shared environment do not leak state"#,
+        r#"🧹 La variable globale "{variable}" définie par "{item}" n'est jamais retirée ; ajoutez un nettoyage final appelant pm.globals.unset("{variable}") pour que les exécutions sur un environnement partagé ne se transmettent pas leur état"#,
+    ),
+    // Performance
+    msg(
+        "response-time-threshold.too-high",
+        r#"⏱️ Request "{request}" has response time threshold too high ({threshold:duration} > {max:duration} recommended)"#,
+        r#"⏱️ La requête "{request}" a un seuil de temps de réponse trop élevé ({threshold:duration} > {max:duration} recommandé)"#,
+    ),
+    msg(
+        "slo-alignment.above-slo",
+        r#"🎯 "{request}" asserts a response time below {threshold:duration}, above the {slo:duration} SLO declared by folder "{folder}""#,
+        r#"🎯 "{request}" vérifie un temps de réponse inférieur à {threshold:duration}, au-delà du SLO de {slo:duration} déclaré par le folder "{folder}""#,
+    ),
+    msg(
+        "slo-alignment.missing-slo",
+        r#"🎯 Folder "{folder}" does not declare an SLO (add "SLO: <n>ms" to its description)"#,
+        r#"🎯 Le folder "{folder}" ne déclare pas de SLO (ajoutez "SLO: <n>ms" à sa description)"#,
+    ),
+    // Security
+    msg(
+        "hardcoded-secrets.found",
+        r#"🔒 Hardcoded {secret_type} detected "{preview}" in '{request}' - Use environment variables ({suggestion})"#,
+        r#"🔒 {secret_type} hardcodé détecté "{preview}" dans '{request}' - Utilisez des variables d'environnement ({suggestion})"#,
+    ),
+    msg(
+        "auth-required-for-hosts.missing",
+        r#"🔒 Request "{request}" targets protected host "{host}" (matches "{pattern}") without any authentication"#,
+        r#"🔒 La requête "{request}" cible l'hôte protégé "{host}" (motif "{pattern}") sans aucune authentification"#,
+    ),
+    msg(
+        "debug-headers.found",
+        r#"🐞 Request "{request}" sends debug/test header "{header}" (disable or remove it before sharing)"#,
+        r#"🐞 La requête "{request}" envoie le header de debug/test "{header}" (désactivez-le ou supprimez-le avant de partager)"#,
+    ),
+    msg(
+        "internal-hosts-disclosure.url",
+        r#"🏢 Request "{request}" discloses internal hosts in its URL: {hosts}"#,
+        r#"🏢 La requête "{request}" expose des hôtes internes dans son URL : {hosts}"#,
+    ),
+    msg(
+        "internal-hosts-disclosure.example",
+        r#"🏢 Request "{request}" discloses internal hosts in its example: {hosts}"#,
+        r#"🏢 La requête "{request}" expose des hôtes internes dans un exemple : {hosts}"#,
+    ),
+    msg(
+        "test-payload-residue.found",
+        r#"🧨 Request "{request}" contains security test payloads ({payloads}); remove them or move the request to an allowed security-test folder"#,
+        r#"🧨 La requête "{request}" contient des payloads de tests de sécurité ({payloads}) ; supprimez-les ou déplacez la requête dans un folder de tests de sécurité autorisé"#,
+    ),
+    msg(
+        "auth-override-awareness.request-from-collection",
+        r#"🔐 Request "{item}" overrides the '{inherited}' auth inherited from the collection with '{auth}'"#,
+        r#"🔐 La requête "{item}" remplace l'auth '{inherited}' héritée de la collection par '{auth}'"#,
+    ),
+    msg(
+        "auth-override-awareness.request-from-folder",
+        r#"🔐 Request "{item}" overrides the '{inherited}' auth inherited from folder "{folder}" with '{auth}'"#,
+        r#"🔐 La requête "{item}" remplace l'auth '{inherited}' héritée du folder "{folder}" par '{auth}'"#,
+    ),
+    msg(
+        "auth-override-awareness.folder-from-collection",
+        r#"🔐 Folder "{item}" overrides the '{inherited}' auth inherited from the collection with '{auth}'"#,
+        r#"🔐 Le folder "{item}" remplace l'auth '{inherited}' héritée de la collection par '{auth}'"#,
+    ),
+    msg(
+        "auth-override-awareness.folder-from-folder",
+        r#"🔐 Folder "{item}" overrides the '{inherited}' auth inherited from folder "{folder}" with '{auth}'"#,
+        r#"🔐 Le folder "{item}" remplace l'auth '{inherited}' héritée du folder "{folder}" par '{auth}'"#,
+    ),
+    msg(
+        "inconsistent-auth-for-same-endpoint.mismatch",
+        r#"🔐 Request "{request}" calls {endpoint} with auth {auth} while other requests to the same endpoint use a different auth: {others}"#,
+        r#"🔐 La requête "{request}" appelle {endpoint} avec l'auth {auth} alors que d'autres requêtes vers le même endpoint utilisent une autre auth : {others}"#,
+    ),
+];
+
+/// Morceau d'un gabarit : texte littéral ou paramètre `{nom}` / `{nom:format}`
+enum Segment<'a> {
+    Text(&'a str),
+    Param(&'a str, Option<&'a str>),
+}
+
+fn segments(template: &str) -> Vec<Segment<'_>> {
+    let mut segments = Vec::new();
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        // `{{variable}}` Postman : texte littéral
+        if rest[start..].starts_with("{{") {
+            let end = rest[start..].find("}}").map(|end| start + end + 2).unwrap_or(rest.len());
+            segments.push(Segment::Text(&rest[..end]));
+            rest = &rest[end..];
+            continue;
+        }
+        let Some(end) = rest[start..].find('}').map(|end| start + end) else {
+            break;
+        };
+        segments.push(Segment::Text(&rest[..start]));
+        let param = &rest[start + 1..end];
+        segments.push(match param.split_once(':') {
+            Some((name, format)) => Segment::Param(name, Some(format)),
+            None => Segment::Param(param, None),
+        });
+        rest = &rest[end + 1..];
+    }
+    segments.push(Segment::Text(rest));
+    segments
+}
+
+/// Rend le message `id` dans la langue demandée (`None` si l'identifiant est inconnu)
+pub fn message(locale: Locale, id: &str, params: &Map<String, Value>) -> Option<String> {
+    let entry = MESSAGES.iter().find(|entry| entry.id == id)?;
+    let numbers = NumberFormat::new(locale);
+    let mut rendered = String::new();
+    for segment in segments(entry.template(locale)) {
+        match segment {
+            Segment::Text(text) => rendered.push_str(text),
+            Segment::Param(name, format) => {
+                if let Some(value) = params.get(name) {
+                    rendered.push_str(&numbers.param(value, format));
+                }
+            }
+        }
+    }
+    Some(rendered)
+}
+
+/// Rend le message d'une issue dans `locale` à partir de son `message_id` et de ses
+/// `params` ; les messages hors catalogue (règles externes) sont conservés tels quels
+pub fn localize(issue: &mut LintIssue, locale: Locale) {
+    if let Some(rendered) = issue.message_id.as_deref().and_then(|id| message(locale, id, &issue.params)) {
+        issue.message = rendered;
+    }
+}
+
+/// Construction des issues des règles intégrées : la règle fixe `message_id` et les
+/// paramètres, le message (en anglais) est rendu depuis le catalogue
+pub trait Render {
+    fn render(self) -> LintIssue;
+}
+
+impl Render for IssueBuilder {
+    fn render(self) -> LintIssue {
+        let mut issue = self.build();
+        localize(&mut issue, Locale::En);
+        debug_assert!(!issue.message.is_empty(), "message absent du catalogue : {:?}", issue.message_id);
+        issue
+    }
+}
+
+/// Formatage des nombres dans les messages.
+/// Sans locale explicite, les messages (en anglais) gardent les conventions anglaises.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberFormat {
+    locale: Locale,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        NumberFormat { locale: Locale::En }
+    }
+}
+
+impl NumberFormat {
+    pub fn new(locale: Locale) -> Self {
+        NumberFormat { locale }
+    }
+
+    /// Nombre avec `decimals` décimales et séparateur de milliers ("1 234,5" / "1,234.5")
+    pub fn number(&self, value: f64, decimals: usize) -> String {
+        let formatted = format!("{:.*}", decimals, value.abs());
+        let (integer, fraction) = formatted.split_once('.').unwrap_or((&formatted, ""));
+        let (group_separator, decimal_separator) = match self.locale {
+            Locale::Fr => ('\u{a0}', ','),
+            Locale::En => (',', '.'),
+        };
+
+        let mut grouped = String::new();
+        for (index, digit) in integer.chars().enumerate() {
+            if index > 0 && (integer.len() - index) % 3 == 0 {
+                grouped.push(group_separator);
+            }
+            grouped.push(digit);
+        }
+        if value < 0.0 && formatted.chars().any(|c| c.is_ascii_digit() && c != '0') {
+            grouped.insert(0, '-');
+        }
+        if !fraction.is_empty() {
+            grouped.push(decimal_separator);
+            grouped.push_str(fraction);
+        }
+        grouped
+    }
+
+    /// Pourcentage ("80,0 %" / "80.0%")
+    pub fn percent(&self, value: f64, decimals: usize) -> String {
+        match self.locale {
+            Locale::Fr => format!("{} %", self.number(value, decimals)),
+            Locale::En => format!("{}%", self.number(value, decimals)),
+        }
+    }
+
+    /// Valeur d'un paramètre de message : les nombres sont formatés selon `format`
+    /// (`percent`, `percent.<décimales>`, `duration`), les entiers sans format avec
+    /// séparateur de milliers
+    fn param(&self, value: &Value, format: Option<&str>) -> String {
+        let Some(number) = value.as_f64() else {
+            return value.as_str().map_or_else(|| value.to_string(), str::to_string);
+        };
+        match format.map(|format| format.split_once('.').unwrap_or((format, "0"))) {
+            Some(("percent", decimals)) => self.percent(number, decimals.parse().unwrap_or(0)),
+            Some(("duration", _)) => self.duration_ms(number),
+            _ if value.is_f64() => value.to_string(),
+            _ => self.number(number, 0),
+        }
+    }
+
+    /// Durée en millisecondes ("1,5 s" / "250 ms" en français, "1500ms" en anglais)
+    pub fn duration_ms(&self, ms: f64) -> String {
+        match self.locale {
+            Locale::Fr if ms >= 1000.0 => {
+                let seconds = ms / 1000.0;
+                let decimals = if (seconds * 10.0).round() % 10.0 == 0.0 { 0 } else { 1 };
+                format!("{} s", self.number(seconds, decimals))
+            }
+            Locale::Fr => format!("{} ms", self.number(ms, 0)),
+            Locale::En => format!("{:.0}ms", ms),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_locale() {
+        assert_eq!(Locale::parse("en"), Some(Locale::En));
+        assert_eq!(Locale::parse("fr-FR"), Some(Locale::Fr));
+        assert_eq!(Locale::parse("de"), None);
+    }
+
+    #[test]
+    fn test_catalog_lookup() {
+        assert_eq!(t(Locale::En, "snippet.location_comment"), "// Define the location variable for tests");
+        assert!(t(Locale::Fr, "snippet.location_comment").contains("Définir"));
+        assert_eq!(t(Locale::En, "unknown.key"), "");
+    }
+
+    #[test]
+    fn test_message_catalog_consistency() {
+        let mut ids = std::collections::HashSet::new();
+        for entry in MESSAGES {
+            assert!(ids.insert(entry.id), "identifiant dupliqué : {}", entry.id);
+            let params = |template| {
+                let mut names: Vec<(&str, Option<&str>)> = segments(template)
+                    .into_iter()
+                    .filter_map(|segment| match segment {
+                        Segment::Param(name, format) => Some((name, format)),
+                        Segment::Text(_) => None,
+                    })
+                    .collect();
+                names.sort();
+                names
+            };
+            assert_eq!(params(entry.en), params(entry.fr), "paramètres différents pour {}", entry.id);
+        }
+    }
+
+    #[test]
+    fn test_render_message() {
+        let mut issue = crate::LintIssue::builder("environment-variables-usage")
+            .message_id("environment-variables-usage.hardcoded-url")
+            .param("request", "GET Users")
+            .render();
+        assert_eq!(issue.message, r#"🔧 Request "GET Users" should use an environment variable for the URL (ex: {{base_url}})"#);
+
+        localize(&mut issue, Locale::Fr);
+        assert_eq!(
+            issue.message,
+            r#"🔧 La requête "GET Users" devrait utiliser une variable d'environnement pour l'URL (ex : {{base_url}})"#
+        );
+
+        // Paramètres typés : formatés au rendu, selon la langue
+        let mut issue = crate::LintIssue::builder("response-time-threshold")
+            .message_id("response-time-threshold.too-high")
+            .param("request", "GET Users")
+            .param("threshold", 1500)
+            .param("max", 1000)
+            .render();
+        assert_eq!(issue.params["threshold"], 1500);
+        assert!(issue.message.ends_with("(1500ms > 1000ms recommended)"));
+        localize(&mut issue, Locale::Fr);
+        assert!(issue.message.ends_with("(1,5 s > 1 s recommandé)"));
+
+        let coverage = crate::LintIssue::builder("test-coverage-minimum")
+            .message_id("test-coverage-minimum.insufficient")
+            .param("coverage", 12.5)
+            .param("tested", 1234)
+            .param("total", 9872)
+            .param("minimum", 80.0)
+            .render();
+        assert!(coverage.message.contains(": 12.5% (1,234/9,872 requests tested). Recommended minimum: 80%"));
+
+        // Messages hors catalogue conservés
+        let mut external = crate::LintIssue::builder("acme-rule").message("Legacy host").message_id("acme-rule.legacy").build();
+        localize(&mut external, Locale::Fr);
+        assert_eq!(external.message, "Legacy host");
+    }
+
+    #[test]
+    fn test_number_format() {
+        let fr = NumberFormat::new(Locale::Fr);
+        assert_eq!(fr.percent(80.0, 1), "80,0 %");
+        assert_eq!(fr.number(12345.678, 2), "12\u{a0}345,68");
+        assert_eq!(fr.duration_ms(1500.0), "1,5 s");
+        assert_eq!(fr.duration_ms(2000.0), "2 s");
+        assert_eq!(fr.duration_ms(250.0), "250 ms");
+
+        let en = NumberFormat::default();
+        assert_eq!(en.percent(80.0, 1), "80.0%");
+        assert_eq!(en.number(-1234.0, 0), "-1,234");
+        assert_eq!(en.duration_ms(2500.0), "2500ms");
+    }
+}
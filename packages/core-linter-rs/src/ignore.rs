@@ -0,0 +1,193 @@
+//! Exclusion d'items par motif (`ignore` dans la configuration)
+//!
+//! Permet d'écarter des folders hérités sans les supprimer de la collection. Deux
+//! formes de motif, où `*` remplace une partie d'un segment et `**` un nombre
+//! quelconque de segments :
+//! - path d'item, commençant par `/` : `/item[3]/**` (l'item 3 et ses descendants) ;
+//! - chemin de noms depuis la racine : `Deprecated/**`, `**/Legacy*/**`
+//!   (insensible à la casse).
+//!
+//! Contrairement aux suppressions, les issues des items ignorés disparaissent du
+//! résultat : ni score, ni stats, ni section `suppressed`. Les issues portant sur la
+//! collection elle-même ne sont jamais ignorées.
+
+use crate::LintIssue;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Motif d'exclusion, pour toutes les règles ou pour certaines seulement
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum IgnorePattern {
+    /// `"Deprecated/**"` : toutes les règles
+    All(String),
+    /// `{ "pattern": "Deprecated/**", "rules": ["request-examples-required"] }`
+    Rules { pattern: String, rules: Vec<String> },
+}
+
+impl IgnorePattern {
+    pub fn pattern(&self) -> &str {
+        match self {
+            IgnorePattern::All(pattern) | IgnorePattern::Rules { pattern, .. } => pattern,
+        }
+    }
+
+    fn applies_to(&self, rule_id: &str) -> bool {
+        match self {
+            IgnorePattern::All(_) => true,
+            IgnorePattern::Rules { rules, .. } => rules.iter().any(|id| id == rule_id),
+        }
+    }
+
+    /// Vérifie le motif sur un item, désigné par son path et son chemin de noms
+    fn matches(&self, item_path: &str, name_path: &str) -> bool {
+        let pattern = self.pattern();
+        let (pattern, target) = if pattern.starts_with('/') { (pattern, item_path) } else { (pattern, name_path) };
+        let pattern_segments: Vec<&str> = pattern.trim_matches('/').split('/').collect();
+        let segments: Vec<&str> = target.trim_matches('/').split('/').collect();
+        glob_segments(&pattern_segments, &segments)
+    }
+}
+
+/// Retire les issues des items correspondant à un motif
+pub fn apply(collection: &Value, issues: Vec<LintIssue>, patterns: &[IgnorePattern]) -> Vec<LintIssue> {
+    if patterns.is_empty() {
+        return issues;
+    }
+
+    let mut name_paths = HashMap::new();
+    collect_name_paths(collection, "", "", &mut name_paths);
+
+    issues
+        .into_iter()
+        .filter(|issue| {
+            let item_path = owning_item(&issue.path);
+            let Some(name_path) = name_paths.get(item_path) else {
+                return true;
+            };
+            !patterns
+                .iter()
+                .any(|pattern| pattern.applies_to(&issue.rule_id) && pattern.matches(item_path, name_path))
+        })
+        .collect()
+}
+
+/// Path de l'item portant l'issue : `/item[0]/item[2]/request/body` → `/item[0]/item[2]`
+pub(crate) fn owning_item(path: &str) -> &str {
+    let mut end = 0;
+    for segment in path.split('/').skip(1) {
+        if !segment.starts_with("item[") {
+            break;
+        }
+        end += segment.len() + 1;
+    }
+    &path[..end]
+}
+
+/// Chemin de noms (`Folder/Sous-folder/Requête`) de chaque item, par path
+fn collect_name_paths(item: &Value, path: &str, name_path: &str, name_paths: &mut HashMap<String, String>) {
+    if let Some(items) = item["item"].as_array() {
+        for (index, child) in items.iter().enumerate() {
+            let child_path = format!("{}/item[{}]", path, index);
+            let name = child["name"].as_str().unwrap_or_default();
+            let child_name_path = if name_path.is_empty() { name.to_string() } else { format!("{}/{}", name_path, name) };
+            collect_name_paths(child, &child_path, &child_name_path, name_paths);
+            name_paths.insert(child_path, child_name_path);
+        }
+    }
+}
+
+/// `**` couvre zéro ou plusieurs segments
+fn glob_segments(pattern: &[&str], segments: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => segments.is_empty(),
+        Some((&"**", rest)) => {
+            glob_segments(rest, segments) || (!segments.is_empty() && glob_segments(pattern, &segments[1..]))
+        }
+        Some((first, rest)) => {
+            !segments.is_empty() && glob_segment(first, segments[0]) && glob_segments(rest, &segments[1..])
+        }
+    }
+}
+
+/// `*` couvre une partie quelconque du segment (comparaison insensible à la casse)
+fn glob_segment(pattern: &str, segment: &str) -> bool {
+    let pattern: Vec<char> = pattern.to_lowercase().chars().collect();
+    let segment: Vec<char> = segment.to_lowercase().chars().collect();
+
+    // matched[j] : le préfixe courant du motif couvre les j premiers caractères
+    let mut matched = vec![false; segment.len() + 1];
+    matched[0] = true;
+    for c in &pattern {
+        if *c == '*' {
+            for j in 1..=segment.len() {
+                matched[j] = matched[j] || matched[j - 1];
+            }
+        } else {
+            for j in (1..=segment.len()).rev() {
+                matched[j] = matched[j - 1] && segment[j - 1] == *c;
+            }
+            matched[0] = false;
+        }
+    }
+    matched[segment.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn collection() -> Value {
+        json!({
+            "info": { "name": "Test" },
+            "item": [
+                { "name": "Users", "item": [{ "name": "GET Users", "request": { "url": "/users" } }] },
+                { "name": "Deprecated", "item": [
+                    { "name": "Legacy v1", "item": [{ "name": "GET Old", "request": { "url": "/old" } }] }
+                ] },
+                { "name": "GET Health", "request": { "url": "/health" } }
+            ]
+        })
+    }
+
+    fn issues() -> Vec<LintIssue> {
+        ["", "/item[0]/item[0]", "/item[1]", "/item[1]/item[0]/item[0]/request/url", "/item[2]/event"]
+            .iter()
+            .map(|path| LintIssue::builder("request-examples-required").path(*path).build())
+            .collect()
+    }
+
+    fn kept_paths(patterns: &[IgnorePattern]) -> Vec<String> {
+        apply(&collection(), issues(), patterns).into_iter().map(|issue| issue.path).collect()
+    }
+
+    #[test]
+    fn test_name_and_path_patterns() {
+        assert_eq!(
+            kept_paths(&[IgnorePattern::All("deprecated/**".to_string())]),
+            vec!["", "/item[0]/item[0]", "/item[2]/event"]
+        );
+        assert_eq!(
+            kept_paths(&[IgnorePattern::All("**/Legacy*/**".to_string())]),
+            vec!["", "/item[0]/item[0]", "/item[1]", "/item[2]/event"]
+        );
+        assert_eq!(
+            kept_paths(&[IgnorePattern::All("/item[2]/**".to_string()), IgnorePattern::All("Users/*".to_string())]),
+            vec!["", "/item[1]", "/item[1]/item[0]/item[0]/request/url"]
+        );
+    }
+
+    #[test]
+    fn test_pattern_limited_to_rules() {
+        let patterns: Vec<IgnorePattern> = serde_json::from_value(json!([
+            { "pattern": "Deprecated/**", "rules": ["test-http-status-mandatory"] }
+        ]))
+        .unwrap();
+
+        assert_eq!(kept_paths(&patterns).len(), 5);
+        assert!(glob_segment("GET *", "get users"));
+        assert!(!glob_segment("GET *", "POST users"));
+    }
+}
@@ -0,0 +1,116 @@
+//! Estimation de l'impact de chaque règle sur le score
+//!
+//! Pour chaque règle, recalcule le score comme si toutes ses issues étaient
+//! corrigées. Sert à la vue de priorisation : corriger d'abord les règles qui
+//! rapportent le plus de points.
+
+use crate::{calculate_score, run_linter, LintConfig, LintResult};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct RuleImpact {
+    pub rule_id: String,
+    /// Nombre d'issues levées par la règle
+    pub issues: usize,
+    /// Score si toutes les issues de la règle étaient corrigées
+    pub score_if_fixed: u32,
+    /// Points gagnés par rapport au score actuel
+    pub score_gain: u32,
+}
+
+#[derive(Serialize, Debug)]
+pub struct ImpactAnalysis {
+    pub score: u32,
+    /// Règles triées par gain décroissant
+    pub rules: Vec<RuleImpact>,
+}
+
+/// Analyse l'impact de chaque règle activée sur le score de la collection
+pub fn impact_analysis(collection: &Value, config: &LintConfig) -> ImpactAnalysis {
+    let config = LintConfig {
+        max_issues_per_rule: None,
+        ..config.clone()
+    };
+    let result = run_linter(collection, &config);
+    let enabled_rules: Option<Vec<String>> = config
+        .rules
+        .as_ref()
+        .map(|rules| rules.iter().filter(|rule_id| config.is_rule_enabled(rule_id)).cloned().collect());
+    analyze_result(&result, enabled_rules.as_deref())
+}
+
+/// Calcule l'impact à partir d'un résultat existant ; les règles explicitement
+/// activées sans issue apparaissent avec un gain nul
+pub fn analyze_result(result: &LintResult, enabled_rules: Option<&[String]>) -> ImpactAnalysis {
+    let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+    for rule_id in enabled_rules.unwrap_or_default() {
+        counts.entry(rule_id.as_str()).or_default();
+    }
+    for issue in &result.issues {
+        *counts.entry(issue.rule_id.as_str()).or_default() += 1;
+    }
+
+    let mut rules: Vec<RuleImpact> = counts
+        .into_iter()
+        .map(|(rule_id, issues)| {
+            let remaining: Vec<_> = result.issues.iter().filter(|i| i.rule_id != rule_id).cloned().collect();
+            let score_if_fixed = calculate_score(&remaining, &result.stats);
+            RuleImpact {
+                rule_id: rule_id.to_string(),
+                issues,
+                score_if_fixed,
+                score_gain: score_if_fixed.saturating_sub(result.score),
+            }
+        })
+        .collect();
+
+    // Gain décroissant, puis nombre d'issues décroissant (ordre stable sur l'id)
+    rules.sort_by(|a, b| b.score_gain.cmp(&a.score_gain).then(b.issues.cmp(&a.issues)));
+
+    ImpactAnalysis {
+        score: result.score,
+        rules,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_impact_per_rule() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [
+                { "name": "GET Users", "request": { "method": "GET", "url": "https://api.example.com/users" } },
+                { "name": "GET Orders", "request": { "method": "GET", "url": "https://api.example.com/orders" } }
+            ]
+        });
+        let config = LintConfig {
+            rules: Some(vec![
+                "test-http-status-mandatory".to_string(),
+                "test-response-time-mandatory".to_string(),
+                "hardcoded-secrets".to_string(),
+            ]),
+            ..Default::default()
+        };
+
+        let analysis = impact_analysis(&collection, &config);
+
+        assert_eq!(analysis.rules.len(), 3);
+        assert_eq!(analysis.rules[0].rule_id, "test-http-status-mandatory");
+        assert_eq!(analysis.rules[0].issues, 2);
+        // Les deux règles de test (-15 et -8) sont plafonnées ensemble à -20
+        assert_eq!(analysis.score, 80);
+        assert_eq!(analysis.rules[0].score_gain, 17);
+        assert_eq!(analysis.rules[1].rule_id, "test-response-time-mandatory");
+        assert_eq!(analysis.rules[1].score_gain, 5);
+
+        let secrets = &analysis.rules[2];
+        assert_eq!((secrets.issues, secrets.score_gain), (0, 0));
+        assert_eq!(secrets.score_if_fixed, analysis.score);
+    }
+}
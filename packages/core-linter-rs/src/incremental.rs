@@ -0,0 +1,114 @@
+//! Analyse incrémentale entre deux versions d'une collection
+//!
+//! Pour la CI des merge requests : seules les issues introduites par les items
+//! ajoutés ou modifiés sont rapportées, la dette de la collection historique ne
+//! fait pas échouer le pipeline. Un item est modifié quand son contenu
+//! (`hashing::content_hash`, sous-arbre compris) n'existe pas dans l'ancienne
+//! version : déplacer une requête ne la rend pas modifiée. Une issue est introduite
+//! si elle porte sur un item modifié (ou sur la collection) et que son empreinte
+//! (`compare::fingerprint`) n'existait pas dans l'analyse de l'ancienne version ;
+//! un folder modifié par l'un de ses enfants ne remonte donc pas ses anciennes issues.
+
+use crate::compare::compare_results;
+use crate::hashing::hash_items;
+use crate::registry::RuleRegistry;
+use crate::{ignore, run_linter_with_registry, LintConfig, LintIssue, LintResult};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashSet;
+
+#[derive(Serialize, Debug)]
+pub struct DiffLintResult {
+    /// Items ajoutés ou modifiés (paths dans la nouvelle version)
+    pub changed_items: Vec<String>,
+    /// Issues introduites par les changements
+    pub issues: Vec<LintIssue>,
+    pub score_before: u32,
+    pub score_after: u32,
+    /// Analyse complète de la nouvelle version
+    pub result: LintResult,
+}
+
+/// Analyse `new_collection` et ne retient que les issues introduites depuis `old_collection`
+pub fn lint_diff(
+    old_collection: &Value,
+    new_collection: &Value,
+    config: &LintConfig,
+    registry: &RuleRegistry,
+) -> DiffLintResult {
+    let old_hashes: HashSet<String> = hash_items(old_collection).into_values().collect();
+    let changed_items: Vec<String> = hash_items(new_collection)
+        .into_iter()
+        .filter(|(_, hash)| !old_hashes.contains(hash))
+        .map(|(path, _)| path)
+        .collect();
+
+    let before = run_linter_with_registry(old_collection, config, registry);
+    let after = run_linter_with_registry(new_collection, config, registry);
+
+    let issues = compare_results(&before, &after)
+        .new_issues
+        .into_iter()
+        .filter(|issue| {
+            let item = ignore::owning_item(&issue.path);
+            item.is_empty() || changed_items.iter().any(|path| path == item)
+        })
+        .collect();
+
+    DiffLintResult {
+        changed_items,
+        issues,
+        score_before: before.score,
+        score_after: after.score,
+        result: after,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn request(name: &str, url: &str) -> Value {
+        json!({ "name": name, "request": { "method": "GET", "url": url } })
+    }
+
+    fn config() -> LintConfig {
+        LintConfig {
+            rules: Some(vec!["test-http-status-mandatory".to_string(), "no-body-on-get".to_string()]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_only_changed_items_report_issues() {
+        let old = json!({ "item": [request("GET Users", "/users"), request("GET Orders", "/orders")] });
+        // Requêtes réordonnées, une modifiée, une ajoutée
+        let mut changed = request("GET Users", "/users");
+        changed["request"]["body"] = json!({ "mode": "raw", "raw": "{}" });
+        let new = json!({ "item": [request("GET Orders", "/orders"), changed, request("GET Invoices", "/invoices")] });
+
+        let diff = lint_diff(&old, &new, &config(), &RuleRegistry::new());
+
+        assert_eq!(diff.changed_items, vec!["/item[1]", "/item[2]"]);
+        let reported: Vec<(&str, &str)> =
+            diff.issues.iter().map(|issue| (issue.rule_id.as_str(), issue.path.as_str())).collect();
+        assert_eq!(reported.len(), 2);
+        assert!(reported.contains(&("test-http-status-mandatory", "/item[2]")));
+        assert!(reported.iter().any(|(rule_id, _)| *rule_id == "no-body-on-get"));
+        assert_eq!(diff.result.issues.len(), 4);
+    }
+
+    #[test]
+    fn test_folder_keeps_legacy_issues_hidden() {
+        let old = json!({ "item": [{ "name": "Users", "item": [request("GET Users", "/users")] }] });
+        let mut new = old.clone();
+        new["item"][0]["item"][0]["event"] = json!([{ "listen": "test", "script": { "exec": ["pm.response.to.have.status(200);"] } }]);
+
+        let diff = lint_diff(&old, &new, &config(), &RuleRegistry::new());
+
+        assert_eq!(diff.changed_items, vec!["/item[0]", "/item[0]/item[0]"]);
+        assert!(diff.issues.is_empty());
+        assert!(diff.score_after > diff.score_before);
+    }
+}
@@ -0,0 +1,164 @@
+//! Règles personnalisées écrites en JavaScript (WASM)
+//!
+//! L'interface web enregistre des callbacks via `register_js_rule(id, severity, fn)`.
+//! Le moteur appelle chaque callback pour chaque item avec un objet simple
+//! (path, name, method, url, scripts...) et collecte les issues retournées,
+//! sans recompiler le WASM.
+//!
+//! Une règle JS peut retourner `null`, une issue ou un tableau d'issues ; seul
+//! `message` est obligatoire (`path` et `severity` prennent les valeurs de l'item
+//! et de la règle par défaut).
+//!
+//! `register_js_collection_rule` enregistre une règle appelée une seule fois avec
+//! la collection entière et ses options : c'est la forme utilisée par les plugins
+//! WASM chargés côté TypeScript (`loadPlugin`).
+
+use crate::registry::{issues_from_value, item_object, RuleRegistry};
+use crate::rule_api::{walk, ItemContext, LintIssue, Rule};
+use crate::wasm_error::{ErrorCode, WasmError};
+use serde_json::Value;
+use std::cell::RefCell;
+use wasm_bindgen::prelude::*;
+
+struct JsRule {
+    id: String,
+    severity: String,
+    callback: js_sys::Function,
+    /// Appelée avec la collection entière plutôt qu'item par item
+    collection_scope: bool,
+}
+
+thread_local! {
+    static JS_RULES: RefCell<Vec<JsRule>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Enregistre (ou remplace) une règle JavaScript
+#[wasm_bindgen]
+pub fn register_js_rule(id: &str, severity: &str, callback: js_sys::Function) -> Result<(), JsValue> {
+    register(id, severity, callback, false)
+}
+
+/// Enregistre (ou remplace) une règle JavaScript appelée avec `(collection, options)`
+#[wasm_bindgen]
+pub fn register_js_collection_rule(id: &str, severity: &str, callback: js_sys::Function) -> Result<(), JsValue> {
+    register(id, severity, callback, true)
+}
+
+fn register(id: &str, severity: &str, callback: js_sys::Function, collection_scope: bool) -> Result<(), JsValue> {
+    if id.trim().is_empty() {
+        return Err(WasmError::new(ErrorCode::InvalidRule, "Rule id must not be empty").into());
+    }
+    if !crate::rules::ISSUE_SEVERITIES.contains(&severity) {
+        return Err(WasmError::new(
+            ErrorCode::InvalidRule,
+            format!("Invalid severity '{}' (expected error, warning, info or hint)", severity),
+        )
+        .into());
+    }
+
+    JS_RULES.with(|rules| {
+        let mut rules = rules.borrow_mut();
+        rules.retain(|rule| rule.id != id);
+        rules.push(JsRule {
+            id: id.to_string(),
+            severity: severity.to_string(),
+            callback,
+            collection_scope,
+        });
+    });
+    Ok(())
+}
+
+/// Supprime toutes les règles JavaScript enregistrées
+#[wasm_bindgen]
+pub fn clear_js_rules() {
+    JS_RULES.with(|rules| rules.borrow_mut().clear());
+}
+
+/// Registre contenant les règles JavaScript actuellement enregistrées
+pub fn registry() -> RuleRegistry {
+    let mut registry = RuleRegistry::new();
+    JS_RULES.with(|rules| {
+        for rule in rules.borrow().iter() {
+            // Les identifiants sont uniques (remplacement à l'enregistrement)
+            let _ = registry.register(JsRuleProxy {
+                id: rule.id.clone(),
+                severity: rule.severity.clone(),
+                collection_scope: rule.collection_scope,
+            });
+        }
+    });
+    registry
+}
+
+/// Règle du registre déléguant au callback JS stocké côté thread
+struct JsRuleProxy {
+    id: String,
+    severity: String,
+    collection_scope: bool,
+}
+
+impl JsRuleProxy {
+    /// Appelle le callback JS ; `subject` nomme l'item ou la collection en cas d'échec
+    fn call(&self, args: &[&Value], subject: &str, default_path: &str) -> Vec<LintIssue> {
+        let args = js_sys::Array::from_iter(
+            args.iter().map(|arg| js_sys::JSON::parse(&arg.to_string()).unwrap_or(JsValue::NULL)),
+        );
+
+        let output = JS_RULES.with(|rules| {
+            rules
+                .borrow()
+                .iter()
+                .find(|rule| rule.id == self.id)
+                .map(|rule| rule.callback.apply(&JsValue::NULL, &args))
+        });
+
+        match output {
+            Some(Ok(returned)) => {
+                let json = js_sys::JSON::stringify(&returned)
+                    .ok()
+                    .and_then(|s| s.as_string())
+                    .and_then(|s| serde_json::from_str(&s).ok())
+                    .unwrap_or(Value::Null);
+                issues_from_value(&self.id, &self.severity, default_path, &json)
+            }
+            Some(Err(error)) => vec![LintIssue::builder(&self.id)
+                .severity("info")
+                .message(format!(
+                    "⚠️ Custom rule '{}' failed on {}: {}",
+                    self.id,
+                    subject,
+                    error
+                        .as_string()
+                        .or_else(|| js_sys::Reflect::get(&error, &"message".into()).ok().and_then(|m| m.as_string()))
+                        .unwrap_or_else(|| format!("{:?}", error))
+                ))
+                .path(default_path)
+                .build()],
+            None => Vec::new(),
+        }
+    }
+}
+
+impl Rule for JsRuleProxy {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn severity(&self) -> &str {
+        &self.severity
+    }
+
+    fn check_item(&self, item: &ItemContext, options: &Value) -> Vec<LintIssue> {
+        self.call(&[&item_object(item, options)], &format!("\"{}\"", item.name), &item.path)
+    }
+
+    fn check(&self, collection: &Value, options: &Value) -> Vec<LintIssue> {
+        if self.collection_scope {
+            return self.call(&[collection, options], "the collection", "");
+        }
+        let mut issues = Vec::new();
+        walk(collection, &mut |item| issues.extend(self.check_item(item, options)));
+        issues
+    }
+}
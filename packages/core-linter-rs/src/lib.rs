@@ -1,34 +1,212 @@
-use wasm_bindgen::prelude::*;
 pub mod rules;
+pub mod baseline;
+pub mod compare;
+pub mod context;
+pub mod coverage;
+pub mod dedup;
+pub mod envelope;
+pub mod environment;
+pub mod error;
 pub mod utils;
 pub mod fixer;
+pub mod graph;
+pub mod grouping;
+pub mod guards;
+pub mod hashing;
+pub mod i18n;
+pub mod ignore;
+pub mod impact;
+pub mod incremental;
+#[cfg(feature = "wasm")]
+pub mod js_rules;
+pub mod linter;
+pub mod maturity;
+pub mod merge;
+pub mod model;
+pub mod newman;
+pub mod normalize;
+pub mod ordering;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod plugins;
+pub mod pointer;
+pub mod profile;
+pub mod project_config;
+pub mod registry;
+pub mod relations;
+pub mod remediation;
+pub mod sanitize;
+pub mod scaffold;
+pub mod scoring;
+#[cfg(feature = "rhai-rules")]
+pub mod script_rules;
+pub mod session;
+pub mod shard;
+pub mod simulate;
+pub mod snippets;
+pub mod source_map;
+pub mod starter;
+pub mod summary;
+pub mod suppression;
+pub mod usage_stats;
+pub mod visibility;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "wasm")]
+pub mod wasm_error;
+pub mod workspace;
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+pub use linterman_rule_api as rule_api;
+pub use rule_api::{FixAction, LintIssue};
+pub use error::LintError;
+pub use linter::{LintConfigBuilder, Linter};
+#[cfg(feature = "wasm")]
+pub use wasm::*;
+
 // ============================================================================
 // Types
 // ============================================================================
 
-#[derive(Deserialize, Clone)]
+/// Version du moteur, reportée dans chaque résultat (`LintResult::engine_version`)
+pub const ENGINE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[derive(Serialize, Deserialize, Clone, Default)]
 pub struct LintConfig {
     pub local_only: bool,
     pub rules: Option<Vec<String>>,
+    /// Règles exclues, appliquées après `rules` : sans `rules`, toutes les règles
+    /// sauf celles-ci (les nouvelles règles restent actives)
+    #[serde(default)]
+    pub disabled_rules: Option<Vec<String>>,
     pub fix: Option<bool>,
     pub custom_templates: Option<std::collections::HashMap<String, String>>,
+    /// Options propres à chaque règle, indexées par rule_id
+    /// (ex: {"example-count-limits": {"max_examples": 5}}) ; les options inconnues
+    /// ou invalides sont signalées dans `LintResult::config_warnings`
+    #[serde(default)]
+    pub rule_options: Option<std::collections::HashMap<String, Value>>,
+    /// Langue des messages et du code suggéré par les fixes ; sans locale, les messages
+    /// restent en anglais et le code suggéré en français
+    #[serde(default)]
+    pub locale: Option<i18n::Locale>,
+    /// Snippets de tests personnalisés (status_test, response_time_test, schema_test)
+    /// utilisés par les fixes `add_test` à la place des modèles intégrés
+    #[serde(default)]
+    pub snippets: Option<std::collections::HashMap<String, String>>,
+    /// Rapport Newman (reporter JSON) dont les temps de réponse observés servent
+    /// à suggérer des seuils réalistes
+    #[serde(default)]
+    pub newman_report: Option<Value>,
+    /// Profil de visibilité : "public" active et durcit les règles d'exposition
+    #[serde(default)]
+    pub visibility: Option<visibility::Visibility>,
+    /// Limites de taille des entrées au-delà desquelles les règles sont ignorées
+    #[serde(default)]
+    pub limits: Option<guards::ResourceLimits>,
+    /// Issues masquées volontairement (règle + préfixe de path), en plus des
+    /// suppressions inline `linterman-disable`
+    #[serde(default)]
+    pub suppressions: Option<Vec<suppression::Suppression>>,
+    /// Mode strict : un fix de type inconnu du registre est une erreur du moteur
+    /// au lieu d'être ignoré silencieusement
+    #[serde(default)]
+    pub strict_fix: Option<bool>,
+    /// Seuils du quality gate (verdict du résumé `--format summary`)
+    #[serde(default)]
+    pub quality_gate: Option<summary::QualityGate>,
+    /// Nombre maximal d'issues conservées par règle dans le résultat
+    /// (les stats et le score restent calculés sur toutes les issues)
+    #[serde(default)]
+    pub max_issues_per_rule: Option<usize>,
+    /// Fusion des issues de règles différentes décrivant un même problème (la plus
+    /// grave est conservée) ; active par défaut, `false` pour garder toutes les issues
+    #[serde(default)]
+    pub deduplicate: Option<bool>,
+    /// Regroupement des issues (par règle ou par item) inclus dans le résultat
+    /// (`LintResult::groups`)
+    #[serde(default)]
+    pub group_by: Option<grouping::GroupBy>,
+    /// Mesure du temps d'exécution et des issues de chaque règle (`LintResult::profile`)
+    #[serde(default)]
+    pub profile: Option<bool>,
+    /// Ligne et colonne des issues dans le JSON d'origine (`source_map`), pour les
+    /// points d'entrée qui reçoivent le texte de la collection
+    #[serde(default)]
+    pub source_map: Option<bool>,
+    /// Ordre des issues dans le résultat (path, règle puis sévérité par défaut)
+    #[serde(default)]
+    pub sort_by: Option<ordering::SortBy>,
+    /// Sévérité imposée par règle ("error", "warning", "info", "hint" ou "off"),
+    /// appliquée après le profil de visibilité ; "off" désactive la règle
+    #[serde(default)]
+    pub severity_overrides: Option<std::collections::HashMap<String, String>>,
+    /// Pénalité maximale (en points de score) par catégorie, remplaçant les plafonds
+    /// par défaut (voir `scoring`)
+    #[serde(default)]
+    pub category_caps: Option<std::collections::HashMap<rules::Category, f64>>,
+    /// Items exclus de l'analyse par motif de path (`/item[3]/**`) ou de noms
+    /// (`Deprecated/**`), pour toutes les règles ou certaines seulement
+    #[serde(default)]
+    pub ignore: Option<Vec<ignore::IgnorePattern>>,
+    /// Niveau de maturité visé (L1, L2 ou L3) : `LintResult::maturity` liste les
+    /// règles manquantes pour l'atteindre
+    #[serde(default)]
+    pub maturity_target: Option<maturity::Maturity>,
+    /// Réglages de la commande `normalize` (tris, champs volatils retirés)
+    #[serde(default)]
+    pub normalize: Option<normalize::NormalizeOptions>,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-pub struct LintIssue {
-    pub rule_id: String,
-    pub severity: String,
-    pub message: String,
-    pub path: String,
-    pub line: Option<u32>,
-    pub fix: Option<Value>,
+impl LintConfig {
+    /// Règle activée par `rules` et non exclue par `disabled_rules`
+    pub fn is_rule_enabled(&self, rule_id: &str) -> bool {
+        let listed = |rules: &Option<Vec<String>>| rules.as_ref().map(|rules| rules.iter().any(|id| id == rule_id));
+        let off = self
+            .severity_overrides
+            .as_ref()
+            .is_some_and(|overrides| overrides.get(rule_id).is_some_and(|severity| severity == "off"));
+        listed(&self.rules).unwrap_or(true) && !listed(&self.disabled_rules).unwrap_or(false) && !off
+    }
+    
+    /// Récupère les options d'une règle, ou ses valeurs par défaut si absentes/invalides
+    pub fn options_for<T: serde::de::DeserializeOwned + Default>(&self, rule_id: &str) -> T {
+        self.rule_options
+            .as_ref()
+            .and_then(|options| options.get(rule_id))
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_default()
+    }
+    
+    /// Lit une configuration JSON (forme de `lint` côté WASM)
+    pub fn from_json(config_json: &str) -> Result<Self, LintError> {
+        serde_json::from_str(config_json).map_err(|e| LintError::parse("config", &e))
+    }
+    
+    /// Validation stricte : là où l'analyse se contente de `config_warnings`, une règle
+    /// inconnue ou une option / sévérité invalide devient une erreur
+    pub fn validate(&self, registry: &registry::RuleRegistry) -> Result<(), LintError> {
+        let referenced = self
+            .rules
+            .iter()
+            .chain(&self.disabled_rules)
+            .flatten()
+            .chain(self.severity_overrides.iter().flat_map(|overrides| overrides.keys()))
+            .chain(self.rule_options.iter().flat_map(|options| options.keys()));
+        for rule_id in referenced {
+            if rules::find(rule_id).is_none() && !registry.contains(rule_id) {
+                return Err(LintError::UnknownRule(rule_id.clone()));
+            }
+        }
+        match rules::validate_config(self, registry).as_slice() {
+            [] => Ok(()),
+            warnings => Err(LintError::InvalidConfig(warnings.join("; "))),
+        }
+    }
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct LintStats {
     pub total_requests: u32,
     pub total_tests: u32,
@@ -36,104 +214,362 @@ pub struct LintStats {
     pub errors: u32,
     pub warnings: u32,
     pub infos: u32,
+    /// Issues de sévérité hint, sans effet sur le score
+    #[serde(default)]
+    pub hints: u32,
+    /// Pourcentage de requêtes possédant au moins un test
+    #[serde(default)]
+    pub test_coverage_percent: f64,
+    /// Nombre de requêtes par méthode HTTP (en majuscules, GET si absente)
+    #[serde(default)]
+    pub methods: std::collections::HashMap<String, u32>,
+    /// Issues et pénalité de chaque catégorie ayant des issues
+    #[serde(default)]
+    pub categories: std::collections::BTreeMap<String, scoring::CategoryScore>,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub struct LintResult {
     pub score: u32,
     pub issues: Vec<LintIssue>,
     pub stats: LintStats,
+    /// Matrice de couverture des tests obligatoires par requête
+    #[serde(default)]
+    pub coverage: coverage::CoverageMatrix,
+    /// Règles non exécutées (entrée trop volumineuse...)
+    #[serde(default)]
+    pub skipped_rules: Vec<guards::SkippedRule>,
+    /// Issues masquées par une suppression (inline ou configuration)
+    #[serde(default)]
+    pub suppressed: Vec<suppression::SuppressedIssue>,
+    /// Nombre d'issues omises par règle (`max_issues_per_rule`)
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub truncated: std::collections::BTreeMap<String, usize>,
+    /// Avertissements sur la configuration (options de règles inconnues ou invalides)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub config_warnings: Vec<String>,
+    /// Shard analysé (`--shard`), absent pour une analyse complète
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shard: Option<shard::Shard>,
+    /// Version du moteur ayant produit le résultat (vide pour les anciens résultats)
+    #[serde(default)]
+    pub engine_version: String,
+    /// Empreinte des règles exécutées et de leur configuration (`rules::ruleset_hash`) :
+    /// deux résultats de même empreinte sont comparables règle à règle
+    #[serde(default)]
+    pub ruleset_hash: String,
+    /// Niveau de maturité atteint et règles manquantes pour le suivant
+    #[serde(default)]
+    pub maturity: maturity::MaturityReport,
+    /// Issues regroupées par règle ou par item (`LintConfig::group_by`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub groups: Option<grouping::IssueGroups>,
+    /// Temps d'exécution et issues brutes par règle (`LintConfig::profile`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<profile::Profile>,
 }
 
 // ============================================================================
 // Moteur Principal
 // ============================================================================
 
+/// Analyse depuis du JSON, avec validation stricte de la configuration
+pub fn lint_json(collection_json: &str, config_json: &str, registry: &registry::RuleRegistry) -> Result<LintResult, LintError> {
+    let collection: Value = serde_json::from_str(collection_json).map_err(|e| LintError::parse("collection", &e))?;
+    let config = LintConfig::from_json(config_json)?;
+    config.validate(registry)?;
+    Ok(run_linter_with_registry(&collection, &config, registry))
+}
+
 pub fn run_linter(collection: &Value, config: &LintConfig) -> LintResult {
-    let mut issues = Vec::new();
-    
-    // Appliquer les règles
-    let enabled_rules = config.rules.as_ref();
-    
-    // Testing rules
-    if enabled_rules.is_none() || enabled_rules.unwrap().contains(&"test-http-status-mandatory".to_string()) {
-        issues.extend(rules::testing::test_http_status_mandatory::check(collection));
-    }
+    run_linter_with_registry(collection, config, &registry::RuleRegistry::default())
+}
+
+/// Exécute les règles intégrées puis les règles externes du registre
+pub fn run_linter_with_registry(collection: &Value, config: &LintConfig, registry: &registry::RuleRegistry) -> LintResult {
+    run_with_scripts(collection, config, registry, context::ScriptIndex::build(collection))
+}
+
+/// Exécution avec un index de scripts déjà construit (partagé entre plusieurs analyses)
+pub(crate) fn run_with_scripts(
+    collection: &Value,
+    config: &LintConfig,
+    registry: &registry::RuleRegistry,
+    scripts: context::ScriptIndex,
+) -> LintResult {
+    let ctx = lint_context(config, scripts, model::Collection::from_value(collection));
+    let guard = guards::ResourceGuard::new(collection, &config.limits.clone().unwrap_or_default());
+    let (rule_issues, skipped_rules, profile) = run_rules(collection, config, registry, &ctx, &guard, |_| true);
+    let issues = rule_issues.into_iter().flat_map(|(_, issues)| issues.unwrap_or_default()).collect();
+    let mut result = finish(collection, config, registry, &guard, issues, skipped_rules);
+    result.profile = profile;
+    result
+}
+
+/// Contexte partagé : scripts indexés et modèle construits une seule fois pour toutes les règles
+pub(crate) fn lint_context(
+    config: &LintConfig,
+    scripts: context::ScriptIndex,
+    model: model::Collection,
+) -> context::LintContext {
+    let timings = config
+        .newman_report
+        .as_ref()
+        .map(newman::ResponseTimings::from_report)
+        .unwrap_or_default();
+    context::LintContext::with_scripts(scripts)
+        .with_model(model)
+        .with_snippets(snippets::SnippetLibrary::new(config.locale.unwrap_or_default(), config.snippets.as_ref()))
+        .with_timings(timings)
+}
+
+/// Issues brutes par règle activée (`None` : règle non exécutée)
+pub(crate) type RuleIssues = Vec<(String, Option<Vec<LintIssue>>)>;
+
+/// Issues brutes de chaque règle activée, dans l'ordre d'exécution ; seules les règles
+/// `selected` sont exécutées (`None` pour les autres, voir `session::LintSession`).
+/// Avec `LintConfig::profile`, retourne aussi le profil des règles exécutées.
+pub(crate) fn run_rules(
+    collection: &Value,
+    config: &LintConfig,
+    registry: &registry::RuleRegistry,
+    ctx: &context::LintContext,
+    guard: &guards::ResourceGuard,
+    selected: impl Fn(&str) -> bool,
+) -> (RuleIssues, Vec<guards::SkippedRule>, Option<profile::Profile>) {
+    let profiling = config.profile.unwrap_or(false);
+    let started = profiling.then(profile::now_ms);
+    let mut durations: std::collections::HashMap<String, f64> = std::collections::HashMap::new();
+    let mut timed = |rule_id: &str, run: &mut dyn FnMut()| {
+        if profiling {
+            let start = profile::now_ms();
+            run();
+            *durations.entry(rule_id.to_string()).or_default() += profile::now_ms() - start;
+        } else {
+            run();
+        }
+    };
     
-    if enabled_rules.is_none() || enabled_rules.unwrap().contains(&"test-description-with-uri".to_string()) {
-        issues.extend(rules::testing::test_description_with_uri::check(collection));
+    // Garde-fous : ignorer les règles dont l'entrée est trop volumineuse
+    let mut skipped_rules = Vec::new();
+    let mut should_run = |rule_id: &str| {
+        if !config.is_rule_enabled(rule_id) {
+            return false;
+        }
+        match guard.skip_reason(rule_id) {
+            Some(reason) => {
+                skipped_rules.push(guards::SkippedRule {
+                    rule_id: rule_id.to_string(),
+                    reason: reason.to_string(),
+                });
+                false
+            }
+            None => true,
+        }
+    };
+    
+    // Règles intégrées, enregistrées par catégorie (voir `rules::builtin`)
+    let builtin_rules: Vec<&dyn rules::Rule> = rules::builtin().filter(|rule| should_run(rule.id())).collect();
+    
+    // Un seul parcours de la collection pour toutes les règles qui visitent les items ;
+    // leurs issues sont regroupées par règle pour conserver l'ordre d'exécution
+    let mut visitors = Vec::new();
+    for rule in &builtin_rules {
+        let mut visitor = None;
+        if selected(rule.id()) {
+            timed(rule.id(), &mut || visitor = rule.visitor(ctx, config));
+        }
+        visitors.push(visitor);
     }
-    
-    if enabled_rules.is_none() || enabled_rules.unwrap().contains(&"test-response-time-mandatory".to_string()) {
-        issues.extend(rules::testing::test_response_time_mandatory::check(collection));
+    let mut item_issues: Vec<Vec<LintIssue>> = builtin_rules.iter().map(|_| Vec::new()).collect();
+    if visitors.iter().any(Option::is_some) {
+        ctx.walk(collection, |item, typed| {
+            for ((rule, visitor), rule_issues) in builtin_rules.iter().zip(visitors.iter_mut()).zip(item_issues.iter_mut()) {
+                if let Some(visitor) = visitor {
+                    timed(rule.id(), &mut || visitor.visit(item, typed, ctx, rule_issues));
+                }
+            }
+        });
     }
-    
-    if enabled_rules.is_none() || enabled_rules.unwrap().contains(&"test-body-content-validation".to_string()) {
-        issues.extend(rules::testing::test_body_content_validation::check(collection));
+    let mut issues: RuleIssues = Vec::new();
+    for ((rule, visitor), mut rule_issues) in builtin_rules.iter().zip(visitors).zip(item_issues) {
+        let rule_issues = if !selected(rule.id()) {
+            None
+        } else if let Some(mut visitor) = visitor {
+            timed(rule.id(), &mut || visitor.finish(ctx, &mut rule_issues));
+            Some(rule_issues)
+        } else {
+            let mut checked = Vec::new();
+            timed(rule.id(), &mut || checked = rule.check(collection, ctx, config));
+            Some(checked)
+        };
+        issues.push((rule.id().to_string(), rule_issues));
     }
     
-    if enabled_rules.is_none() || enabled_rules.unwrap().contains(&"test-schema-validation-recommended".to_string()) {
-        issues.extend(rules::testing::test_schema_validation_recommended::check(collection));
+    // Règles externes (crates de règles compilés dans un build personnalisé)
+    for rule in registry.rules() {
+        if should_run(rule.id()) {
+            let options = config
+                .rule_options
+                .as_ref()
+                .and_then(|options| options.get(rule.id()))
+                .cloned()
+                .unwrap_or(Value::Null);
+            let rule_issues = selected(rule.id()).then(|| {
+                let mut checked = Vec::new();
+                timed(rule.id(), &mut || checked = rule.check(collection, &options));
+                checked
+            });
+            issues.push((rule.id().to_string(), rule_issues));
+        }
     }
     
-    // Structure rules
-    if enabled_rules.is_none() || enabled_rules.unwrap().contains(&"request-naming-convention".to_string()) {
-        issues.extend(rules::structure::request_naming_convention::check(collection));
-    }
+    let profile = started.map(|started| {
+        let rules = issues
+            .iter()
+            .filter_map(|(rule_id, rule_issues)| {
+                Some(profile::RuleProfile {
+                    rule_id: rule_id.clone(),
+                    duration_ms: durations.get(rule_id).copied().unwrap_or_default(),
+                    issues: rule_issues.as_ref()?.len(),
+                })
+            })
+            .collect();
+        profile::Profile::new(profile::now_ms() - started, rules)
+    });
     
-    // Performance rules
-    if enabled_rules.is_none() || enabled_rules.unwrap().contains(&"response-time-threshold".to_string()) {
-        issues.extend(rules::performance::response_time_threshold::check(collection));
+    (issues, skipped_rules, profile)
+}
+
+/// Sévérités, suppressions, stats, score et annotations à partir des issues brutes
+pub(crate) fn finish(
+    collection: &Value,
+    config: &LintConfig,
+    registry: &registry::RuleRegistry,
+    guard: &guards::ResourceGuard,
+    issues: Vec<LintIssue>,
+    skipped_rules: Vec<guards::SkippedRule>,
+) -> LintResult {
+    let visibility = config.visibility.unwrap_or_default();
+    
+    // Retirer les issues des items ignorés
+    let mut issues = ignore::apply(collection, issues, config.ignore.as_deref().unwrap_or_default());
+    
+    // Messages rendus dans la langue demandée (les règles émettent en anglais), et
+    // path au format JSON Pointer
+    for issue in issues.iter_mut() {
+        if let Some(locale) = config.locale {
+            i18n::localize(issue, locale);
+        }
+        issue.pointer = Some(pointer::from_path(&issue.path));
     }
     
-    // Best practices rules
-    if enabled_rules.is_none() || enabled_rules.unwrap().contains(&"environment-variables-usage".to_string()) {
-        issues.extend(rules::best_practices::environment_variables_usage::check(collection));
-    }
+    // Durcir les sévérités selon le profil de visibilité
+    visibility.adjust_severities(&mut issues);
     
-    if enabled_rules.is_none() || enabled_rules.unwrap().contains(&"test-coverage-minimum".to_string()) {
-        issues.extend(rules::best_practices::test_coverage_minimum::check(collection));
+    // Sévérités imposées par la configuration
+    if let Some(overrides) = &config.severity_overrides {
+        for issue in issues.iter_mut() {
+            match overrides.get(&issue.rule_id) {
+                Some(severity) if rules::ISSUE_SEVERITIES.contains(&severity.as_str()) => issue.severity = severity.clone(),
+                _ => {}
+            }
+        }
     }
     
-    // Documentation rules
-    if enabled_rules.is_none() || enabled_rules.unwrap().contains(&"collection-overview-template".to_string()) {
-        // Pass custom template config if available
-        let template_config = config.custom_templates.as_ref()
-            .and_then(|t| t.get("collection-overview-template"))
-            .cloned();
-        issues.extend(rules::documentation::collection_overview_template::check_with_config(collection, template_config));
-    }
+    // Écarter les issues masquées (elles restent listées dans le rapport)
+    let (mut issues, suppressed) =
+        suppression::apply(collection, issues, config.suppressions.as_deref().unwrap_or_default());
     
-    if enabled_rules.is_none() || enabled_rules.unwrap().contains(&"request-examples-required".to_string()) {
-        issues.extend(rules::documentation::request_examples_required::check(collection));
+    // Un même problème signalé par plusieurs règles ne compte qu'une fois
+    if config.deduplicate.unwrap_or(true) {
+        dedup::apply(&mut issues);
     }
     
-    // Security rules
-    if enabled_rules.is_none() || enabled_rules.unwrap().contains(&"hardcoded-secrets".to_string()) {
-        issues.extend(rules::security::hardcoded_secrets::check(collection));
-    }
+    // Relier les issues en cascade
+    relations::link(&mut issues);
     
     // Calculer les stats
-    let stats = calculate_stats(collection, &issues);
+    let mut stats = calculate_stats(collection, &issues);
+    stats.categories = scoring::tally(&issues, |category| scoring::cap_for(config, category));
     
     // Calculer le score
-    let score = calculate_score(&issues, &stats);
+    let score = scoring::score(&mut stats.categories, stats.total_requests) as u32;
+    
+    // Effort et gain de score de chaque correction
+    remediation::annotate(&mut issues, &stats);
     
-    LintResult {
+    // Niveau de maturité, avant troncature
+    let maturity = maturity::assess(config, &issues, &skipped_rules);
+    
+    // Ordre stable, indépendant de l'ordre d'exécution des règles
+    ordering::sort(&mut issues, config.sort_by.unwrap_or_default());
+    
+    let mut result = LintResult {
         score,
         issues,
         stats,
+        coverage: if guard.scripts_within_limits() {
+            coverage::compute(collection)
+        } else {
+            coverage::CoverageMatrix::default()
+        },
+        skipped_rules,
+        suppressed,
+        truncated: Default::default(),
+        config_warnings: rules::validate_config(config, registry),
+        shard: None,
+        engine_version: ENGINE_VERSION.to_string(),
+        ruleset_hash: rules::ruleset_hash(config, registry),
+        maturity,
+        groups: None,
+        profile: None,
+    };
+    if let Some(by) = config.group_by {
+        result.groups = Some(result.group_issues(by));
+    }
+    // Limiter le volume du résultat (les stats gardent les vrais totaux)
+    if let Some(max) = config.max_issues_per_rule {
+        result.truncate_per_rule(max);
+    }
+    result
+}
+
+impl LintResult {
+    /// Ne conserve que les `max` premières issues de chaque règle, les omissions étant
+    /// comptées dans `truncated`. À appliquer après tout filtrage des issues (baseline) :
+    /// la CLI analyse sans `max_issues_per_rule` et tronque juste avant la sortie.
+    pub fn truncate_per_rule(&mut self, max: usize) {
+        let mut kept: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
+        let truncated = &mut self.truncated;
+        self.issues.retain(|issue| {
+            let count = kept.entry(issue.rule_id.clone()).or_default();
+            *count += 1;
+            if *count > max {
+                *truncated.entry(issue.rule_id.clone()).or_default() += 1;
+                false
+            } else {
+                true
+            }
+        });
+        self.regroup();
     }
 }
 
 fn calculate_stats(collection: &Value, issues: &[LintIssue]) -> LintStats {
-    let total_requests = count_requests(collection);
+    let mut methods = std::collections::HashMap::new();
+    let total_requests = count_requests(collection, &mut methods);
     let total_tests = count_tests(collection);
     let total_folders = count_folders(collection);
     
     let errors = issues.iter().filter(|i| i.severity == "error").count() as u32;
     let warnings = issues.iter().filter(|i| i.severity == "warning").count() as u32;
     let infos = issues.iter().filter(|i| i.severity == "info").count() as u32;
+    let hints = issues.iter().filter(|i| i.severity == "hint").count() as u32;
+    
+    // Arrondi à 0.1% pour la sortie JSON
+    let test_coverage_percent =
+        (rules::best_practices::test_coverage_minimum::coverage_percent(collection) * 10.0).round() / 10.0;
     
     LintStats {
         total_requests,
@@ -142,17 +578,23 @@ fn calculate_stats(collection: &Value, issues: &[LintIssue]) -> LintStats {
         errors,
         warnings,
         infos,
+        hints,
+        test_coverage_percent,
+        methods,
+        categories: Default::default(),
     }
 }
 
-fn count_requests(value: &Value) -> u32 {
+fn count_requests(value: &Value, methods: &mut std::collections::HashMap<String, u32>) -> u32 {
     let mut count = 0;
     if let Some(items) = value["item"].as_array() {
         for item in items {
-            if item.get("request").is_some() {
+            if let Some(request) = item.get("request") {
                 count += 1;
+                let method = request["method"].as_str().unwrap_or("GET").to_uppercase();
+                *methods.entry(method).or_default() += 1;
             }
-            count += count_requests(item);
+            count += count_requests(item, methods);
         }
     }
     count
@@ -188,94 +630,17 @@ fn count_folders(value: &Value) -> u32 {
     count
 }
 
-fn calculate_score(issues: &[LintIssue], stats: &LintStats) -> u32 {
-    let base_score = 100.0;
-    
-    // Compter les issues par sévérité
-    let errors = issues.iter().filter(|i| i.severity == "error").count() as f64;
-    let warnings = issues.iter().filter(|i| i.severity == "warning").count() as f64;
-    let infos = issues.iter().filter(|i| i.severity == "info").count() as f64;
-    
-    // Calculer le score basé sur le pourcentage de requêtes avec des problèmes
-    // Au lieu de pénaliser par nombre absolu, on pénalise par ratio
-    let total_requests = stats.total_requests.max(1) as f64; // Éviter division par zéro
-    
-    // Pourcentage de requêtes affectées par chaque type de problème
-    let error_ratio = (errors / total_requests).min(1.0); // Max 100%
-    let warning_ratio = (warnings / total_requests).min(1.0);
-    let info_ratio = (infos / total_requests).min(1.0);
-    
-    // Pénalités basées sur le ratio (pas le nombre absolu)
-    // Si 100% des requêtes ont une erreur = -15%
-    // Si 50% des requêtes ont une erreur = -7.5%
-    let error_penalty = error_ratio * 15.0;
-    let warning_penalty = warning_ratio * 8.0;
-    let info_penalty = info_ratio * 3.0;
-    
-    let mut score = base_score - error_penalty - warning_penalty - info_penalty;
-    
-    // Bonus: +5% si 0 erreurs ET ≤2 warnings (comme dans le projet source)
-    if errors == 0.0 && warnings <= 2.0 {
-        score += 5.0;
-    }
-    
-    // Limiter entre 0 et 100
-    score.max(0.0).min(100.0) as u32
-}
-
-// ============================================================================
-// WASM Bindings
-// ============================================================================
-
-#[wasm_bindgen]
-pub fn lint(collection_json: &str, config_json: &str) -> Result<String, JsValue> {
-    let collection: Value = serde_json::from_str(collection_json)
-        .map_err(|e| JsValue::from_str(&format!("Failed to parse collection: {}", e)))?;
-    
-    let config: LintConfig = serde_json::from_str(config_json)
-        .map_err(|e| JsValue::from_str(&format!("Failed to parse config: {}", e)))?;
-    
-    let result = run_linter(&collection, &config);
-    
-    serde_json::to_string(&result)
-        .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))
-}
-
-/// Applique les corrections automatiques et retourne la collection corrigée + le nombre de fixes appliqués
-#[wasm_bindgen]
-pub fn lint_and_fix(collection_json: &str, config_json: &str) -> Result<String, JsValue> {
-    let mut collection: Value = serde_json::from_str(collection_json)
-        .map_err(|e| JsValue::from_str(&format!("Failed to parse collection: {}", e)))?;
-    
-    let config: LintConfig = serde_json::from_str(config_json)
-        .map_err(|e| JsValue::from_str(&format!("Failed to parse config: {}", e)))?;
-    
-    // 1. Lancer le linter pour obtenir les issues
-    let result = run_linter(&collection, &config);
-    
-    // 2. Appliquer les corrections
-    let fixes_applied = fixer::apply_fixes(&mut collection, &result.issues);
-    
-    // 3. Re-lancer le linter sur la collection corrigée
-    let new_result = run_linter(&collection, &config);
-    
-    // 4. Retourner la collection corrigée + les stats
-    let response = serde_json::json!({
-        "fixed_collection": collection,
-        "fixes_applied": fixes_applied,
-        "before": {
-            "score": result.score,
-            "issues": result.issues.len(),
-        },
-        "after": {
-            "score": new_result.score,
-            "issues": new_result.issues.len(),
-        },
-        "remaining_issues": new_result.issues,
+/// Score d'un ensemble d'issues, avec les plafonds de catégorie de `stats`
+pub(crate) fn calculate_score(issues: &[LintIssue], stats: &LintStats) -> u32 {
+    let defaults = LintConfig::default();
+    let mut categories = scoring::tally(issues, |category| {
+        stats
+            .categories
+            .get(category)
+            .map(|score| score.max_penalty)
+            .unwrap_or_else(|| scoring::cap_for(&defaults, category))
     });
-    
-    serde_json::to_string(&response)
-        .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))
+    scoring::score(&mut categories, stats.total_requests) as u32
 }
 
 #[cfg(test)]
@@ -291,9 +656,283 @@ mod tests {
         let config = LintConfig {
             local_only: true,
             rules: Some(vec![]), // Désactiver toutes les règles pour ce test
-            fix: None,
+            ..Default::default()
         };
         let result = run_linter(&collection, &config);
         assert_eq!(result.score, 100);
     }
+
+    #[test]
+    fn test_stats_methods() {
+        let collection = serde_json::json!({
+            "item": [
+                { "name": "Users", "item": [
+                    { "name": "GET Users", "request": { "method": "GET", "url": "/users" } },
+                    { "name": "POST Users", "request": { "method": "post", "url": "/users" } }
+                ] },
+                { "name": "Health", "request": { "url": "/health" } }
+            ]
+        });
+        let config = LintConfig { rules: Some(vec![]), ..Default::default() };
+
+        let methods = run_linter(&collection, &config).stats.methods;
+        assert_eq!(methods.len(), 2);
+        assert_eq!(methods["GET"], 2);
+        assert_eq!(methods["POST"], 1);
+    }
+
+    #[test]
+    fn test_public_visibility_profile() {
+        let collection = serde_json::json!({
+            "info": { "name": "Test" },
+            "item": [{ "name": "GET Users", "request": { "method": "GET", "url": "http://10.1.2.3/users" } }]
+        });
+        let mut config = LintConfig {
+            local_only: true,
+            rules: Some(vec!["internal-hosts-disclosure".to_string()]),
+            ..Default::default()
+        };
+
+        assert_eq!(run_linter(&collection, &config).issues.len(), 0);
+
+        config.visibility = Some(visibility::Visibility::Public);
+        let result = run_linter(&collection, &config);
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].severity, "error");
+    }
+
+    #[test]
+    fn test_disabled_rules() {
+        let collection = serde_json::json!({
+            "info": { "name": "Test" },
+            "item": [{ "name": "GET Users", "request": { "method": "GET", "url": "https://api.example.com/users" } }]
+        });
+        let mut config = LintConfig {
+            disabled_rules: Some(vec!["test-http-status-mandatory".to_string(), "test-http-statuss".to_string()]),
+            ..Default::default()
+        };
+
+        let result = run_linter(&collection, &config);
+        assert!(!result.issues.is_empty());
+        assert!(result.issues.iter().all(|issue| issue.rule_id != "test-http-status-mandatory"));
+        assert_eq!(result.config_warnings, vec!["disabled_rules: unknown rule 'test-http-statuss'"]);
+
+        // L'exclusion l'emporte sur la liste des règles activées
+        config.rules = Some(vec!["test-http-status-mandatory".to_string()]);
+        assert!(run_linter(&collection, &config).issues.is_empty());
+    }
+
+    #[test]
+    fn test_lint_json_errors() {
+        let collection = r#"{ "info": { "name": "Test" }, "item": [] }"#;
+        let registry = registry::RuleRegistry::new();
+
+        lint_json(collection, r#"{ "local_only": true }"#, &registry).unwrap();
+        assert!(matches!(
+            lint_json("{ \"info\": ", r#"{ "local_only": true }"#, &registry),
+            Err(LintError::Parse { ref input, line: Some(1), .. }) if input == "collection"
+        ));
+        assert_eq!(
+            lint_json(collection, r#"{ "local_only": true, "disabled_rules": ["test-http-statuss"] }"#, &registry).unwrap_err(),
+            LintError::UnknownRule("test-http-statuss".to_string())
+        );
+        let invalid = lint_json(collection, r#"{ "local_only": true, "severity_overrides": { "debug-headers": "fatal" } }"#, &registry);
+        assert!(matches!(invalid, Err(LintError::InvalidConfig(_))));
+    }
+
+    #[test]
+    fn test_ignored_folder() {
+        let collection = serde_json::json!({
+            "info": { "name": "Test" },
+            "item": [
+                { "name": "Deprecated", "item": [{ "name": "GET Old", "request": { "method": "GET", "url": "https://api.example.com/old" } }] },
+                { "name": "GET Users", "request": { "method": "GET", "url": "https://api.example.com/users" } }
+            ]
+        });
+        let mut config = LintConfig { rules: Some(vec!["test-http-status-mandatory".to_string()]), ..Default::default() };
+        assert_eq!(run_linter(&collection, &config).issues.len(), 2);
+
+        config.ignore = Some(vec![ignore::IgnorePattern::All("Deprecated/**".to_string())]);
+        let result = run_linter(&collection, &config);
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].path, "/item[1]");
+        assert!(result.suppressed.is_empty());
+    }
+
+    #[test]
+    fn test_severity_overrides() {
+        let collection = serde_json::json!({
+            "info": { "name": "Test" },
+            "item": [{ "name": "GET Users", "request": { "method": "GET", "url": "{{base_url}}/users", "body": { "mode": "raw", "raw": "{}" } } }]
+        });
+        let config = LintConfig {
+            local_only: true,
+            rules: Some(vec!["no-body-on-get".to_string()]),
+            severity_overrides: Some(
+                [("no-body-on-get", "error"), ("debug-headers", "critical")]
+                    .into_iter()
+                    .map(|(rule, severity)| (rule.to_string(), severity.to_string()))
+                    .collect(),
+            ),
+            ..Default::default()
+        };
+
+        let result = run_linter(&collection, &config);
+
+        assert_eq!(result.issues[0].severity, "error");
+        assert_eq!(result.stats.errors, 1);
+        assert_eq!(
+            result.config_warnings,
+            vec!["severities: 'critical' is not a valid severity for rule 'debug-headers' (expected one of: error, warning, info, hint, off)"]
+        );
+    }
+
+    #[test]
+    fn test_hint_and_off_severities() {
+        let collection = serde_json::json!({
+            "info": { "name": "Test" },
+            "item": [{ "name": "GET Users", "request": { "method": "GET", "url": "{{base_url}}/users", "body": { "mode": "raw", "raw": "{}" } } }]
+        });
+        let overrides = |severities: &[(&str, &str)]| {
+            Some(severities.iter().map(|(rule, severity)| (rule.to_string(), severity.to_string())).collect())
+        };
+        let mut config = LintConfig {
+            local_only: true,
+            rules: Some(vec!["no-body-on-get".to_string(), "test-http-status-mandatory".to_string()]),
+            severity_overrides: overrides(&[("no-body-on-get", "hint"), ("test-http-status-mandatory", "hint")]),
+            ..Default::default()
+        };
+
+        // Issues conservées mais sans effet sur le score
+        let result = run_linter(&collection, &config);
+        assert_eq!(result.issues.len(), 2);
+        assert!(result.issues.iter().all(|issue| issue.severity == "hint" && issue.score_impact == Some(0.0)));
+        assert_eq!(result.stats.hints, 2);
+        assert_eq!(result.score, 100);
+        assert!(result.config_warnings.is_empty());
+
+        // "off" : la règle n'est plus exécutée
+        config.severity_overrides = overrides(&[("no-body-on-get", "off")]);
+        let result = run_linter(&collection, &config);
+        assert!(!config.is_rule_enabled("no-body-on-get"));
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].rule_id, "test-http-status-mandatory");
+    }
+
+    #[test]
+    fn test_oversized_script_skips_rule() {
+        let collection = serde_json::json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "GET Users",
+                "request": { "method": "GET", "url": "{{base_url}}/users" },
+                "event": [{ "listen": "test", "script": { "exec": ["x".repeat(4096)] } }]
+            }]
+        });
+        let config = LintConfig {
+            local_only: true,
+            rules: Some(vec!["test-http-status-mandatory".to_string()]),
+            limits: Some(guards::ResourceLimits { max_script_bytes: 1024, ..Default::default() }),
+            ..Default::default()
+        };
+
+        let result = run_linter(&collection, &config);
+
+        assert!(result.issues.is_empty());
+        assert_eq!(result.skipped_rules.len(), 1);
+        assert_eq!(result.skipped_rules[0].rule_id, "test-http-status-mandatory");
+    }
+
+    #[test]
+    fn test_localized_messages() {
+        let collection = serde_json::json!({
+            "info": { "name": "Test", "description": "Short" },
+            "item": [
+                { "name": "Users", "item": [
+                    { "name": "List users", "request": { "method": "GET", "url": "https://api.acme.com/users?page=1", "body": { "mode": "raw", "raw": "{}" } } },
+                    { "name": "x Old login", "request": { "method": "POST", "url": "{{base_url}}/login?api_key=abcdefghijklmnopqrstuvwxyz" } }
+                ] }
+            ]
+        });
+        let mut config = LintConfig { local_only: true, ..Default::default() };
+        let english = run_linter(&collection, &config);
+
+        config.locale = Some(i18n::Locale::Fr);
+        let french = run_linter(&collection, &config);
+
+        assert_eq!(english.issues.len(), french.issues.len());
+        for (en, fr) in english.issues.iter().zip(&french.issues) {
+            assert!(en.message_id.is_some(), "message hors catalogue : {}", en.message);
+            assert_ne!(en.message, fr.message);
+            assert_eq!(en.message_id, fr.message_id);
+        }
+    }
+
+    #[test]
+    fn test_cross_rule_deduplication() {
+        let collection = serde_json::json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "GET Users",
+                "request": { "method": "GET", "url": "{{base_url}}/users" },
+                "event": [{ "listen": "test", "script": { "exec": ["pm.response.to.have.status(200);"] } }]
+            }]
+        });
+        let mut config = LintConfig {
+            local_only: true,
+            rules: Some(vec![
+                "test-body-content-validation".to_string(),
+                "test-schema-validation-recommended".to_string(),
+            ]),
+            ..Default::default()
+        };
+        let deduplicated = run_linter(&collection, &config);
+        assert_eq!(deduplicated.issues.len(), 1);
+        assert_eq!(deduplicated.issues[0].rule_id, "test-body-content-validation");
+
+        config.deduplicate = Some(false);
+        assert_eq!(run_linter(&collection, &config).issues.len(), 2);
+    }
+
+    #[test]
+    fn test_structured_messages() {
+        let collection = serde_json::json!({
+            "info": { "name": "Test" },
+            "item": [{ "name": "GET Users", "request": { "method": "GET", "url": "{{base_url}}/users" } }]
+        });
+        let config = LintConfig {
+            local_only: true,
+            rules: Some(vec!["test-http-status-mandatory".to_string()]),
+            ..Default::default()
+        };
+        let result = run_linter(&collection, &config);
+        let issue = &result.issues[0];
+
+        assert_eq!(issue.message_id.as_deref(), Some("test-http-status-mandatory.missing"));
+        assert_eq!(issue.params["request"], "GET Users");
+        let json = serde_json::to_value(issue).unwrap();
+        assert_eq!(json["params"], serde_json::json!({ "request": "GET Users" }));
+    }
+
+    #[test]
+    fn test_max_issues_per_rule_keeps_true_stats() {
+        let requests: Vec<Value> = (0..5)
+            .map(|i| serde_json::json!({ "name": format!("GET Item {}", i), "request": { "method": "GET", "url": "{{base_url}}/items" } }))
+            .collect();
+        let collection = serde_json::json!({ "info": { "name": "Test" }, "item": requests });
+        let mut config = LintConfig {
+            local_only: true,
+            rules: Some(vec!["test-http-status-mandatory".to_string()]),
+            ..Default::default()
+        };
+        let full = run_linter(&collection, &config);
+
+        config.max_issues_per_rule = Some(2);
+        let result = run_linter(&collection, &config);
+
+        assert_eq!(result.issues.len(), 2);
+        assert_eq!(result.truncated["test-http-status-mandatory"], 3);
+        assert_eq!(result.stats.errors, 5);
+        assert_eq!(result.score, full.score);
+    }
 }
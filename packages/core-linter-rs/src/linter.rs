@@ -0,0 +1,261 @@
+//! API Rust pour l'intégration (bots de CI, validateurs côté serveur)
+//!
+//! Surface stable du crate pour les outils Rust, sans passer par des chaînes JSON :
+//! `LintConfig::builder()` construit la configuration, `Linter` garde la
+//! configuration et les règles externes, et retourne des résultats typés
+//! (`LintResult`, `FixOutcome`).
+//!
+//! ```
+//! use postman_linter_core::{LintConfig, Linter};
+//! use serde_json::json;
+//!
+//! let collection = json!({
+//!     "info": { "name": "Shop" },
+//!     "item": [{ "name": "GET Users", "request": { "method": "GET", "url": "{{base_url}}/users" } }]
+//! });
+//! let config = LintConfig::builder()
+//!     .rules(["test-http-status-mandatory"])
+//!     .severity("test-http-status-mandatory", "warning")
+//!     .build();
+//!
+//! let result = Linter::new(config).lint(&collection);
+//! assert_eq!(result.issues[0].severity, "warning");
+//! ```
+//!
+//! Les autres modules restent publics pour la CLI et les bindings, mais peuvent
+//! évoluer entre deux versions mineures.
+
+use crate::i18n::Locale;
+use crate::grouping::GroupBy;
+use crate::ignore::IgnorePattern;
+use crate::ordering::SortBy;
+use crate::registry::RuleRegistry;
+use crate::rule_api::Rule;
+use crate::rules::Category;
+use crate::session::{FixOutcome, LintSession};
+use crate::source_map;
+use crate::summary::QualityGate;
+use crate::visibility::Visibility;
+use crate::{run_linter_with_registry, LintConfig, LintError, LintResult};
+use serde_json::Value;
+
+/// Linter configuré, réutilisable pour plusieurs collections
+pub struct Linter {
+    config: LintConfig,
+    registry: RuleRegistry,
+}
+
+impl Linter {
+    /// Linter limité aux règles intégrées
+    pub fn new(config: LintConfig) -> Self {
+        Linter { config, registry: RuleRegistry::new() }
+    }
+
+    /// Linter exécutant aussi les règles externes du registre
+    pub fn with_registry(config: LintConfig, registry: RuleRegistry) -> Self {
+        Linter { config, registry }
+    }
+
+    /// Ajoute une règle externe (voir `linterman_rule_api::Rule`)
+    pub fn register(&mut self, rule: impl Rule + 'static) -> Result<(), String> {
+        self.registry.register(rule)
+    }
+
+    pub fn config(&self) -> &LintConfig {
+        &self.config
+    }
+
+    /// Validation stricte de la configuration (voir `LintConfig::validate`)
+    pub fn validate(&self) -> Result<(), LintError> {
+        self.config.validate(&self.registry)
+    }
+
+    pub fn lint(&self, collection: &Value) -> LintResult {
+        run_linter_with_registry(collection, &self.config, &self.registry)
+    }
+
+    /// Analyse une collection JSON ; une erreur de syntaxe est localisée
+    pub fn lint_str(&self, collection_json: &str) -> Result<LintResult, LintError> {
+        let collection: Value = serde_json::from_str(collection_json).map_err(|e| LintError::parse("collection", &e))?;
+        let mut result = self.lint(&collection);
+        if self.config.source_map.unwrap_or(false) {
+            source_map::annotate(&mut result, collection_json);
+        }
+        Ok(result)
+    }
+
+    /// Applique les corrections automatiques à la collection puis la ré-analyse
+    pub fn fix(&self, collection: &mut Value) -> Result<FixOutcome, LintError> {
+        let mut session = LintSession::new(std::mem::take(collection));
+        let outcome = session.fix(&self.config, &self.registry);
+        *collection = session.collection().clone();
+        outcome
+    }
+}
+
+impl LintConfig {
+    /// Démarre la construction d'une configuration (toutes les règles par défaut)
+    pub fn builder() -> LintConfigBuilder {
+        LintConfigBuilder::default()
+    }
+}
+
+/// Construction pas à pas d'une [`LintConfig`]
+#[derive(Default, Clone)]
+pub struct LintConfigBuilder {
+    config: LintConfig,
+}
+
+impl LintConfigBuilder {
+    /// Règles activées (toutes si non appelé)
+    pub fn rules<S: Into<String>>(mut self, rules: impl IntoIterator<Item = S>) -> Self {
+        self.config.rules = Some(rules.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Règles exclues, appliquées après `rules`
+    pub fn disable<S: Into<String>>(mut self, rules: impl IntoIterator<Item = S>) -> Self {
+        self.config.disabled_rules = Some(rules.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Options d'une règle (ex: `json!({ "max_examples": 5 })`)
+    pub fn rule_option(mut self, rule_id: &str, options: Value) -> Self {
+        self.config.rule_options.get_or_insert_with(Default::default).insert(rule_id.to_string(), options);
+        self
+    }
+
+    /// Sévérité imposée à une règle ("error", "warning", "info", "hint" ou "off")
+    pub fn severity(mut self, rule_id: &str, severity: &str) -> Self {
+        self.config
+            .severity_overrides
+            .get_or_insert_with(Default::default)
+            .insert(rule_id.to_string(), severity.to_string());
+        self
+    }
+
+    /// Pénalité maximale d'une catégorie sur le score
+    pub fn category_cap(mut self, category: Category, max_penalty: f64) -> Self {
+        self.config.category_caps.get_or_insert_with(Default::default).insert(category, max_penalty);
+        self
+    }
+
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.config.locale = Some(locale);
+        self
+    }
+
+    pub fn visibility(mut self, visibility: Visibility) -> Self {
+        self.config.visibility = Some(visibility);
+        self
+    }
+
+    /// Exclut des items de l'analyse
+    pub fn ignore(mut self, pattern: IgnorePattern) -> Self {
+        self.config.ignore.get_or_insert_with(Vec::new).push(pattern);
+        self
+    }
+
+    pub fn quality_gate(mut self, gate: QualityGate) -> Self {
+        self.config.quality_gate = Some(gate);
+        self
+    }
+
+    /// Garde toutes les issues (`false`) au lieu de fusionner celles qui décrivent un
+    /// même problème
+    pub fn deduplicate(mut self, enabled: bool) -> Self {
+        self.config.deduplicate = Some(enabled);
+        self
+    }
+
+    /// Inclut les issues regroupées par règle ou par item dans le résultat
+    pub fn group_by(mut self, by: GroupBy) -> Self {
+        self.config.group_by = Some(by);
+        self
+    }
+
+    /// Mesure le temps d'exécution et les issues de chaque règle (`LintResult::profile`)
+    pub fn profile(mut self, enabled: bool) -> Self {
+        self.config.profile = Some(enabled);
+        self
+    }
+
+    /// Renseigne la ligne et la colonne des issues dans le texte analysé par `lint_str`
+    pub fn source_map(mut self, enabled: bool) -> Self {
+        self.config.source_map = Some(enabled);
+        self
+    }
+
+    /// Ordre des issues dans le résultat
+    pub fn sort_by(mut self, order: SortBy) -> Self {
+        self.config.sort_by = Some(order);
+        self
+    }
+
+    pub fn max_issues_per_rule(mut self, max: usize) -> Self {
+        self.config.max_issues_per_rule = Some(max);
+        self
+    }
+
+    pub fn build(self) -> LintConfig {
+        self.config
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rule_api::{ItemContext, LintIssue};
+    use serde_json::json;
+
+    fn collection() -> Value {
+        json!({
+            "info": { "name": "Test" },
+            "item": [{ "name": "GET Users", "request": { "method": "GET", "url": "https://legacy.acme.com/users" } }]
+        })
+    }
+
+    struct LegacyHost;
+
+    impl Rule for LegacyHost {
+        fn id(&self) -> &str {
+            "acme-legacy-host"
+        }
+
+        fn check_item(&self, item: &ItemContext, _options: &Value) -> Vec<LintIssue> {
+            if item.is_request() && item.url().contains("legacy.acme.com") {
+                vec![LintIssue::builder(self.id()).message("Legacy host").path(&item.path).build()]
+            } else {
+                Vec::new()
+            }
+        }
+    }
+
+    #[test]
+    fn test_linter_with_external_rule() {
+        let config = LintConfig::builder().rules(["test-http-status-mandatory", "acme-legacy-host"]).build();
+        let mut linter = Linter::new(config);
+        linter.register(LegacyHost).unwrap();
+        linter.validate().unwrap();
+
+        let result = linter.lint(&collection());
+        let rule_ids: Vec<&str> = result.issues.iter().map(|issue| issue.rule_id.as_str()).collect();
+        // Même path : issues triées par règle
+        assert_eq!(rule_ids, vec!["acme-legacy-host", "test-http-status-mandatory"]);
+
+        assert!(matches!(linter.lint_str("{ \"item\": ["), Err(LintError::Parse { .. })));
+    }
+
+    #[test]
+    fn test_fix_updates_collection() {
+        let linter = Linter::new(
+            LintConfig::builder().rules(["test-http-status-mandatory"]).locale(Locale::En).build(),
+        );
+        let mut collection = collection();
+
+        let outcome = linter.fix(&mut collection).unwrap();
+        assert_eq!(outcome.fixes_applied, 1);
+        assert!(outcome.after.issues.is_empty());
+        assert!(collection["item"][0]["event"].to_string().contains("pm.response.to.be.success"));
+    }
+}
@@ -0,0 +1,141 @@
+//! Niveaux de maturité (L1 / L2 / L3)
+//!
+//! Parcours d'adoption progressif pour les équipes découvrant la qualité des
+//! collections : chaque règle intégrée appartient à un niveau (par défaut L1 pour
+//! les erreurs, L2 pour les warnings, L3 pour les infos). Un niveau est atteint
+//! quand toutes les règles de ce niveau et des niveaux inférieurs sont activées,
+//! exécutées et sans issue. Le rapport donne le niveau atteint et les règles
+//! manquantes pour le suivant (ou pour `maturity_target`).
+
+use crate::guards::SkippedRule;
+use crate::{rules, LintConfig, LintIssue};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Maturity {
+    /// Fondamentaux : tests de statut, secrets, erreurs bloquantes
+    L1,
+    /// Bonnes pratiques courantes
+    L2,
+    /// Excellence : documentation et finitions
+    L3,
+}
+
+impl Maturity {
+    pub const ALL: [Maturity; 3] = [Maturity::L1, Maturity::L2, Maturity::L3];
+
+    /// Niveau par défaut d'une règle selon sa sévérité
+    pub fn from_severity(severity: &str) -> Self {
+        match severity {
+            "error" => Maturity::L1,
+            "warning" => Maturity::L2,
+            _ => Maturity::L3,
+        }
+    }
+
+    pub fn next(self) -> Option<Self> {
+        match self {
+            Maturity::L1 => Some(Maturity::L2),
+            Maturity::L2 => Some(Maturity::L3),
+            Maturity::L3 => None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct MaturityReport {
+    /// Niveau atteint (`null` tant que L1 n'est pas atteint)
+    pub level: Option<Maturity>,
+    /// Niveau visé (`maturity_target`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<Maturity>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_reached: Option<bool>,
+    /// Niveau suivant (`null` une fois L3 atteint)
+    pub next_level: Option<Maturity>,
+    /// Règles à activer ou à satisfaire pour atteindre le niveau suivant, ou le niveau
+    /// visé s'il est plus élevé (par niveau puis par id)
+    pub missing_rules: Vec<String>,
+}
+
+/// Évalue la maturité d'un résultat : une règle désactivée, ignorée par les
+/// garde-fous ou ayant des issues n'est pas satisfaite
+pub fn assess(config: &LintConfig, issues: &[LintIssue], skipped_rules: &[SkippedRule]) -> MaturityReport {
+    let unsatisfied = rules::builtin()
+        .map(|rule| rule.id())
+        .filter(|id| {
+            !config.is_rule_enabled(id)
+                || issues.iter().any(|issue| issue.rule_id == *id)
+                || skipped_rules.iter().any(|skipped| skipped.rule_id == *id)
+        })
+        .map(str::to_string);
+    evaluate(unsatisfied, config.maturity_target)
+}
+
+/// Rapport à partir des règles non satisfaites (les ids inconnus sont ignorés)
+pub fn evaluate(unsatisfied: impl IntoIterator<Item = String>, target: Option<Maturity>) -> MaturityReport {
+    let unsatisfied: BTreeSet<(Maturity, String)> = unsatisfied
+        .into_iter()
+        .filter_map(|id| rules::find(&id).map(|rule| (rule.maturity(), id)))
+        .collect();
+
+    let level = Maturity::ALL
+        .into_iter()
+        .take_while(|level| unsatisfied.iter().all(|(maturity, _)| maturity > level))
+        .last();
+    let next_level = level.map_or(Some(Maturity::L1), Maturity::next);
+    let goal = next_level.max(target.filter(|target| Some(*target) > level));
+
+    MaturityReport {
+        level,
+        target,
+        target_reached: target.map(|target| level >= Some(target)),
+        next_level,
+        missing_rules: unsatisfied
+            .into_iter()
+            .filter(|(maturity, _)| Some(*maturity) <= goal)
+            .map(|(_, id)| id)
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::run_linter;
+    use serde_json::json;
+
+    #[test]
+    fn test_levels_from_unsatisfied_rules() {
+        let report = evaluate(["test-http-status-mandatory".to_string()], None);
+        assert_eq!(report.level, None);
+        assert_eq!(report.next_level, Some(Maturity::L1));
+        assert_eq!(report.missing_rules, vec!["test-http-status-mandatory"]);
+
+        // Seules des règles L3 manquent : L2 atteint, les règles L3 sont listées
+        let l3 = rules::builtin().find(|rule| rule.maturity() == Maturity::L3).unwrap().id().to_string();
+        let report = evaluate([l3.clone()], Some(Maturity::L2));
+        assert_eq!(report.level, Some(Maturity::L2));
+        assert_eq!(report.target_reached, Some(true));
+        assert_eq!(report.missing_rules, vec![l3]);
+
+        assert_eq!(evaluate(Vec::new(), None).next_level, None);
+    }
+
+    #[test]
+    fn test_disabled_rules_block_the_level() {
+        let collection = json!({ "info": { "name": "Test" }, "item": [] });
+        let config = LintConfig {
+            rules: Some(vec!["test-http-status-mandatory".to_string()]),
+            maturity_target: Some(Maturity::L1),
+            ..Default::default()
+        };
+
+        let report = run_linter(&collection, &config).maturity;
+        assert_eq!(report.level, None);
+        assert_eq!(report.target_reached, Some(false));
+        assert!(!report.missing_rules.contains(&"test-http-status-mandatory".to_string()));
+        assert!(report.missing_rules.contains(&"hardcoded-secrets".to_string()));
+    }
+}
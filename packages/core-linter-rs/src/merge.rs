@@ -0,0 +1,213 @@
+//! Fusion de deux collections (commande `merge`)
+//!
+//! Les équipes fusionnent à la main les exports JSON d'une branche et de `main`,
+//! au risque de casser la collection. La fusion apparie les items de même niveau
+//! par identifiant (`id` / `_postman_id`), à défaut par nom, puis :
+//! - un item présent d'un seul côté est conservé (ceux de `theirs` sont ajoutés
+//!   après ceux de `ours`) ;
+//! - deux folders appariés sont fusionnés récursivement ;
+//! - deux versions différentes d'une requête sont un conflit : la version de
+//!   `ours` est conservée et le conflit est remonté comme issue `merge-conflict`.
+//!
+//! Les variables de collection sont fusionnées par clé ; Overview, scripts et
+//! propriétés de folder divergents sont aussi des conflits.
+
+use crate::hashing::content_hash;
+use crate::LintIssue;
+use serde_json::Value;
+
+/// Identifiant des issues de conflit
+pub const MERGE_CONFLICT: &str = "merge-conflict";
+
+pub struct MergeOutcome {
+    pub collection: Value,
+    /// Conflits, avec leur path dans la collection fusionnée
+    pub conflicts: Vec<LintIssue>,
+}
+
+/// Fusionne `theirs` dans `ours`
+pub fn merge_collections(ours: &Value, theirs: &Value) -> MergeOutcome {
+    let mut conflicts = Vec::new();
+    let mut collection = ours.clone();
+
+    let description = |collection: &Value| collection["info"]["description"].clone();
+    if description(ours) != description(theirs) && !description(theirs).is_null() {
+        if description(ours).is_null() {
+            collection["info"]["description"] = description(theirs);
+        } else {
+            conflicts.push(conflict("/info/description", "the collection Overview"));
+        }
+    }
+    merge_properties(&mut collection, theirs, &["event", "auth"], "", "the collection", &mut conflicts);
+    merge_variables(&mut collection, theirs, &mut conflicts);
+
+    let items = merge_items(
+        ours["item"].as_array().map(Vec::as_slice).unwrap_or_default(),
+        theirs["item"].as_array().map(Vec::as_slice).unwrap_or_default(),
+        "",
+        &mut conflicts,
+    );
+    collection["item"] = Value::Array(items);
+
+    MergeOutcome { collection, conflicts }
+}
+
+/// Clé d'appariement : identifiant Postman, sinon nom
+fn item_key(item: &Value) -> String {
+    match item["id"].as_str().or_else(|| item["_postman_id"].as_str()) {
+        Some(id) => format!("id:{}", id),
+        None => format!("name:{}", item["name"].as_str().unwrap_or_default()),
+    }
+}
+
+fn merge_items(ours: &[Value], theirs: &[Value], parent_path: &str, conflicts: &mut Vec<LintIssue>) -> Vec<Value> {
+    let mut unmatched: Vec<Option<&Value>> = theirs.iter().map(Some).collect();
+    let mut merged = Vec::new();
+
+    for item in ours {
+        let path = format!("{}/item[{}]", parent_path, merged.len());
+        let key = item_key(item);
+        let counterpart = unmatched
+            .iter_mut()
+            .find(|candidate| candidate.is_some_and(|candidate| item_key(candidate) == key))
+            .and_then(Option::take);
+
+        merged.push(match counterpart {
+            None => item.clone(),
+            Some(other) if content_hash(item) == content_hash(other) => item.clone(),
+            Some(other) => match (item["item"].as_array(), other["item"].as_array()) {
+                (Some(children), Some(other_children)) => {
+                    let mut folder = item.clone();
+                    let name = format!("folder \"{}\"", item["name"].as_str().unwrap_or_default());
+                    merge_properties(&mut folder, other, &["description", "event", "auth"], &path, &name, conflicts);
+                    folder["item"] = Value::Array(merge_items(children, other_children, &path, conflicts));
+                    folder
+                }
+                _ => {
+                    conflicts.push(conflict(&path, &format!("\"{}\"", item["name"].as_str().unwrap_or_default())));
+                    item.clone()
+                }
+            },
+        });
+    }
+
+    merged.extend(unmatched.into_iter().flatten().cloned());
+    merged
+}
+
+/// Propriétés conservées de `target` ; une valeur absente est reprise de `other`,
+/// une valeur différente est un conflit
+fn merge_properties(
+    target: &mut Value,
+    other: &Value,
+    keys: &[&str],
+    path: &str,
+    subject: &str,
+    conflicts: &mut Vec<LintIssue>,
+) {
+    for key in keys {
+        let (current, incoming) = (&target[*key], &other[*key]);
+        if incoming.is_null() || current == incoming {
+            continue;
+        }
+        if current.is_null() {
+            target[*key] = incoming.clone();
+        } else {
+            conflicts.push(conflict(&format!("{}/{}", path, key), &format!("the {} of {}", key, subject)));
+        }
+    }
+}
+
+/// Variables de collection fusionnées par clé
+fn merge_variables(collection: &mut Value, theirs: &Value, conflicts: &mut Vec<LintIssue>) {
+    let Some(incoming) = theirs["variable"].as_array() else {
+        return;
+    };
+    if !collection["variable"].is_array() {
+        collection["variable"] = Value::Array(Vec::new());
+    }
+    let variables = collection["variable"].as_array_mut().expect("tableau créé ci-dessus");
+
+    for variable in incoming {
+        match variables.iter().position(|existing| existing["key"] == variable["key"]) {
+            None => variables.push(variable.clone()),
+            Some(index) if variables[index]["value"] != variable["value"] => conflicts.push(conflict(
+                &format!("/variable[{}]", index),
+                &format!("variable \"{}\"", variable["key"].as_str().unwrap_or_default()),
+            )),
+            Some(_) => {}
+        }
+    }
+}
+
+fn conflict(path: &str, subject: &str) -> LintIssue {
+    LintIssue::builder(MERGE_CONFLICT)
+        .severity("error")
+        .message(format!(
+            "⚔️ Merge conflict: both collections changed {} differently (the first collection's version was kept)",
+            subject
+        ))
+        .path(path)
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn request(name: &str, url: &str) -> Value {
+        json!({ "name": name, "request": { "method": "GET", "url": url } })
+    }
+
+    #[test]
+    fn test_merge_by_id_and_name() {
+        let ours = json!({
+            "info": { "name": "Shop" },
+            "variable": [{ "key": "base_url", "value": "https://api.example.com" }],
+            "item": [
+                { "name": "Users", "item": [request("GET Users", "/users")] },
+                { "id": "42", "name": "GET Orders", "request": { "method": "GET", "url": "/orders" } }
+            ]
+        });
+        let theirs = json!({
+            "info": { "name": "Shop" },
+            "variable": [{ "key": "base_url", "value": "https://api.example.com" }, { "key": "token", "value": "" }],
+            "item": [
+                { "id": "42", "name": "GET All Orders", "request": { "method": "GET", "url": "/orders" } },
+                { "name": "Users", "item": [request("GET Users", "/users"), request("POST Users", "/users")] },
+                request("GET Health", "/health")
+            ]
+        });
+
+        let outcome = merge_collections(&ours, &theirs);
+        let items = outcome.collection["item"].as_array().unwrap();
+
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[0]["item"].as_array().unwrap().len(), 2);
+        assert_eq!(items[2]["name"], "GET Health");
+        assert_eq!(outcome.collection["variable"].as_array().unwrap().len(), 2);
+        // Même id, nom différent : conflit, la version de ours est gardée
+        assert_eq!(outcome.conflicts.len(), 1);
+        assert_eq!(outcome.conflicts[0].path, "/item[1]");
+        assert_eq!(items[1]["name"], "GET Orders");
+    }
+
+    #[test]
+    fn test_conflicts_on_collection_properties() {
+        let ours = json!({
+            "info": { "name": "Shop", "description": "Main" },
+            "variable": [{ "key": "base_url", "value": "https://api.example.com" }],
+            "item": [{ "name": "Users", "description": "Users", "item": [] }]
+        });
+        let theirs = json!({
+            "info": { "name": "Shop", "description": "Feature" },
+            "variable": [{ "key": "base_url", "value": "https://staging.example.com" }],
+            "item": [{ "name": "Users", "description": "User accounts", "item": [] }]
+        });
+
+        let paths: Vec<String> = merge_collections(&ours, &theirs).conflicts.into_iter().map(|issue| issue.path).collect();
+
+        assert_eq!(paths, vec!["/info/description", "/variable[0]", "/item[0]/description"]);
+    }
+}
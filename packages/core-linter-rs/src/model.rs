@@ -0,0 +1,482 @@
+//! Modèle typé d'une collection Postman (format v2.x)
+//!
+//! La collection est désérialisée une seule fois par analyse (voir `LintContext::model`)
+//! au lieu que chaque règle relise `item["request"]["url"]` sur la `Value` brute.
+//! La désérialisation est tolérante : un champ de type inattendu prend sa valeur par
+//! défaut plutôt que de faire échouer tout le modèle, comme le fait l'accès indexé
+//! sur `Value`.
+//!
+//! Les règles lisent les items via ce modèle (`TypedItem`, fourni par le parcours
+//! partagé). Restent sur la `Value` brute, faute d'équivalent typé : les règles qui
+//! analysent la requête sérialisée (secrets, résidus de payloads, placeholders, variables
+//! lues), la détection des auths au format v2.0 (`schema-version-current`), les
+//! métadonnées libres de la description de collection (`collection-overview-template`)
+//! et la correspondance avec les timings Newman (par `id` d'item).
+
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+
+/// Désérialise un champ, ou retourne sa valeur par défaut s'il est mal formé
+fn lenient<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Default,
+{
+    let value = Value::deserialize(deserializer)?;
+    Ok(T::deserialize(value).unwrap_or_default())
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct Collection {
+    #[serde(deserialize_with = "lenient")]
+    pub info: Info,
+    #[serde(deserialize_with = "lenient")]
+    pub item: Vec<Item>,
+    #[serde(deserialize_with = "lenient")]
+    pub event: Vec<Event>,
+    #[serde(deserialize_with = "lenient")]
+    pub auth: Option<Auth>,
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct Info {
+    #[serde(deserialize_with = "lenient")]
+    pub name: String,
+    #[serde(deserialize_with = "lenient")]
+    pub description: Description,
+    #[serde(deserialize_with = "lenient")]
+    pub schema: String,
+}
+
+/// Folder (avec `item`) ou requête (avec `request`)
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct Item {
+    #[serde(deserialize_with = "lenient")]
+    pub name: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub description: Description,
+    #[serde(deserialize_with = "lenient")]
+    pub request: Option<Request>,
+    #[serde(deserialize_with = "lenient")]
+    pub response: Vec<Response>,
+    #[serde(deserialize_with = "lenient")]
+    pub item: Option<Vec<Item>>,
+    #[serde(deserialize_with = "lenient")]
+    pub event: Vec<Event>,
+    #[serde(deserialize_with = "lenient")]
+    pub auth: Option<Auth>,
+}
+
+impl Item {
+    pub fn is_request(&self) -> bool {
+        self.request.is_some()
+    }
+
+    pub fn is_folder(&self) -> bool {
+        self.request.is_none() && self.item.is_some()
+    }
+
+    /// Sous-items d'un folder (vide pour une requête)
+    pub fn children(&self) -> &[Item] {
+        self.item.as_deref().unwrap_or_default()
+    }
+
+    /// Nom de l'item, ou "Item-N" (N = position parmi ses voisins, à partir de 1)
+    pub fn display_name(&self, index: usize) -> String {
+        self.name.clone().unwrap_or_else(|| format!("Item-{}", index + 1))
+    }
+
+    /// Description de l'item et, pour une requête, celle de `request.description`,
+    /// jointes par "\n"
+    pub fn description_text(&self) -> String {
+        let request_description = self.request.as_ref().map(|request| request.description.0.as_str());
+        [Some(self.description.0.as_str()), request_description]
+            .into_iter()
+            .flatten()
+            .filter(|description| !description.is_empty())
+            .collect::<Vec<&str>>()
+            .join("\n")
+    }
+
+    /// Type du bloc `auth` de l'item (celui de `request.auth` pour une requête),
+    /// `None` s'il n'en déclare pas
+    pub fn auth_type(&self) -> Option<&str> {
+        let auth = match &self.request {
+            Some(request) => &request.auth,
+            None => &self.auth,
+        };
+        auth_type(auth)
+    }
+
+    /// Scripts de test de l'item, joints par "\n"
+    pub fn test_script(&self) -> String {
+        scripts(&self.event, "test")
+    }
+
+    /// Scripts pre-request de l'item, joints par "\n"
+    pub fn prerequest_script(&self) -> String {
+        scripts(&self.event, "prerequest")
+    }
+}
+
+fn auth_type(auth: &Option<Auth>) -> Option<&str> {
+    auth.as_ref().map(|auth| auth.auth_type.as_str()).filter(|auth_type| !auth_type.is_empty())
+}
+
+fn scripts(events: &[Event], listen: &str) -> String {
+    events
+        .iter()
+        .filter(|event| event.listen == listen)
+        .map(|event| event.script.exec.join("\n"))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct Request {
+    #[serde(deserialize_with = "lenient")]
+    pub method: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub url: Url,
+    #[serde(deserialize_with = "lenient")]
+    pub header: Vec<Header>,
+    #[serde(deserialize_with = "lenient")]
+    pub body: Option<Body>,
+    #[serde(deserialize_with = "lenient")]
+    pub auth: Option<Auth>,
+    #[serde(deserialize_with = "lenient")]
+    pub description: Description,
+}
+
+impl Request {
+    /// Méthode HTTP en majuscules ("GET" par défaut, comme Postman)
+    pub fn method(&self) -> String {
+        self.method.as_deref().unwrap_or("GET").to_uppercase()
+    }
+
+    /// Valeur du premier header actif portant ce nom (insensible à la casse)
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.header
+            .iter()
+            .find(|header| !header.disabled && header.key.eq_ignore_ascii_case(name))
+            .map(|header| header.value.as_str())
+    }
+
+    /// Vrai si la requête porte un body non vide (raw, urlencoded, formdata, graphql...)
+    pub fn has_body(&self) -> bool {
+        self.body.as_ref().map(Body::has_content).unwrap_or(false)
+    }
+}
+
+/// URL Postman : chaîne brute ou objet détaillé (`raw`, `host`, `path`...)
+#[derive(Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum Url {
+    Raw(String),
+    Detailed {
+        #[serde(default, deserialize_with = "lenient")]
+        raw: String,
+        #[serde(default, deserialize_with = "lenient")]
+        host: Vec<String>,
+        #[serde(default, deserialize_with = "lenient")]
+        query: Vec<QueryParam>,
+    },
+}
+
+/// Paramètre de query d'une URL détaillée
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct QueryParam {
+    #[serde(deserialize_with = "lenient")]
+    pub key: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub description: Description,
+}
+
+impl Default for Url {
+    fn default() -> Self {
+        Url::Raw(String::new())
+    }
+}
+
+impl Url {
+    pub fn raw(&self) -> &str {
+        match self {
+            Url::Raw(raw) | Url::Detailed { raw, .. } => raw,
+        }
+    }
+
+    /// Paramètres de query déclarés (vide pour une URL brute)
+    pub fn query(&self) -> &[QueryParam] {
+        match self {
+            Url::Raw(_) => &[],
+            Url::Detailed { query, .. } => query,
+        }
+    }
+
+    /// Hôte sans schéma ni port (ex: "api.example.com" ou "{{base_url}}")
+    pub fn host(&self) -> String {
+        if let Url::Detailed { host, .. } = self {
+            if !host.is_empty() {
+                return host.join(".").split(':').next().unwrap_or("").to_string();
+            }
+        }
+
+        let raw = self.raw();
+        let without_scheme = raw.split_once("://").map(|(_, rest)| rest).unwrap_or(raw);
+        let authority = without_scheme.split(['/', '?', '#']).next().unwrap_or("");
+        // Les variables {{...}} peuvent contenir ':' : ne retirer le port que hors variables
+        if authority.starts_with("{{") {
+            authority.to_string()
+        } else {
+            authority.split(':').next().unwrap_or("").to_string()
+        }
+    }
+
+    /// Path sans schéma, hôte (ou variable d'hôte `{{base_url}}`), query string ni fragment
+    pub fn path(&self) -> String {
+        let raw = self.raw();
+        let without_scheme = raw.split_once("://").map(|(_, rest)| rest);
+        let without_host = match without_scheme {
+            Some(rest) => rest.find(['/', '?', '#']).map(|i| &rest[i..]).unwrap_or(""),
+            None if raw.starts_with("{{") => raw.find("}}").map(|i| &raw[i + 2..]).unwrap_or(""),
+            None => raw,
+        };
+        without_host.split(['?', '#']).next().unwrap_or("").to_string()
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct Header {
+    #[serde(deserialize_with = "lenient")]
+    pub key: String,
+    #[serde(deserialize_with = "lenient")]
+    pub value: String,
+    #[serde(deserialize_with = "lenient")]
+    pub disabled: bool,
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct Body {
+    #[serde(deserialize_with = "lenient")]
+    pub mode: Option<String>,
+    #[serde(deserialize_with = "lenient")]
+    pub raw: String,
+    /// Langage du body raw (`options.raw.language` : "json", "xml"...)
+    #[serde(deserialize_with = "lenient")]
+    pub options: Value,
+    #[serde(deserialize_with = "lenient")]
+    pub urlencoded: Value,
+    #[serde(deserialize_with = "lenient")]
+    pub formdata: Value,
+    #[serde(deserialize_with = "lenient")]
+    pub graphql: Value,
+    #[serde(deserialize_with = "lenient")]
+    pub file: Value,
+}
+
+impl Body {
+    pub fn raw_language(&self) -> &str {
+        self.options["raw"]["language"].as_str().unwrap_or("")
+    }
+
+    /// Vrai si le body du mode courant n'est pas vide
+    pub fn has_content(&self) -> bool {
+        let content = match self.mode.as_deref() {
+            Some("raw") => return !self.raw.trim().is_empty(),
+            Some("urlencoded") => &self.urlencoded,
+            Some("formdata") => &self.formdata,
+            Some("graphql") => &self.graphql,
+            Some("file") => &self.file,
+            _ => return false,
+        };
+        content.as_array().map(|a| !a.is_empty()).unwrap_or(!content.is_null())
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct Auth {
+    /// Type d'auth Postman ("bearer", "basic", "noauth", "inherit"...)
+    #[serde(rename = "type", deserialize_with = "lenient")]
+    pub auth_type: String,
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct Event {
+    #[serde(deserialize_with = "lenient")]
+    pub listen: String,
+    #[serde(deserialize_with = "lenient")]
+    pub script: Script,
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct Script {
+    /// Lignes du script (`exec` accepte aussi une chaîne unique)
+    #[serde(deserialize_with = "exec_lines")]
+    pub exec: Vec<String>,
+}
+
+fn exec_lines<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+    Ok(match Value::deserialize(deserializer)? {
+        Value::String(line) => vec![line],
+        Value::Array(lines) => lines.iter().filter_map(|line| line.as_str().map(str::to_string)).collect(),
+        _ => Vec::new(),
+    })
+}
+
+/// Exemple de réponse sauvegardé
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct Response {
+    #[serde(deserialize_with = "lenient")]
+    pub name: String,
+    #[serde(deserialize_with = "lenient")]
+    pub code: Option<u16>,
+    #[serde(deserialize_with = "lenient")]
+    pub status: String,
+    #[serde(deserialize_with = "lenient")]
+    pub header: Vec<Header>,
+    #[serde(deserialize_with = "lenient")]
+    pub body: String,
+    #[serde(rename = "originalRequest", deserialize_with = "lenient")]
+    pub original_request: Option<Request>,
+}
+
+/// Description d'un item : chaîne ou objet `{content}`
+#[derive(Debug, Clone, Default)]
+pub struct Description(pub String);
+
+impl<'de> Deserialize<'de> for Description {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        let text = value.as_str().or_else(|| value["content"].as_str()).unwrap_or("");
+        Ok(Description(text.to_string()))
+    }
+}
+
+/// Item du modèle typé visité pendant le parcours partagé (voir `LintContext::walk`)
+pub struct TypedItem<'a> {
+    pub item: &'a Item,
+    /// Folders parents, du plus haut au plus proche
+    pub folders: &'a [&'a Item],
+}
+
+/// Item de la collection avec sa position
+pub struct Entry<'a> {
+    /// Path de l'item (`/item[0]/item[2]`)
+    pub path: String,
+    /// Position parmi ses voisins
+    pub index: usize,
+    pub item: &'a Item,
+}
+
+impl Collection {
+    /// Construit le modèle depuis la collection brute (sans la cloner)
+    pub fn from_value(collection: &Value) -> Self {
+        Collection::deserialize(collection).unwrap_or_default()
+    }
+
+    /// Type du bloc `auth` de la collection, `None` s'il n'en déclare pas
+    pub fn auth_type(&self) -> Option<&str> {
+        auth_type(&self.auth)
+    }
+
+    /// Tous les items en profondeur, chaque folder avant ses enfants
+    pub fn entries(&self) -> Vec<Entry<'_>> {
+        fn collect<'a>(items: &'a [Item], parent_path: &str, entries: &mut Vec<Entry<'a>>) {
+            for (index, item) in items.iter().enumerate() {
+                let path = format!("{}/item[{}]", parent_path, index);
+                entries.push(Entry { path: path.clone(), index, item });
+                collect(item.children(), &path, entries);
+            }
+        }
+
+        let mut entries = Vec::new();
+        collect(&self.item, "", &mut entries);
+        entries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_model_from_value() {
+        let collection = json!({
+            "info": { "name": "Shop", "description": { "content": "Overview" } },
+            "item": [{
+                "name": "Orders",
+                "auth": { "type": "bearer" },
+                "item": [{
+                    "name": "GET Orders",
+                    "request": {
+                        "method": "get",
+                        "url": {
+                            "raw": "https://api.example.com:8443/orders?page=1",
+                            "host": ["api", "example", "com"],
+                            "query": [{ "key": "page", "description": "Page number" }]
+                        },
+                        "auth": { "type": "inherit" },
+                        "description": "Lists orders",
+                        "header": [{ "key": "Accept", "value": "application/json" }, { "key": "X-Debug", "value": "1", "disabled": true }]
+                    },
+                    "event": [{ "listen": "test", "script": { "exec": "pm.response.to.have.status(200);" } }]
+                }]
+            }]
+        });
+
+        let model = Collection::from_value(&collection);
+        assert_eq!(model.info.description.0, "Overview");
+
+        let entries = model.entries();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].path, "/item[0]/item[0]");
+        assert!(entries[0].item.is_folder());
+
+        let request = entries[1].item.request.as_ref().unwrap();
+        assert_eq!(request.method(), "GET");
+        assert_eq!(request.url.host(), "api.example.com");
+        assert_eq!(request.url.path(), "/orders");
+        assert_eq!(request.header("accept"), Some("application/json"));
+        assert_eq!(request.header("X-Debug"), None);
+        assert!(entries[1].item.test_script().contains("status(200)"));
+        assert_eq!(request.url.query()[0].key.as_deref(), Some("page"));
+        assert_eq!(entries[1].item.description_text(), "Lists orders");
+        assert_eq!(entries[0].item.auth_type(), Some("bearer"));
+        assert_eq!(entries[1].item.auth_type(), Some("inherit"));
+        assert_eq!(model.auth_type(), None);
+    }
+
+    #[test]
+    fn test_malformed_fields_fall_back_to_defaults() {
+        let collection = json!({
+            "info": "not an object",
+            "item": [
+                { "name": 42, "request": { "method": 5, "url": "{{base_url}}/users/{{id}}", "body": { "mode": "raw", "raw": " " } } },
+                { "request": { "url": { "raw": 12 } } }
+            ]
+        });
+
+        let model = Collection::from_value(&collection);
+        let entries = model.entries();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].item.display_name(0), "Item-1");
+
+        let request = entries[0].item.request.as_ref().unwrap();
+        assert_eq!(request.method(), "GET");
+        assert_eq!(request.url.path(), "/users/{{id}}");
+        assert!(!request.has_body());
+        assert_eq!(entries[1].item.request.as_ref().unwrap().url.raw(), "");
+    }
+}
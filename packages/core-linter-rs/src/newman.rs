@@ -0,0 +1,107 @@
+//! Exploitation des rapports Newman (reporter JSON)
+//!
+//! Calcule des statistiques de temps de réponse observés par requête, utilisées
+//! pour proposer des seuils réalistes plutôt que des valeurs arbitraires.
+
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Multiplicateur appliqué au p95 pour obtenir le seuil suggéré
+pub const THRESHOLD_P95_FACTOR: f64 = 1.5;
+
+/// Temps de réponse observés par requête, indexés par id d'item et par nom
+#[derive(Debug, Default, Clone)]
+pub struct ResponseTimings {
+    by_id: HashMap<String, Vec<u64>>,
+    by_name: HashMap<String, Vec<u64>>,
+}
+
+impl ResponseTimings {
+    /// Construit les timings depuis un rapport Newman (`run.executions[]`)
+    pub fn from_report(report: &Value) -> Self {
+        let mut timings = ResponseTimings::default();
+
+        if let Some(executions) = report["run"]["executions"].as_array() {
+            for execution in executions {
+                let response_time = match execution["response"]["responseTime"].as_u64() {
+                    Some(time) => time,
+                    None => continue,
+                };
+
+                if let Some(id) = execution["item"]["id"].as_str() {
+                    timings.by_id.entry(id.to_string()).or_default().push(response_time);
+                }
+                if let Some(name) = execution["item"]["name"].as_str() {
+                    timings.by_name.entry(name.to_string()).or_default().push(response_time);
+                }
+            }
+        }
+
+        timings
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_id.is_empty() && self.by_name.is_empty()
+    }
+
+    /// p95 des temps de réponse d'un item (recherche par id, puis par nom)
+    pub fn p95_for(&self, item: &Value) -> Option<u64> {
+        let samples = item["id"]
+            .as_str()
+            .and_then(|id| self.by_id.get(id))
+            .or_else(|| item["name"].as_str().and_then(|name| self.by_name.get(name)))?;
+
+        percentile(samples, 95.0)
+    }
+
+    /// Seuil recommandé pour un item : p95 × 1.5, arrondi à la dizaine supérieure
+    pub fn suggested_threshold_for(&self, item: &Value) -> Option<u64> {
+        self.p95_for(item).map(|p95| {
+            let raw = (p95 as f64 * THRESHOLD_P95_FACTOR).ceil() as u64;
+            raw.div_ceil(10) * 10
+        })
+    }
+}
+
+/// Percentile par la méthode du rang le plus proche
+fn percentile(samples: &[u64], pct: f64) -> Option<u64> {
+    if samples.is_empty() {
+        return None;
+    }
+
+    let mut sorted = samples.to_vec();
+    sorted.sort_unstable();
+    let rank = ((pct / 100.0) * sorted.len() as f64).ceil() as usize;
+    Some(sorted[rank.clamp(1, sorted.len()) - 1])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn report(times: &[u64]) -> Value {
+        let executions: Vec<Value> = times
+            .iter()
+            .map(|t| json!({ "item": { "id": "req-1", "name": "GET Users" }, "response": { "responseTime": t } }))
+            .collect();
+        json!({ "run": { "executions": executions } })
+    }
+
+    #[test]
+    fn test_p95_nearest_rank() {
+        let times: Vec<u64> = (1..=100).collect();
+        let timings = ResponseTimings::from_report(&report(&times));
+
+        assert_eq!(timings.p95_for(&json!({ "id": "req-1" })), Some(95));
+    }
+
+    #[test]
+    fn test_suggested_threshold_by_name() {
+        let timings = ResponseTimings::from_report(&report(&[100, 120, 200]));
+
+        // p95 = 200 → 300ms
+        assert_eq!(timings.suggested_threshold_for(&json!({ "name": "GET Users" })), Some(300));
+        assert_eq!(timings.suggested_threshold_for(&json!({ "name": "Unknown" })), None);
+    }
+}
@@ -0,0 +1,202 @@
+//! Normalisation d'une collection versionnée dans git (commande `normalize`)
+//!
+//! Chaque export Postman réordonne les headers, les paramètres de query et les
+//! variables, et régénère des champs volatils : les diffs deviennent illisibles.
+//! La normalisation trie ces tableaux par clé (tri stable, insensible à la casse)
+//! et retire les champs volatils configurés. Les clés des objets sont déjà triées
+//! à la sérialisation.
+//!
+//! Les formulaires (`formdata`, `urlencoded`) gardent leur ordre, qui peut compter
+//! pour le serveur.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Réglages de la normalisation (`normalize` dans la configuration)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct NormalizeOptions {
+    /// Trie les headers des requêtes et des exemples de réponse
+    pub sort_headers: bool,
+    /// Trie les paramètres de query (et reconstruit la query de `url.raw`)
+    pub sort_query: bool,
+    /// Trie les variables (collection, items, variables de path)
+    pub sort_variables: bool,
+    /// Retire les identifiants régénérés par Postman (`id`, `_postman_id`) ; à
+    /// éviter si la collection est réimportée dans un workspace synchronisé
+    pub strip_ids: bool,
+    /// Autres champs volatils retirés partout dans la collection
+    pub strip_fields: Vec<String>,
+}
+
+impl Default for NormalizeOptions {
+    fn default() -> Self {
+        NormalizeOptions {
+            sort_headers: true,
+            sort_query: true,
+            sort_variables: true,
+            strip_ids: false,
+            strip_fields: vec!["_postman_previewlanguage".to_string()],
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Default, PartialEq)]
+pub struct NormalizeReport {
+    /// Tableaux dont l'ordre a changé
+    pub arrays_sorted: u32,
+    /// Champs volatils retirés
+    pub fields_stripped: u32,
+}
+
+/// Normalise la collection en place
+pub fn normalize(collection: &mut Value, options: &NormalizeOptions) -> NormalizeReport {
+    let mut report = NormalizeReport::default();
+    normalize_value(collection, options, &mut report);
+    report
+}
+
+fn normalize_value(value: &mut Value, options: &NormalizeOptions, report: &mut NormalizeReport) {
+    match value {
+        Value::Array(values) => {
+            for child in values {
+                normalize_value(child, options, report);
+            }
+        }
+        Value::Object(map) => {
+            let before = map.len();
+            map.retain(|key, _| {
+                !(options.strip_ids && (key == "id" || key == "_postman_id"))
+                    && !options.strip_fields.iter().any(|field| field == key)
+            });
+            report.fields_stripped += (before - map.len()) as u32;
+
+            let mut query_sorted = false;
+            for (key, child) in map.iter_mut() {
+                let sort = match key.as_str() {
+                    "header" => options.sort_headers,
+                    "query" => options.sort_query,
+                    "variable" => options.sort_variables,
+                    _ => false,
+                };
+                if sort && sort_by_key(child) {
+                    report.arrays_sorted += 1;
+                    query_sorted |= key == "query";
+                }
+                normalize_value(child, options, report);
+            }
+
+            if query_sorted {
+                rebuild_raw_query(map);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Trie un tableau d'entrées `{ key, ... }` ; retourne true si l'ordre a changé
+fn sort_by_key(value: &mut Value) -> bool {
+    let Some(entries) = value.as_array_mut() else {
+        return false;
+    };
+    if !entries.iter().all(|entry| entry["key"].is_string()) {
+        return false;
+    }
+    let sort_key = |entry: &Value| {
+        let key = entry["key"].as_str().unwrap_or_default();
+        (key.to_lowercase(), key.to_string())
+    };
+    if entries.windows(2).all(|pair| sort_key(&pair[0]) <= sort_key(&pair[1])) {
+        return false;
+    }
+    entries.sort_by_cached_key(sort_key);
+    true
+}
+
+/// Aligne la query de `url.raw` sur le tableau `query` trié (paramètres activés)
+fn rebuild_raw_query(url: &mut serde_json::Map<String, Value>) {
+    let Some(raw) = url.get("raw").and_then(Value::as_str) else {
+        return;
+    };
+    let Some((base, rest)) = raw.split_once('?') else {
+        return;
+    };
+    let fragment = rest.find('#').map(|index| &rest[index..]).unwrap_or_default();
+
+    let params: Vec<String> = url["query"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter(|param| param["disabled"] != true)
+        .map(|param| match param["value"].as_str() {
+            Some(value) => format!("{}={}", param["key"].as_str().unwrap_or_default(), value),
+            None => param["key"].as_str().unwrap_or_default().to_string(),
+        })
+        .collect();
+    let raw = format!("{}?{}{}", base, params.join("&"), fragment);
+    url.insert("raw".to_string(), Value::String(raw));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn collection() -> Value {
+        json!({
+            "info": { "_postman_id": "a1b2", "name": "Shop" },
+            "variable": [{ "key": "token", "value": "" }, { "key": "base_url", "value": "https://api.example.com" }],
+            "item": [{
+                "id": "c3d4",
+                "name": "GET Users",
+                "request": {
+                    "method": "GET",
+                    "header": [{ "key": "X-Trace", "value": "1" }, { "key": "accept", "value": "application/json" }],
+                    "url": {
+                        "raw": "{{base_url}}/users?page=2&limit=10&debug",
+                        "query": [
+                            { "key": "page", "value": "2" },
+                            { "key": "limit", "value": "10" },
+                            { "key": "debug", "value": null },
+                            { "key": "beta", "value": "1", "disabled": true }
+                        ]
+                    }
+                },
+                "response": [{ "name": "200", "_postman_previewlanguage": "json", "header": [] }]
+            }]
+        })
+    }
+
+    #[test]
+    fn test_sorts_arrays_and_strips_volatile_fields() {
+        let mut collection = collection();
+        let report = normalize(&mut collection, &NormalizeOptions::default());
+
+        assert_eq!(report, NormalizeReport { arrays_sorted: 3, fields_stripped: 1 });
+        assert_eq!(collection["variable"][0]["key"], "base_url");
+        assert_eq!(collection["item"][0]["request"]["header"][0]["key"], "accept");
+        let url = &collection["item"][0]["request"]["url"];
+        assert_eq!(url["query"][0]["key"], "beta");
+        assert_eq!(url["raw"], "{{base_url}}/users?debug&limit=10&page=2");
+        assert!(collection["item"][0]["response"][0].get("_postman_previewlanguage").is_none());
+        // Identifiants conservés par défaut
+        assert_eq!(collection["item"][0]["id"], "c3d4");
+
+        // Idempotente
+        assert_eq!(normalize(&mut collection, &NormalizeOptions::default()), NormalizeReport::default());
+    }
+
+    #[test]
+    fn test_options() {
+        let mut collection = collection();
+        let options: NormalizeOptions =
+            serde_json::from_value(json!({ "sort_headers": false, "sort_query": false, "strip_ids": true })).unwrap();
+        let report = normalize(&mut collection, &options);
+
+        assert_eq!(report, NormalizeReport { arrays_sorted: 1, fields_stripped: 3 });
+        assert!(collection["info"].get("_postman_id").is_none());
+        assert!(collection["item"][0].get("id").is_none());
+        assert_eq!(collection["item"][0]["request"]["header"][0]["key"], "X-Trace");
+        assert_eq!(collection["item"][0]["request"]["url"]["raw"], "{{base_url}}/users?page=2&limit=10&debug");
+    }
+}
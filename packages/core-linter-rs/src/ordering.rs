@@ -0,0 +1,129 @@
+//! Ordre des issues dans le résultat
+//!
+//! Les règles émettent leurs issues dans l'ordre d'enregistrement puis de parcours,
+//! qui change dès qu'une règle est ajoutée : le résultat est donc trié selon un ordre
+//! stable (path, règle, sévérité par défaut) pour que deux analyses se comparent
+//! ligne à ligne. Les paths sont comparés par indices (`/item[2]` avant `/item[10]`).
+
+use crate::LintIssue;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+/// Critère de tri principal des issues
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum SortBy {
+    /// Path, puis règle, puis sévérité
+    #[default]
+    PathFirst,
+    /// Sévérité (erreurs d'abord), puis path, puis règle
+    SeverityFirst,
+    /// Règle, puis path, puis sévérité
+    RuleFirst,
+}
+
+impl SortBy {
+    /// Parse un ordre ("path-first", "severity-first", "rule-first" ; "path",
+    /// "severity" et "rule" acceptés)
+    pub fn parse(value: &str) -> Option<SortBy> {
+        match value.to_lowercase().trim_end_matches("-first") {
+            "path" => Some(SortBy::PathFirst),
+            "severity" => Some(SortBy::SeverityFirst),
+            "rule" => Some(SortBy::RuleFirst),
+            _ => None,
+        }
+    }
+}
+
+/// Trie les issues ; le message départage les issues d'une même règle sur un même path
+pub fn sort(issues: &mut [LintIssue], order: SortBy) {
+    issues.sort_by(|a, b| {
+        let path = || compare_paths(&a.path, &b.path);
+        let rule = || a.rule_id.cmp(&b.rule_id);
+        let severity = || severity_rank(&a.severity).cmp(&severity_rank(&b.severity));
+        match order {
+            SortBy::PathFirst => path().then_with(rule).then_with(severity),
+            SortBy::SeverityFirst => severity().then_with(path).then_with(rule),
+            SortBy::RuleFirst => rule().then_with(path).then_with(severity),
+        }
+        .then_with(|| a.message.cmp(&b.message))
+    });
+}
+
+/// Rang d'une sévérité, la plus grave d'abord
+fn severity_rank(severity: &str) -> usize {
+    match severity {
+        "error" => 0,
+        "warning" => 1,
+        "info" => 2,
+        "hint" => 3,
+        _ => 4,
+    }
+}
+
+/// Compare deux paths segment par segment, les indices `[n]` numériquement
+pub(crate) fn compare_paths(a: &str, b: &str) -> Ordering {
+    let key = |path: &str| -> Vec<(String, Option<usize>)> {
+        path.split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| match segment.strip_suffix(']').and_then(|s| s.split_once('[')) {
+                Some((name, index)) if index.parse::<usize>().is_ok() => (name.to_string(), index.parse().ok()),
+                _ => (segment.to_string(), None),
+            })
+            .collect()
+    };
+    key(a).cmp(&key(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(rule_id: &str, severity: &str, path: &str) -> LintIssue {
+        LintIssue::builder(rule_id).severity(severity).path(path).build()
+    }
+
+    fn order(issues: &[LintIssue]) -> Vec<(&str, &str)> {
+        issues.iter().map(|issue| (issue.rule_id.as_str(), issue.path.as_str())).collect()
+    }
+
+    #[test]
+    fn test_path_first_is_numeric() {
+        let mut issues = vec![
+            issue("b-rule", "info", "/item[10]"),
+            issue("a-rule", "error", "/item[2]/item[0]"),
+            issue("b-rule", "warning", "/item[2]"),
+            issue("a-rule", "warning", "/item[2]"),
+            issue("c-rule", "info", ""),
+        ];
+        sort(&mut issues, SortBy::PathFirst);
+        assert_eq!(
+            order(&issues),
+            vec![
+                ("c-rule", ""),
+                ("a-rule", "/item[2]"),
+                ("b-rule", "/item[2]"),
+                ("a-rule", "/item[2]/item[0]"),
+                ("b-rule", "/item[10]"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_severity_and_rule_first() {
+        let mut issues = vec![
+            issue("b-rule", "info", "/item[0]"),
+            issue("a-rule", "warning", "/item[1]"),
+            issue("b-rule", "error", "/item[1]"),
+        ];
+        sort(&mut issues, SortBy::SeverityFirst);
+        assert_eq!(order(&issues), vec![("b-rule", "/item[1]"), ("a-rule", "/item[1]"), ("b-rule", "/item[0]")]);
+
+        sort(&mut issues, SortBy::RuleFirst);
+        assert_eq!(order(&issues), vec![("a-rule", "/item[1]"), ("b-rule", "/item[0]"), ("b-rule", "/item[1]")]);
+
+        assert_eq!(SortBy::parse("severity"), Some(SortBy::SeverityFirst));
+        assert_eq!(SortBy::parse("rule-first"), Some(SortBy::RuleFirst));
+        assert_eq!(SortBy::parse("random"), None);
+    }
+}
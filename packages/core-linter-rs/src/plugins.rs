@@ -0,0 +1,220 @@
+//! Chargement des plugins natifs (`--plugin` sur la CLI)
+//!
+//! Un plugin est une bibliothèque dynamique produite par un crate de règles avec
+//! `linterman_rule_api::export_plugin!` (voir `linterman_rule_api::plugin` pour
+//! l'interface). Chaque règle du plugin est enregistrée dans le registre comme une
+//! règle externe : elle reçoit la collection et ses `rule_options`, et ses issues
+//! rejoignent celles des règles intégrées. Le navigateur charge la version WASM du
+//! même plugin côté TypeScript (`loadPlugin`).
+
+use crate::registry::RuleRegistry;
+use crate::rule_api::plugin::{PluginRuleInfo, PLUGIN_ABI_VERSION};
+use crate::rule_api::{LintIssue, Rule};
+use libloading::Library;
+use serde_json::Value;
+use std::ffi::{c_char, CStr, CString};
+use std::path::Path;
+use std::sync::Arc;
+
+type AbiVersionFn = unsafe extern "C" fn() -> u32;
+type RulesFn = unsafe extern "C" fn() -> *mut c_char;
+type CheckFn = unsafe extern "C" fn(*const c_char, *const c_char, *const c_char) -> *mut c_char;
+type FreeFn = unsafe extern "C" fn(*mut c_char);
+
+/// Fonctions d'un plugin chargé ; la bibliothèque reste chargée tant qu'une de ses
+/// règles est enregistrée
+struct PluginApi {
+    check: CheckFn,
+    free: FreeFn,
+    _library: Option<Library>,
+}
+
+impl PluginApi {
+    /// Copie puis libère une chaîne retournée par le plugin
+    fn take_string(&self, ptr: *mut c_char) -> String {
+        if ptr.is_null() {
+            return String::new();
+        }
+        // SAFETY : chaîne terminée par un zéro allouée par le plugin, libérée une seule fois
+        unsafe {
+            let s = CStr::from_ptr(ptr).to_string_lossy().into_owned();
+            (self.free)(ptr);
+            s
+        }
+    }
+}
+
+/// Règle exécutée par un plugin
+struct PluginRule {
+    info: PluginRuleInfo,
+    plugin: String,
+    api: Arc<PluginApi>,
+}
+
+impl Rule for PluginRule {
+    fn id(&self) -> &str {
+        &self.info.id
+    }
+
+    fn severity(&self) -> &str {
+        &self.info.severity
+    }
+
+    fn description(&self) -> &str {
+        &self.info.description
+    }
+
+    fn check(&self, collection: &Value, options: &Value) -> Vec<LintIssue> {
+        let [rule_id, collection, options] = [self.info.id.clone(), collection.to_string(), options.to_string()]
+            .map(|s| CString::new(s).unwrap_or_default());
+        // SAFETY : arguments terminés par un zéro, valides pendant l'appel
+        let output = unsafe { (self.api.check)(rule_id.as_ptr(), collection.as_ptr(), options.as_ptr()) };
+        let output: Value = serde_json::from_str(&self.api.take_string(output)).unwrap_or(Value::Null);
+
+        let error = match serde_json::from_value::<Vec<LintIssue>>(output.clone()) {
+            Ok(issues) => {
+                return issues
+                    .into_iter()
+                    .map(|mut issue| {
+                        if issue.rule_id.is_empty() {
+                            issue.rule_id = self.info.id.clone();
+                        }
+                        issue
+                    })
+                    .collect()
+            }
+            Err(e) => output["error"].as_str().map(str::to_string).unwrap_or_else(|| e.to_string()),
+        };
+        vec![LintIssue::builder(&self.info.id)
+            .severity("info")
+            .message(format!("⚠️ Plugin rule '{}' ({}) failed: {}", self.info.id, self.plugin, error))
+            .build()]
+    }
+}
+
+/// Charge un plugin natif et enregistre ses règles ; retourne leurs identifiants
+pub fn load(path: &Path, registry: &mut RuleRegistry) -> Result<Vec<String>, String> {
+    let name = path.display().to_string();
+    // SAFETY : le plugin est du code natif choisi par l'utilisateur, exécuté tel quel
+    unsafe {
+        let library = Library::new(path).map_err(|e| format!("Cannot load plugin '{}': {}", name, e))?;
+        let symbol_error = |e: libloading::Error| format!("'{}' is not a linterman plugin: {}", name, e);
+        let abi_version = *library.get::<AbiVersionFn>(b"linterman_plugin_abi_version").map_err(symbol_error)?;
+        let rules = *library.get::<RulesFn>(b"linterman_plugin_rules").map_err(symbol_error)?;
+        let check = *library.get::<CheckFn>(b"linterman_plugin_check").map_err(symbol_error)?;
+        let free = *library.get::<FreeFn>(b"linterman_plugin_free").map_err(symbol_error)?;
+
+        if abi_version() != PLUGIN_ABI_VERSION {
+            return Err(format!(
+                "Plugin '{}' targets plugin ABI {} (this linter supports {})",
+                name,
+                abi_version(),
+                PLUGIN_ABI_VERSION
+            ));
+        }
+        let api = PluginApi { check, free, _library: Some(library) };
+        register(api, rules, &name, registry)
+    }
+}
+
+/// Enregistre les règles déclarées par `rules` ; aucune n'est enregistrée si l'une
+/// d'elles est invalide ou déjà présente
+fn register(api: PluginApi, rules: RulesFn, plugin: &str, registry: &mut RuleRegistry) -> Result<Vec<String>, String> {
+    // SAFETY : fonction exportée par le plugin, sans argument
+    let rules_json = api.take_string(unsafe { rules() });
+    let infos: Vec<PluginRuleInfo> = serde_json::from_str(&rules_json)
+        .map_err(|e| format!("Plugin '{}' returned invalid rule metadata: {}", plugin, e))?;
+
+    for info in &infos {
+        if !crate::rules::ISSUE_SEVERITIES.contains(&info.severity.as_str()) {
+            return Err(format!("Plugin rule '{}' has an invalid severity '{}'", info.id, info.severity));
+        }
+        if registry.contains(&info.id) || crate::rules::find(&info.id).is_some() {
+            return Err(format!("Plugin rule '{}' is already registered", info.id));
+        }
+    }
+
+    let api = Arc::new(api);
+    let ids = infos.iter().map(|info| info.id.clone()).collect();
+    for info in infos {
+        registry.register(PluginRule { info, plugin: plugin.to_string(), api: Arc::clone(&api) })?;
+    }
+    Ok(ids)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{run_linter_with_registry, LintConfig};
+    use serde_json::json;
+
+    /// Plugin compilé dans le binaire de test (mêmes exports qu'un cdylib)
+    mod acme_plugin {
+        use crate::rule_api::{ItemContext, LintIssue, Rule};
+        use serde_json::Value;
+
+        pub struct NoLegacyHost;
+
+        impl Rule for NoLegacyHost {
+            fn id(&self) -> &str {
+                "acme-no-legacy-host"
+            }
+
+            fn check_item(&self, item: &ItemContext, options: &Value) -> Vec<LintIssue> {
+                let host = options["host"].as_str().unwrap_or("legacy.acme.com");
+                if item.is_request() && item.url().contains(host) {
+                    vec![LintIssue::builder(self.id()).message("Legacy host").path(&item.path).build()]
+                } else {
+                    Vec::new()
+                }
+            }
+        }
+
+        crate::rule_api::export_plugin!(NoLegacyHost);
+    }
+
+    fn test_api() -> PluginApi {
+        PluginApi { check: acme_plugin::linterman_plugin_check, free: acme_plugin::linterman_plugin_free, _library: None }
+    }
+
+    #[test]
+    fn test_plugin_rules_merged_with_builtin() {
+        let mut registry = RuleRegistry::new();
+        let ids = register(test_api(), acme_plugin::linterman_plugin_rules, "acme", &mut registry).unwrap();
+        assert_eq!(ids, vec!["acme-no-legacy-host"]);
+
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [{ "name": "GET Users", "request": { "method": "GET", "url": "https://old.acme.com/users" } }]
+        });
+        let config = LintConfig {
+            rules: Some(vec!["acme-no-legacy-host".to_string(), "test-http-status-mandatory".to_string()]),
+            rule_options: Some([("acme-no-legacy-host".to_string(), json!({ "host": "old.acme.com" }))].into()),
+            ..Default::default()
+        };
+
+        let result = run_linter_with_registry(&collection, &config, &registry);
+        let rule_ids: Vec<&str> = result.issues.iter().map(|issue| issue.rule_id.as_str()).collect();
+        assert!(rule_ids.contains(&"acme-no-legacy-host"));
+        assert!(rule_ids.contains(&"test-http-status-mandatory"));
+    }
+
+    #[test]
+    fn test_duplicate_and_failing_rules() {
+        let mut registry = RuleRegistry::new();
+        register(test_api(), acme_plugin::linterman_plugin_rules, "acme", &mut registry).unwrap();
+        let error = register(test_api(), acme_plugin::linterman_plugin_rules, "acme", &mut registry).unwrap_err();
+        assert_eq!(error, "Plugin rule 'acme-no-legacy-host' is already registered");
+
+        // Règle inconnue du plugin : l'échec devient une issue info
+        let rule = PluginRule {
+            info: PluginRuleInfo { id: "acme-other".to_string(), severity: "warning".to_string(), description: String::new() },
+            plugin: "acme".to_string(),
+            api: Arc::new(test_api()),
+        };
+        let issues = rule.check(&json!({ "item": [] }), &Value::Null);
+        assert_eq!(issues[0].severity, "info");
+        assert!(issues[0].message.contains("Unknown rule 'acme-other'"));
+        assert!(load(Path::new("/nonexistent/libacme.so"), &mut registry).is_err());
+    }
+}
@@ -0,0 +1,97 @@
+//! Chemins des issues au format JSON Pointer
+//!
+//! Les règles désignent les nœuds par un chemin propre au linter (`/item[0]/item[2]/request`),
+//! que les outils JSON standards (`jq`, bibliothèques JSON Pointer, éditeurs) ne savent
+//! pas résoudre. Le moteur ajoute à chaque issue le même chemin au format RFC 6901
+//! (`/item/0/item/2/request`) dans `LintIssue::pointer` ; le fixer accepte les deux formes.
+
+/// Convertit un path du linter en JSON Pointer (`""` désigne la collection entière)
+pub fn from_path(path: &str) -> String {
+    let mut pointer = String::new();
+    for segment in path.split('/').filter(|segment| !segment.is_empty()) {
+        match segment.strip_suffix(']').and_then(|s| s.split_once('[')) {
+            Some((name, index)) if index.parse::<usize>().is_ok() => {
+                pointer.push('/');
+                pointer.push_str(&escape(name));
+                pointer.push('/');
+                pointer.push_str(index);
+            }
+            _ => {
+                pointer.push('/');
+                pointer.push_str(&escape(segment));
+            }
+        }
+    }
+    pointer
+}
+
+/// Échappement d'un segment (`~` → `~0`, `/` → `~1`)
+pub(crate) fn escape(segment: &str) -> String {
+    segment.replace('~', "~0").replace('/', "~1")
+}
+
+/// Indices des items traversés par un path (`/item[0]/item[2]/request`) ou un JSON
+/// Pointer (`/item/0/item/2/request`), jusqu'au premier segment qui n'est pas un item
+pub(crate) fn item_indices(path: &str) -> Option<Vec<usize>> {
+    let mut segments = path.split('/').filter(|segment| !segment.is_empty()).peekable();
+    let mut indices = Vec::new();
+    while let Some(segment) = segments.next() {
+        let index = if segment == "item" {
+            match segments.peek().map(|next| next.parse::<usize>()) {
+                Some(Ok(index)) => {
+                    segments.next();
+                    index
+                }
+                _ => return None,
+            }
+        } else if let Some(index) = segment.strip_prefix("item[").and_then(|s| s.strip_suffix(']')) {
+            index.parse().ok()?
+        } else {
+            break;
+        };
+        indices.push(index);
+    }
+    Some(indices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{run_linter, LintConfig};
+    use serde_json::json;
+
+    #[test]
+    fn test_from_path() {
+        assert_eq!(from_path("/item[0]/item[2]/request/url"), "/item/0/item/2/request/url");
+        assert_eq!(from_path("/item[1]/response[0]/body"), "/item/1/response/0/body");
+        assert_eq!(from_path("/info/description"), "/info/description");
+        assert_eq!(from_path("/"), "");
+        assert_eq!(from_path(""), "");
+    }
+
+    #[test]
+    fn test_item_indices_accepts_both_forms() {
+        assert_eq!(item_indices("/item[0]/item[2]/request/url"), Some(vec![0, 2]));
+        assert_eq!(item_indices("/item/0/item/2/request/url"), Some(vec![0, 2]));
+        assert_eq!(item_indices(""), Some(vec![]));
+        assert_eq!(item_indices("/item/first"), None);
+        assert_eq!(item_indices("/item[x]"), None);
+    }
+
+    #[test]
+    fn test_issues_carry_a_resolvable_pointer() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [{ "name": "Users", "item": [{ "name": "GET Users", "request": { "method": "GET", "url": "https://api.example.com/users" } }] }]
+        });
+        let config = LintConfig { local_only: true, ..Default::default() };
+        let result = run_linter(&collection, &config);
+        assert!(!result.issues.is_empty());
+        for issue in &result.issues {
+            assert_eq!(issue.pointer.as_deref(), Some(from_path(&issue.path).as_str()));
+            // L'item porteur de l'issue se résout avec `Value::pointer`
+            let item = from_path(crate::relations::item_path(&issue.path));
+            assert!(collection.pointer(&item).is_some(), "{}", item);
+        }
+    }
+}
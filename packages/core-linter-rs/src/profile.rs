@@ -0,0 +1,120 @@
+//! Profilage de l'exécution des règles
+//!
+//! Avec `LintConfig::profile`, le moteur mesure le temps passé dans chaque règle et
+//! compte ses issues brutes (avant ignore, suppressions et déduplication), pour
+//! repérer les règles à base de regex qui dominent le temps d'analyse des grosses
+//! collections. Les règles par item sont mesurées appel par appel pendant le parcours
+//! partagé ; le parcours lui-même n'est attribué à aucune règle. Dans une
+//! `session::LintSession`, les règles servies par le cache n'y figurent pas. En WASM,
+//! l'horloge est `Date.now()` (précision de la milliseconde).
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Temps d'exécution et issues d'une règle
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct RuleProfile {
+    pub rule_id: String,
+    pub duration_ms: f64,
+    /// Issues brutes émises par la règle
+    pub issues: usize,
+}
+
+/// Profil d'une analyse : règles exécutées, la plus lente d'abord
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct Profile {
+    /// Durée de la phase d'exécution des règles
+    pub total_ms: f64,
+    pub rules: Vec<RuleProfile>,
+}
+
+impl Profile {
+    pub(crate) fn new(total_ms: f64, mut rules: Vec<RuleProfile>) -> Self {
+        for rule in &mut rules {
+            rule.duration_ms = round(rule.duration_ms);
+        }
+        rules.sort_by(|a, b| b.duration_ms.total_cmp(&a.duration_ms).then_with(|| a.rule_id.cmp(&b.rule_id)));
+        Profile { total_ms: round(total_ms), rules }
+    }
+}
+
+/// Somme des profils de plusieurs analyses (shards d'une même collection)
+pub fn merge<'a>(profiles: impl IntoIterator<Item = &'a Profile>) -> Option<Profile> {
+    let mut total_ms = 0.0;
+    let mut rules: BTreeMap<&str, RuleProfile> = BTreeMap::new();
+    let mut any = false;
+    for profile in profiles {
+        any = true;
+        total_ms += profile.total_ms;
+        for rule in &profile.rules {
+            let merged = rules.entry(&rule.rule_id).or_insert_with(|| RuleProfile {
+                rule_id: rule.rule_id.clone(),
+                ..Default::default()
+            });
+            merged.duration_ms += rule.duration_ms;
+            merged.issues += rule.issues;
+        }
+    }
+    any.then(|| Profile::new(total_ms, rules.into_values().collect()))
+}
+
+/// Arrondi au millième de milliseconde pour la sortie JSON
+fn round(ms: f64) -> f64 {
+    (ms * 1000.0).round() / 1000.0
+}
+
+/// Horloge en millisecondes, depuis une origine arbitraire
+pub(crate) fn now_ms() -> f64 {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        static EPOCH: std::sync::OnceLock<std::time::Instant> = std::sync::OnceLock::new();
+        EPOCH.get_or_init(std::time::Instant::now).elapsed().as_secs_f64() * 1000.0
+    }
+    // `Instant` n'est pas disponible sur wasm32-unknown-unknown
+    #[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+    {
+        js_sys::Date::now()
+    }
+    #[cfg(all(target_arch = "wasm32", not(feature = "wasm")))]
+    {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{run_linter, LintConfig};
+    use serde_json::json;
+
+    #[test]
+    fn test_profile_lists_executed_rules() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [{ "name": "GET Users", "request": { "method": "GET", "url": "https://api.example.com/users" } }]
+        });
+        let rules = vec!["test-http-status-mandatory".to_string(), "environment-variables-usage".to_string()];
+        let mut config = LintConfig { local_only: true, rules: Some(rules), ..Default::default() };
+        assert!(run_linter(&collection, &config).profile.is_none());
+
+        config.profile = Some(true);
+        let profile = run_linter(&collection, &config).profile.unwrap();
+        assert_eq!(profile.rules.len(), 2);
+        assert!(profile.rules.windows(2).all(|pair| pair[0].duration_ms >= pair[1].duration_ms));
+        let status = profile.rules.iter().find(|rule| rule.rule_id == "test-http-status-mandatory").unwrap();
+        assert_eq!(status.issues, 1);
+        assert!(profile.total_ms >= 0.0);
+    }
+
+    #[test]
+    fn test_merge_sums_rules() {
+        let shard = |ms: f64, issues: usize| Profile {
+            total_ms: ms,
+            rules: vec![RuleProfile { rule_id: "a-rule".to_string(), duration_ms: ms, issues }],
+        };
+        let merged = merge(&[shard(1.5, 2), shard(2.0, 1)]).unwrap();
+        assert_eq!(merged.total_ms, 3.5);
+        assert_eq!(merged.rules, vec![RuleProfile { rule_id: "a-rule".to_string(), duration_ms: 3.5, issues: 3 }]);
+        assert!(merge(&[]).is_none());
+    }
+}
@@ -0,0 +1,262 @@
+//! Fichier de configuration du projet (`.linterman.toml`, `.yaml` ou `.json`)
+//!
+//! Alternative versionnable à la configuration exportée depuis l'IHM : le fichier
+//! est recherché dans le répertoire courant puis dans ses parents, ce qui permet de
+//! le placer à la racine du dépôt. Même contenu quel que soit le format :
+//!
+//! ```toml
+//! rules = ["test-http-status-mandatory", "debug-headers"]   # toutes les règles si absent
+//! disabled_rules = ["debug-headers"]                        # exclusions, prioritaires
+//! maturity_target = "L2"
+//! sort_by = "severity-first"
+//!
+//! [severities]
+//! debug-headers = "error"
+//!
+//! ignore = ["Deprecated/**", { pattern = "/item[3]/**", rules = ["debug-headers"] }]
+//!
+//! [rule_options.debug-headers]
+//! denylist = ["X-Debug", "X-Trace"]
+//!
+//! [category_caps]
+//! documentation = 5
+//!
+//! [fix]
+//! enabled = true
+//! strict = true
+//!
+//! [normalize]
+//! strip_ids = true
+//! ```
+
+use crate::grouping::GroupBy;
+use crate::ignore::IgnorePattern;
+use crate::maturity::Maturity;
+use crate::normalize::NormalizeOptions;
+use crate::ordering::SortBy;
+use crate::rules::Category;
+use crate::LintConfig;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Noms de fichier recherchés, par ordre de priorité dans un même répertoire
+pub const FILE_NAMES: &[&str] = &[".linterman.toml", ".linterman.yaml", ".linterman.yml", ".linterman.json"];
+
+/// Format d'un fichier de configuration, déduit de son extension
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+    Toml,
+    Yaml,
+    Json,
+}
+
+impl ConfigFormat {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()?.to_lowercase().as_str() {
+            "toml" => Some(ConfigFormat::Toml),
+            "yaml" | "yml" => Some(ConfigFormat::Yaml),
+            "json" => Some(ConfigFormat::Json),
+            _ => None,
+        }
+    }
+}
+
+/// Réglages des corrections automatiques
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct FixSettings {
+    /// Active les corrections (`LintConfig::fix`)
+    pub enabled: Option<bool>,
+    /// Un fix de type inconnu est une erreur (`LintConfig::strict_fix`)
+    pub strict: Option<bool>,
+}
+
+/// Contenu du fichier de configuration du projet
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProjectConfig {
+    /// Règles activées (toutes si absent)
+    pub rules: Option<Vec<String>>,
+    /// Règles exclues (`LintConfig::disabled_rules`)
+    pub disabled_rules: Option<Vec<String>>,
+    /// Sévérité imposée par règle
+    pub severities: HashMap<String, String>,
+    /// Pénalité maximale par catégorie (`LintConfig::category_caps`)
+    pub category_caps: HashMap<Category, f64>,
+    /// Options propres à chaque règle, indexées par rule_id
+    pub rule_options: HashMap<String, Value>,
+    /// Items exclus par motif (`LintConfig::ignore`)
+    pub ignore: Vec<IgnorePattern>,
+    /// Niveau de maturité visé (`LintConfig::maturity_target`)
+    pub maturity_target: Option<Maturity>,
+    /// Ordre des issues (`LintConfig::sort_by`)
+    pub sort_by: Option<SortBy>,
+    /// Fusion des issues en double entre règles (`LintConfig::deduplicate`)
+    pub deduplicate: Option<bool>,
+    /// Regroupement des issues dans le résultat (`LintConfig::group_by`)
+    pub group_by: Option<GroupBy>,
+    /// Profilage des règles (`LintConfig::profile`)
+    pub profile: Option<bool>,
+    /// Positions des issues dans le JSON d'origine (`LintConfig::source_map`)
+    pub source_map: Option<bool>,
+    pub fix: FixSettings,
+    /// Réglages de `normalize` (`LintConfig::normalize`)
+    pub normalize: Option<NormalizeOptions>,
+}
+
+impl ProjectConfig {
+    /// Applique le fichier à une configuration ; les valeurs absentes du fichier
+    /// laissent la configuration inchangée
+    pub fn apply(&self, config: &mut LintConfig) {
+        if let Some(rules) = &self.rules {
+            config.rules = Some(rules.clone());
+        }
+        if let Some(disabled_rules) = &self.disabled_rules {
+            config.disabled_rules = Some(disabled_rules.clone());
+        }
+        if !self.severities.is_empty() {
+            config.severity_overrides = Some(self.severities.clone());
+        }
+        if !self.category_caps.is_empty() {
+            config.category_caps = Some(self.category_caps.clone());
+        }
+        if !self.rule_options.is_empty() {
+            config.rule_options = Some(self.rule_options.clone());
+        }
+        if !self.ignore.is_empty() {
+            config.ignore = Some(self.ignore.clone());
+        }
+        if let Some(target) = self.maturity_target {
+            config.maturity_target = Some(target);
+        }
+        if let Some(sort_by) = self.sort_by {
+            config.sort_by = Some(sort_by);
+        }
+        if let Some(deduplicate) = self.deduplicate {
+            config.deduplicate = Some(deduplicate);
+        }
+        if let Some(group_by) = self.group_by {
+            config.group_by = Some(group_by);
+        }
+        if let Some(profile) = self.profile {
+            config.profile = Some(profile);
+        }
+        if let Some(source_map) = self.source_map {
+            config.source_map = Some(source_map);
+        }
+        if let Some(enabled) = self.fix.enabled {
+            config.fix = Some(enabled);
+        }
+        if let Some(strict) = self.fix.strict {
+            config.strict_fix = Some(strict);
+        }
+        if let Some(normalize) = &self.normalize {
+            config.normalize = Some(normalize.clone());
+        }
+    }
+}
+
+/// Premier fichier de configuration trouvé dans `start` ou l'un de ses parents
+pub fn discover(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .flat_map(|dir| FILE_NAMES.iter().map(move |name| dir.join(name)))
+        .find(|path| path.is_file())
+}
+
+/// Lit un fichier de configuration (format déduit de l'extension)
+pub fn load(path: &Path) -> Result<ProjectConfig, String> {
+    let format = ConfigFormat::from_path(path)
+        .ok_or_else(|| format!("{}: unsupported config format (expected .toml, .yaml, .yml or .json)", path.display()))?;
+    let content = std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    parse(&content, format).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Parse le contenu d'un fichier de configuration
+pub fn parse(content: &str, format: ConfigFormat) -> Result<ProjectConfig, String> {
+    match format {
+        ConfigFormat::Toml => toml::from_str(content).map_err(|e| e.to_string()),
+        ConfigFormat::Yaml => serde_yaml::from_str(content).map_err(|e| e.to_string()),
+        ConfigFormat::Json => serde_json::from_str(content).map_err(|e| e.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_formats_are_equivalent() {
+        let toml = r#"
+rules = ["debug-headers", "no-body-on-get"]
+
+[severities]
+debug-headers = "error"
+
+[rule_options.debug-headers]
+denylist = ["X-Debug"]
+
+[category_caps]
+documentation = 5
+
+[fix]
+strict = true
+"#;
+        let yaml = "
+rules: [debug-headers, no-body-on-get]
+severities:
+  debug-headers: error
+rule_options:
+  debug-headers:
+    denylist: [X-Debug]
+category_caps:
+  documentation: 5
+fix:
+  strict: true
+";
+        let json = r#"{
+            "rules": ["debug-headers", "no-body-on-get"],
+            "severities": { "debug-headers": "error" },
+            "rule_options": { "debug-headers": { "denylist": ["X-Debug"] } },
+            "category_caps": { "documentation": 5 },
+            "fix": { "strict": true }
+        }"#;
+
+        let expected = parse(json, ConfigFormat::Json).unwrap();
+        assert_eq!(parse(toml, ConfigFormat::Toml).unwrap(), expected);
+        assert_eq!(parse(yaml, ConfigFormat::Yaml).unwrap(), expected);
+        assert_eq!(expected.rule_options["debug-headers"], json!({ "denylist": ["X-Debug"] }));
+
+        let mut config = LintConfig { rules: Some(vec!["placeholder-syntax".to_string()]), ..Default::default() };
+        expected.apply(&mut config);
+        assert_eq!(config.rules.unwrap(), vec!["debug-headers", "no-body-on-get"]);
+        assert_eq!(config.severity_overrides.unwrap()["debug-headers"], "error");
+        assert_eq!(config.strict_fix, Some(true));
+        assert_eq!(config.category_caps.unwrap()[&Category::Documentation], 5.0);
+        assert_eq!(config.fix, None);
+    }
+
+    #[test]
+    fn test_unknown_keys_rejected() {
+        let error = parse("enabledRules = []", ConfigFormat::Toml).unwrap_err();
+        assert!(error.contains("unknown field"));
+    }
+
+    #[test]
+    fn test_discovered_upward() {
+        let root = std::env::temp_dir().join(format!("linterman-config-{}", std::process::id()));
+        let nested = root.join("collections").join("billing");
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::write(root.join(".linterman.yaml"), "rules: [debug-headers]\n").unwrap();
+
+        let found = discover(&nested);
+        let config = found.as_deref().map(load);
+        std::fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(found, Some(root.join(".linterman.yaml")));
+        assert_eq!(config.unwrap().unwrap().rules, Some(vec!["debug-headers".to_string()]));
+    }
+}
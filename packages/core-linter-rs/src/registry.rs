@@ -0,0 +1,184 @@
+//! Registre des règles externes
+//!
+//! Les équipes peuvent livrer des règles propriétaires dans des crates séparés
+//! (voir `linterman-rule-api`). Un build personnalisé de la CLI les enregistre ici,
+//! ou `plugins::load` les charge depuis une bibliothèque dynamique ;
+//! le moteur les exécute après les règles intégrées, avec les mêmes mécanismes
+//! (`rules`, `rule_options`, garde-fous de taille).
+
+use crate::rule_api::{FixAction, ItemContext, LintIssue, Rule};
+use serde_json::Value;
+
+#[derive(Default)]
+pub struct RuleRegistry {
+    rules: Vec<Box<dyn Rule>>,
+}
+
+impl RuleRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enregistre une règle ; refuse un identifiant déjà enregistré
+    pub fn register(&mut self, rule: impl Rule + 'static) -> Result<(), String> {
+        if self.contains(rule.id()) {
+            return Err(format!("Rule '{}' is already registered", rule.id()));
+        }
+        self.rules.push(Box::new(rule));
+        Ok(())
+    }
+
+    pub fn contains(&self, rule_id: &str) -> bool {
+        self.rules.iter().any(|rule| rule.id() == rule_id)
+    }
+
+    pub fn rules(&self) -> impl Iterator<Item = &dyn Rule> {
+        self.rules.iter().map(|rule| rule.as_ref())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+}
+
+/// Objet transmis pour un item aux règles externes écrites en JavaScript ou en Rhai
+pub fn item_object(item: &ItemContext, options: &Value) -> Value {
+    serde_json::json!({
+        "path": item.path,
+        "name": item.name,
+        "folders": item.folders,
+        "is_request": item.is_request(),
+        "is_folder": item.is_folder(),
+        "method": if item.is_request() { Value::from(item.method()) } else { Value::Null },
+        "url": if item.is_request() { Value::from(item.url()) } else { Value::Null },
+        "test_script": item.test_script,
+        "prerequest_script": item.prerequest_script,
+        "inherited_test_scripts": item.inherited_test_scripts,
+        "item": item.item,
+        "options": options,
+    })
+}
+
+/// Convertit la valeur retournée par une règle JavaScript ou Rhai en issues : `null`,
+/// une issue ou un tableau d'issues `{ message, path?, severity?, fix? }`
+pub fn issues_from_value(rule_id: &str, severity: &str, default_path: &str, value: &Value) -> Vec<LintIssue> {
+    let entries: Vec<&Value> = match value {
+        Value::Array(entries) => entries.iter().collect(),
+        Value::Object(_) => vec![value],
+        _ => Vec::new(),
+    };
+
+    entries
+        .into_iter()
+        .filter_map(|entry| {
+            let message = entry["message"].as_str()?;
+            let severity = match entry["severity"].as_str() {
+                Some(s) if crate::rules::ISSUE_SEVERITIES.contains(&s) => s,
+                _ => severity,
+            };
+            let mut builder = LintIssue::builder(rule_id)
+                .severity(severity)
+                .message(message)
+                .path(entry["path"].as_str().unwrap_or(default_path));
+            // Payload typé si le type est connu, conservé tel quel (`Custom`) sinon
+            if !entry["fix"].is_null() {
+                if let Ok(fix) = serde_json::from_value::<FixAction>(entry["fix"].clone()) {
+                    builder = builder.fix(fix);
+                }
+            }
+            Some(builder.build())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rule_api::{ItemContext, LintIssue};
+    use crate::{run_linter_with_registry, LintConfig};
+    use serde_json::{json, Value};
+
+    struct ForbiddenHost;
+
+    impl Rule for ForbiddenHost {
+        fn id(&self) -> &str {
+            "acme-forbidden-host"
+        }
+
+        fn check_item(&self, item: &ItemContext, options: &Value) -> Vec<LintIssue> {
+            let host = options["host"].as_str().unwrap_or("legacy.acme.com");
+            if item.is_request() && item.url().contains(host) {
+                vec![LintIssue::builder(self.id())
+                    .message(format!("Request \"{}\" targets {}", item.name, host))
+                    .path(&item.path)
+                    .build()]
+            } else {
+                Vec::new()
+            }
+        }
+    }
+
+    #[test]
+    fn test_duplicate_registration_rejected() {
+        let mut registry = RuleRegistry::new();
+        assert!(registry.register(ForbiddenHost).is_ok());
+        assert!(registry.register(ForbiddenHost).is_err());
+        assert!(registry.contains("acme-forbidden-host"));
+    }
+
+    #[test]
+    fn test_external_rule_runs_with_options() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [{ "name": "GET Users", "request": { "method": "GET", "url": "https://old.acme.com/users" } }]
+        });
+        let mut registry = RuleRegistry::new();
+        registry.register(ForbiddenHost).unwrap();
+
+        let mut rule_options = std::collections::HashMap::new();
+        rule_options.insert("acme-forbidden-host".to_string(), json!({ "host": "old.acme.com" }));
+        let config = LintConfig {
+            rules: Some(vec!["acme-forbidden-host".to_string()]),
+            rule_options: Some(rule_options),
+            ..Default::default()
+        };
+
+        let result = run_linter_with_registry(&collection, &config, &registry);
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].message, "Request \"GET Users\" targets old.acme.com");
+    }
+
+    #[test]
+    fn test_issues_from_value() {
+        let returned = json!([
+            { "message": "Missing owner tag" },
+            { "message": "Legacy host", "severity": "error", "path": "/item[0]/request/url" },
+            { "severity": "warning" }
+        ]);
+
+        let issues = issues_from_value("team-owner-tag", "warning", "/item[0]", &returned);
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].path, "/item[0]");
+        assert_eq!(issues[0].severity, "warning");
+        assert_eq!(issues[1].severity, "error");
+        assert_eq!(issues[1].path, "/item[0]/request/url");
+
+        assert_eq!(issues_from_value("r", "info", "/item[0]", &json!({ "message": "One" })).len(), 1);
+        assert!(issues_from_value("r", "info", "/item[0]", &Value::Null).is_empty());
+    }
+
+    #[test]
+    fn test_item_object() {
+        let collection = json!({
+            "item": [{ "name": "POST Users", "request": { "method": "post", "url": "{{base_url}}/users" } }]
+        });
+
+        let mut objects = Vec::new();
+        crate::rule_api::walk(&collection, &mut |item| objects.push(item_object(item, &json!({ "tag": "owner" }))));
+
+        assert_eq!(objects[0]["path"], "/item[0]");
+        assert_eq!(objects[0]["method"], "POST");
+        assert_eq!(objects[0]["url"], "{{base_url}}/users");
+        assert_eq!(objects[0]["options"]["tag"], "owner");
+    }
+}
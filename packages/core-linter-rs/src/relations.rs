@@ -0,0 +1,88 @@
+//! Liens entre issues en cascade
+//!
+//! Certaines issues sont la conséquence d'une autre sur la même requête (une URL
+//! codée en dur qui expose un hôte interne, un seuil de temps de réponse trop
+//! large qui dépasse aussi le SLO du folder...). Après l'exécution des règles, le
+//! moteur relie ces issues entre elles via `related` pour que l'interface puisse
+//! les regrouper.
+
+use crate::rule_api::IssueRef;
+use crate::LintIssue;
+
+/// Paires de règles dont les issues sur une même requête sont liées
+const CASCADES: &[(&str, &str)] = &[
+    ("environment-variables-usage", "internal-hosts-disclosure"),
+    ("response-time-threshold", "slo-alignment"),
+    ("auth-required-for-hosts", "inconsistent-auth-for-same-endpoint"),
+    ("debug-headers", "internal-hosts-disclosure"),
+];
+
+/// Path de l'item porteur d'une issue (`/item[0]/item[2]/request/url` → `/item[0]/item[2]`)
+pub(crate) fn item_path(path: &str) -> &str {
+    let mut end = 0;
+    while path[end..].starts_with("/item[") {
+        match path[end..].find(']') {
+            Some(close) => end += close + 1,
+            None => break,
+        }
+    }
+    &path[..end]
+}
+
+/// Relie les issues en cascade (dans les deux sens, sans doublon)
+pub fn link(issues: &mut [LintIssue]) {
+    let mut links: Vec<(usize, IssueRef)> = Vec::new();
+
+    for (a, first) in issues.iter().enumerate() {
+        for (b, second) in issues.iter().enumerate() {
+            if a == b || item_path(&first.path).is_empty() || item_path(&first.path) != item_path(&second.path) {
+                continue;
+            }
+            let cascades = CASCADES.iter().any(|(x, y)| {
+                (first.rule_id == *x && second.rule_id == *y) || (first.rule_id == *y && second.rule_id == *x)
+            });
+            if cascades {
+                links.push((a, IssueRef { rule_id: second.rule_id.clone(), path: second.path.clone() }));
+            }
+        }
+    }
+
+    for (index, reference) in links {
+        if !issues[index].related.contains(&reference) {
+            issues[index].related.push(reference);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(rule_id: &str, path: &str) -> LintIssue {
+        LintIssue::builder(rule_id).path(path).build()
+    }
+
+    #[test]
+    fn test_item_path() {
+        assert_eq!(item_path("/item[0]/item[12]/request/url"), "/item[0]/item[12]");
+        assert_eq!(item_path("/item[3]"), "/item[3]");
+        assert_eq!(item_path("/"), "");
+    }
+
+    #[test]
+    fn test_cascading_issues_linked_both_ways() {
+        let mut issues = vec![
+            issue("environment-variables-usage", "/item[0]/request/url"),
+            issue("internal-hosts-disclosure", "/item[0]/request/url"),
+            issue("internal-hosts-disclosure", "/item[1]/request/url"),
+            issue("test-http-status-mandatory", "/item[0]"),
+        ];
+
+        link(&mut issues);
+
+        assert_eq!(issues[0].related, vec![IssueRef { rule_id: "internal-hosts-disclosure".into(), path: "/item[0]/request/url".into() }]);
+        assert_eq!(issues[1].related[0].rule_id, "environment-variables-usage");
+        assert!(issues[2].related.is_empty());
+        assert!(issues[3].related.is_empty());
+    }
+}
@@ -0,0 +1,118 @@
+//! Estimation de l'effort et du gain de chaque correction
+//!
+//! Chaque issue reçoit un effort (corrigeable automatiquement, correction manuelle
+//! rapide, correction significative) et les points de score regagnés en la
+//! corrigeant, pour trier la liste de remédiation par meilleur retour sur effort.
+
+use crate::rule_api::Effort;
+use crate::{fixer, scoring, LintIssue, LintStats};
+
+/// Règles dont la correction demande une réflexion ou une coordination
+/// (rotation de secrets, choix d'authentification, écriture de schémas...)
+const SIGNIFICANT_RULES: &[&str] = &[
+    "hardcoded-secrets",
+    "auth-required-for-hosts",
+    "inconsistent-auth-for-same-endpoint",
+    "internal-hosts-disclosure",
+    "test-schema-validation-recommended",
+    "test-body-content-validation",
+    "test-coverage-minimum",
+    "request-examples-required",
+];
+
+/// Effort de correction d'une issue
+pub fn effort_for(issue: &LintIssue) -> Effort {
+    if issue.fix.as_ref().is_some_and(fixer::is_auto_fixable) {
+        Effort::AutoFixable
+    } else if SIGNIFICANT_RULES.contains(&issue.rule_id.as_str()) {
+        Effort::Significant
+    } else {
+        Effort::QuickManual
+    }
+}
+
+/// Renseigne `effort` et `score_impact` sur chaque issue
+pub fn annotate(issues: &mut [LintIssue], stats: &LintStats) {
+    // Le gain dépend de la catégorie : corriger une issue d'une catégorie déjà au
+    // plafond ne rend aucun point
+    let current = scoring::score(&mut stats.categories.clone(), stats.total_requests);
+    let gain = |category: &str, severity: &str| {
+        let mut categories = stats.categories.clone();
+        let Some(entry) = categories.get_mut(category) else {
+            return 0.0;
+        };
+        let count = match severity {
+            "error" => &mut entry.errors,
+            "warning" => &mut entry.warnings,
+            "info" => &mut entry.infos,
+            _ => return 0.0,
+        };
+        if *count == 0 {
+            return 0.0;
+        }
+        *count -= 1;
+        let gain = scoring::score(&mut categories, stats.total_requests) - current;
+        (gain.max(0.0) * 100.0).round() / 100.0
+    };
+
+    let mut gains: std::collections::HashMap<(String, String), f64> = std::collections::HashMap::new();
+    for issue in issues.iter_mut() {
+        issue.effort = Some(effort_for(issue));
+        let key = (scoring::category_of(&issue.rule_id), issue.severity.clone());
+        let impact = *gains.entry(key).or_insert_with_key(|(category, severity)| gain(category, severity));
+        issue.score_impact = Some(impact);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{run_linter, LintConfig};
+    use serde_json::json;
+
+    #[test]
+    fn test_effort_classification() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "get users",
+                "request": { "method": "GET", "url": "https://api.example.com/users" }
+            }]
+        });
+        let config = LintConfig {
+            rules: Some(vec![
+                "test-http-status-mandatory".to_string(),
+                "environment-variables-usage".to_string(),
+                "test-schema-validation-recommended".to_string(),
+            ]),
+            ..Default::default()
+        };
+
+        let result = run_linter(&collection, &config);
+        let effort = |rule_id: &str| result.issues.iter().find(|i| i.rule_id == rule_id).and_then(|i| i.effort);
+
+        assert_eq!(effort("test-http-status-mandatory"), Some(Effort::AutoFixable));
+        assert_eq!(effort("environment-variables-usage"), Some(Effort::QuickManual));
+        assert_eq!(effort("test-schema-validation-recommended"), Some(Effort::Significant));
+    }
+
+    #[test]
+    fn test_score_impact() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [
+                { "name": "GET Users", "request": { "method": "GET", "url": "https://api.example.com/users" } },
+                { "name": "GET Orders", "request": { "method": "GET", "url": "https://api.example.com/orders" } }
+            ]
+        });
+        let config = LintConfig {
+            rules: Some(vec!["test-http-status-mandatory".to_string()]),
+            ..Default::default()
+        };
+
+        let result = run_linter(&collection, &config);
+
+        // 2 erreurs sur 2 requêtes : corriger l'une d'elles rend 7.5 points
+        assert_eq!(result.issues[0].score_impact, Some(7.5));
+    }
+}
@@ -0,0 +1,180 @@
+use crate::context::LintContext;
+use crate::{LintConfig, LintIssue};
+use crate::model::TypedItem;
+use crate::rules::{Category, ItemVisitor, Rule, RuleInput};
+use crate::utils::{self, ItemContext};
+use crate::i18n::Render;
+use regex::Regex;
+use serde_json::Value;
+use std::sync::LazyLock;
+
+/// Génération aléatoire
+static RANDOM: LazyLock<Regex> = LazyLock::new(|| utils::build_regex(r"Math\.random\s*\(|_\.random\s*\(").unwrap());
+/// Initialisation explicite d'une graine (`faker.seed(`, `Math.seedrandom(`, `seedrandom(`)
+static SEED: LazyLock<Regex> = LazyLock::new(|| utils::build_regex(r"\b(?:seed|seedrandom)\s*\(").unwrap());
+
+/// Règle : deterministic-test-data
+///
+/// Détecte la génération de données aléatoires non reproductibles dans les scripts
+/// pre-request (Math.random(), _.random()). Un run qui échoue ne peut alors pas être rejoué
+/// avec les mêmes données.
+///
+/// Alternatives recommandées :
+/// - pm.variables.replaceIn('{{$randomInt}}') (variables dynamiques Postman)
+/// - un helper seedé défini au niveau collection/folder
+///
+/// Les scripts qui initialisent explicitement une graine (`faker.seed(42)`,
+/// `Math.seedrandom('x')`) sont ignorés ; une simple mention (« seeded », `seedData`)
+/// ne suffit pas.
+///
+/// Sévérité : INFO (-3%)
+pub fn check(collection: &Value) -> Vec<LintIssue> {
+    check_with_context(collection, &LintContext::new(collection))
+}
+
+/// Version utilisant le contexte partagé (index des scripts)
+pub fn check_with_context(collection: &Value, ctx: &LintContext) -> Vec<LintIssue> {
+    DeterministicTestData.check(collection, ctx, &LintConfig::default())
+}
+
+fn check_scripts(prerequest_script: &str, item_name: &str, path: &str, issues: &mut Vec<LintIssue>) {
+    if !RANDOM.is_match(prerequest_script) || SEED.is_match(prerequest_script) {
+        return;
+    }
+
+    issues.push(LintIssue::builder("deterministic-test-data")
+        .severity("info")
+        .message_id("deterministic-test-data.random")
+        .param("request", item_name)
+        .path(path)
+        .render());
+}
+
+/// Déclaration de la règle dans le registre
+pub struct DeterministicTestData;
+
+impl Rule for DeterministicTestData {
+    fn id(&self) -> &'static str {
+        "deterministic-test-data"
+    }
+
+    fn category(&self) -> Category {
+        Category::BestPractices
+    }
+
+    fn severity(&self) -> &'static str {
+        "info"
+    }
+
+    fn description(&self) -> &'static str {
+        "Avoid unseeded `Math.random()` / `_.random()` in pre-request scripts"
+    }
+
+    fn inputs(&self) -> &'static [RuleInput] {
+        &[RuleInput::Scripts]
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, _config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        Some(Box::new(DeterministicTestData))
+    }
+}
+
+impl ItemVisitor for DeterministicTestData {
+    // Requêtes et folders peuvent porter des scripts pre-request
+    fn visit(&mut self, item: &ItemContext, _typed: &TypedItem, ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        check_scripts(&ctx.scripts.get(&item.path).prerequest, &item.name, &item.path, issues);
+    }
+
+    // Scripts au niveau collection
+    fn finish(&mut self, ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        check_scripts(&ctx.scripts.get("").prerequest, "Collection", "/", issues);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_math_random_detected() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "POST Create User",
+                "request": { "method": "POST", "url": "{{base_url}}/users" },
+                "event": [{
+                    "listen": "prerequest",
+                    "script": {
+                        "exec": ["pm.environment.set('age', Math.floor(Math.random() * 100));"]
+                    }
+                }]
+            }]
+        });
+
+        let issues = check(&collection);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].rule_id, "deterministic-test-data");
+        assert_eq!(issues[0].severity, "info");
+        assert_eq!(issues[0].path, "/item[0]");
+    }
+
+    #[test]
+    fn test_dynamic_variables_allowed() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "POST Create User",
+                "request": { "method": "POST", "url": "{{base_url}}/users" },
+                "event": [{
+                    "listen": "prerequest",
+                    "script": {
+                        "exec": ["pm.environment.set('age', pm.variables.replaceIn('{{$randomInt}}'));"]
+                    }
+                }]
+            }]
+        });
+
+        let issues = check(&collection);
+        assert_eq!(issues.len(), 0);
+    }
+
+    #[test]
+    fn test_seeded_helper_allowed() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "event": [{
+                "listen": "prerequest",
+                "script": {
+                    "exec": [
+                        "const rng = seedrandom(pm.environment.get('seed'));",
+                        "pm.variables.set('n', _.random(1, 10));"
+                    ]
+                }
+            }],
+            "item": []
+        });
+
+        let issues = check(&collection);
+        assert_eq!(issues.len(), 0);
+    }
+
+    #[test]
+    fn test_seed_mention_is_not_a_seeding_call() {
+        let collection = |line: &str| {
+            json!({
+                "info": { "name": "Test" },
+                "event": [{
+                    "listen": "prerequest",
+                    "script": { "exec": [line, "pm.variables.set('n', Math.random());"] }
+                }],
+                "item": []
+            })
+        };
+
+        assert_eq!(check(&collection("// data is seeded by the CI job")).len(), 1);
+        assert_eq!(check(&collection("const seedData = loadSeedData();")).len(), 1);
+        assert_eq!(check(&collection("faker.seed(42);")).len(), 0);
+        assert_eq!(check(&collection("Math.seedrandom('run-1');")).len(), 0);
+    }
+}
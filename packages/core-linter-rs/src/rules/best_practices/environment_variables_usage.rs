@@ -1,6 +1,15 @@
-use crate::LintIssue;
+use crate::context::LintContext;
+use crate::{FixAction, LintConfig, LintIssue};
+use crate::model::TypedItem;
+use crate::rules::{Category, ItemVisitor, Rule};
+use crate::utils::{self, ItemContext};
+use crate::i18n::Render;
 use regex::Regex;
 use serde_json::Value;
+use std::sync::LazyLock;
+
+/// URL commençant par un schéma et un hôte littéral
+static HARDCODED_URL: LazyLock<Regex> = LazyLock::new(|| utils::build_regex(r"^https?://[^{]").unwrap());
 
 /// Règle : environment-variables-usage
 /// 
@@ -13,67 +22,61 @@ use serde_json::Value;
 /// 
 /// Sévérité : WARNING (-8%)
 pub fn check(collection: &Value) -> Vec<LintIssue> {
-    let mut issues = Vec::new();
-    
-    if let Some(items) = collection["item"].as_array() {
-        check_items(items, &mut issues, "");
+    EnvironmentVariablesUsage.check(collection, &LintContext::new(collection), &LintConfig::default())
+}
+
+/// Déclaration de la règle dans le registre
+pub struct EnvironmentVariablesUsage;
+
+impl Rule for EnvironmentVariablesUsage {
+    fn id(&self) -> &'static str {
+        "environment-variables-usage"
+    }
+
+    fn category(&self) -> Category {
+        Category::BestPractices
+    }
+
+    fn severity(&self) -> &'static str {
+        "warning"
+    }
+
+    fn description(&self) -> &'static str {
+        "Use environment variables"
+    }
+
+    fn fix_types(&self) -> &'static [&'static str] {
+        &["use_environment_variable"]
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, _config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        Some(Box::new(EnvironmentVariablesUsage))
     }
-    
-    issues
 }
 
-fn check_items(items: &[Value], issues: &mut Vec<LintIssue>, parent_path: &str) {
-    for (index, item) in items.iter().enumerate() {
-        let default_name = format!("Item-{}", index + 1);
-        let item_name = item["name"].as_str().unwrap_or(&default_name);
-        let current_path = if parent_path.is_empty() {
-            format!("/item[{}]", index)
-        } else {
-            format!("{}/item[{}]", parent_path, index)
+impl ItemVisitor for EnvironmentVariablesUsage {
+    fn visit(&mut self, item: &ItemContext, typed: &TypedItem, _ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        let Some(request) = &typed.item.request else {
+            return;
         };
-        
-        // Si c'est une requête
-        if item.get("request").is_some() {
-            // Vérifier l'URL
-            let url = if let Some(url_str) = item["request"]["url"].as_str() {
-                url_str.to_string()
-            } else if let Some(url_obj) = item["request"]["url"].as_object() {
-                url_obj.get("raw")
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("")
-                    .to_string()
-            } else {
-                String::new()
-            };
-            
-            // Détecter les URLs en dur (sans variables {{...}})
-            let has_hardcoded_url = Regex::new(r"^https?://[^{]").unwrap().is_match(&url) &&
-                !url.contains("{{") && 
-                !url.contains("localhost") && 
-                !url.contains("127.0.0.1");
-            
-            if has_hardcoded_url {
-                issues.push(LintIssue {
-                    rule_id: "environment-variables-usage".to_string(),
-                    severity: "warning".to_string(),
-                    message: format!(
-                        "🔧 Request \"{}\" should use an environment variable for the URL (ex: {{{{base_url}}}})",
-                        item_name
-                    ),
-                    path: format!("{}/request/url", current_path),
-                    line: None,
-                    fix: Some(serde_json::json!({
-                        "type": "use_environment_variable",
-                        "field": "url",
-                        "suggested_variable": "{{base_url}}",
-                    })),
-                });
-            }
-        }
-        
-        // Si c'est un folder, récurser
-        if let Some(sub_items) = item["item"].as_array() {
-            check_items(sub_items, issues, &current_path);
+        let url = request.url.raw();
+
+        // Détecter les URLs en dur (sans variables {{...}})
+        let has_hardcoded_url = HARDCODED_URL.is_match(url) &&
+            !url.contains("{{") &&
+            !url.contains("localhost") &&
+            !url.contains("127.0.0.1");
+
+        if has_hardcoded_url {
+            issues.push(LintIssue::builder("environment-variables-usage")
+                .message_id("environment-variables-usage.hardcoded-url")
+                .param("request", item.name.as_str())
+                .path(format!("{}/request/url", item.path))
+                .fix(FixAction::UseEnvironmentVariable {
+                    field: "url".to_string(),
+                    suggested_variable: "{{base_url}}".to_string(),
+                })
+                .render());
         }
     }
 }
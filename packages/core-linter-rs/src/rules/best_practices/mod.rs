@@ -1,2 +1,20 @@
+use super::Rule;
+
 pub mod environment_variables_usage;
 pub mod test_coverage_minimum;
+pub mod deterministic_test_data;
+pub mod variable_scope_appropriate;
+pub mod token_refresh_pattern;
+pub mod placeholder_syntax;
+pub mod variable_cleanup;
+
+/// Règles de la catégorie, dans l'ordre d'exécution
+pub const RULES: &[&dyn Rule] = &[
+    &environment_variables_usage::EnvironmentVariablesUsage,
+    &test_coverage_minimum::TestCoverageMinimum,
+    &deterministic_test_data::DeterministicTestData,
+    &variable_scope_appropriate::VariableScopeAppropriate,
+    &token_refresh_pattern::TokenRefreshPattern,
+    &placeholder_syntax::PlaceholderSyntax,
+    &variable_cleanup::VariableCleanup,
+];
@@ -0,0 +1,197 @@
+use crate::context::LintContext;
+use crate::{FixAction, LintConfig, LintIssue};
+use crate::model::TypedItem;
+use crate::rules::{Category, ItemVisitor, Rule, RuleInput};
+use crate::utils::{self, ItemContext};
+use crate::i18n::Render;
+use regex::Regex;
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::sync::LazyLock;
+
+/// Règle : placeholder-syntax
+///
+/// Détecte les variables Postman mal formées dans les requêtes (URL, headers, body, auth) :
+/// - accolades déséquilibrées : `{base_url}}`, `{{base_url}`
+/// - espaces parasites : `{{ base_url }}`
+/// - syntaxe shell : `${base_url}`
+///
+/// Postman n'interpole pas ces formes : la requête part avec le texte brut.
+/// Les scripts ne sont pas analysés (`${...}` y est une template string JS légitime).
+/// Auto-fix : normalisation en `{{base_url}}`.
+///
+/// Sévérité : WARNING (-8%)
+pub fn check(collection: &Value) -> Vec<LintIssue> {
+    PlaceholderSyntax.check(collection, &LintContext::new(collection), &LintConfig::default())
+}
+
+struct Patterns {
+    braces: Regex,
+    shell: Regex,
+}
+
+static PATTERNS: LazyLock<Patterns> = LazyLock::new(|| Patterns {
+    braces: utils::build_regex(r"(\{+)(\s*)([A-Za-z_][\w.-]*)(\s*)(\}+)").unwrap(),
+    shell: utils::build_regex(r"\$\{([A-Za-z_][\w.-]*)\}").unwrap(),
+});
+
+impl Patterns {
+    /// Placeholders mal formés d'un texte, avec leur forme normalisée
+    fn malformed(&self, text: &str, found: &mut BTreeSet<(String, String)>) {
+        for caps in self.shell.captures_iter(text) {
+            found.insert((caps[0].to_string(), format!("{{{{{}}}}}", &caps[1])));
+        }
+
+        for caps in self.braces.captures_iter(text) {
+            let open = caps[1].len();
+            let close = caps[5].len();
+            let spaced = !caps[2].is_empty() || !caps[4].is_empty();
+            let shell = caps.get(0).map(|m| m.start() > 0 && text[..m.start()].ends_with('$')).unwrap_or(false);
+
+            // `{{x}}}` ou `{{{x}}}` : accolades JSON autour d'une variable valide
+            let unbalanced = (open == 1 && close == 2) || (open == 2 && close == 1);
+            let spaced_variable = open >= 2 && close >= 2 && spaced;
+            if shell || !(unbalanced || spaced_variable) {
+                continue;
+            }
+
+            // Ne garder que les accolades du placeholder (hors accolades JSON voisines)
+            let original = format!(
+                "{}{}{}{}{}",
+                "{".repeat(open.min(2)),
+                &caps[2],
+                &caps[3],
+                &caps[4],
+                "}".repeat(close.min(2))
+            );
+            found.insert((original, format!("{{{{{}}}}}", &caps[3])));
+        }
+    }
+}
+
+/// Collecte récursivement les chaînes d'une valeur JSON
+fn collect_strings<'a>(value: &'a Value, strings: &mut Vec<&'a str>) {
+    match value {
+        Value::String(s) => strings.push(s),
+        Value::Array(values) => values.iter().for_each(|v| collect_strings(v, strings)),
+        Value::Object(map) => map.values().for_each(|v| collect_strings(v, strings)),
+        _ => {}
+    }
+}
+
+/// Déclaration de la règle dans le registre
+pub struct PlaceholderSyntax;
+
+impl Rule for PlaceholderSyntax {
+    fn id(&self) -> &'static str {
+        "placeholder-syntax"
+    }
+
+    fn category(&self) -> Category {
+        Category::BestPractices
+    }
+
+    fn severity(&self) -> &'static str {
+        "warning"
+    }
+
+    fn description(&self) -> &'static str {
+        "Malformed variables (`{base_url}}`, `{{ base_url }}`, `${base_url}`) in URLs, headers and bodies"
+    }
+
+    fn fix_types(&self) -> &'static [&'static str] {
+        &["normalize_placeholder"]
+    }
+
+    fn inputs(&self) -> &'static [RuleInput] {
+        &[RuleInput::RequestContent]
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, _config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        Some(Box::new(PlaceholderSyntax))
+    }
+}
+
+impl ItemVisitor for PlaceholderSyntax {
+    fn visit(&mut self, item: &ItemContext, _typed: &TypedItem, _ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        if !item.is_request() {
+            return;
+        }
+
+        let mut strings = Vec::new();
+        collect_strings(&item.item["request"], &mut strings);
+
+        let mut found = BTreeSet::new();
+        for text in strings {
+            PATTERNS.malformed(text, &mut found);
+        }
+
+        for (original, normalized) in found {
+            issues.push(LintIssue::builder("placeholder-syntax")
+                .message_id("placeholder-syntax.malformed")
+                .param("request", item.name.as_str())
+                .param("found", original.as_str())
+                .param("expected", normalized.as_str())
+                .path(item.path.clone())
+                .fix(FixAction::NormalizePlaceholder { original, normalized })
+                .render());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_malformed_placeholders() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "GET Users",
+                "request": {
+                    "method": "GET",
+                    "url": { "raw": "{base_url}}/users/{{ userId }}", "host": ["{base_url}}"] },
+                    "header": [
+                        { "key": "Authorization", "value": "Bearer ${token}" },
+                        { "key": "X-Tenant", "value": "{{tenant}" }
+                    ]
+                }
+            }]
+        });
+
+        let issues = check(&collection);
+        let fixes: Vec<(String, String)> = issues
+            .iter()
+            .filter_map(|issue| match &issue.fix {
+                Some(FixAction::NormalizePlaceholder { original, normalized }) => Some((original.clone(), normalized.clone())),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(issues.len(), 4);
+        assert!(fixes.contains(&("{base_url}}".to_string(), "{{base_url}}".to_string())));
+        assert!(fixes.contains(&("{{ userId }}".to_string(), "{{userId}}".to_string())));
+        assert!(fixes.contains(&("${token}".to_string(), "{{token}}".to_string())));
+        assert!(fixes.contains(&("{{tenant}".to_string(), "{{tenant}}".to_string())));
+    }
+
+    #[test]
+    fn test_valid_placeholders_and_scripts_are_ignored() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "POST User",
+                "request": {
+                    "method": "POST",
+                    "url": "{{base_url}}/users/{{userId}}",
+                    "body": { "mode": "raw", "raw": "{\"user\":{\"id\":{{userId}}}}" }
+                },
+                "event": [{ "listen": "prerequest", "script": { "exec": ["const url = `${base}/users`;"] } }]
+            }]
+        });
+
+        assert!(check(&collection).is_empty());
+    }
+}
@@ -1,66 +1,163 @@
-use crate::LintIssue;
-use crate::utils;
+use crate::context::LintContext;
+use crate::{LintConfig, LintIssue};
+use crate::model::{Collection, Item, TypedItem};
+use crate::rules::{self, Category, ItemVisitor, Rule};
+use crate::utils::ItemContext;
+use crate::i18n::Render;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+/// Configuration de la règle test-coverage-minimum (seuils en % de requêtes testées)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct TestCoverageConfig {
+    /// En dessous : INFO
+    pub info_below: f64,
+    /// En dessous : WARNING
+    pub warning_below: f64,
+    /// En dessous : ERROR
+    pub error_below: f64,
+    /// Ancien seuil unique (WARNING en dessous) : remplace `warning_below` s'il est
+    /// renseigné, pour que les configurations antérieures aux seuils gradués gardent
+    /// leur comportement
+    pub min_percent: Option<f64>,
+}
+
+impl Default for TestCoverageConfig {
+    fn default() -> Self {
+        TestCoverageConfig {
+            info_below: 90.0,
+            warning_below: 80.0,
+            error_below: 50.0,
+            min_percent: None,
+        }
+    }
+}
+
+impl TestCoverageConfig {
+    /// Seuils effectifs (error, warning, info), `min_percent` compris ; le seuil INFO
+    /// n'est jamais inférieur au seuil WARNING
+    fn thresholds(&self) -> (f64, f64, f64) {
+        let warning_below = self.min_percent.unwrap_or(self.warning_below);
+        (self.error_below, warning_below, self.info_below.max(warning_below))
+    }
+}
+
 /// Règle : test-coverage-minimum
 /// 
 /// Vérifie qu'un minimum de requêtes ont des tests.
-/// Recommandation : Au moins 80% des requêtes devraient avoir des tests.
+/// La sévérité est graduée selon le taux de couverture (seuils configurables) :
+/// - < 50% : ERROR
+/// - < 80% : WARNING
+/// - < 90% : INFO
 /// 
-/// Sévérité : WARNING (-8%)
+/// Sévérité : ERROR (-15%) / WARNING (-8%) / INFO (-3%)
 pub fn check(collection: &Value) -> Vec<LintIssue> {
-    let mut issues = Vec::new();
-    
-    let (total_requests, requests_with_tests) = count_test_coverage(collection);
-    
-    if total_requests > 0 {
-        let coverage_percent = (requests_with_tests as f32 / total_requests as f32) * 100.0;
-        
-        if coverage_percent < 80.0 {
-            issues.push(LintIssue {
-                rule_id: "test-coverage-minimum".to_string(),
-                severity: "warning".to_string(),
-                message: format!(
-                    "📊 Insufficient test coverage: {:.1}% ({}/{} requests tested). Recommended minimum: 80%",
-                    coverage_percent, requests_with_tests, total_requests
-                ),
-                path: "/".to_string(),
-                line: None,
-                fix: None,
-            });
-        }
-    }
-    
-    issues
+    check_with_config(collection, &TestCoverageConfig::default())
+}
+
+/// Version avec configuration personnalisable
+pub fn check_with_config(collection: &Value, config: &TestCoverageConfig) -> Vec<LintIssue> {
+    rules::visit(collection, &LintContext::new(collection), &mut CoverageVisitor::new(config.clone()))
 }
 
-fn count_test_coverage(collection: &Value) -> (usize, usize) {
-    let mut total = 0;
-    let mut with_tests = 0;
-    
-    if let Some(items) = collection["item"].as_array() {
-        count_items(items, &mut total, &mut with_tests);
+/// Compte les requêtes et celles qui ont des tests ; la couverture n'est connue
+/// qu'en fin de parcours
+struct CoverageVisitor {
+    config: TestCoverageConfig,
+    total: usize,
+    with_tests: usize,
+}
+
+impl CoverageVisitor {
+    fn new(config: TestCoverageConfig) -> Self {
+        CoverageVisitor { config, total: 0, with_tests: 0 }
     }
-    
-    (total, with_tests)
 }
 
-fn count_items(items: &[Value], total: &mut usize, with_tests: &mut usize) {
-    for item in items {
-        // Si c'est une requête
-        if item.get("request").is_some() {
-            *total += 1;
-            
-            let test_scripts = utils::extract_test_scripts(item);
-            if !test_scripts.is_empty() && test_scripts.iter().any(|s| !s.trim().is_empty()) {
-                *with_tests += 1;
+impl ItemVisitor for CoverageVisitor {
+    fn visit(&mut self, _item: &ItemContext, typed: &TypedItem, _ctx: &LintContext, _issues: &mut Vec<LintIssue>) {
+        if typed.item.is_request() {
+            self.total += 1;
+            if has_tests(typed.item) {
+                self.with_tests += 1;
             }
         }
-        
-        // Si c'est un folder, récurser
-        if let Some(sub_items) = item["item"].as_array() {
-            count_items(sub_items, total, with_tests);
+    }
+
+    fn finish(&mut self, _ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        let (total_requests, requests_with_tests) = (self.total, self.with_tests);
+        if total_requests == 0 {
+            return;
         }
+
+        let coverage_percent = (requests_with_tests as f64 / total_requests as f64) * 100.0;
+        let (error_below, warning_below, info_below) = self.config.thresholds();
+
+        let severity = if coverage_percent < error_below {
+            "error"
+        } else if coverage_percent < warning_below {
+            "warning"
+        } else if coverage_percent < info_below {
+            "info"
+        } else {
+            return;
+        };
+
+        issues.push(LintIssue::builder("test-coverage-minimum")
+            .severity(severity)
+            .message_id("test-coverage-minimum.insufficient")
+            .param("coverage", coverage_percent)
+            .param("tested", requests_with_tests)
+            .param("total", total_requests)
+            .param("minimum", info_below)
+            .path("/")
+            .render());
+    }
+}
+
+/// Pourcentage de requêtes possédant au moins un test (0 si la collection est vide)
+pub fn coverage_percent(collection: &Value) -> f64 {
+    let model = Collection::from_value(collection);
+    let requests: Vec<&Item> = model.entries().into_iter().map(|entry| entry.item).filter(|item| item.is_request()).collect();
+    let (total, with_tests) = (requests.len(), requests.iter().filter(|item| has_tests(item)).count());
+    if total == 0 {
+        return 0.0;
+    }
+    (with_tests as f64 / total as f64) * 100.0
+}
+
+/// Vrai si l'item porte au moins un script de test non vide
+fn has_tests(item: &Item) -> bool {
+    !item.test_script().trim().is_empty()
+}
+
+/// Déclaration de la règle dans le registre
+pub struct TestCoverageMinimum;
+
+impl Rule for TestCoverageMinimum {
+    fn id(&self) -> &'static str {
+        "test-coverage-minimum"
+    }
+
+    fn category(&self) -> Category {
+        Category::BestPractices
+    }
+
+    fn severity(&self) -> &'static str {
+        "warning"
+    }
+
+    fn description(&self) -> &'static str {
+        "Minimum test coverage (graded: error <50%, warning <80%, info <90%)"
+    }
+
+    fn default_options(&self) -> Option<Value> {
+        serde_json::to_value(TestCoverageConfig::default()).ok()
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        Some(Box::new(CoverageVisitor::new(config.options_for(self.id()))))
     }
 }
 
@@ -134,6 +231,47 @@ mod tests {
         let issues = check(&collection);
         // 20% coverage (1/5)
         assert_eq!(issues.len(), 1);
-        assert!(issues[0].message.contains("Insufficient test coverage"));
+        assert!(issues[0].message.contains("Insufficient test coverage: 20.0% (1/5"));
+        assert_eq!(issues[0].severity, "error");
+
+        assert_eq!(issues[0].params["coverage"], 20.0);
+
+        // --locale fr : conventions françaises, appliquées au rendu
+        let mut issue = issues[0].clone();
+        crate::i18n::localize(&mut issue, crate::i18n::Locale::Fr);
+        assert!(issue.message.contains("20,0 % (1/5 requêtes testées). Minimum recommandé : 90 %"));
+    }
+
+    #[test]
+    fn test_graded_severity() {
+        let request = |tested: bool| {
+            let mut item = json!({ "name": "Request", "request": { "method": "GET", "url": "https://api.example.com" } });
+            if tested {
+                item["event"] = json!([{ "listen": "test", "script": { "exec": ["pm.test('test', () => {});"] } }]);
+            }
+            item
+        };
+        // 85% de couverture (17/20)
+        let items: Vec<Value> = (0..20).map(|i| request(i < 17)).collect();
+        let collection = json!({ "info": { "name": "Test" }, "item": items });
+
+        let issues = check(&collection);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].severity, "info");
+        assert!((coverage_percent(&collection) - 85.0).abs() < f64::EPSILON);
+
+        // Seuils personnalisés : 85% passe sous le seuil WARNING
+        let config = TestCoverageConfig { info_below: 95.0, warning_below: 90.0, error_below: 60.0, min_percent: None };
+        let issues = check_with_config(&collection, &config);
+        assert_eq!(issues[0].severity, "warning");
+
+        // Configuration antérieure aux seuils gradués : WARNING sous `min_percent`
+        let config = TestCoverageConfig { min_percent: Some(95.0), ..TestCoverageConfig::default() };
+        let issues = check_with_config(&collection, &config);
+        assert_eq!(issues[0].severity, "warning");
+        assert_eq!(issues[0].params["minimum"], 95.0);
+
+        let config = TestCoverageConfig { min_percent: Some(80.0), ..TestCoverageConfig::default() };
+        assert_eq!(check_with_config(&collection, &config)[0].severity, "info");
     }
 }
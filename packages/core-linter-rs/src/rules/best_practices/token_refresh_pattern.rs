@@ -0,0 +1,154 @@
+use crate::context::LintContext;
+use crate::{LintConfig, LintIssue};
+use crate::model::TypedItem;
+use crate::rules::{Category, ItemVisitor, Rule, RuleInput};
+use crate::utils::{self, ItemContext};
+use crate::i18n::Render;
+use regex::Regex;
+use serde_json::Value;
+use std::sync::LazyLock;
+
+/// Règle : token-refresh-pattern
+///
+/// Détecte les scripts pre-request (collection, folder ou requête) qui récupèrent
+/// un token OAuth via `pm.sendRequest` sans le mettre en cache : un nouveau token
+/// est alors demandé avant CHAQUE requête (lenteur, rate-limit du serveur d'auth).
+///
+/// Le cache est reconnu si le script vérifie l'expiration du token (`expires_in`,
+/// `tokenExpiry`, `Date.now()`...) ou la présence d'un token existant avant de
+/// refaire l'appel (`if (!pm.environment.get('token'))`).
+///
+/// Sévérité : WARNING (-8%)
+pub fn check(collection: &Value) -> Vec<LintIssue> {
+    check_with_context(collection, &LintContext::new(collection))
+}
+
+/// Version utilisant le contexte partagé (index des scripts)
+pub fn check_with_context(collection: &Value, ctx: &LintContext) -> Vec<LintIssue> {
+    TokenRefreshPattern.check(collection, ctx, &LintConfig::default())
+}
+
+struct RefreshPatterns {
+    send_request: Regex,
+    token: Regex,
+    expiry: Regex,
+    guard: Regex,
+}
+
+static PATTERNS: LazyLock<RefreshPatterns> = LazyLock::new(|| RefreshPatterns {
+    send_request: utils::build_regex(r"pm\.sendRequest\s*\(").unwrap(),
+    token: utils::build_regex(r"(?i)access_token|oauth|/token\b|grant_type|\.set\(\s*['\x22][^'\x22]*token").unwrap(),
+    expiry: utils::build_regex(r"(?i)expir|expires_in|\bexp\b").unwrap(),
+    guard: utils::build_regex(r"if\s*\(\s*!?\s*pm\.(?:environment|collectionVariables|globals|variables)\.(?:get|has)\(").unwrap(),
+});
+
+fn check_script(script: &str, item_name: &str, path: &str, issues: &mut Vec<LintIssue>) {
+    let fetches_token = PATTERNS.send_request.is_match(script) && PATTERNS.token.is_match(script);
+    let is_cached = PATTERNS.expiry.is_match(script) || PATTERNS.guard.is_match(script);
+
+    if fetches_token && !is_cached {
+        issues.push(LintIssue::builder("token-refresh-pattern")
+            .message_id("token-refresh-pattern.every-request")
+            .param("request", item_name)
+            .path(path)
+            .render());
+    }
+}
+
+/// Déclaration de la règle dans le registre
+pub struct TokenRefreshPattern;
+
+impl Rule for TokenRefreshPattern {
+    fn id(&self) -> &'static str {
+        "token-refresh-pattern"
+    }
+
+    fn category(&self) -> Category {
+        Category::BestPractices
+    }
+
+    fn severity(&self) -> &'static str {
+        "warning"
+    }
+
+    fn description(&self) -> &'static str {
+        "Pre-request token refresh caches the token (checks expiry) instead of fetching before every request"
+    }
+
+    fn inputs(&self) -> &'static [RuleInput] {
+        &[RuleInput::Scripts]
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, _config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        Some(Box::new(TokenRefreshPattern))
+    }
+}
+
+impl ItemVisitor for TokenRefreshPattern {
+    fn visit(&mut self, item: &ItemContext, _typed: &TypedItem, ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        check_script(&ctx.scripts.get(&item.path).prerequest, &item.name, &item.path, issues);
+    }
+
+    // Script pre-request de la collection
+    fn finish(&mut self, ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        let collection_name = match ctx.model.info.name.as_str() {
+            "" => "Collection",
+            name => name,
+        };
+        check_script(&ctx.scripts.get("").prerequest, collection_name, "/", issues);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn collection_with_prerequest(exec: Vec<&str>) -> Value {
+        json!({
+            "info": { "name": "Shop API" },
+            "event": [{ "listen": "prerequest", "script": { "exec": exec } }],
+            "item": [{ "name": "GET Users", "request": { "method": "GET", "url": "{{base_url}}/users" } }]
+        })
+    }
+
+    #[test]
+    fn test_token_fetched_every_time() {
+        let collection = collection_with_prerequest(vec![
+            "pm.sendRequest({ url: pm.environment.get('auth_url') + '/oauth/token', method: 'POST' }, (err, res) => {",
+            "  if (err) { return; }",
+            "  pm.environment.set('access_token', res.json().access_token);",
+            "});",
+        ]);
+
+        let issues = check(&collection);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].path, "/");
+        assert!(issues[0].message.contains("\"Shop API\" fetches a new token"));
+    }
+
+    #[test]
+    fn test_cached_token_patterns() {
+        let with_expiry = collection_with_prerequest(vec![
+            "if (Date.now() < pm.environment.get('token_expires_at')) { return; }",
+            "pm.sendRequest({ url: '/oauth/token' }, (err, res) => {",
+            "  pm.environment.set('access_token', res.json().access_token);",
+            "  pm.environment.set('token_expires_at', Date.now() + res.json().expires_in * 1000);",
+            "});",
+        ]);
+        assert_eq!(check(&with_expiry).len(), 0);
+
+        let with_guard = collection_with_prerequest(vec![
+            "if (!pm.environment.get('access_token')) {",
+            "  pm.sendRequest({ url: '/oauth/token' }, (err, res) => pm.environment.set('access_token', res.json().access_token));",
+            "}",
+        ]);
+        assert_eq!(check(&with_guard).len(), 0);
+    }
+
+    #[test]
+    fn test_unrelated_send_request_ignored() {
+        let collection = collection_with_prerequest(vec!["pm.sendRequest('https://postman-echo.com/get', () => {});"]);
+        assert_eq!(check(&collection).len(), 0);
+    }
+}
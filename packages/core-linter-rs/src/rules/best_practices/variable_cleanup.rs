@@ -0,0 +1,225 @@
+use crate::context::LintContext;
+use crate::{LintConfig, LintIssue};
+use crate::model::TypedItem;
+use crate::rules::{self, Category, ItemVisitor, Rule, RuleInput};
+use crate::utils::{self, ItemContext};
+use crate::i18n::Render;
+use serde::{Deserialize, Serialize};
+use regex::Regex;
+use serde_json::Value;
+use std::collections::HashSet;
+use std::sync::LazyLock;
+
+/// Configuration de la règle variable-cleanup
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct VariableCleanupConfig {
+    /// Variables conservées volontairement d'une exécution à l'autre (`*` accepté,
+    /// ex: `"auth_*"`)
+    pub keep: Vec<String>,
+}
+
+impl Default for VariableCleanupConfig {
+    fn default() -> Self {
+        Self {
+            // Réécrite avant chaque requête par le snippet standard (`snippets::location_prerequest`)
+            keep: vec!["location".to_string()],
+        }
+    }
+}
+
+/// Retrait d'une variable : (scope, nom)
+static UNSET: LazyLock<Regex> =
+    LazyLock::new(|| utils::build_regex(r#"pm\.(environment|globals)\.unset\(\s*['"]([^'"]+)['"]"#).unwrap());
+/// Vidage d'un scope
+static CLEAR: LazyLock<Regex> = LazyLock::new(|| utils::build_regex(r"pm\.(environment|globals)\.clear\(\s*\)").unwrap());
+
+/// Règle : variable-cleanup
+///
+/// Les variables d'environnement (ou globales) écrites par les scripts persistent
+/// après l'exécution : lancées par Newman sur un environnement partagé, deux
+/// exécutions successives se transmettent leur état (un id créé, un token expiré...).
+/// Signale chaque variable écrite dans ces scopes qui n'est jamais retirée, ni par un
+/// `unset` dans un script d'item, ni par un `clear()` dans le nettoyage final (scripts
+/// de la dernière requête exécutée et de ses folders parents). Les scripts de niveau
+/// collection sont ignorés : ils s'exécutent à chaque requête, réécrivent leurs
+/// variables avant tout usage (ex: `location`) et ne peuvent pas servir de nettoyage.
+///
+/// Sévérité : INFO (-3%)
+pub fn check(collection: &Value) -> Vec<LintIssue> {
+    check_with_config(collection, &LintContext::new(collection), &VariableCleanupConfig::default())
+}
+
+/// Version avec configuration personnalisable
+pub fn check_with_config(collection: &Value, ctx: &LintContext, config: &VariableCleanupConfig) -> Vec<LintIssue> {
+    rules::visit(collection, ctx, &mut CleanupVisitor::new(config))
+}
+
+/// Écritures (dans l'ordre d'exécution), retraits et scripts du nettoyage final ; les
+/// variables jamais retirées ne sont connues qu'en fin de parcours
+struct CleanupVisitor {
+    keep: Vec<Regex>,
+    /// (scope, variable, path, nom de l'item)
+    setters: Vec<(String, String, String, String)>,
+    unset: HashSet<(String, String)>,
+    final_cleanup: Vec<String>,
+}
+
+impl CleanupVisitor {
+    fn new(config: &VariableCleanupConfig) -> Self {
+        CleanupVisitor {
+            keep: config.keep.iter().filter_map(|pattern| utils::wildcard_regex(pattern)).collect(),
+            setters: Vec::new(),
+            unset: HashSet::new(),
+            final_cleanup: Vec::new(),
+        }
+    }
+}
+
+impl ItemVisitor for CleanupVisitor {
+    fn visit(&mut self, item: &ItemContext, _typed: &TypedItem, ctx: &LintContext, _issues: &mut Vec<LintIssue>) {
+        let scripts = ctx.scripts.get(&item.path);
+        for (scope, variable) in &scripts.set_variables {
+            if scope == "environment" || scope == "globals" {
+                self.setters.push((scope.clone(), variable.clone(), item.path.clone(), item.name.clone()));
+            }
+        }
+        for script in [&scripts.prerequest, &scripts.test] {
+            self.unset.extend(UNSET.captures_iter(script).map(|caps| (caps[1].to_string(), caps[2].to_string())));
+        }
+        if item.is_request() {
+            self.final_cleanup = vec![scripts.prerequest.clone(), scripts.test.clone()];
+            self.final_cleanup.extend(item.parent_prerequest_scripts.iter().cloned());
+            self.final_cleanup.extend(item.parent_test_scripts.iter().cloned());
+        }
+    }
+
+    fn finish(&mut self, _ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        let cleared: HashSet<String> = self
+            .final_cleanup
+            .iter()
+            .flat_map(|script| CLEAR.captures_iter(script).map(|caps| caps[1].to_string()).collect::<Vec<_>>())
+            .collect();
+
+        let mut reported = HashSet::new();
+        for (scope, variable, path, setter) in std::mem::take(&mut self.setters) {
+            if cleared.contains(&scope)
+                || self.unset.contains(&(scope.clone(), variable.clone()))
+                || self.keep.iter().any(|pattern| pattern.is_match(&variable))
+                || !reported.insert((scope.clone(), variable.clone()))
+            {
+                continue;
+            }
+            issues.push(LintIssue::builder("variable-cleanup")
+                .severity("info")
+                .message_id(if scope == "globals" { "variable-cleanup.globals" } else { "variable-cleanup.environment" })
+                .param("variable", variable)
+                .param("item", setter)
+                .path(path)
+                .render());
+        }
+    }
+}
+
+/// Déclaration de la règle dans le registre
+pub struct VariableCleanup;
+
+impl Rule for VariableCleanup {
+    fn id(&self) -> &'static str {
+        "variable-cleanup"
+    }
+
+    fn category(&self) -> Category {
+        Category::BestPractices
+    }
+
+    fn severity(&self) -> &'static str {
+        "info"
+    }
+
+    fn description(&self) -> &'static str {
+        "Environment and global variables set by scripts are unset by a final cleanup"
+    }
+
+    fn inputs(&self) -> &'static [RuleInput] {
+        &[RuleInput::Scripts]
+    }
+
+    fn default_options(&self) -> Option<Value> {
+        serde_json::to_value(VariableCleanupConfig::default()).ok()
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        Some(Box::new(CleanupVisitor::new(&config.options_for(self.id()))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn request(name: &str, test: &[&str]) -> Value {
+        json!({
+            "name": name,
+            "request": { "method": "GET", "url": "{{base_url}}/users" },
+            "event": [{ "listen": "test", "script": { "exec": test } }]
+        })
+    }
+
+    #[test]
+    fn test_variables_left_behind() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "event": [{ "listen": "prerequest", "script": { "exec": ["pm.environment.set('location', pm.info.requestName);"] } }],
+            "item": [
+                request("POST User", &["pm.environment.set('userId', pm.response.json().id);", "pm.collectionVariables.set('page', 1);"]),
+                request("GET User", &["pm.globals.set(\"lastRun\", Date.now());"]),
+                request("DELETE User", &["pm.environment.unset('other');"])
+            ]
+        });
+        let issues = check(&collection);
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].path, "/item[0]");
+        assert!(issues[0].message.contains("Environment variable \"userId\" set by \"POST User\""));
+        assert!(issues[1].message.contains("pm.globals.unset(\"lastRun\")"));
+        assert_eq!(issues[1].message_id.as_deref(), Some("variable-cleanup.globals"));
+        assert_eq!(issues[0].message_id.as_deref(), Some("variable-cleanup.environment"));
+    }
+
+    #[test]
+    fn test_unset_and_final_clear() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [
+                request("POST User", &["pm.environment.set('userId', 1);", "pm.globals.set('lastRun', 1);"]),
+                {
+                    "name": "Teardown",
+                    "event": [{ "listen": "test", "script": { "exec": ["pm.environment.clear();"] } }],
+                    "item": [request("DELETE User", &["pm.globals.unset('lastRun');"])]
+                }
+            ]
+        });
+        assert!(check(&collection).is_empty());
+
+        // Un clear() ailleurs que dans le nettoyage final ne compte pas
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [
+                request("POST User", &["pm.environment.clear();", "pm.environment.set('userId', 1);"]),
+                request("GET User", &[])
+            ]
+        });
+        assert_eq!(check(&collection).len(), 1);
+    }
+
+    #[test]
+    fn test_kept_variables() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [request("Login", &["pm.environment.set('auth_token', 't');", "pm.environment.set('auth_expiry', 1);"])]
+        });
+        let config = VariableCleanupConfig { keep: vec!["location".to_string(), "auth_*".to_string()] };
+        assert!(check_with_config(&collection, &LintContext::new(&collection), &config).is_empty());
+    }
+}
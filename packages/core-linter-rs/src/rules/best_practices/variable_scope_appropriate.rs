@@ -0,0 +1,248 @@
+use crate::context::LintContext;
+use crate::{LintConfig, LintIssue};
+use crate::model::TypedItem;
+use crate::rules::{Category, ItemVisitor, Rule, RuleInput};
+use crate::utils::{self, ItemContext};
+use crate::i18n::Render;
+use regex::Regex;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::sync::LazyLock;
+
+/// Écriture : (scope, nom)
+static SET: LazyLock<Regex> = LazyLock::new(|| {
+    utils::build_regex(
+        r#"pm\.(environment|collectionVariables|globals|variables)\.set\(\s*['"]([^'"]+)['"]"#,
+    )
+    .unwrap()
+});
+/// Lecture par script
+static GET: LazyLock<Regex> = LazyLock::new(|| {
+    utils::build_regex(
+        r#"pm\.(?:environment|collectionVariables|globals|variables)\.get\(\s*['"]([^'"]+)['"]"#,
+    )
+    .unwrap()
+});
+/// Lecture par placeholder `{{nom}}`
+static PLACEHOLDER: LazyLock<Regex> = LazyLock::new(|| utils::build_regex(r"\{\{([^{}\s]+)\}\}").unwrap());
+/// Nom de variable sensible
+static SECRET: LazyLock<Regex> =
+    LazyLock::new(|| utils::build_regex(r"(?i)token|password|passwd|secret|api[_-]?key|credential").unwrap());
+
+/// Règle : variable-scope-appropriate
+///
+/// Analyse les appels pm.environment / pm.collectionVariables / pm.globals / pm.variables
+/// et suggère le scope adapté à chaque variable :
+/// - valeur définie et lue dans la même requête → pm.variables (scope local)
+/// - valeur partagée entre requêtes → pm.collectionVariables
+/// - secret (token, password, api key...) → environnement, jamais collection ni globals
+///
+/// Sévérité : INFO (-3%)
+pub fn check(collection: &Value) -> Vec<LintIssue> {
+    VariableScopeAppropriate.check(collection, &LintContext::new(collection), &LintConfig::default())
+}
+
+struct ItemVariableUsage {
+    path: String,
+    name: String,
+    /// (scope, nom de variable)
+    sets: Vec<(String, String)>,
+    reads: HashSet<String>,
+}
+
+/// Inventaire des écritures et lectures de variables, par item ; les conseils
+/// dépendent des lectures de toute la collection et sont émis en fin de parcours
+#[derive(Default)]
+struct ScopeVisitor {
+    usages: Vec<ItemVariableUsage>,
+}
+
+impl ItemVisitor for ScopeVisitor {
+    fn visit(&mut self, item: &ItemContext, _typed: &TypedItem, ctx: &LintContext, _issues: &mut Vec<LintIssue>) {
+        let scripts = ctx.scripts.get(&item.path);
+        let script = format!("{}\n{}", scripts.prerequest, scripts.test);
+
+        let sets: Vec<(String, String)> = SET
+            .captures_iter(&script)
+            .map(|caps| (caps[1].to_string(), caps[2].to_string()))
+            .collect();
+
+        let mut reads: HashSet<String> = GET
+            .captures_iter(&script)
+            .map(|caps| caps[1].to_string())
+            .collect();
+
+        // Les placeholders {{var}} de la requête (URL, headers, body) sont aussi des lectures
+        if let Some(request) = item.item.get("request") {
+            let request_str = serde_json::to_string(request).unwrap_or_default();
+            reads.extend(PLACEHOLDER.captures_iter(&request_str).map(|caps| caps[1].to_string()));
+        }
+
+        if !sets.is_empty() || !reads.is_empty() {
+            self.usages.push(ItemVariableUsage {
+                path: item.path.clone(),
+                name: item.name.clone(),
+                sets,
+                reads,
+            });
+        }
+    }
+
+    fn finish(&mut self, _ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        let usages = &self.usages;
+        let mut readers: HashMap<&str, HashSet<&str>> = HashMap::new();
+        for usage in usages {
+            for name in &usage.reads {
+                readers.entry(name.as_str()).or_default().insert(usage.path.as_str());
+            }
+        }
+
+        // Conseils par variable écrite
+        let mut reported = HashSet::new();
+        for usage in usages {
+            for (scope, name) in &usage.sets {
+                if !reported.insert((usage.path.as_str(), name.as_str())) {
+                    continue;
+                }
+
+                let read_in = readers.get(name.as_str());
+                let read_elsewhere = read_in
+                    .map(|paths| paths.iter().any(|p| *p != usage.path))
+                    .unwrap_or(false);
+                let read_locally = read_in
+                    .map(|paths| paths.contains(usage.path.as_str()))
+                    .unwrap_or(false);
+
+                let advice = if SECRET.is_match(name) && (scope == "collectionVariables" || scope == "globals") {
+                    Some("variable-scope-appropriate.secret-in-shared-scope")
+                } else if scope != "variables" && read_locally && !read_elsewhere && !SECRET.is_match(name) {
+                    Some("variable-scope-appropriate.local-only")
+                } else if scope == "variables" && read_elsewhere {
+                    Some("variable-scope-appropriate.shared-via-variables")
+                } else {
+                    None
+                };
+
+                if let Some(advice) = advice {
+                    issues.push(LintIssue::builder("variable-scope-appropriate")
+                        .severity("info")
+                        .message_id(advice)
+                        .param("request", usage.name.as_str())
+                        .param("variable", name.as_str())
+                        .param("scope", scope.as_str())
+                        .path(usage.path.clone())
+                        .render());
+                }
+            }
+        }
+    }
+}
+
+/// Déclaration de la règle dans le registre
+pub struct VariableScopeAppropriate;
+
+impl Rule for VariableScopeAppropriate {
+    fn id(&self) -> &'static str {
+        "variable-scope-appropriate"
+    }
+
+    fn category(&self) -> Category {
+        Category::BestPractices
+    }
+
+    fn severity(&self) -> &'static str {
+        "info"
+    }
+
+    fn description(&self) -> &'static str {
+        "Variables set in the appropriate scope (local, collection, environment)"
+    }
+
+    fn inputs(&self) -> &'static [RuleInput] {
+        &[RuleInput::Scripts, RuleInput::RequestContent]
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, _config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        Some(Box::<ScopeVisitor>::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn request(name: &str, url: &str, prerequest: &[&str], test: &[&str]) -> Value {
+        json!({
+            "name": name,
+            "request": { "method": "GET", "url": url },
+            "event": [
+                { "listen": "prerequest", "script": { "exec": prerequest } },
+                { "listen": "test", "script": { "exec": test } }
+            ]
+        })
+    }
+
+    #[test]
+    fn test_local_value_in_environment() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [request(
+                "GET Users",
+                "{{base_url}}/users",
+                &["pm.environment.set('startedAt', Date.now());"],
+                &["const startedAt = pm.environment.get('startedAt');"]
+            )]
+        });
+
+        let issues = check(&collection);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("use pm.variables"));
+    }
+
+    #[test]
+    fn test_cross_request_value_in_local_scope() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [
+                request("POST Create User", "{{base_url}}/users", &[], &["pm.variables.set('userId', pm.response.json().id);"]),
+                request("GET User", "{{base_url}}/users/{{userId}}", &[], &[])
+            ]
+        });
+
+        let issues = check(&collection);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("pm.collectionVariables"));
+        assert_eq!(issues[0].path, "/item[0]");
+    }
+
+    #[test]
+    fn test_secret_in_collection_variables() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [
+                request("POST Login", "{{base_url}}/login", &[], &["pm.collectionVariables.set('auth_token', pm.response.json().token);"]),
+                request("GET Profile", "{{base_url}}/me", &[], &[]),
+                request("GET Orders", "{{base_url}}/orders?token={{auth_token}}", &[], &[])
+            ]
+        });
+
+        let issues = check(&collection);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("environment scope"));
+    }
+
+    #[test]
+    fn test_appropriate_scopes() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [
+                request("POST Create Order", "{{base_url}}/orders", &[], &["pm.collectionVariables.set('orderId', pm.response.json().id);"]),
+                request("GET Order", "{{base_url}}/orders/{{orderId}}", &["pm.variables.set('start', Date.now());"], &["pm.variables.get('start');"])
+            ]
+        });
+
+        let issues = check(&collection);
+        assert_eq!(issues.len(), 0);
+    }
+}
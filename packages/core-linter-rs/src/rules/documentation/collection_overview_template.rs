@@ -1,10 +1,16 @@
 
 
 
-use crate::LintIssue;
+use crate::context::LintContext;
+use crate::{FixAction, LintConfig, LintIssue};
+use crate::rules::{Category, Rule};
+use crate::utils;
+use crate::i18n::Render;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::LazyLock;
 
 /// Configuration du template personnalisable
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -50,10 +56,109 @@ impl Default for TemplateConfig {
     }
 }
 
+/// Template compilé : les motifs des métadonnées génériques (hors référent et
+/// version, détectés par des motifs dédiés) sont compilés une seule fois
+pub struct Template {
+    pub config: TemplateConfig,
+    metadata_patterns: HashMap<String, Regex>,
+}
+
+impl Template {
+    /// Compile les motifs du template ; une métadonnée dont le motif ne compile pas
+    /// est considérée absente et signalée dans les avertissements retournés
+    pub fn compile(config: TemplateConfig) -> (Template, Vec<String>) {
+        let mut metadata_patterns = HashMap::new();
+        let mut warnings = Vec::new();
+        for meta_name in &config.required_metadata {
+            let meta_lower = meta_name.to_lowercase();
+            if is_referent(&meta_lower) || meta_lower.contains("version") {
+                continue;
+            }
+            match utils::build_regex(&format!(r"(?i){}", regex::escape(&meta_lower))) {
+                Ok(pattern) => {
+                    metadata_patterns.insert(meta_name.clone(), pattern);
+                }
+                Err(error) => warnings.push(format!(
+                    "custom_templates: invalid metadata '{}' for rule 'collection-overview-template' ({})",
+                    meta_name, error
+                )),
+            }
+        }
+        (Template { config, metadata_patterns }, warnings)
+    }
+
+    /// Template issu de la configuration JSON de `custom_templates`
+    pub fn from_json(config_json: &str) -> (Template, Vec<String>) {
+        match serde_json::from_str(config_json) {
+            Ok(config) => Template::compile(config),
+            Err(error) => {
+                let (template, mut warnings) = Template::compile(TemplateConfig::default());
+                warnings.insert(
+                    0,
+                    format!("custom_templates: invalid template for rule 'collection-overview-template' ({}), defaults are used", error),
+                );
+                (template, warnings)
+            }
+        }
+    }
+}
+
+/// Template par défaut, compilé au premier usage
+static DEFAULT_TEMPLATE: LazyLock<Template> = LazyLock::new(|| Template::compile(TemplateConfig::default()).0);
+
+/// Avertissements sur la configuration du template (`LintResult::config_warnings`)
+pub fn template_warnings(config_json: &str) -> Vec<String> {
+    Template::from_json(config_json).1
+}
+
+fn is_referent(meta_lower: &str) -> bool {
+    meta_lower.contains("référent") || meta_lower.contains("referent")
+}
+
+/// Mention, colonne de tableau et champ `Référent :` de la description
+static REFERENT_MENTION: LazyLock<Regex> = LazyLock::new(|| utils::build_regex(r"(?i)référent").unwrap());
+static REFERENT_COLUMN: LazyLock<Regex> = LazyLock::new(|| utils::build_regex(r"(?i)\|.*référent.*\|").unwrap());
+static REFERENT_FIELD: LazyLock<Regex> = LazyLock::new(|| utils::build_regex(r"(?i)référent\s*:").unwrap());
+/// Mention, colonne de tableau et champ `Version collection :` de la description
+static VERSION_MENTION: LazyLock<Regex> = LazyLock::new(|| utils::build_regex(r"(?i)version.*collection").unwrap());
+static VERSION_COLUMN: LazyLock<Regex> =
+    LazyLock::new(|| utils::build_regex(r"(?i)\|.*version.*collection.*\|").unwrap());
+static VERSION_FIELD: LazyLock<Regex> = LazyLock::new(|| utils::build_regex(r"(?i)version.*collection\s*:").unwrap());
+
+/// Valeur de la version, hors tableau de métadonnées
+const VERSION_VALUE_PATTERNS: &[&str] = &[
+    r"(?i)version.*collection\s*:?\s*([v]?\d+\.\d+\.\d+)",
+    r"(?i)version\s+de\s+collection\s*:?\s*([v]?\d+\.\d+\.\d+)",
+    r"(?i)collection\s+version\s*:?\s*([v]?\d+\.\d+\.\d+)",
+];
+static VERSION_VALUES: LazyLock<Vec<Regex>> = LazyLock::new(|| utils::build_regexes(VERSION_VALUE_PATTERNS));
+
+/// Valeur du référent, hors tableau de métadonnées
+const REFERENT_VALUE_PATTERNS: &[&str] = &[
+    r"(?i)référent\s*:?\s*([^\n\r\|*]+)",
+    r"(?i)referent\s*:?\s*([^\n\r\|*]+)",
+    r"(?i)contact\s*:?\s*([^\n\r\|*]+)",
+    r"(?i)responsable\s*:?\s*([^\n\r\|*]+)",
+];
+static REFERENT_VALUES: LazyLock<Vec<Regex>> = LazyLock::new(|| utils::build_regexes(REFERENT_VALUE_PATTERNS));
+/// Valeur vide ou de remplissage (`-`, `***`)
+static EMPTY_VALUE: LazyLock<Regex> = LazyLock::new(|| utils::build_regex(r"^[\*\-\s]*$").unwrap());
+
+/// Liens Gitlab vers la collection et le rapport Newman
+static COLLECTION_LINK: LazyLock<Regex> =
+    LazyLock::new(|| utils::build_regex(r"(?i)\[Collection[^\]]*\]\((https?://[^\)]+)\)").unwrap());
+static NEWMAN_LINK: LazyLock<Regex> =
+    LazyLock::new(|| utils::build_regex(r"(?i)\[Rapport\s+Newman[^\]]*\]\((https?://[^\)]+)\)").unwrap());
+
 /// Règle : collection-overview-template
 /// 
 /// Vérifie que l'Overview de la collection respecte le template.
 /// Le template peut être personnalisé via la configuration.
+///
+/// Fix : ajoute à la description un squelette avec les sections manquantes
+/// (contenu "TODO") et le tableau des métadonnées manquantes, pré-rempli avec
+/// la version de la collection (`info.version`) ; un Overview vide reçoit aussi
+/// un titre avec `info.name`.
 /// 
 /// Sévérité : ERROR (-15%)
 pub fn check(collection: &Value) -> Vec<LintIssue> {
@@ -62,17 +167,24 @@ pub fn check(collection: &Value) -> Vec<LintIssue> {
 
 /// Version avec configuration personnalisable
 pub fn check_with_config(collection: &Value, config_json: Option<String>) -> Vec<LintIssue> {
+    match config_json {
+        Some(json) => check_with_template(collection, &Template::from_json(&json).0),
+        None => check_with_template(collection, &DEFAULT_TEMPLATE),
+    }
+}
+
+/// Version avec un template déjà compilé
+pub fn check_with_template(collection: &Value, template: &Template) -> Vec<LintIssue> {
     let mut issues = Vec::new();
-    
-    // Parse custom config or use default
-    let config: TemplateConfig = config_json
-        .and_then(|json| serde_json::from_str(&json).ok())
-        .unwrap_or_default();
+    let config = &template.config;
     
     let description = collection["info"]["description"]
         .as_str()
         .unwrap_or("");
     
+    let mut missing_sections = Vec::new();
+    let mut missing_metadata = Vec::new();
+    
     // Vérifier les sections obligatoires (from config)
     for section in &config.required_sections {
         let has_section = section.patterns.iter().any(|pattern| {
@@ -80,14 +192,13 @@ pub fn check_with_config(collection: &Value, config_json: Option<String>) -> Vec
         });
         
         if !has_section {
-            issues.push(LintIssue {
-                rule_id: "collection-overview-template".to_string(),
-                severity: "error".to_string(),
-                message: format!("❌ Section de documentation manquante : \"{}\"", section.name),
-                path: "/info/description".to_string(),
-                line: None,
-                fix: None,
-            });
+            missing_sections.push(section.name.as_str());
+            issues.push(LintIssue::builder("collection-overview-template")
+                .severity("error")
+                .message_id("collection-overview-template.missing-section")
+                .param("section", section.name.as_str())
+                .path("/info/description")
+                .render());
         }
     }
     
@@ -99,22 +210,19 @@ pub fn check_with_config(collection: &Value, config_json: Option<String>) -> Vec
         let meta_lower = meta_name.to_lowercase();
         
         // Check if metadata column/field is present
-        let has_column = if meta_lower.contains("référent") || meta_lower.contains("referent") {
-            Regex::new(r"(?i)référent").unwrap().is_match(description) &&
-                (Regex::new(r"(?i)\|.*référent.*\|").unwrap().is_match(description) ||
-                 Regex::new(r"(?i)référent\s*:").unwrap().is_match(description))
+        let has_column = if is_referent(&meta_lower) {
+            REFERENT_MENTION.is_match(description) &&
+                (REFERENT_COLUMN.is_match(description) || REFERENT_FIELD.is_match(description))
         } else if meta_lower.contains("version") {
-            Regex::new(r"(?i)version.*collection").unwrap().is_match(description) &&
-                (Regex::new(r"(?i)\|.*version.*collection.*\|").unwrap().is_match(description) ||
-                 Regex::new(r"(?i)version.*collection\s*:").unwrap().is_match(description))
+            VERSION_MENTION.is_match(description) &&
+                (VERSION_COLUMN.is_match(description) || VERSION_FIELD.is_match(description))
         } else {
             // Generic check for other metadata
-            let pattern = format!(r"(?i){}", regex::escape(&meta_lower));
-            Regex::new(&pattern).map(|re| re.is_match(description)).unwrap_or(false)
+            template.metadata_patterns.get(meta_name).is_some_and(|pattern| pattern.is_match(description))
         };
         
         // Check if metadata has a value
-        let has_value = if meta_lower.contains("référent") || meta_lower.contains("referent") {
+        let has_value = if is_referent(&meta_lower) {
             metadata.referent.is_some()
         } else if meta_lower.contains("version") {
             metadata.collection_version.is_some()
@@ -123,41 +231,94 @@ pub fn check_with_config(collection: &Value, config_json: Option<String>) -> Vec
         };
         
         if !has_column {
-            issues.push(LintIssue {
-                rule_id: "collection-documentation-structure".to_string(),
-                severity: "error".to_string(),
-                message: format!("� Métadonnée manquante : \"{}\" non présente dans la documentation", meta_name),
-                path: "/info/description".to_string(),
-                line: None,
-                fix: None,
-            });
+            missing_metadata.push(meta_name.as_str());
+            issues.push(LintIssue::builder("collection-overview-template")
+                .severity("error")
+                .message_id("collection-overview-template.missing-metadata")
+                .param("metadata", meta_name.as_str())
+                .path("/info/description")
+                .render());
         } else if !has_value {
-            issues.push(LintIssue {
-                rule_id: "collection-documentation-structure".to_string(),
-                severity: "error".to_string(),
-                message: format!("📋 Métadonnée incomplète : \"{}\" est présente mais vide", meta_name),
-                path: "/info/description".to_string(),
-                line: None,
-                fix: None,
-            });
+            issues.push(LintIssue::builder("collection-overview-template")
+                .severity("error")
+                .message_id("collection-overview-template.incomplete-metadata")
+                .param("metadata", meta_name.as_str())
+                .path("/info/description")
+                .render());
         }
     }
     
     // Vérifier la longueur minimale (from config)
     if description.len() < config.min_length {
-        issues.push(LintIssue {
-            rule_id: "collection-documentation-structure".to_string(),
-            severity: "error".to_string(),
-            message: format!("📝 Description de collection trop courte (minimum {} caractères requis)", config.min_length),
-            path: "/info/description".to_string(),
-            line: None,
-            fix: None,
-        });
+        issues.push(LintIssue::builder("collection-overview-template")
+            .severity("error")
+            .message_id("collection-overview-template.description-too-short")
+            .param("min", config.min_length)
+            .path("/info/description")
+            .render());
+    }
+    
+    // Un même squelette corrige toutes les issues (appliqué une seule fois)
+    if !issues.is_empty() {
+        let skeleton = overview_skeleton(collection, description, &missing_sections, &missing_metadata);
+        for issue in &mut issues {
+            issue.fix = Some(FixAction::GenerateOverview { content: skeleton.clone() });
+        }
     }
     
     issues
 }
 
+/// Squelette markdown des sections et métadonnées manquantes
+fn overview_skeleton(collection: &Value, description: &str, missing_sections: &[&str], missing_metadata: &[&str]) -> String {
+    let mut blocks = Vec::new();
+    
+    if description.trim().is_empty() {
+        let name = collection["info"]["name"].as_str().unwrap_or("Collection");
+        blocks.push(format!("# {}", name));
+    }
+    
+    for section in missing_sections {
+        blocks.push(format!("## {}\n\nTODO", section));
+    }
+    
+    if !missing_metadata.is_empty() {
+        let version = collection_version(&collection["info"]["version"]);
+        let mut table = String::from("| Métadonnée | Valeur |\n|------------|--------|");
+        for meta_name in missing_metadata {
+            let value = if meta_name.to_lowercase().contains("version") {
+                version.as_deref().unwrap_or("TODO")
+            } else {
+                "TODO"
+            };
+            table.push_str(&format!("\n| {} | {} |", meta_name, value));
+        }
+        blocks.push(table);
+    }
+    
+    // Description trop courte uniquement : sections à compléter
+    if blocks.is_empty() {
+        blocks.push("TODO : compléter la présentation de la collection".to_string());
+    }
+    
+    blocks.join("\n\n")
+}
+
+/// Version Postman (`info.version`) : chaîne ou objet `{ major, minor, patch }`
+fn collection_version(version: &Value) -> Option<String> {
+    let version = match version {
+        Value::String(version) if !version.trim().is_empty() => version.trim().to_string(),
+        Value::Object(parts) => format!(
+            "{}.{}.{}",
+            parts.get("major").and_then(Value::as_u64).unwrap_or(0),
+            parts.get("minor").and_then(Value::as_u64).unwrap_or(0),
+            parts.get("patch").and_then(Value::as_u64).unwrap_or(0)
+        ),
+        _ => return None,
+    };
+    Some(if version.starts_with('v') { version } else { format!("v{}", version) })
+}
+
 #[derive(Debug)]
 struct CollectionMetadata {
     collection_version: Option<String>,
@@ -180,51 +341,33 @@ fn extract_collection_metadata(description: &str) -> CollectionMetadata {
     
     // Si pas trouvé, essayer avec des patterns regex simples
     if metadata.collection_version.is_none() {
-        let version_patterns = vec![
-            r"(?i)version.*collection\s*:?\s*([v]?\d+\.\d+\.\d+)",
-            r"(?i)version\s+de\s+collection\s*:?\s*([v]?\d+\.\d+\.\d+)",
-            r"(?i)collection\s+version\s*:?\s*([v]?\d+\.\d+\.\d+)",
-        ];
-        
-        for pattern in version_patterns {
-            if let Ok(re) = Regex::new(pattern) {
-                if let Some(caps) = re.captures(description) {
-                    if let Some(version) = caps.get(1) {
-                        let mut v = version.as_str().trim().to_string();
-                        if !v.starts_with('v') {
-                            v = format!("v{}", v);
-                        }
-                        metadata.collection_version = Some(v);
-                        break;
+        for re in VERSION_VALUES.iter() {
+            if let Some(caps) = re.captures(description) {
+                if let Some(version) = caps.get(1) {
+                    let mut v = version.as_str().trim().to_string();
+                    if !v.starts_with('v') {
+                        v = format!("v{}", v);
                     }
+                    metadata.collection_version = Some(v);
+                    break;
                 }
             }
         }
     }
     
     if metadata.referent.is_none() {
-        let referent_patterns = vec![
-            r"(?i)référent\s*:?\s*([^\n\r\|*]+)",
-            r"(?i)referent\s*:?\s*([^\n\r\|*]+)",
-            r"(?i)contact\s*:?\s*([^\n\r\|*]+)",
-            r"(?i)responsable\s*:?\s*([^\n\r\|*]+)",
-        ];
-        
-        for pattern in referent_patterns {
-            if let Ok(re) = Regex::new(pattern) {
-                if let Some(caps) = re.captures(description) {
-                    if let Some(referent) = caps.get(1) {
-                        let r = referent.as_str()
-                            .trim()
-                            .replace('|', "")
-                            .replace('*', "")
-                            .trim()
-                            .to_string();
-                        
-                        if !r.is_empty() && !Regex::new(r"^[\*\-\s]*$").unwrap().is_match(&r) {
-                            metadata.referent = Some(r);
-                            break;
-                        }
+        for re in REFERENT_VALUES.iter() {
+            if let Some(caps) = re.captures(description) {
+                if let Some(referent) = caps.get(1) {
+                    let r = referent.as_str()
+                        .trim()
+                        .replace(['|', '*'], "")
+                        .trim()
+                        .to_string();
+                    
+                    if !r.is_empty() && !EMPTY_VALUE.is_match(&r) {
+                        metadata.referent = Some(r);
+                        break;
                     }
                 }
             }
@@ -232,24 +375,20 @@ fn extract_collection_metadata(description: &str) -> CollectionMetadata {
     }
     
     // Extraire les liens Gitlab
-    if let Ok(re) = Regex::new(r"(?i)\[Collection[^\]]*\]\((https?://[^\)]+)\)") {
-        if let Some(caps) = re.captures(description) {
-            if let Some(url) = caps.get(1) {
-                let u = url.as_str().trim();
-                if !u.to_lowercase().contains("null") {
-                    metadata.gitlab_collection_link = Some(u.to_string());
-                }
+    if let Some(caps) = COLLECTION_LINK.captures(description) {
+        if let Some(url) = caps.get(1) {
+            let u = url.as_str().trim();
+            if !u.to_lowercase().contains("null") {
+                metadata.gitlab_collection_link = Some(u.to_string());
             }
         }
     }
     
-    if let Ok(re) = Regex::new(r"(?i)\[Rapport\s+Newman[^\]]*\]\((https?://[^\)]+)\)") {
-        if let Some(caps) = re.captures(description) {
-            if let Some(url) = caps.get(1) {
-                let u = url.as_str().trim();
-                if !u.to_lowercase().contains("null") {
-                    metadata.gitlab_newman_report_link = Some(u.to_string());
-                }
+    if let Some(caps) = NEWMAN_LINK.captures(description) {
+        if let Some(url) = caps.get(1) {
+            let u = url.as_str().trim();
+            if !u.to_lowercase().contains("null") {
+                metadata.gitlab_newman_report_link = Some(u.to_string());
             }
         }
     }
@@ -264,7 +403,7 @@ fn extract_from_table(description: &str, metadata: &mut CollectionMetadata) {
     let mut headers: Vec<String> = Vec::new();
     let mut header_indices: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
     
-    for (_i, line) in lines.iter().enumerate() {
+    for line in lines.iter() {
         let trimmed = line.trim();
         
         // Détecter le début d'un tableau
@@ -357,6 +496,37 @@ fn extract_from_table(description: &str, metadata: &mut CollectionMetadata) {
     }
 }
 
+/// Déclaration de la règle dans le registre
+pub struct CollectionOverviewTemplate;
+
+impl Rule for CollectionOverviewTemplate {
+    fn id(&self) -> &'static str {
+        "collection-overview-template"
+    }
+
+    fn category(&self) -> Category {
+        Category::Documentation
+    }
+
+    fn severity(&self) -> &'static str {
+        "error"
+    }
+
+    fn description(&self) -> &'static str {
+        "Collection must follow documentation template"
+    }
+
+    fn fix_types(&self) -> &'static [&'static str] {
+        &["generate_overview"]
+    }
+
+    // Pas de visiteur : la règle ne lit que la description de la collection (`info.description`)
+    fn check(&self, collection: &Value, _ctx: &LintContext, config: &LintConfig) -> Vec<LintIssue> {
+        let template_config = config.custom_templates.as_ref().and_then(|t| t.get(self.id())).cloned();
+        check_with_config(collection, template_config)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -447,10 +617,10 @@ Cette collection teste l'API avec une description suffisamment longue pour passe
         });
         
         let issues = check(&collection);
-        assert!(issues.len() > 0);
+        assert!(!issues.is_empty());
         // Vérifier qu'au moins une section manquante est détectée
         let has_missing_section = issues.iter().any(|i| 
-            i.message.contains("Section de documentation manquante")
+            i.message.contains("Missing documentation section")
         );
         assert!(has_missing_section, "Should detect missing sections");
     }
@@ -481,5 +651,39 @@ Description longue de plus de 100 caractères pour passer la validation de longu
         let issues = check(&collection);
         assert!(issues.iter().any(|i| i.message.contains("Référent")));
         assert!(issues.iter().any(|i| i.message.contains("Version")));
+        assert!(issues.iter().all(|i| i.rule_id == "collection-overview-template"));
+    }
+
+    #[test]
+    fn test_custom_metadata_patterns_compiled_once() {
+        let config = json!({ "requiredSections": [], "requiredMetadata": ["Owner", "x".repeat(300_000)], "minLength": 0 });
+        let (template, warnings) = Template::compile(serde_json::from_value(config).unwrap());
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].starts_with("custom_templates: invalid metadata 'xxx"));
+
+        let collection = json!({ "info": { "name": "Test", "description": "Owner : team-orders" } });
+        let issues = check_with_template(&collection, &template);
+        // Motif invalide : métadonnée considérée absente
+        assert_eq!(issues.iter().filter(|i| i.message_id.as_deref() == Some("collection-overview-template.missing-metadata")).count(), 1);
+        assert_eq!(template_warnings("{").len(), 1);
+    }
+
+    #[test]
+    fn test_generate_overview_fix() {
+        let mut collection = json!({
+            "info": { "name": "Orders API", "version": { "major": 2, "minor": 1, "patch": 0 } }
+        });
+
+        let issues = check(&collection);
+        let Some(FixAction::GenerateOverview { content }) = &issues[0].fix else {
+            panic!("expected a generate_overview fix");
+        };
+        assert!(content.starts_with("# Orders API\n\n## Prérequis\n\nTODO"));
+        assert!(content.contains("| Version de collection | v2.1.0 |"));
+        assert!(issues.iter().all(|issue| issue.fix == issues[0].fix));
+
+        // Un seul fix appliqué pour toutes les issues, puis la documentation est conforme
+        assert_eq!(crate::fixer::apply_fixes(&mut collection, &issues), 1);
+        assert!(check(&collection).is_empty());
     }
 }
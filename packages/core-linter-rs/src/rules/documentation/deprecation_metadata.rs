@@ -0,0 +1,124 @@
+use crate::context::LintContext;
+use crate::{LintConfig, LintIssue};
+use crate::model::TypedItem;
+use crate::rules::{Category, ItemVisitor, Rule};
+use crate::utils::{self, ItemContext};
+use crate::i18n::Render;
+use regex::Regex;
+use serde_json::Value;
+use std::sync::LazyLock;
+
+/// Mention de dépréciation
+static DEPRECATED: LazyLock<Regex> = LazyLock::new(|| utils::build_regex(r"(?i)\bdeprecated\b").unwrap());
+/// Métadonnées `Deprecated: <date> → <remplaçant>`
+static METADATA: LazyLock<Regex> =
+    LazyLock::new(|| utils::build_regex(r"(?im)^\s*deprecated\s*:\s*(\S+)\s*(?:→|->)\s*(\S+)").unwrap());
+
+/// Règle : deprecation-metadata
+///
+/// Les items dont le nom ou la description mentionne "deprecated" doivent indiquer
+/// une date de fin de vie (sunset) et l'endpoint de remplacement, selon la convention :
+///
+/// `Deprecated: 2025-06-30 → /v2/users` (`->` accepté)
+///
+/// La date doit être au format AAAA-MM-JJ.
+///
+/// Sévérité : WARNING (-8%)
+pub fn check(collection: &Value) -> Vec<LintIssue> {
+    DeprecationMetadata.check(collection, &LintContext::new(collection), &LintConfig::default())
+}
+
+/// Date AAAA-MM-JJ plausible (mois 1-12, jour 1-31)
+fn is_valid_date(date: &str) -> bool {
+    let parts: Vec<&str> = date.split('-').collect();
+    if parts.len() != 3 || parts[0].len() != 4 || parts[1].len() != 2 || parts[2].len() != 2 {
+        return false;
+    }
+    match (parts[0].parse::<u32>(), parts[1].parse::<u32>(), parts[2].parse::<u32>()) {
+        (Ok(_), Ok(month), Ok(day)) => (1..=12).contains(&month) && (1..=31).contains(&day),
+        _ => false,
+    }
+}
+
+/// Déclaration de la règle dans le registre
+pub struct DeprecationMetadata;
+
+impl Rule for DeprecationMetadata {
+    fn id(&self) -> &'static str {
+        "deprecation-metadata"
+    }
+
+    fn category(&self) -> Category {
+        Category::Documentation
+    }
+
+    fn severity(&self) -> &'static str {
+        "warning"
+    }
+
+    fn description(&self) -> &'static str {
+        "Deprecated items state a sunset date and replacement (`Deprecated: <date> → <path>`)"
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, _config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        Some(Box::new(DeprecationMetadata))
+    }
+}
+
+impl ItemVisitor for DeprecationMetadata {
+    fn visit(&mut self, item: &ItemContext, typed: &TypedItem, _ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        let description = typed.item.description_text();
+        if !DEPRECATED.is_match(&item.name) && !DEPRECATED.is_match(&description) {
+            return;
+        }
+
+        let issue = LintIssue::builder("deprecation-metadata").param("item", item.name.as_str());
+        let issue = match METADATA.captures(&description) {
+            None => issue.message_id("deprecation-metadata.missing"),
+            Some(caps) if !is_valid_date(&caps[1]) => {
+                issue.message_id("deprecation-metadata.invalid-date").param("date", &caps[1])
+            }
+            Some(_) => return,
+        };
+        issues.push(issue.path(item.path.clone()).render());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_deprecated_without_metadata() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [
+                { "name": "GET Users (deprecated)", "request": { "method": "GET", "url": "{{base_url}}/v1/users" } },
+                {
+                    "name": "GET Orders",
+                    "request": { "method": "GET", "url": "{{base_url}}/v1/orders", "description": "Deprecated: 2025-13-01 → /v2/orders" }
+                }
+            ]
+        });
+
+        let issues = check(&collection);
+        assert_eq!(issues.len(), 2);
+        assert!(issues[0].message.contains("does not state its sunset date"));
+        assert!(issues[1].message.contains("invalid sunset date \"2025-13-01\""));
+    }
+
+    #[test]
+    fn test_valid_metadata() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "Legacy (deprecated)",
+                "description": "Old endpoints.\nDeprecated: 2025-06-30 -> /v2/users",
+                "item": [{ "name": "GET Users", "request": { "method": "GET", "url": "{{base_url}}/v1/users" } }]
+            }]
+        });
+
+        assert_eq!(check(&collection).len(), 0);
+    }
+}
@@ -0,0 +1,166 @@
+use crate::context::LintContext;
+use crate::{LintConfig, LintIssue};
+use crate::model::TypedItem;
+use crate::rules::{self, Category, ItemVisitor, Rule};
+use crate::utils::ItemContext;
+use crate::i18n::Render;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Configuration de la règle error-body-convention
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct ErrorBodyConventionConfig {
+    /// Champs obligatoires de l'enveloppe d'erreur ; `error.code` désigne un champ
+    /// imbriqué
+    pub required_fields: Vec<String>,
+}
+
+impl Default for ErrorBodyConventionConfig {
+    fn default() -> Self {
+        ErrorBodyConventionConfig {
+            required_fields: vec!["code".to_string(), "message".to_string()],
+        }
+    }
+}
+
+/// Règle : error-body-convention
+///
+/// Vérifie que les exemples d'erreur (codes 4xx et 5xx) documentent l'enveloppe
+/// d'erreur de l'organisation : un body JSON objet contenant les champs configurés
+/// (`{ "code", "message" }` par défaut). Un exemple sans body ou avec un body non
+/// JSON est signalé, puisque le contrat n'y est pas visible.
+///
+/// Sévérité : WARNING (-8%)
+pub fn check(collection: &Value) -> Vec<LintIssue> {
+    check_with_config(collection, &ErrorBodyConventionConfig::default())
+}
+
+/// Version avec configuration personnalisable
+pub fn check_with_config(collection: &Value, config: &ErrorBodyConventionConfig) -> Vec<LintIssue> {
+    if config.required_fields.is_empty() {
+        return Vec::new();
+    }
+    rules::visit(collection, &LintContext::new(collection), &mut EnvelopeVisitor { required_fields: config.required_fields.clone() })
+}
+
+struct EnvelopeVisitor {
+    required_fields: Vec<String>,
+}
+
+impl ItemVisitor for EnvelopeVisitor {
+    fn visit(&mut self, item: &ItemContext, typed: &TypedItem, _ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        for (response_index, response) in typed.item.response.iter().enumerate() {
+            let Some(code) = response.code.filter(|code| *code >= 400) else {
+                continue;
+            };
+            let example_name = if response.name.is_empty() { "unnamed" } else { response.name.as_str() };
+
+            let issue = LintIssue::builder("error-body-convention");
+            let issue = match serde_json::from_str::<Value>(response.body.trim()) {
+                Ok(body @ Value::Object(_)) => {
+                    let missing: Vec<&str> = self
+                        .required_fields
+                        .iter()
+                        .filter(|field| field_value(&body, field).is_none())
+                        .map(String::as_str)
+                        .collect();
+                    if missing.is_empty() {
+                        continue;
+                    }
+                    issue.message_id("error-body-convention.missing-fields").param("fields", missing.join(", "))
+                }
+                _ if response.body.trim().is_empty() => issue.message_id("error-body-convention.no-body"),
+                _ => issue.message_id("error-body-convention.not-json-object"),
+            };
+
+            issues.push(issue
+                .param("example", example_name)
+                .param("code", code)
+                .param("request", item.name.as_str())
+                .param("envelope", self.required_fields.join(", "))
+                .path(format!("{}/response[{}]/body", item.path, response_index))
+                .render());
+        }
+    }
+}
+
+/// Valeur d'un champ, éventuellement imbriqué (`error.code`)
+fn field_value<'a>(body: &'a Value, field: &str) -> Option<&'a Value> {
+    field.split('.').try_fold(body, |value, key| value.get(key))
+}
+
+/// Déclaration de la règle dans le registre
+pub struct ErrorBodyConvention;
+
+impl Rule for ErrorBodyConvention {
+    fn id(&self) -> &'static str {
+        "error-body-convention"
+    }
+
+    fn category(&self) -> Category {
+        Category::Documentation
+    }
+
+    fn severity(&self) -> &'static str {
+        "warning"
+    }
+
+    fn description(&self) -> &'static str {
+        "4xx/5xx examples have a JSON body following the error envelope"
+    }
+
+    fn default_options(&self) -> Option<Value> {
+        serde_json::to_value(ErrorBodyConventionConfig::default()).ok()
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        // Sans champ obligatoire, il n'y a pas d'enveloppe à vérifier
+        let config: ErrorBodyConventionConfig = config.options_for(self.id());
+        (!config.required_fields.is_empty())
+            .then(|| Box::new(EnvelopeVisitor { required_fields: config.required_fields }) as Box<dyn ItemVisitor>)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn collection() -> Value {
+        json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "GET User",
+                "request": { "method": "GET", "url": "{{base_url}}/users/1" },
+                "response": [
+                    { "name": "OK", "code": 200, "body": "{\"id\": 1}" },
+                    { "name": "Not found", "code": 404, "body": "{\"code\": \"USER_NOT_FOUND\", \"message\": \"No user 1\"}" },
+                    { "name": "Bad request", "code": 400, "body": "{\"error\": {\"code\": \"INVALID_ID\"}}" },
+                    { "name": "Gateway", "code": 502, "body": "<html>Bad Gateway</html>" },
+                    { "name": "Unavailable", "code": 503, "body": "" }
+                ]
+            }]
+        })
+    }
+
+    #[test]
+    fn test_default_envelope() {
+        let issues = check(&collection());
+        assert_eq!(issues.len(), 3);
+        assert_eq!(issues[0].path, "/item[0]/response[2]/body");
+        assert!(issues[0].message.contains("misses code, message"));
+        assert!(issues[1].message.contains("is not a JSON object"));
+        assert!(issues[2].message.contains("has no body"));
+    }
+
+    #[test]
+    fn test_nested_fields() {
+        let config = ErrorBodyConventionConfig { required_fields: vec!["error.code".to_string()] };
+        let issues = check_with_config(&collection(), &config);
+        // 404 sans enveloppe `error`, 502 et 503 sans body JSON
+        assert_eq!(issues.len(), 3);
+        assert!(issues[0].message.contains("\"Not found\" (404)"));
+        assert!(issues[0].message.contains("misses error.code"));
+    }
+}
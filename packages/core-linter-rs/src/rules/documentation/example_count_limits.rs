@@ -0,0 +1,166 @@
+use crate::context::LintContext;
+use crate::{FixAction, LintConfig, LintIssue};
+use crate::model::{Response, TypedItem};
+use crate::rules::{self, Category, ItemVisitor, Rule};
+use crate::utils::ItemContext;
+use crate::i18n::Render;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Configuration de la règle example-count-limits
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct ExampleCountConfig {
+    /// Nombre maximum d'exemples de réponse par requête
+    pub max_examples: usize,
+}
+
+impl Default for ExampleCountConfig {
+    fn default() -> Self {
+        ExampleCountConfig { max_examples: 10 }
+    }
+}
+
+/// Règle : example-count-limits
+///
+/// Signale les requêtes qui accumulent trop d'exemples de réponse sauvegardés.
+/// Des exemples en surnombre alourdissent Postman comme le linter, sans apporter
+/// plus de documentation qu'un exemple par code de statut.
+///
+/// Fix proposé : ne conserver que le premier exemple de chaque code de statut.
+///
+/// Sévérité : WARNING (-8%)
+pub fn check(collection: &Value) -> Vec<LintIssue> {
+    check_with_config(collection, &ExampleCountConfig::default())
+}
+
+/// Version avec configuration personnalisable
+pub fn check_with_config(collection: &Value, config: &ExampleCountConfig) -> Vec<LintIssue> {
+    rules::visit(collection, &LintContext::new(collection), &mut CountVisitor { max_examples: config.max_examples })
+}
+
+struct CountVisitor {
+    max_examples: usize,
+}
+
+impl ItemVisitor for CountVisitor {
+    fn visit(&mut self, item: &ItemContext, typed: &TypedItem, _ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        let responses = &typed.item.response;
+        if !typed.item.is_request() || responses.len() <= self.max_examples {
+            return;
+        }
+
+        let mut issue = LintIssue::builder("example-count-limits")
+            .message_id("example-count-limits.too-many")
+            .param("request", item.name.as_str())
+            .param("count", responses.len())
+            .param("max", self.max_examples)
+            .path(item.path.clone());
+        let keep_indices = one_example_per_status(responses);
+        if keep_indices.len() < responses.len() {
+            issue = issue.fix(FixAction::PruneExamples { keep_indices });
+        }
+        issues.push(issue.render());
+    }
+}
+
+/// Retourne les index du premier exemple rencontré pour chaque code de statut
+fn one_example_per_status(responses: &[Response]) -> Vec<usize> {
+    let mut seen = std::collections::HashSet::new();
+    let mut keep = Vec::new();
+
+    for (index, response) in responses.iter().enumerate() {
+        let status_key = response.code.map(|code| code.to_string()).unwrap_or_else(|| response.status.clone());
+
+        if seen.insert(status_key) {
+            keep.push(index);
+        }
+    }
+
+    keep
+}
+
+/// Déclaration de la règle dans le registre
+pub struct ExampleCountLimits;
+
+impl Rule for ExampleCountLimits {
+    fn id(&self) -> &'static str {
+        "example-count-limits"
+    }
+
+    fn category(&self) -> Category {
+        Category::Documentation
+    }
+
+    fn severity(&self) -> &'static str {
+        "warning"
+    }
+
+    fn description(&self) -> &'static str {
+        "Too many saved examples per request"
+    }
+
+    fn default_options(&self) -> Option<Value> {
+        serde_json::to_value(ExampleCountConfig::default()).ok()
+    }
+
+    fn fix_types(&self) -> &'static [&'static str] {
+        &["prune_examples"]
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        let config: ExampleCountConfig = config.options_for(self.id());
+        Some(Box::new(CountVisitor { max_examples: config.max_examples }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn request_with_examples(codes: &[u64]) -> Value {
+        let responses: Vec<Value> = codes
+            .iter()
+            .map(|code| json!({ "name": format!("Example {}", code), "code": code, "body": "{}" }))
+            .collect();
+
+        json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "GET Users",
+                "request": { "method": "GET", "url": "{{base_url}}/users" },
+                "response": responses
+            }]
+        })
+    }
+
+    #[test]
+    fn test_under_limit() {
+        let collection = request_with_examples(&[200, 404]);
+
+        let issues = check(&collection);
+        assert_eq!(issues.len(), 0);
+    }
+
+    #[test]
+    fn test_over_limit_keeps_one_per_status() {
+        let codes = [200, 200, 200, 404, 200, 500, 404, 200, 200, 200, 200];
+        let collection = request_with_examples(&codes);
+
+        let issues = check(&collection);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].rule_id, "example-count-limits");
+        assert_eq!(issues[0].fix, Some(FixAction::PruneExamples { keep_indices: vec![0, 3, 5] }));
+    }
+
+    #[test]
+    fn test_custom_limit() {
+        let collection = request_with_examples(&[200, 201, 400]);
+
+        let issues = check_with_config(&collection, &ExampleCountConfig { max_examples: 2 });
+        assert_eq!(issues.len(), 1);
+        // Un exemple par code de statut : rien à élaguer automatiquement
+        assert_eq!(issues[0].fix, None);
+    }
+}
@@ -0,0 +1,154 @@
+use crate::context::LintContext;
+use crate::{LintConfig, LintIssue};
+use crate::model::{Response, TypedItem};
+use crate::rules::{Category, ItemVisitor, Rule};
+use crate::utils::{self, ItemContext};
+use crate::i18n::Render;
+use regex::Regex;
+use serde_json::Value;
+use std::sync::LazyLock;
+
+/// Titre d'une page d'erreur HTML
+static ERROR_PAGE: LazyLock<Regex> = LazyLock::new(|| {
+    utils::build_regex(
+        r"<(?:title|h1)[^>]*>[^<]*(?:error|exception|not found|forbidden|unauthorized|bad gateway|unavailable|\b[45]\d\d\b)",
+    )
+    .unwrap()
+});
+
+/// Règle : example-header-consistency
+///
+/// Vérifie la cohérence des exemples de réponse sauvegardés :
+/// - un body JSON doit être accompagné d'un header `Content-Type: application/json`
+///   (ou `+json`), sinon Postman et les outils de documentation l'affichent en texte brut
+/// - une page d'erreur HTML (proxy, gateway, stack trace) ne doit pas être sauvegardée
+///   comme exemple de succès (code 2xx ou nom "Success")
+///
+/// Sévérité : WARNING (-8%)
+pub fn check(collection: &Value) -> Vec<LintIssue> {
+    ExampleHeaderConsistency.check(collection, &LintContext::new(collection), &LintConfig::default())
+}
+
+/// Valeur du header Content-Type d'un exemple, le cas échéant
+fn content_type(response: &Response) -> Option<&str> {
+    response
+        .header
+        .iter()
+        .find(|header| header.key.eq_ignore_ascii_case("Content-Type"))
+        .map(|header| header.value.as_str())
+}
+
+fn is_json_body(body: &str) -> bool {
+    let body = body.trim_start();
+    (body.starts_with('{') || body.starts_with('[')) && serde_json::from_str::<Value>(body).is_ok()
+}
+
+/// Page HTML d'erreur : titre ou en-tête évoquant une erreur
+fn is_html_error_page(body: &str) -> bool {
+    let lower = body.trim_start().to_lowercase();
+    if !(lower.starts_with("<!doctype html") || lower.starts_with("<html")) {
+        return false;
+    }
+    ERROR_PAGE.is_match(&lower)
+}
+
+/// L'exemple se présente comme un succès (code 2xx ou nom contenant "success")
+fn claims_success(response: &Response) -> bool {
+    let code_is_success = response.code.map(|code| (200..300).contains(&code)).unwrap_or(false);
+    code_is_success || response.name.to_lowercase().contains("success")
+}
+
+/// Déclaration de la règle dans le registre
+pub struct ExampleHeaderConsistency;
+
+impl Rule for ExampleHeaderConsistency {
+    fn id(&self) -> &'static str {
+        "example-header-consistency"
+    }
+
+    fn category(&self) -> Category {
+        Category::Documentation
+    }
+
+    fn severity(&self) -> &'static str {
+        "warning"
+    }
+
+    fn description(&self) -> &'static str {
+        "Saved examples with a JSON body declare `Content-Type: application/json`; no HTML error pages saved as success"
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, _config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        Some(Box::new(ExampleHeaderConsistency))
+    }
+}
+
+impl ItemVisitor for ExampleHeaderConsistency {
+    fn visit(&mut self, item: &ItemContext, typed: &TypedItem, _ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        for (response_index, response) in typed.item.response.iter().enumerate() {
+            let example_name = if response.name.is_empty() { "unnamed" } else { response.name.as_str() };
+            let content_type = content_type(response);
+
+            let issue = LintIssue::builder("example-header-consistency")
+                .param("example", example_name)
+                .param("request", item.name.as_str());
+            let issue = if is_html_error_page(&response.body) && claims_success(response) {
+                issue.message_id("example-header-consistency.html-error-page")
+            } else if is_json_body(&response.body) && !content_type.map(|ct| ct.to_lowercase().contains("json")).unwrap_or(false) {
+                issue
+                    .message_id("example-header-consistency.content-type")
+                    .param("content_type", content_type.unwrap_or("none"))
+            } else {
+                continue;
+            };
+
+            issues.push(issue.path(format!("{}/response[{}]", item.path, response_index)).render());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn collection_with_examples(examples: Value) -> Value {
+        json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "GET Users",
+                "request": { "method": "GET", "url": "{{base_url}}/users" },
+                "response": examples
+            }]
+        })
+    }
+
+    #[test]
+    fn test_json_body_without_json_content_type() {
+        let collection = collection_with_examples(json!([
+            { "name": "OK", "code": 200, "header": [{ "key": "Content-Type", "value": "text/plain" }], "body": "{\"id\": 1}" },
+            { "name": "No header", "code": 200, "body": "[1, 2]" },
+            { "name": "Valid", "code": 200, "header": [{ "key": "content-type", "value": "application/problem+json" }], "body": "{}" }
+        ]));
+
+        let issues = check(&collection);
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].path, "/item[0]/response[0]");
+        assert!(issues[0].message.contains("declares Content-Type \"text/plain\""));
+        assert!(issues[1].message.contains("declares Content-Type \"none\""));
+    }
+
+    #[test]
+    fn test_html_error_page_saved_as_success() {
+        let html = "<!DOCTYPE html><html><head><title>502 Bad Gateway</title></head><body></body></html>";
+        let collection = collection_with_examples(json!([
+            { "name": "Success", "code": 200, "header": [{ "key": "Content-Type", "value": "text/html" }], "body": html },
+            { "name": "Gateway error", "code": 502, "header": [{ "key": "Content-Type", "value": "text/html" }], "body": html },
+            { "name": "Docs page", "code": 200, "body": "<html><title>Welcome</title></html>" }
+        ]));
+
+        let issues = check(&collection);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("HTML error page"));
+    }
+}
@@ -0,0 +1,163 @@
+use crate::context::LintContext;
+use crate::{FixAction, LintConfig, LintIssue};
+use crate::model::TypedItem;
+use crate::rules::{self, Category, ItemVisitor, Rule};
+use crate::utils::{self, ItemContext};
+use crate::i18n::Render;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Configuration de la règle example-url-hygiene
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct ExampleUrlHygieneConfig {
+    /// Hôtes acceptés dans les exemples, avec jokers `*` (domaines de documentation)
+    pub allowed_hosts: Vec<String>,
+}
+
+impl Default for ExampleUrlHygieneConfig {
+    fn default() -> Self {
+        ExampleUrlHygieneConfig {
+            allowed_hosts: vec!["example.com".to_string(), "*.example.com".to_string()],
+        }
+    }
+}
+
+/// Règle : example-url-hygiene
+///
+/// Signale les exemples de réponse dont `originalRequest.url` pointe vers un hôte
+/// absolu (souvent la production, au moment de la capture) alors que la requête
+/// utilise une variable d'hôte (`{{base_url}}`) : la documentation montre alors une
+/// URL qui ne correspond pas à l'environnement du lecteur.
+///
+/// Les domaines de documentation (`example.com`) sont acceptés par défaut.
+///
+/// Sévérité : WARNING (-8%)
+pub fn check(collection: &Value) -> Vec<LintIssue> {
+    check_with_config(collection, &ExampleUrlHygieneConfig::default())
+}
+
+/// Version avec configuration personnalisable
+pub fn check_with_config(collection: &Value, config: &ExampleUrlHygieneConfig) -> Vec<LintIssue> {
+    rules::visit(collection, &LintContext::new(collection), &mut HygieneVisitor::new(config))
+}
+
+struct HygieneVisitor {
+    allowed: Vec<Regex>,
+}
+
+impl HygieneVisitor {
+    fn new(config: &ExampleUrlHygieneConfig) -> Self {
+        HygieneVisitor { allowed: config.allowed_hosts.iter().filter_map(|host| utils::wildcard_regex(host)).collect() }
+    }
+}
+
+impl ItemVisitor for HygieneVisitor {
+    fn visit(&mut self, item: &ItemContext, typed: &TypedItem, _ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        let Some(request) = &typed.item.request else {
+            return;
+        };
+        let request_host = request.url.host();
+        if !request_host.starts_with("{{") {
+            return;
+        }
+
+        for (response_index, response) in typed.item.response.iter().enumerate() {
+            let Some(original) = &response.original_request else {
+                continue;
+            };
+            let example_host = original.url.host();
+            if example_host.is_empty()
+                || example_host.contains("{{")
+                || self.allowed.iter().any(|pattern| pattern.is_match(&example_host))
+            {
+                continue;
+            }
+
+            let example_name = if response.name.is_empty() { "unnamed" } else { response.name.as_str() };
+            issues.push(LintIssue::builder("example-url-hygiene")
+                .message_id("example-url-hygiene.absolute-host")
+                .param("example", example_name)
+                .param("request", item.name.as_str())
+                .param("example_host", example_host)
+                .param("request_host", request_host.as_str())
+                .path(format!("{}/response[{}]/originalRequest/url", item.path, response_index))
+                .fix(FixAction::UseEnvironmentVariable {
+                    field: "originalRequest.url".to_string(),
+                    suggested_variable: request_host.clone(),
+                })
+                .render());
+        }
+    }
+}
+
+/// Déclaration de la règle dans le registre
+pub struct ExampleUrlHygiene;
+
+impl Rule for ExampleUrlHygiene {
+    fn id(&self) -> &'static str {
+        "example-url-hygiene"
+    }
+
+    fn category(&self) -> Category {
+        Category::Documentation
+    }
+
+    fn severity(&self) -> &'static str {
+        "warning"
+    }
+
+    fn description(&self) -> &'static str {
+        "Saved examples do not show absolute hosts when the request uses a host variable"
+    }
+
+    fn default_options(&self) -> Option<Value> {
+        serde_json::to_value(ExampleUrlHygieneConfig::default()).ok()
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        Some(Box::new(HygieneVisitor::new(&config.options_for(self.id()))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn collection(request_url: &str) -> Value {
+        json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "GET Users",
+                "request": { "method": "GET", "url": request_url },
+                "response": [
+                    { "name": "OK", "code": 200, "originalRequest": { "method": "GET", "url": "https://api.acme.com/users" } },
+                    { "name": "Doc", "code": 200, "originalRequest": { "method": "GET", "url": { "raw": "https://api.example.com/users", "host": ["api", "example", "com"] } } },
+                    { "name": "Templated", "code": 404, "originalRequest": { "method": "GET", "url": "{{base_url}}/users" } },
+                    { "name": "No request", "code": 500 }
+                ]
+            }]
+        })
+    }
+
+    #[test]
+    fn test_absolute_example_host() {
+        let issues = check(&collection("{{base_url}}/users"));
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].path, "/item[0]/response[0]/originalRequest/url");
+        assert!(issues[0].message.contains("shows api.acme.com while the request uses {{base_url}}"));
+
+        // Requête elle-même en hôte absolu : rien à signaler
+        assert!(check(&collection("https://api.acme.com/users")).is_empty());
+    }
+
+    #[test]
+    fn test_allowed_hosts() {
+        let config = ExampleUrlHygieneConfig { allowed_hosts: vec!["*.acme.com".to_string()] };
+        let issues = check_with_config(&collection("{{base_url}}/users"), &config);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("shows api.example.com"));
+    }
+}
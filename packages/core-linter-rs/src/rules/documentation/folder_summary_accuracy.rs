@@ -0,0 +1,224 @@
+use crate::context::LintContext;
+use crate::model::{Item, TypedItem};
+use crate::{FixAction, LintConfig, LintIssue};
+use crate::rules::{Category, ItemVisitor, Rule};
+use crate::utils::{self, ItemContext};
+use crate::i18n::Render;
+use regex::Regex;
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::sync::LazyLock;
+
+/// Ligne `| METHOD | path |` du tableau récapitulatif
+static SUMMARY_ROW: LazyLock<Regex> = LazyLock::new(|| {
+    utils::build_regex(
+        r"(?im)^\s*\|\s*`?(GET|POST|PUT|PATCH|DELETE|HEAD|OPTIONS)`?\s*\|\s*`?([^|`\s]+)`?\s*\|",
+    )
+    .unwrap()
+});
+
+/// Règle : folder-summary-accuracy
+///
+/// Vérifie que la description (README) de chaque folder liste les endpoints qu'il
+/// contient, sous forme de table markdown `| Method | Path |`, et que cette table
+/// est à jour : requêtes ajoutées au folder sans être documentées, ou lignes de la
+/// table qui ne correspondent plus à aucune requête.
+///
+/// Les segments variables sont équivalents quelle que soit leur syntaxe
+/// (`/users/:id`, `/users/{{id}}`, `/users/{id}`).
+///
+/// Fix : régénère la table des endpoints dans la description du folder.
+///
+/// Sévérité : INFO (-3%)
+pub fn check(collection: &Value) -> Vec<LintIssue> {
+    FolderSummaryAccuracy.check(collection, &LintContext::new(collection), &LintConfig::default())
+}
+
+/// Endpoints (méthode, path) des requêtes du folder et de ses sous-folders, sans doublons
+fn collect_endpoints(items: &[Item], endpoints: &mut Vec<(String, String)>) {
+    for item in items {
+        if let Some(request) = &item.request {
+            let path = request.url.path();
+            let endpoint = (request.method(), if path.is_empty() { "/".to_string() } else { path });
+            if !endpoints.contains(&endpoint) {
+                endpoints.push(endpoint);
+            }
+        }
+        collect_endpoints(item.children(), endpoints);
+    }
+}
+
+/// Lignes `| GET | /users |` de la description (la colonne path suit la méthode)
+fn documented_endpoints(description: &str) -> BTreeSet<(String, String)> {
+    SUMMARY_ROW
+        .captures_iter(description)
+        .map(|caps| (caps[1].to_uppercase(), normalize_path(&caps[2])))
+        .collect()
+}
+
+/// Path comparable : minuscules, sans `/` final, segments variables uniformisés
+fn normalize_path(path: &str) -> String {
+    let segments: Vec<String> = path
+        .trim_end_matches('/')
+        .split('/')
+        .map(|segment| {
+            if segment.starts_with(':') || segment.starts_with('{') {
+                "{}".to_string()
+            } else {
+                segment.to_lowercase()
+            }
+        })
+        .collect();
+    let normalized = segments.join("/");
+    if normalized.is_empty() {
+        "/".to_string()
+    } else {
+        normalized
+    }
+}
+
+fn format_endpoint((method, path): &(String, String)) -> String {
+    format!("{} {}", method, path)
+}
+
+/// Table markdown des endpoints, dans l'ordre des requêtes
+pub fn endpoint_table(endpoints: &[(String, String)]) -> String {
+    let mut table = String::from("| Method | Path |\n|--------|------|");
+    for (method, path) in endpoints {
+        table.push_str(&format!("\n| {} | {} |", method, path));
+    }
+    table
+}
+
+/// Déclaration de la règle dans le registre
+pub struct FolderSummaryAccuracy;
+
+impl Rule for FolderSummaryAccuracy {
+    fn id(&self) -> &'static str {
+        "folder-summary-accuracy"
+    }
+
+    fn category(&self) -> Category {
+        Category::Documentation
+    }
+
+    fn severity(&self) -> &'static str {
+        "info"
+    }
+
+    fn description(&self) -> &'static str {
+        "Folder descriptions list the endpoints they contain in an up-to-date method + path table"
+    }
+
+    fn fix_types(&self) -> &'static [&'static str] {
+        &["regenerate_endpoint_table"]
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, _config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        Some(Box::new(FolderSummaryAccuracy))
+    }
+}
+
+impl ItemVisitor for FolderSummaryAccuracy {
+    fn visit(&mut self, item: &ItemContext, typed: &TypedItem, _ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        if !typed.item.is_folder() {
+            return;
+        }
+
+        let mut endpoints = Vec::new();
+        collect_endpoints(typed.item.children(), &mut endpoints);
+        if endpoints.is_empty() {
+            return;
+        }
+
+        let actual: BTreeSet<(String, String)> = endpoints
+            .iter()
+            .map(|(method, path)| (method.clone(), normalize_path(path)))
+            .collect();
+        let documented = documented_endpoints(&typed.item.description.0);
+
+        let issue = LintIssue::builder("folder-summary-accuracy").severity("info").param("folder", item.name.as_str());
+        let issue = if documented.is_empty() {
+            issue.message_id("folder-summary-accuracy.not-listed").param("count", actual.len())
+        } else {
+            let added: Vec<String> = actual.difference(&documented).map(format_endpoint).collect();
+            let removed: Vec<String> = documented.difference(&actual).map(format_endpoint).collect();
+            let message_id = match (added.is_empty(), removed.is_empty()) {
+                (true, true) => return,
+                (false, false) => "folder-summary-accuracy.out-of-date",
+                (false, true) => "folder-summary-accuracy.undocumented",
+                (true, false) => "folder-summary-accuracy.removed",
+            };
+            let mut issue = issue.message_id(message_id);
+            if !added.is_empty() {
+                issue = issue.param("added", added.join(", "));
+            }
+            if !removed.is_empty() {
+                issue = issue.param("removed", removed.join(", "));
+            }
+            issue
+        };
+
+        issues.push(issue
+            .path(item.path.clone())
+            .fix(FixAction::RegenerateEndpointTable {
+                table: endpoint_table(&endpoints),
+            })
+            .render());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn folder(description: &str) -> Value {
+        json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "Users",
+                "description": description,
+                "item": [
+                    { "name": "GET Users", "request": { "method": "GET", "url": "{{base_url}}/users" } },
+                    { "name": "GET User", "request": { "method": "GET", "url": "{{base_url}}/users/{{id}}" } },
+                    { "name": "Admin", "item": [
+                        { "name": "DELETE User", "request": { "method": "DELETE", "url": "{{base_url}}/users/:id" } }
+                    ] }
+                ]
+            }]
+        })
+    }
+
+    #[test]
+    fn test_up_to_date_table_is_accepted() {
+        let collection = folder(
+            "# Users\n\n| Method | Path |\n|---|---|\n| GET | `/users` |\n| GET | /users/{id} |\n| DELETE | /users/:id |",
+        );
+
+        let issues = check(&collection);
+        // Seul le sous-folder "Admin", sans description, est signalé
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].path, "/item[0]/item[2]");
+        assert!(issues[0].message.contains("does not list its 1 endpoints"));
+    }
+
+    #[test]
+    fn test_drift_reported_with_regenerated_table() {
+        let collection = folder("| Method | Path |\n|---|---|\n| GET | /users |\n| POST | /users |");
+
+        let issues = check(&collection);
+        assert_eq!(issues[0].path, "/item[0]");
+        assert!(issues[0]
+            .message
+            .contains("undocumented: DELETE /users/{}, GET /users/{}; no longer in the folder: POST /users"));
+
+        let Some(FixAction::RegenerateEndpointTable { table }) = &issues[0].fix else {
+            panic!("expected a regenerate_endpoint_table fix");
+        };
+        assert_eq!(
+            table,
+            "| Method | Path |\n|--------|------|\n| GET | /users |\n| GET | /users/{{id}} |\n| DELETE | /users/:id |"
+        );
+    }
+}
@@ -1,2 +1,22 @@
+use super::Rule;
+
 pub mod collection_overview_template;
 pub mod request_examples_required;
+pub mod example_count_limits;
+pub mod deprecation_metadata;
+pub mod example_header_consistency;
+pub mod folder_summary_accuracy;
+pub mod example_url_hygiene;
+pub mod error_body_convention;
+
+/// Règles de la catégorie, dans l'ordre d'exécution
+pub const RULES: &[&dyn Rule] = &[
+    &collection_overview_template::CollectionOverviewTemplate,
+    &request_examples_required::RequestExamplesRequired,
+    &example_count_limits::ExampleCountLimits,
+    &deprecation_metadata::DeprecationMetadata,
+    &example_header_consistency::ExampleHeaderConsistency,
+    &folder_summary_accuracy::FolderSummaryAccuracy,
+    &example_url_hygiene::ExampleUrlHygiene,
+    &error_body_convention::ErrorBodyConvention,
+];
@@ -1,4 +1,9 @@
-use crate::LintIssue;
+use crate::context::LintContext;
+use crate::{LintConfig, LintIssue};
+use crate::model::{Item, Request, TypedItem};
+use crate::rules::{Category, ItemVisitor, Rule};
+use crate::utils::ItemContext;
+use crate::i18n::Render;
 use serde_json::Value;
 
 /// Règle : request-examples-required
@@ -10,123 +15,98 @@ use serde_json::Value;
 /// 
 /// Sévérité : ERROR (-15%)
 pub fn check(collection: &Value) -> Vec<LintIssue> {
-    let mut issues = Vec::new();
-    
-    if let Some(items) = collection["item"].as_array() {
-        check_items(items, &mut issues, "");
-    }
-    
-    issues
+    RequestExamplesRequired.check(collection, &LintContext::new(collection), &LintConfig::default())
 }
 
-fn check_items(items: &[Value], issues: &mut Vec<LintIssue>, parent_path: &str) {
-    for (index, item) in items.iter().enumerate() {
-        let default_name = format!("Item-{}", index + 1);
-        let item_name = item["name"].as_str().unwrap_or(&default_name);
-        let current_path = if parent_path.is_empty() {
-            format!("/item[{}]", index)
-        } else {
-            format!("{}/item[{}]", parent_path, index)
-        };
-        
-        // Si c'est une requête
-        if item.get("request").is_some() {
-            check_request_documentation(item, issues, &current_path, item_name);
-        }
-        
-        // Si c'est un folder, récurser
-        if let Some(sub_items) = item["item"].as_array() {
-            check_items(sub_items, issues, &current_path);
-        }
-    }
-}
-
-fn check_request_documentation(item: &Value, issues: &mut Vec<LintIssue>, path: &str, item_name: &str) {
+fn check_request_documentation(item: &Item, request: &Request, issues: &mut Vec<LintIssue>, path: &str, item_name: &str) {
     // 1. Vérifier les exemples de réponse
-    let responses = item["response"].as_array();
-    
-    if responses.is_none() || responses.unwrap().is_empty() {
-        issues.push(LintIssue {
-            rule_id: "request-examples-required".to_string(),
-            severity: "error".to_string(),
-            message: format!("📋 Request \"{}\" has no response examples", item_name),
-            path: path.to_string(),
-            line: None,
-            fix: None,
-        });
+    if item.response.is_empty() {
+        issues.push(LintIssue::builder("request-examples-required")
+            .severity("error")
+            .message_id("request-examples-required.no-examples")
+            .param("request", item_name)
+            .path(path)
+            .render());
     } else {
         // Vérifier la qualité des exemples existants
-        for (resp_index, response) in responses.unwrap().iter().enumerate() {
+        for (resp_index, response) in item.response.iter().enumerate() {
             // Vérifier le nom de l'exemple
-            if response["name"].as_str().is_none() || response["name"].as_str().unwrap().is_empty() {
-                issues.push(LintIssue {
-                    rule_id: "documentation-completeness".to_string(),
-                    severity: "error".to_string(),
-                    message: format!(
-                        "🏷️ Example #{} for \"{}\" is missing name",
-                        resp_index + 1,
-                        item_name
-                    ),
-                    path: format!("{}/response[{}]", path, resp_index),
-                    line: None,
-                    fix: None,
-                });
+            if response.name.is_empty() {
+                issues.push(LintIssue::builder("documentation-completeness")
+                    .severity("error")
+                    .message_id("request-examples-required.missing-name")
+                    .param("number", resp_index + 1)
+                    .param("request", item_name)
+                    .path(format!("{}/response[{}]", path, resp_index))
+                    .render());
             }
             
             // Vérifier le contenu (sauf pour 204 No Content)
-            let is_204_no_content = response["code"].as_u64() == Some(204)
-                || response["status"].as_str() == Some("No Content")
-                || response["name"]
-                    .as_str()
-                    .map(|n| n.to_lowercase().contains("no content"))
-                    .unwrap_or(false);
-            
-            let has_body = response["body"].as_str().is_some() 
-                && !response["body"].as_str().unwrap().is_empty();
+            let is_204_no_content = response.code == Some(204)
+                || response.status == "No Content"
+                || response.name.to_lowercase().contains("no content");
             
-            if !has_body && !is_204_no_content {
-                issues.push(LintIssue {
-                    rule_id: "documentation-completeness".to_string(),
-                    severity: "error".to_string(),
-                    message: format!(
-                        "📄 Example #{} for \"{}\" is missing content",
-                        resp_index + 1,
-                        item_name
-                    ),
-                    path: format!("{}/response[{}]", path, resp_index),
-                    line: None,
-                    fix: None,
-                });
+            if response.body.is_empty() && !is_204_no_content {
+                issues.push(LintIssue::builder("documentation-completeness")
+                    .severity("error")
+                    .message_id("request-examples-required.missing-content")
+                    .param("number", resp_index + 1)
+                    .param("request", item_name)
+                    .path(format!("{}/response[{}]", path, resp_index))
+                    .render());
             }
         }
     }
     
     // 2. Vérifier la documentation des paramètres de query
-    if let Some(query_params) = item["request"]["url"]["query"].as_array() {
-        let mut undocumented_params = Vec::new();
-        
-        for param in query_params {
-            let param_key = param["key"].as_str().unwrap_or("paramètre sans nom");
-            let param_desc = param["description"].as_str().unwrap_or("");
-            
-            if param_desc.trim().is_empty() {
-                undocumented_params.push(param_key.to_string());
-            }
-        }
-        
-        if !undocumented_params.is_empty() {
-            issues.push(LintIssue {
-                rule_id: "documentation-completeness".to_string(),
-                severity: "error".to_string(),
-                message: format!(
-                    "📝 Request \"{}\" has undocumented parameters: {}",
-                    item_name,
-                    undocumented_params.join(", ")
-                ),
-                path: format!("{}/request/url/query", path),
-                line: None,
-                fix: None,
-            });
+    let undocumented_params: Vec<&str> = request
+        .url
+        .query()
+        .iter()
+        .filter(|param| param.description.0.trim().is_empty())
+        .map(|param| param.key.as_deref().unwrap_or("paramètre sans nom"))
+        .collect();
+    
+    if !undocumented_params.is_empty() {
+        issues.push(LintIssue::builder("documentation-completeness")
+            .severity("error")
+            .message_id("request-examples-required.undocumented-params")
+            .param("request", item_name)
+            .param("params", undocumented_params.join(", "))
+            .path(format!("{}/request/url/query", path))
+            .render());
+    }
+}
+
+/// Déclaration de la règle dans le registre
+pub struct RequestExamplesRequired;
+
+impl Rule for RequestExamplesRequired {
+    fn id(&self) -> &'static str {
+        "request-examples-required"
+    }
+
+    fn category(&self) -> Category {
+        Category::Documentation
+    }
+
+    fn severity(&self) -> &'static str {
+        "error"
+    }
+
+    fn description(&self) -> &'static str {
+        "Response examples required"
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, _config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        Some(Box::new(RequestExamplesRequired))
+    }
+}
+
+impl ItemVisitor for RequestExamplesRequired {
+    fn visit(&mut self, item: &ItemContext, typed: &TypedItem, _ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        if let Some(request) = &typed.item.request {
+            check_request_documentation(typed.item, request, issues, &item.path, &item.name);
         }
     }
 }
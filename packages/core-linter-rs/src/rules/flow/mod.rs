@@ -0,0 +1,8 @@
+use super::Rule;
+
+pub mod unreachable_requests;
+
+/// Règles de la catégorie, dans l'ordre d'exécution
+pub const RULES: &[&dyn Rule] = &[
+    &unreachable_requests::UnreachableRequests,
+];
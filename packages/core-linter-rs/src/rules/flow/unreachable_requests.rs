@@ -0,0 +1,221 @@
+use crate::context::LintContext;
+use crate::{LintConfig, LintIssue};
+use crate::model::TypedItem;
+use crate::rules::{self, Category, ItemVisitor, Rule, RuleInput};
+use crate::utils::{self, ItemContext};
+use crate::i18n::Render;
+use regex::Regex;
+use serde_json::Value;
+use std::collections::{HashMap, VecDeque};
+use std::sync::LazyLock;
+
+/// Appel de `setNextRequest` : nom entre quotes, `null` ou expression
+static SET_NEXT_REQUEST: LazyLock<Regex> = LazyLock::new(|| {
+    utils::build_regex(
+        r#"(?:postman|pm\.execution)\.setNextRequest\(\s*(?:'([^']*)'|"([^"]*)"|(null)|([^)]*))\s*\)"#,
+    )
+    .unwrap()
+});
+
+/// Règle : unreachable-requests
+///
+/// Pour les collections pilotées par `setNextRequest` (`postman.setNextRequest`,
+/// `pm.execution.setNextRequest`), construit le graphe d'exécution du runner et
+/// signale les requêtes qui ne peuvent jamais s'exécuter : elles pourrissent sans
+/// que personne ne le remarque.
+///
+/// Modèle d'exécution :
+/// - le run démarre à la première requête (ordre de la collection, folders aplatis)
+/// - une requête dont les scripts (ou ceux de ses folders parents) appellent
+///   `setNextRequest('Nom')` enchaîne sur la/les requêtes ciblées ; sinon sur la suivante
+/// - `setNextRequest(null)` arrête le run
+///
+/// Si une cible est dynamique (variable, expression), le graphe est inconnu et la
+/// règle ne signale rien.
+///
+/// Sévérité : WARNING (-8%)
+pub fn check(collection: &Value) -> Vec<LintIssue> {
+    check_with_context(collection, &LintContext::new(collection))
+}
+
+/// Version utilisant le contexte partagé (index des scripts)
+pub fn check_with_context(collection: &Value, ctx: &LintContext) -> Vec<LintIssue> {
+    rules::visit(collection, ctx, &mut FlowVisitor::default())
+}
+
+struct FlowRequest {
+    name: String,
+    path: String,
+    /// Paths dont les scripts s'appliquent à la requête (collection, folders, requête)
+    scopes: Vec<String>,
+}
+
+/// Requêtes dans l'ordre d'exécution ; le graphe n'est construit qu'une fois la
+/// collection entière parcourue
+#[derive(Default)]
+struct FlowVisitor {
+    /// Paths des folders parents, du plus haut au plus proche
+    folders: Vec<String>,
+    requests: Vec<FlowRequest>,
+}
+
+impl ItemVisitor for FlowVisitor {
+    fn visit(&mut self, item: &ItemContext, typed: &TypedItem, _ctx: &LintContext, _issues: &mut Vec<LintIssue>) {
+        self.folders.truncate(item.depth);
+
+        // Si c'est une requête
+        if typed.item.is_request() {
+            let mut scopes = vec![String::new()];
+            scopes.extend(self.folders.iter().cloned());
+            scopes.push(item.path.clone());
+            self.requests.push(FlowRequest {
+                name: typed.item.name.clone().unwrap_or_else(|| "unknown".to_string()),
+                path: item.path.clone(),
+                scopes,
+            });
+        }
+
+        if item.has_children() {
+            self.folders.push(item.path.clone());
+        }
+    }
+
+    fn finish(&mut self, ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        // Cibles de chaque requête : None = enchaînement par défaut sur la suivante
+        let mut uses_flow = false;
+        let mut targets: Vec<Option<Vec<String>>> = Vec::new();
+        for request in &self.requests {
+            let mut calls: Option<Vec<String>> = None;
+            for scope in &request.scopes {
+                let scripts = ctx.scripts.get(scope);
+                for script in [&scripts.prerequest, &scripts.test] {
+                    for caps in SET_NEXT_REQUEST.captures_iter(script) {
+                        uses_flow = true;
+                        let target = caps.get(1).or(caps.get(2)).map(|m| m.as_str().to_string());
+                        match (target, caps.get(3)) {
+                            (Some(name), _) => calls.get_or_insert_with(Vec::new).push(name),
+                            (None, Some(_)) => {
+                                calls.get_or_insert_with(Vec::new);
+                            }
+                            // Cible dynamique : graphe inconnu
+                            (None, None) => return,
+                        }
+                    }
+                }
+            }
+            targets.push(calls);
+        }
+
+        if !uses_flow || self.requests.is_empty() {
+            return;
+        }
+
+        let mut by_name: HashMap<&str, Vec<usize>> = HashMap::new();
+        for (index, request) in self.requests.iter().enumerate() {
+            by_name.entry(request.name.as_str()).or_default().push(index);
+        }
+
+        // Parcours en largeur depuis la première requête
+        let mut reachable = vec![false; self.requests.len()];
+        let mut queue = VecDeque::from([0]);
+        reachable[0] = true;
+        while let Some(current) = queue.pop_front() {
+            let next: Vec<usize> = match &targets[current] {
+                None => vec![current + 1],
+                Some(names) => names.iter().flat_map(|name| by_name.get(name.as_str()).cloned().unwrap_or_default()).collect(),
+            };
+            for index in next {
+                if index < self.requests.len() && !reachable[index] {
+                    reachable[index] = true;
+                    queue.push_back(index);
+                }
+            }
+        }
+
+        for (request, reached) in self.requests.iter().zip(reachable) {
+            if !reached {
+                issues.push(LintIssue::builder("unreachable-requests")
+                    .message_id("unreachable-requests.unreachable")
+                    .param("request", request.name.clone())
+                    .path(request.path.clone())
+                    .render());
+            }
+        }
+    }
+}
+
+/// Déclaration de la règle dans le registre
+pub struct UnreachableRequests;
+
+impl Rule for UnreachableRequests {
+    fn id(&self) -> &'static str {
+        "unreachable-requests"
+    }
+
+    fn category(&self) -> Category {
+        Category::Flow
+    }
+
+    fn severity(&self) -> &'static str {
+        "warning"
+    }
+
+    fn description(&self) -> &'static str {
+        "Requests that no `setNextRequest` flow can ever reach"
+    }
+
+    fn inputs(&self) -> &'static [RuleInput] {
+        &[RuleInput::Scripts]
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, _config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        Some(Box::<FlowVisitor>::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn request(name: &str, next: Option<&str>) -> Value {
+        let exec = next.map(|n| vec![format!("postman.setNextRequest({});", n)]).unwrap_or_default();
+        json!({
+            "name": name,
+            "request": { "method": "GET", "url": "{{base_url}}/x" },
+            "event": [{ "listen": "test", "script": { "exec": exec } }]
+        })
+    }
+
+    #[test]
+    fn test_unreachable_requests() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [
+                request("Login", Some("'Get Orders'")),
+                request("Get Users", None),
+                { "name": "Orders", "item": [request("Get Orders", Some("null")), request("Delete Order", None)] }
+            ]
+        });
+
+        let issues = check(&collection);
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].path, "/item[1]");
+        assert!(issues[1].message.contains("\"Delete Order\" can never run"));
+    }
+
+    #[test]
+    fn test_collections_without_flow_or_with_dynamic_targets() {
+        let linear = json!({
+            "info": { "name": "Test" },
+            "item": [request("Login", None), request("Get Users", None)]
+        });
+        assert_eq!(check(&linear).len(), 0);
+
+        let dynamic = json!({
+            "info": { "name": "Test" },
+            "item": [request("Login", Some("pm.environment.get('next')")), request("Get Users", None)]
+        });
+        assert_eq!(check(&dynamic).len(), 0);
+    }
+}
@@ -1,6 +1,427 @@
+//! Règles intégrées
+//!
+//! Chaque règle déclare sa structure implémentant [`Rule`] dans son propre module,
+//! et s'enregistre dans la liste `RULES` de sa catégorie : le moteur exécute
+//! [`builtin()`] sans connaître les règles une à une. Les règles externes
+//! (`linterman-rule-api`) passent par `registry::RuleRegistry`.
+//!
+//! Les règles qui examinent les items un par un fournissent un [`ItemVisitor`]
+//! ([`Rule::visitor`]) : le moteur parcourt alors la collection une seule fois pour
+//! toutes ces règles (`LintContext::walk`). Les autres implémentent [`Rule::check`],
+//! chacune documentant pourquoi elle n'utilise pas ce parcours.
+
+use crate::context::LintContext;
+use crate::maturity::Maturity;
+use crate::model::TypedItem;
+use crate::registry::RuleRegistry;
+use crate::utils::{self, ItemContext};
+use crate::{LintConfig, LintIssue};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
 pub mod testing;
 pub mod security;
 pub mod documentation;
 pub mod structure;
 pub mod performance;
 pub mod best_practices;
+pub mod flow;
+
+/// Catégorie d'une règle intégrée
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum Category {
+    Testing,
+    Structure,
+    Performance,
+    BestPractices,
+    Flow,
+    Documentation,
+    Security,
+}
+
+/// Entrées volumineuses analysées par une règle (voir `guards`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleInput {
+    /// Contenu des scripts de test / pre-request
+    Scripts,
+    /// Contenu sérialisé des requêtes (URL, headers, body)
+    RequestContent,
+}
+
+/// Règle intégrée au moteur
+pub trait Rule: Send + Sync {
+    /// Identifiant unique en kebab-case (ex: "test-http-status-mandatory")
+    fn id(&self) -> &'static str;
+
+    fn category(&self) -> Category;
+
+    /// Sévérité par défaut (les règles graduées peuvent en émettre d'autres)
+    fn severity(&self) -> &'static str;
+
+    /// Description courte (en anglais) affichée dans les sélecteurs de règles
+    fn description(&self) -> &'static str;
+
+    /// Niveau de maturité (voir `maturity`) ; par défaut déduit de la sévérité
+    fn maturity(&self) -> Maturity {
+        Maturity::from_severity(self.severity())
+    }
+
+    /// Options acceptées dans `rule_options`, avec leurs valeurs par défaut
+    fn default_options(&self) -> Option<Value> {
+        None
+    }
+
+    /// Types de fix (`FixAction::fix_type`) proposés par la règle
+    fn fix_types(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Entrées soumises aux garde-fous de taille
+    fn inputs(&self) -> &'static [RuleInput] {
+        &[]
+    }
+
+    /// Visiteur de la règle pour le parcours partagé des items ; `None` pour une règle
+    /// qui n'examine pas les items un par un (elle implémente [`Rule::check`]) ou qui
+    /// n'a rien à vérifier avec cette configuration
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, _config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        None
+    }
+
+    /// Vérifie la collection ; les options se lisent via `config.options_for(self.id())`
+    ///
+    /// Par défaut : parcours de la collection avec le visiteur de la règle (exécution
+    /// isolée, le moteur mutualise ce parcours entre les règles)
+    fn check(&self, collection: &Value, ctx: &LintContext, config: &LintConfig) -> Vec<LintIssue> {
+        match self.visitor(ctx, config) {
+            Some(mut visitor) => visit(collection, ctx, visitor.as_mut()),
+            None => Vec::new(),
+        }
+    }
+}
+
+/// Vérifications d'une règle item par item pendant le parcours partagé
+///
+/// Créé par [`Rule::visitor`] pour une analyse : la règle y lit ses options et compile
+/// ses motifs une seule fois, et y accumule ce qu'elle vérifie sur l'ensemble de la
+/// collection jusqu'à [`ItemVisitor::finish`]. Une règle sans état est son propre visiteur.
+pub trait ItemVisitor {
+    /// Vérifie un item ; `typed` est le même item dans le modèle typé
+    fn visit(&mut self, item: &ItemContext, typed: &TypedItem, ctx: &LintContext, issues: &mut Vec<LintIssue>);
+
+    /// Fin du parcours : issues portant sur l'ensemble de la collection
+    fn finish(&mut self, _ctx: &LintContext, _issues: &mut Vec<LintIssue>) {}
+}
+
+/// Exécute un visiteur seul sur la collection (`check` des règles, hors moteur)
+pub fn visit(collection: &Value, ctx: &LintContext, visitor: &mut dyn ItemVisitor) -> Vec<LintIssue> {
+    let mut issues = Vec::new();
+    ctx.walk(collection, |item, typed| visitor.visit(item, typed, ctx, &mut issues));
+    visitor.finish(ctx, &mut issues);
+    issues
+}
+
+/// Toutes les règles intégrées, dans l'ordre d'exécution
+pub fn builtin() -> impl Iterator<Item = &'static dyn Rule> {
+    [
+        testing::RULES,
+        structure::RULES,
+        performance::RULES,
+        best_practices::RULES,
+        flow::RULES,
+        documentation::RULES,
+        security::RULES,
+    ]
+    .into_iter()
+    .flatten()
+    .copied()
+}
+
+/// Métadonnées d'une règle intégrée (sélecteurs de règles de l'IHM et de la CLI)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RuleMetadata {
+    pub id: String,
+    pub category: Category,
+    pub severity: String,
+    pub description: String,
+    pub maturity: Maturity,
+    /// La règle propose des corrections automatiques
+    pub fixable: bool,
+    pub fix_types: Vec<String>,
+    /// Options configurables et leurs valeurs par défaut (`null` si aucune)
+    pub options: Option<Value>,
+}
+
+/// Métadonnées de toutes les règles intégrées, dans l'ordre d'exécution
+pub fn list_rules() -> Vec<RuleMetadata> {
+    builtin()
+        .map(|rule| RuleMetadata {
+            id: rule.id().to_string(),
+            category: rule.category(),
+            severity: rule.severity().to_string(),
+            description: rule.description().to_string(),
+            maturity: rule.maturity(),
+            fixable: !rule.fix_types().is_empty(),
+            fix_types: rule.fix_types().iter().map(|fix_type| fix_type.to_string()).collect(),
+            options: rule.default_options(),
+        })
+        .collect()
+}
+
+/// Empreinte du jeu de règles effectif : profil de visibilité, puis identifiant,
+/// sévérité (après `severity_overrides`) et options de chaque règle activée,
+/// intégrée ou externe.
+/// Change dès qu'une règle est ajoutée, retirée ou reconfigurée ; combinée à
+/// `ENGINE_VERSION`, elle indique si deux résultats sont comparables.
+pub fn ruleset_hash(config: &LintConfig, registry: &RuleRegistry) -> String {
+    let configured = |rule_id: &str| config.rule_options.as_ref().and_then(|options| options.get(rule_id)).cloned();
+    let severity = |rule_id: &str, default: &str| {
+        config
+            .severity_overrides
+            .as_ref()
+            .and_then(|overrides| overrides.get(rule_id))
+            .filter(|severity| SEVERITIES.contains(&severity.as_str()))
+            .cloned()
+            .unwrap_or_else(|| default.to_string())
+    };
+
+    let mut ruleset = vec![serde_json::json!(config.visibility.unwrap_or_default())];
+    ruleset.extend(builtin().filter(|rule| config.is_rule_enabled(rule.id())).map(|rule| {
+        serde_json::json!([
+            rule.id(),
+            severity(rule.id(), rule.severity()),
+            configured(rule.id()).or_else(|| rule.default_options()),
+        ])
+    }));
+    ruleset.extend(
+        registry
+            .rules()
+            .filter(|rule| config.is_rule_enabled(rule.id()))
+            .map(|rule| serde_json::json!([rule.id(), severity(rule.id(), rule.severity()), configured(rule.id())])),
+    );
+
+    utils::stable_hash(Value::Array(ruleset).to_string().into_bytes())
+}
+
+/// Avertissements sur la configuration (`LintResult::config_warnings`)
+pub fn validate_config(config: &LintConfig, registry: &RuleRegistry) -> Vec<String> {
+    let mut warnings = Vec::new();
+    if let Some(options) = &config.rule_options {
+        warnings.extend(validate_options(options, registry));
+    }
+    if let Some(overrides) = &config.severity_overrides {
+        warnings.extend(validate_severities(overrides, registry));
+    }
+    if let Some(template) = config.custom_templates.as_ref().and_then(|templates| templates.get("collection-overview-template")) {
+        warnings.extend(documentation::collection_overview_template::template_warnings(template));
+    }
+    // Une faute de frappe dans l'exclusion laisserait la règle active sans le signaler
+    for rule_id in config.disabled_rules.iter().flatten() {
+        if find(rule_id).is_none() && !registry.contains(rule_id) {
+            warnings.push(format!("disabled_rules: unknown rule '{}'", rule_id));
+        }
+    }
+    warnings
+}
+
+/// Sévérités acceptées par `LintConfig::severity_overrides` : `hint` signale
+/// l'issue sans pénaliser le score, `off` désactive la règle
+pub const SEVERITIES: &[&str] = &["error", "warning", "info", "hint", "off"];
+
+/// Sévérités qu'une issue peut porter
+pub const ISSUE_SEVERITIES: &[&str] = &["error", "warning", "info", "hint"];
+
+/// Vérifie `severity_overrides` : règles inconnues et sévérités invalides (ignorées)
+pub fn validate_severities(overrides: &HashMap<String, String>, registry: &RuleRegistry) -> Vec<String> {
+    let mut warnings = Vec::new();
+    let mut rule_ids: Vec<&String> = overrides.keys().collect();
+    rule_ids.sort();
+
+    for rule_id in rule_ids {
+        if find(rule_id).is_none() && !registry.contains(rule_id) {
+            warnings.push(format!("severities: unknown rule '{}'", rule_id));
+        } else if !SEVERITIES.contains(&overrides[rule_id].as_str()) {
+            warnings.push(format!(
+                "severities: '{}' is not a valid severity for rule '{}' (expected one of: {})",
+                overrides[rule_id],
+                rule_id,
+                SEVERITIES.join(", ")
+            ));
+        }
+    }
+
+    warnings
+}
+
+/// Vérifie `rule_options` : règles inconnues, options inconnues ou de mauvais type
+/// (comparées aux options par défaut de la règle). Les options invalides sont
+/// ignorées par `LintConfig::options_for` : ces avertissements évitent qu'une faute
+/// de frappe passe inaperçue.
+pub fn validate_options(rule_options: &HashMap<String, Value>, registry: &RuleRegistry) -> Vec<String> {
+    let mut warnings = Vec::new();
+    let mut rule_ids: Vec<&String> = rule_options.keys().collect();
+    rule_ids.sort();
+
+    for rule_id in rule_ids {
+        let options = &rule_options[rule_id];
+        let Some(rule) = find(rule_id) else {
+            if !registry.contains(rule_id) {
+                warnings.push(format!("rule_options: unknown rule '{}'", rule_id));
+            }
+            continue;
+        };
+
+        let Some(Value::Object(defaults)) = rule.default_options() else {
+            if options.as_object().is_some_and(|options| !options.is_empty()) {
+                warnings.push(format!("rule_options: rule '{}' has no options", rule_id));
+            }
+            continue;
+        };
+        let Some(options) = options.as_object() else {
+            warnings.push(format!("rule_options: options of rule '{}' must be an object", rule_id));
+            continue;
+        };
+
+        for (key, value) in options {
+            match defaults.get(key) {
+                None => warnings.push(format!(
+                    "rule_options: unknown option '{}' for rule '{}' (expected one of: {})",
+                    key,
+                    rule_id,
+                    defaults.keys().cloned().collect::<Vec<_>>().join(", ")
+                )),
+                Some(default) if !same_json_type(default, value) => warnings.push(format!(
+                    "rule_options: option '{}' of rule '{}' must be {}, defaults are used",
+                    key,
+                    rule_id,
+                    json_type_name(default)
+                )),
+                Some(_) => {}
+            }
+        }
+    }
+
+    warnings
+}
+
+/// Même type JSON (les nombres entiers et décimaux sont interchangeables ; une option
+/// sans valeur par défaut accepte tout)
+fn same_json_type(default: &Value, value: &Value) -> bool {
+    match (default, value) {
+        (Value::Null, _) => true,
+        (Value::Number(_), Value::Number(number)) => !default.is_u64() || number.is_u64(),
+        _ => std::mem::discriminant(default) == std::mem::discriminant(value),
+    }
+}
+
+fn json_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(number) if number.is_u64() => "a positive integer",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+    }
+}
+
+/// Règle intégrée par identifiant
+pub fn find(rule_id: &str) -> Option<&'static dyn Rule> {
+    builtin().find(|rule| rule.id() == rule_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_builtin_rule_ids_are_unique() {
+        let ids: Vec<&str> = builtin().map(|rule| rule.id()).collect();
+        let unique: HashSet<&str> = ids.iter().copied().collect();
+        assert_eq!(ids.len(), unique.len());
+        assert!(ids.len() >= 30);
+    }
+
+    #[test]
+    fn test_find_rule() {
+        let rule = find("hardcoded-secrets").unwrap();
+        assert_eq!(rule.category(), Category::Security);
+        assert_eq!(rule.severity(), "error");
+        assert!(rule.inputs().contains(&RuleInput::RequestContent));
+        assert!(find("acme-no-legacy-host").is_none());
+    }
+
+    #[test]
+    fn test_list_rules() {
+        let rules = list_rules();
+        assert_eq!(rules.len(), builtin().count());
+        assert!(rules.iter().all(|rule| !rule.description.is_empty()));
+
+        let debug_headers = rules.iter().find(|rule| rule.id == "debug-headers").unwrap();
+        assert!(debug_headers.fixable);
+        assert_eq!(debug_headers.category, Category::Security);
+        assert!(debug_headers.options.as_ref().unwrap()["denylist"].is_array());
+
+        let naming = rules.iter().find(|rule| rule.id == "request-naming-convention").unwrap();
+        assert!(naming.options.is_none());
+    }
+
+    #[test]
+    fn test_validate_options() {
+        let rule_options: HashMap<String, Value> = serde_json::from_value(serde_json::json!({
+            "example-count-limits": { "max_examples": 5 },
+            "debug-headers": { "denylist": "X-Debug", "deny_list": [] },
+            "test-coverage-minimum": { "min_percent": 90 },
+            "request-naming-convention": { "style": "verb-first" },
+            "acme-no-legacy-host": {}
+        }))
+        .unwrap();
+
+        let warnings = validate_options(&rule_options, &RuleRegistry::new());
+
+        assert_eq!(
+            warnings,
+            vec![
+                "rule_options: unknown rule 'acme-no-legacy-host'".to_string(),
+                "rule_options: unknown option 'deny_list' for rule 'debug-headers' (expected one of: denylist)".to_string(),
+                "rule_options: option 'denylist' of rule 'debug-headers' must be an array, defaults are used".to_string(),
+                "rule_options: rule 'request-naming-convention' has no options".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ruleset_hash() {
+        let registry = RuleRegistry::new();
+        let config = LintConfig { rules: Some(vec!["debug-headers".to_string()]), ..Default::default() };
+        let hash = ruleset_hash(&config, &registry);
+
+        assert_eq!(hash, ruleset_hash(&config.clone(), &registry));
+        assert_ne!(hash, ruleset_hash(&LintConfig::default(), &registry));
+
+        let mut configured = config.clone();
+        configured.rule_options = Some(HashMap::from([(
+            "debug-headers".to_string(),
+            serde_json::json!({ "denylist": ["X-Debug"] }),
+        )]));
+        assert_ne!(hash, ruleset_hash(&configured, &registry));
+
+        let mut overridden = config.clone();
+        overridden.severity_overrides = Some(HashMap::from([("debug-headers".to_string(), "error".to_string())]));
+        assert_ne!(hash, ruleset_hash(&overridden, &registry));
+    }
+
+    #[test]
+    fn test_declared_fix_types_are_registered() {
+        let registry = crate::fixer::FixRegistry::builtin();
+        for rule in builtin() {
+            for fix_type in rule.fix_types() {
+                assert!(registry.is_registered(fix_type), "{}: {}", rule.id(), fix_type);
+            }
+        }
+    }
+}
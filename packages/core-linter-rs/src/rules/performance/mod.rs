@@ -1 +1,10 @@
+use super::Rule;
+
 pub mod response_time_threshold;
+pub mod slo_alignment;
+
+/// Règles de la catégorie, dans l'ordre d'exécution
+pub const RULES: &[&dyn Rule] = &[
+    &response_time_threshold::ResponseTimeThreshold,
+    &slo_alignment::SloAlignment,
+];
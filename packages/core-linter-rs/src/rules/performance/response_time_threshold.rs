@@ -1,7 +1,31 @@
-use crate::LintIssue;
-use crate::utils;
+use crate::context::LintContext;
+use crate::{FixAction, LintConfig, LintIssue};
+use crate::model::TypedItem;
+use crate::rules::{self, Category, ItemVisitor, Rule, RuleInput};
+use crate::utils::{self, ItemContext};
+use crate::i18n::Render;
 use regex::Regex;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::sync::LazyLock;
+
+/// Configuration de la règle response-time-threshold
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct ResponseTimeThresholdConfig {
+    /// Seuil maximal accepté dans les assertions de temps de réponse (ms)
+    pub max_ms: u32,
+}
+
+impl Default for ResponseTimeThresholdConfig {
+    fn default() -> Self {
+        ResponseTimeThresholdConfig { max_ms: 2000 }
+    }
+}
+
+/// Seuil d'une assertion `responseTime ... .to.be.below(n)`
+static THRESHOLD: LazyLock<Regex> =
+    LazyLock::new(|| utils::build_regex(r"responseTime.*\.to\.be\.below\((\d+)\)").unwrap());
 
 /// Règle : response-time-threshold
 /// 
@@ -10,68 +34,106 @@ use serde_json::Value;
 /// - < 200ms : Excellent
 /// - < 500ms : Bon
 /// - < 1000ms : Acceptable
-/// - > 2000ms : Trop élevé (WARNING)
+/// - > 2000ms : Trop élevé (WARNING, seuil configurable via `max_ms`)
+/// 
+/// Lorsqu'un rapport Newman est fourni, le seuil suggéré dans le fix est calculé
+/// à partir des temps observés (p95 × 1.5) au lieu du seuil maximal, sans le dépasser.
 /// 
 /// Sévérité : WARNING (-8%)
 pub fn check(collection: &Value) -> Vec<LintIssue> {
-    let mut issues = Vec::new();
-    
-    if let Some(items) = collection["item"].as_array() {
-        check_items(items, &mut issues, "");
-    }
-    
-    issues
+    check_with_context(collection, &LintContext::new(collection))
 }
 
-fn check_items(items: &[Value], issues: &mut Vec<LintIssue>, parent_path: &str) {
-    for (index, item) in items.iter().enumerate() {
-        let default_name = format!("Item-{}", index + 1);
-        let item_name = item["name"].as_str().unwrap_or(&default_name);
-        let current_path = if parent_path.is_empty() {
-            format!("/item[{}]", index)
-        } else {
-            format!("{}/item[{}]", parent_path, index)
-        };
-        
-        // Si c'est une requête
-        if item.get("request").is_some() {
-            let test_script = utils::extract_test_scripts(item).join("\n");
-            
-            // Détecter les seuils de temps de réponse trop élevés (> 2000ms)
-            let threshold_pattern = Regex::new(r"responseTime.*\.to\.be\.below\((\d+)\)").unwrap();
-            
-            for caps in threshold_pattern.captures_iter(&test_script) {
-                if let Some(threshold_match) = caps.get(1) {
-                    if let Ok(threshold) = threshold_match.as_str().parse::<u32>() {
-                        if threshold > 2000 {
-                            issues.push(LintIssue {
-                                rule_id: "response-time-threshold".to_string(),
-                                severity: "warning".to_string(),
-                                message: format!(
-                                    "⏱️ Request \"{}\" has response time threshold too high ({}ms > 2000ms recommended)",
-                                    item_name, threshold
-                                ),
-                                path: current_path.clone(),
-                                line: None,
-                                fix: Some(serde_json::json!({
-                                    "type": "adjust_threshold",
-                                    "current_threshold": threshold,
-                                    "suggested_threshold": 2000,
-                                })),
-                            });
-                        }
-                    }
-                }
-            }
+/// Version utilisant le contexte partagé (index des scripts, timings Newman)
+pub fn check_with_context(collection: &Value, ctx: &LintContext) -> Vec<LintIssue> {
+    check_with_config(collection, ctx, &ResponseTimeThresholdConfig::default())
+}
+
+/// Version avec seuil maximal personnalisable
+pub fn check_with_config(collection: &Value, ctx: &LintContext, config: &ResponseTimeThresholdConfig) -> Vec<LintIssue> {
+    rules::visit(collection, ctx, &mut ThresholdVisitor { max_ms: config.max_ms })
+}
+
+struct ThresholdVisitor {
+    max_ms: u32,
+}
+
+impl ItemVisitor for ThresholdVisitor {
+    fn visit(&mut self, item: &ItemContext, _typed: &TypedItem, ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        if !item.is_request() {
+            return;
         }
-        
-        // Si c'est un folder, récurser
-        if let Some(sub_items) = item["item"].as_array() {
-            check_items(sub_items, issues, &current_path);
+        let max_ms = self.max_ms;
+        let test_script = &ctx.scripts.get(&item.path).test;
+
+        // Détecter les seuils de temps de réponse trop élevés (> max_ms)
+        for caps in THRESHOLD.captures_iter(test_script) {
+            let Ok(threshold) = caps[1].parse::<u32>() else {
+                continue;
+            };
+            if threshold <= max_ms {
+                continue;
+            }
+
+            // Seuil réaliste si des temps observés sont disponibles, plafonné à
+            // `max_ms` pour que le fix ne relâche pas l'assertion
+            let suggested = ctx.timings.suggested_threshold_for(item.item).map_or(max_ms.into(), |s| s.min(max_ms.into()));
+            let fix = FixAction::AdjustThreshold {
+                current_threshold: threshold.into(),
+                suggested_threshold: suggested,
+                observed_p95: ctx.timings.p95_for(item.item),
+            };
+
+            issues.push(LintIssue::builder("response-time-threshold")
+                .message_id("response-time-threshold.too-high")
+                .param("request", item.name.as_str())
+                .param("threshold", threshold)
+                .param("max", max_ms)
+                .path(item.path.clone())
+                .fix(fix)
+                .render());
         }
     }
 }
 
+/// Déclaration de la règle dans le registre
+pub struct ResponseTimeThreshold;
+
+impl Rule for ResponseTimeThreshold {
+    fn id(&self) -> &'static str {
+        "response-time-threshold"
+    }
+
+    fn category(&self) -> Category {
+        Category::Performance
+    }
+
+    fn severity(&self) -> &'static str {
+        "warning"
+    }
+
+    fn description(&self) -> &'static str {
+        "Response time thresholds"
+    }
+
+    fn default_options(&self) -> Option<Value> {
+        serde_json::to_value(ResponseTimeThresholdConfig::default()).ok()
+    }
+
+    fn fix_types(&self) -> &'static [&'static str] {
+        &["adjust_threshold"]
+    }
+
+    fn inputs(&self) -> &'static [RuleInput] {
+        &[RuleInput::Scripts]
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        let config: ResponseTimeThresholdConfig = config.options_for(self.id());
+        Some(Box::new(ThresholdVisitor { max_ms: config.max_ms }))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -131,4 +193,83 @@ mod tests {
         assert_eq!(issues.len(), 1);
         assert!(issues[0].message.contains("threshold too high"));
     }
+
+    #[test]
+    fn test_threshold_suggested_from_newman_p95() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "id": "req-users",
+                "name": "Get Users",
+                "request": { "method": "GET", "url": "https://api.example.com/users" },
+                "event": [{
+                    "listen": "test",
+                    "script": { "exec": ["pm.expect(pm.response.responseTime).to.be.below(5000);"] }
+                }]
+            }]
+        });
+        let report = json!({
+            "run": {
+                "executions": [
+                    { "item": { "id": "req-users", "name": "Get Users" }, "response": { "responseTime": 180 } },
+                    { "item": { "id": "req-users", "name": "Get Users" }, "response": { "responseTime": 240 } }
+                ]
+            }
+        });
+
+        let ctx = LintContext::new(&collection).with_timings(crate::newman::ResponseTimings::from_report(&report));
+        let issues = check_with_context(&collection, &ctx);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].fix,
+            Some(FixAction::AdjustThreshold {
+                current_threshold: 5000,
+                suggested_threshold: 360,
+                observed_p95: Some(240),
+            })
+        );
+    }
+
+    #[test]
+    fn test_suggested_threshold_capped_at_max() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "id": "req-users",
+                "name": "Get Users",
+                "request": { "method": "GET", "url": "https://api.example.com/users" },
+                "event": [{ "listen": "test", "script": { "exec": ["pm.expect(pm.response.responseTime).to.be.below(5000);"] } }]
+            }]
+        });
+        // p95 × 1.5 = 3000 ms, au-dessus de max_ms
+        let report = json!({
+            "run": { "executions": [{ "item": { "id": "req-users", "name": "Get Users" }, "response": { "responseTime": 2000 } }] }
+        });
+
+        let ctx = LintContext::new(&collection).with_timings(crate::newman::ResponseTimings::from_report(&report));
+        let issues = check_with_context(&collection, &ctx);
+        assert_eq!(issues.len(), 1);
+        assert!(matches!(
+            issues[0].fix,
+            Some(FixAction::AdjustThreshold { suggested_threshold: 2000, observed_p95: Some(2000), .. })
+        ));
+    }
+
+    #[test]
+    fn test_configured_max_threshold() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "Get Users",
+                "request": { "method": "GET", "url": "https://api.example.com/users" },
+                "event": [{ "listen": "test", "script": { "exec": ["pm.expect(pm.response.responseTime).to.be.below(800);"] } }]
+            }]
+        });
+        let ctx = LintContext::new(&collection);
+
+        assert!(check_with_context(&collection, &ctx).is_empty());
+        let issues = check_with_config(&collection, &ctx, &ResponseTimeThresholdConfig { max_ms: 500 });
+        assert_eq!(issues.len(), 1);
+        assert!(matches!(issues[0].fix, Some(FixAction::AdjustThreshold { suggested_threshold: 500, .. })));
+    }
 }
@@ -0,0 +1,209 @@
+use crate::context::LintContext;
+use crate::{LintConfig, LintIssue};
+use crate::model::{Item, TypedItem};
+use crate::rules::{self, Category, ItemVisitor, Rule, RuleInput};
+use crate::utils::{self, ItemContext};
+use crate::i18n::Render;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::LazyLock;
+
+/// Configuration de la règle slo-alignment
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct SloAlignmentConfig {
+    /// Signaler aussi les folders sans SLO déclaré (ni hérité)
+    pub strict: bool,
+}
+
+/// Règle : slo-alignment
+///
+/// Un folder peut déclarer un SLO dans sa description (`SLO: 300ms`, `SLO: 1.5s`),
+/// hérité par ses sous-folders. Les assertions de temps de réponse des requêtes
+/// et scripts du folder ne doivent pas dépasser ce SLO (un test à 2000ms sous un
+/// SLO de 300ms ne détecte jamais une violation).
+///
+/// En mode `strict`, les folders sans SLO déclaré ni hérité sont aussi signalés.
+///
+/// Sévérité : WARNING (-8%)
+pub fn check(collection: &Value) -> Vec<LintIssue> {
+    check_with_context(collection, &LintContext::new(collection), &SloAlignmentConfig::default())
+}
+
+/// Version avec contexte partagé et configuration personnalisable
+pub fn check_with_context(collection: &Value, ctx: &LintContext, config: &SloAlignmentConfig) -> Vec<LintIssue> {
+    rules::visit(collection, ctx, &mut SloVisitor::new(config))
+}
+
+struct SloPatterns {
+    slo: Regex,
+    threshold: Regex,
+}
+
+static PATTERNS: LazyLock<SloPatterns> = LazyLock::new(|| SloPatterns {
+    slo: utils::build_regex(r"(?im)^\s*SLO\s*:\s*(\d+(?:\.\d+)?)\s*(ms|s)\b").unwrap(),
+    threshold: utils::build_regex(r"responseTime\)?\s*(?:\.to\.be\.(?:below|lessThan)\(\s*|<=?\s*)(\d+)").unwrap(),
+});
+
+/// SLO applicable : (valeur en ms, nom du folder déclarant)
+type Slo = Option<(u64, String)>;
+
+struct SloVisitor {
+    strict: bool,
+    /// SLO transmis par chaque folder parent, du plus haut au plus proche
+    folder_slos: Vec<Slo>,
+}
+
+impl SloVisitor {
+    fn new(config: &SloAlignmentConfig) -> Self {
+        SloVisitor { strict: config.strict, folder_slos: Vec::new() }
+    }
+}
+
+impl ItemVisitor for SloVisitor {
+    fn visit(&mut self, item: &ItemContext, typed: &TypedItem, ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        self.folder_slos.truncate(item.depth);
+        let parent_slo = self.folder_slos.last().cloned().flatten();
+
+        // Le SLO déclaré par un folder remplace celui hérité
+        let is_folder = typed.item.is_folder();
+        let slo = if is_folder {
+            declared_slo(typed.item).map(|ms| (ms, item.name.clone())).or(parent_slo)
+        } else {
+            parent_slo
+        };
+
+        if is_folder && slo.is_none() && self.strict {
+            issues.push(LintIssue::builder("slo-alignment")
+                .message_id("slo-alignment.missing-slo")
+                .param("folder", item.name.as_str())
+                .path(item.path.clone())
+                .render());
+        }
+
+        // Assertions de temps de réponse (requête ou script de folder)
+        if let Some((slo_ms, slo_folder)) = &slo {
+            let test_script = &ctx.scripts.get(&item.path).test;
+            let loosest = PATTERNS
+                .threshold
+                .captures_iter(test_script)
+                .filter_map(|caps| caps[1].parse::<u64>().ok())
+                .max();
+
+            if let Some(threshold) = loosest.filter(|threshold| threshold > slo_ms) {
+                issues.push(LintIssue::builder("slo-alignment")
+                    .message_id("slo-alignment.above-slo")
+                    .param("request", item.name.as_str())
+                    .param("threshold", threshold)
+                    .param("slo", *slo_ms)
+                    .param("folder", slo_folder.as_str())
+                    .path(item.path.clone())
+                    .render());
+            }
+        }
+
+        // Ses sous-items héritent de son SLO
+        if item.has_children() {
+            self.folder_slos.push(slo);
+        }
+    }
+}
+
+/// SLO déclaré dans la description du folder, converti en ms
+fn declared_slo(folder: &Item) -> Option<u64> {
+    let description = folder.description_text();
+    let caps = PATTERNS.slo.captures(&description)?;
+    let value: f64 = caps[1].parse().ok()?;
+    let factor = if caps[2].eq_ignore_ascii_case("s") { 1000.0 } else { 1.0 };
+    Some((value * factor).round() as u64)
+}
+
+/// Déclaration de la règle dans le registre
+pub struct SloAlignment;
+
+impl Rule for SloAlignment {
+    fn id(&self) -> &'static str {
+        "slo-alignment"
+    }
+
+    fn category(&self) -> Category {
+        Category::Performance
+    }
+
+    fn severity(&self) -> &'static str {
+        "warning"
+    }
+
+    fn description(&self) -> &'static str {
+        "Response-time assertions do not exceed the folder SLO declared as `SLO: 300ms`"
+    }
+
+    fn default_options(&self) -> Option<Value> {
+        serde_json::to_value(SloAlignmentConfig::default()).ok()
+    }
+
+    fn inputs(&self) -> &'static [RuleInput] {
+        &[RuleInput::Scripts]
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        Some(Box::new(SloVisitor::new(&config.options_for(self.id()))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn request_with_threshold(name: &str, threshold: u32) -> Value {
+        json!({
+            "name": name,
+            "request": { "method": "GET", "url": "{{base_url}}/users" },
+            "event": [{
+                "listen": "test",
+                "script": { "exec": [format!("pm.expect(pm.response.responseTime).to.be.below({});", threshold)] }
+            }]
+        })
+    }
+
+    #[test]
+    fn test_threshold_above_slo() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "Users",
+                "description": "User endpoints\nSLO: 300ms",
+                "item": [
+                    request_with_threshold("GET Users", 2000),
+                    request_with_threshold("GET User", 250),
+                    { "name": "Slow", "description": "SLO: 1.5s", "item": [request_with_threshold("GET Export", 1500)] }
+                ]
+            }]
+        });
+
+        let issues = check(&collection);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].path, "/item[0]/item[0]");
+        assert!(issues[0].message.contains("below 2000ms, above the 300ms SLO declared by folder \"Users\""));
+    }
+
+    #[test]
+    fn test_strict_mode_flags_folders_without_slo() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [
+                { "name": "Orders", "item": [request_with_threshold("GET Orders", 5000)] },
+                { "name": "Users", "description": "SLO: 500ms", "item": [{ "name": "Admin", "item": [] }] }
+            ]
+        });
+
+        assert_eq!(check(&collection).len(), 0);
+
+        let ctx = LintContext::new(&collection);
+        let issues = check_with_context(&collection, &ctx, &SloAlignmentConfig { strict: true });
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("Folder \"Orders\" does not declare an SLO"));
+    }
+}
@@ -0,0 +1,146 @@
+use crate::context::LintContext;
+use crate::{LintConfig, LintIssue};
+use crate::model::TypedItem;
+use crate::rules::{Category, ItemVisitor, Rule};
+use crate::utils::ItemContext;
+use crate::i18n::Render;
+use serde_json::Value;
+
+/// Règle : auth-override-awareness
+///
+/// Signale chaque folder ou requête qui remplace l'auth héritée (collection ou
+/// folder parent) par une auth d'un autre type. Dans l'UI Postman, ces surcharges
+/// passent facilement inaperçues en revue : une requête en `noauth` au milieu d'un
+/// folder `bearer` par exemple.
+///
+/// Seules les surcharges d'une auth explicitement déclarée plus haut sont signalées :
+/// un folder qui déclare une auth sous une collection sans auth n'est pas une surcharge.
+/// `inherit` (ou l'absence de bloc `auth`) n'est jamais une surcharge.
+///
+/// Sévérité : INFO (-3%)
+pub fn check(collection: &Value) -> Vec<LintIssue> {
+    AuthOverrideAwareness.check(collection, &LintContext::new(collection), &LintConfig::default())
+}
+
+/// Auth déclarée plus haut dans la hiérarchie, avec son origine
+struct InheritedAuth<'a> {
+    /// Type d'auth Postman ("bearer", "basic"...) ou "noauth"
+    auth_type: &'a str,
+    /// Folder qui la déclare (`None` pour la collection)
+    folder: Option<&'a str>,
+}
+
+/// Type d'un bloc `auth` explicite, `None` s'il hérite
+fn declared_auth(auth_type: Option<&str>) -> Option<&str> {
+    auth_type.filter(|auth_type| *auth_type != "inherit")
+}
+
+/// Déclaration de la règle dans le registre
+pub struct AuthOverrideAwareness;
+
+impl Rule for AuthOverrideAwareness {
+    fn id(&self) -> &'static str {
+        "auth-override-awareness"
+    }
+
+    fn category(&self) -> Category {
+        Category::Security
+    }
+
+    fn severity(&self) -> &'static str {
+        "info"
+    }
+
+    fn description(&self) -> &'static str {
+        "Folders/requests overriding the inherited auth with a different type"
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, _config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        Some(Box::new(AuthOverrideAwareness))
+    }
+}
+
+impl ItemVisitor for AuthOverrideAwareness {
+    fn visit(&mut self, item: &ItemContext, typed: &TypedItem, ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        let Some(auth_type) = declared_auth(typed.item.auth_type()) else {
+            return;
+        };
+
+        // Auth du folder parent le plus proche qui en déclare une, sinon de la collection
+        let inherited = typed
+            .folders
+            .iter()
+            .rev()
+            .find_map(|folder| {
+                declared_auth(folder.auth_type()).map(|auth_type| InheritedAuth {
+                    auth_type,
+                    folder: Some(folder.name.as_deref().unwrap_or("unknown")),
+                })
+            })
+            .or_else(|| declared_auth(ctx.model.auth_type()).map(|auth_type| InheritedAuth { auth_type, folder: None }));
+        let Some(parent) = inherited.filter(|parent| parent.auth_type != auth_type) else {
+            return;
+        };
+
+        let kind = if typed.item.is_request() { "request" } else { "folder" };
+        let issue = LintIssue::builder("auth-override-awareness")
+            .severity("info")
+            .param("item", typed.item.name.as_deref().unwrap_or("unknown"))
+            .param("inherited", parent.auth_type)
+            .param("auth", auth_type);
+        let issue = match parent.folder {
+            Some(folder) => issue
+                .message_id(&format!("auth-override-awareness.{}-from-folder", kind))
+                .param("folder", folder),
+            None => issue.message_id(&format!("auth-override-awareness.{}-from-collection", kind)),
+        };
+        issues.push(issue.path(item.path.clone()).render());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_overrides_are_reported() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "auth": { "type": "bearer" },
+            "item": [{
+                "name": "Legacy",
+                "auth": { "type": "basic" },
+                "item": [
+                    { "name": "GET Legacy", "request": { "method": "GET", "url": "{{base_url}}/legacy" } },
+                    { "name": "GET Health", "request": { "method": "GET", "url": "{{base_url}}/health", "auth": { "type": "noauth" } } }
+                ]
+            }]
+        });
+
+        let issues = check(&collection);
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].path, "/item[0]");
+        assert!(issues[0].message.contains("'bearer' auth inherited from the collection with 'basic'"));
+        assert_eq!(issues[1].path, "/item[0]/item[1]");
+        assert!(issues[1].message.contains("inherited from folder \"Legacy\" with 'noauth'"));
+        assert_eq!(issues[1].severity, "info");
+    }
+
+    #[test]
+    fn test_same_type_and_first_declaration_are_ignored() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "Users",
+                "auth": { "type": "bearer" },
+                "item": [
+                    { "name": "GET Users", "request": { "method": "GET", "url": "{{base_url}}/users", "auth": { "type": "bearer" } } },
+                    { "name": "GET User", "request": { "method": "GET", "url": "{{base_url}}/users/1", "auth": { "type": "inherit" } } }
+                ]
+            }]
+        });
+
+        assert!(check(&collection).is_empty());
+    }
+}
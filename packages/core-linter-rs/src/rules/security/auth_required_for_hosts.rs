@@ -0,0 +1,201 @@
+use crate::context::LintContext;
+use crate::{LintConfig, LintIssue};
+use crate::model::{Request, TypedItem};
+use crate::rules::{self, Category, ItemVisitor, Rule};
+use crate::utils::{self, ItemContext};
+use crate::i18n::Render;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Configuration de la règle auth-required-for-hosts
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct AuthRequiredForHostsConfig {
+    /// Hôtes protégés, avec jokers `*` (ex: "*.internal.example.com", "{{base_url}}")
+    pub protected_hosts: Vec<String>,
+}
+
+/// Règle : auth-required-for-hosts
+///
+/// Signale les requêtes vers un hôte déclaré comme protégé qui partent sans
+/// authentification : auth `noauth` (ou aucune auth héritée des folders / de la
+/// collection) et aucun header Authorization.
+///
+/// Inactive tant qu'aucun hôte protégé n'est configuré.
+///
+/// Sévérité : ERROR (-15%)
+pub fn check(collection: &Value) -> Vec<LintIssue> {
+    check_with_config(collection, &AuthRequiredForHostsConfig::default())
+}
+
+/// Version avec configuration personnalisable
+pub fn check_with_config(collection: &Value, config: &AuthRequiredForHostsConfig) -> Vec<LintIssue> {
+    match HostAuthVisitor::new(config) {
+        Some(mut visitor) => rules::visit(collection, &LintContext::new(collection), &mut visitor),
+        None => Vec::new(),
+    }
+}
+
+/// Auth effective d'un item (`auth_type` : le type de son bloc `auth`), sinon celle héritée
+fn resolve_auth(auth_type: Option<&str>, inherited: bool) -> bool {
+    match auth_type {
+        Some("inherit") | None => inherited,
+        Some(auth_type) => auth_type != "noauth",
+    }
+}
+
+fn has_authorization_header(request: &Request) -> bool {
+    request.header.iter().any(|header| {
+        !header.disabled && header.key.eq_ignore_ascii_case("authorization") && !header.value.trim().is_empty()
+    })
+}
+
+struct HostAuthVisitor {
+    /// Hôte protégé tel que configuré, avec sa regex
+    host_patterns: Vec<(String, Regex)>,
+}
+
+impl HostAuthVisitor {
+    /// `None` tant qu'aucun hôte protégé n'est configuré
+    fn new(config: &AuthRequiredForHostsConfig) -> Option<Self> {
+        let host_patterns: Vec<(String, Regex)> = config
+            .protected_hosts
+            .iter()
+            .filter_map(|host| utils::wildcard_regex(host).map(|re| (host.clone(), re)))
+            .collect();
+        (!host_patterns.is_empty()).then_some(HostAuthVisitor { host_patterns })
+    }
+}
+
+impl ItemVisitor for HostAuthVisitor {
+    fn visit(&mut self, item: &ItemContext, typed: &TypedItem, ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        let Some(request) = &typed.item.request else {
+            return;
+        };
+        let host = request.url.host();
+        let Some((pattern, _)) = self.host_patterns.iter().find(|(_, re)| re.is_match(&host)) else {
+            return;
+        };
+
+        // Auth héritée de la collection puis des folders parents, du plus haut au plus proche
+        let inherited = typed
+            .folders
+            .iter()
+            .fold(resolve_auth(ctx.model.auth_type(), false), |inherited, folder| resolve_auth(folder.auth_type(), inherited));
+        let authenticated = resolve_auth(typed.item.auth_type(), inherited);
+
+        if !authenticated && !has_authorization_header(request) {
+            issues.push(LintIssue::builder("auth-required-for-hosts")
+                .severity("error")
+                .message_id("auth-required-for-hosts.missing")
+                .param("request", item.name.as_str())
+                .param("host", host)
+                .param("pattern", pattern.as_str())
+                .path(format!("{}/request/auth", item.path))
+                .render());
+        }
+    }
+}
+
+/// Déclaration de la règle dans le registre
+pub struct AuthRequiredForHosts;
+
+impl Rule for AuthRequiredForHosts {
+    fn id(&self) -> &'static str {
+        "auth-required-for-hosts"
+    }
+
+    fn category(&self) -> Category {
+        Category::Security
+    }
+
+    fn severity(&self) -> &'static str {
+        "error"
+    }
+
+    fn description(&self) -> &'static str {
+        "No unauthenticated requests to protected hosts"
+    }
+
+    fn default_options(&self) -> Option<Value> {
+        serde_json::to_value(AuthRequiredForHostsConfig::default()).ok()
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        let options: AuthRequiredForHostsConfig = config.options_for(self.id());
+        HostAuthVisitor::new(&options).map(|visitor| Box::new(visitor) as Box<dyn ItemVisitor>)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn config(hosts: &[&str]) -> AuthRequiredForHostsConfig {
+        AuthRequiredForHostsConfig {
+            protected_hosts: hosts.iter().map(|h| h.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_noauth_on_protected_host() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "auth": { "type": "bearer" },
+            "item": [
+                {
+                    "name": "GET Admin Users",
+                    "request": { "method": "GET", "url": "https://admin.internal.example.com/users", "auth": { "type": "noauth" } }
+                },
+                {
+                    "name": "GET Public Status",
+                    "request": { "method": "GET", "url": "https://status.example.com/health", "auth": { "type": "noauth" } }
+                }
+            ]
+        });
+
+        let issues = check_with_config(&collection, &config(&["*.internal.example.com"]));
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].severity, "error");
+        assert_eq!(issues[0].path, "/item[0]/request/auth");
+    }
+
+    #[test]
+    fn test_inherited_auth_or_header_is_accepted() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [
+                {
+                    "name": "Secured",
+                    "auth": { "type": "apikey" },
+                    "item": [{ "name": "GET Orders", "request": { "method": "GET", "url": "{{base_url}}/orders" } }]
+                },
+                {
+                    "name": "GET Profile",
+                    "request": {
+                        "method": "GET",
+                        "url": "{{base_url}}/me",
+                        "header": [{ "key": "Authorization", "value": "Bearer {{token}}" }]
+                    }
+                },
+                { "name": "GET Unsecured", "request": { "method": "GET", "url": "{{base_url}}/debug" } }
+            ]
+        });
+
+        let issues = check_with_config(&collection, &config(&["{{base_url}}"]));
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("GET Unsecured"));
+    }
+
+    #[test]
+    fn test_inactive_without_protected_hosts() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [{ "name": "GET Users", "request": { "method": "GET", "url": "{{base_url}}/users", "auth": { "type": "noauth" } } }]
+        });
+
+        assert_eq!(check(&collection).len(), 0);
+    }
+}
@@ -0,0 +1,169 @@
+use crate::context::LintContext;
+use crate::{FixAction, LintConfig, LintIssue};
+use crate::model::TypedItem;
+use crate::rules::{self, Category, ItemVisitor, Rule};
+use crate::utils::{self, ItemContext};
+use crate::i18n::Render;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Configuration de la règle debug-headers
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct DebugHeadersConfig {
+    /// Headers interdits, insensibles à la casse, avec jokers `*`
+    pub denylist: Vec<String>,
+}
+
+impl Default for DebugHeadersConfig {
+    fn default() -> Self {
+        DebugHeadersConfig {
+            denylist: [
+                "X-Debug*",
+                "X-Test-Override*",
+                "X-Mock-Response*",
+                "X-Force-Error",
+                "X-Bypass-*",
+                "Access-Control-Allow-*",
+            ]
+            .iter()
+            .map(|s| s.to_string())
+            .collect(),
+        }
+    }
+}
+
+/// Règle : debug-headers
+///
+/// Détecte les headers de debug / de test / CORS laissés actifs dans les requêtes
+/// (X-Debug, X-Test-Override, X-Mock-Response...). Ils peuvent modifier le
+/// comportement du serveur et fausser les tests, voire ouvrir des accès en production.
+/// Les headers désactivés (`disabled: true`) sont ignorés.
+///
+/// Sévérité : WARNING (-8%)
+pub fn check(collection: &Value) -> Vec<LintIssue> {
+    check_with_config(collection, &DebugHeadersConfig::default())
+}
+
+/// Version avec configuration personnalisable
+pub fn check_with_config(collection: &Value, config: &DebugHeadersConfig) -> Vec<LintIssue> {
+    rules::visit(collection, &LintContext::new(collection), &mut DenylistVisitor::new(config))
+}
+
+struct DenylistVisitor {
+    denylist: Vec<Regex>,
+}
+
+impl DenylistVisitor {
+    fn new(config: &DebugHeadersConfig) -> Self {
+        DenylistVisitor { denylist: config.denylist.iter().filter_map(|header| utils::wildcard_regex(header)).collect() }
+    }
+}
+
+impl ItemVisitor for DenylistVisitor {
+    fn visit(&mut self, item: &ItemContext, typed: &TypedItem, _ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        let Some(request) = &typed.item.request else {
+            return;
+        };
+        for header in request.header.iter().filter(|header| !header.disabled) {
+            let key = header.key.as_str();
+            if self.denylist.iter().any(|re| re.is_match(key)) {
+                issues.push(LintIssue::builder("debug-headers")
+                    .message_id("debug-headers.found")
+                    .param("request", item.name.as_str())
+                    .param("header", key)
+                    .path(format!("{}/request/header", item.path))
+                    .fix(FixAction::DisableHeader {
+                        header: key.to_string(),
+                    })
+                    .render());
+            }
+        }
+    }
+}
+
+/// Déclaration de la règle dans le registre
+pub struct DebugHeaders;
+
+impl Rule for DebugHeaders {
+    fn id(&self) -> &'static str {
+        "debug-headers"
+    }
+
+    fn category(&self) -> Category {
+        Category::Security
+    }
+
+    fn severity(&self) -> &'static str {
+        "warning"
+    }
+
+    fn description(&self) -> &'static str {
+        "No debug/test/CORS headers left enabled"
+    }
+
+    fn default_options(&self) -> Option<Value> {
+        serde_json::to_value(DebugHeadersConfig::default()).ok()
+    }
+
+    fn fix_types(&self) -> &'static [&'static str] {
+        &["disable_header"]
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        Some(Box::new(DenylistVisitor::new(&config.options_for(self.id()))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn collection_with_headers(headers: Value) -> Value {
+        json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "GET Users",
+                "request": { "method": "GET", "url": "{{base_url}}/users", "header": headers }
+            }]
+        })
+    }
+
+    #[test]
+    fn test_debug_headers_detected() {
+        let collection = collection_with_headers(json!([
+            { "key": "x-debug-mode", "value": "true" },
+            { "key": "X-Mock-Response-Code", "value": "500" },
+            { "key": "Accept", "value": "application/json" }
+        ]));
+
+        let issues = check(&collection);
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].fix.as_ref().unwrap().fix_type(), "disable_header");
+        assert_eq!(issues[0].path, "/item[0]/request/header");
+    }
+
+    #[test]
+    fn test_disabled_headers_ignored() {
+        let collection = collection_with_headers(json!([
+            { "key": "X-Debug", "value": "1", "disabled": true }
+        ]));
+
+        assert_eq!(check(&collection).len(), 0);
+    }
+
+    #[test]
+    fn test_custom_denylist() {
+        let collection = collection_with_headers(json!([
+            { "key": "X-Debug", "value": "1" },
+            { "key": "X-Internal-Trace", "value": "1" }
+        ]));
+
+        let config = DebugHeadersConfig { denylist: vec!["x-internal-*".to_string()] };
+        let issues = check_with_config(&collection, &config);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("X-Internal-Trace"));
+    }
+}
@@ -1,6 +1,65 @@
-use crate::LintIssue;
+use crate::context::LintContext;
+use crate::{LintConfig, LintIssue};
+use crate::model::TypedItem;
+use crate::rules::{Category, ItemVisitor, Rule, RuleInput};
+use crate::utils::{self, ItemContext};
+use crate::i18n::Render;
 use regex::Regex;
 use serde_json::Value;
+use std::sync::LazyLock;
+
+/// Patterns de secrets : (regex, type de secret, variable suggérée)
+pub const SECRET_PATTERNS: &[(&str, &str, &str)] = &[
+    // API Keys
+    (r#"api[_-]?key\s*[=:]\s*["']?([a-zA-Z0-9_\-]{20,})["']?"#, "API Key", "{{api_key}}"),
+    (r#"apikey\s*[=:]\s*["']?([a-zA-Z0-9_\-]{20,})["']?"#, "API Key", "{{api_key}}"),
+    
+    // Bearer Tokens
+    (r#"(?i)bearer\s+([a-zA-Z0-9_\-\.]{20,})"#, "Bearer Token", "{{auth_token}}"),
+    (r#"token\s*[=:]\s*["']?([a-zA-Z0-9_\-\.]{20,})["']?"#, "Token", "{{auth_token}}"),
+    
+    // AWS Keys
+    (r"AKIA[0-9A-Z]{16}", "AWS Access Key", "{{aws_access_key}}"),
+    (r#"aws[_-]?secret[_-]?access[_-]?key\s*[=:]\s*["']?([a-zA-Z0-9/\+]{40})["']?"#, "AWS Secret Key", "{{aws_secret_key}}"),
+    
+    // Private Keys
+    (r"-----BEGIN\s+(?:RSA\s+)?PRIVATE\s+KEY-----", "Private Key", "{{private_key}}"),
+    
+    // Passwords (la classe de caractères exclut les variables {{...}})
+    (r"password=([a-zA-Z0-9]{3,})", "Password", "{{password}}"),
+    (r"pwd=([a-zA-Z0-9]{3,})", "Password", "{{password}}"),
+    
+    // Generic secrets
+    (r#"secret\s*[=:]\s*["']([^"'\s]{8,})["']"#, "Secret", "{{secret}}"),
+    (r#"client[_-]?secret\s*[=:]\s*["']?([a-zA-Z0-9_\-]{20,})["']?"#, "Client Secret", "{{client_secret}}"),
+    
+    // Database credentials (bornés à une chaîne JSON pour ne pas balayer toute la requête sérialisée)
+    (r#"jdbc:[^\s"]*password=([^&\s"]+)"#, "Database Password", "{{db_password}}"),
+    (r#"mongodb(?:\+srv)?://[^:/\s"@]+:([^@/\s"]+)@"#, "MongoDB Password", "{{mongo_password}}"),
+    
+    // OAuth
+    (r#"client_id\s*[=:]\s*["']?([a-zA-Z0-9_\-]{20,})["']?"#, "OAuth Client ID", "{{client_id}}"),
+    
+    // Slack tokens
+    (r"xox[baprs]-[0-9]{10,13}-[0-9]{10,13}-[a-zA-Z0-9]{24,}", "Slack Token", "{{slack_token}}"),
+    
+    // GitHub tokens
+    (r"gh[pousr]_[A-Za-z0-9_]{36,}", "GitHub Token", "{{github_token}}"),
+    
+    // Stripe keys
+    (r"sk_live_[a-zA-Z0-9]{24,}", "Stripe Secret Key", "{{stripe_secret_key}}"),
+    (r"pk_live_[a-zA-Z0-9]{24,}", "Stripe Publishable Key", "{{stripe_public_key}}"),
+];
+
+/// Patterns compilés une seule fois pour toutes les analyses
+static SECRET_REGEXES: LazyLock<Vec<(Regex, &str, &str)>> = LazyLock::new(|| {
+    SECRET_PATTERNS
+        .iter()
+        .filter_map(|(pattern, type_name, suggestion)| {
+            utils::build_regex(pattern).ok().map(|r| (r, *type_name, *suggestion))
+        })
+        .collect()
+});
 
 /// Règle : hardcoded-secrets
 /// 
@@ -17,103 +76,14 @@ use serde_json::Value;
 /// - OAuth tokens
 /// - Slack/GitHub/Stripe tokens
 pub fn check(collection: &Value) -> Vec<LintIssue> {
-    let mut issues = Vec::new();
-    
-    // Définir les patterns de secrets
-    let secret_patterns = vec![
-        // API Keys
-        (r#"api[_-]?key\s*[=:]\s*["']?([a-zA-Z0-9_\-]{20,})["']?"#, "API Key", "{{api_key}}"),
-        (r#"apikey\s*[=:]\s*["']?([a-zA-Z0-9_\-]{20,})["']?"#, "API Key", "{{api_key}}"),
-        
-        // Bearer Tokens
-        (r#"bearer\s+([a-zA-Z0-9_\-\.]{20,})"#, "Bearer Token", "{{auth_token}}"),
-        (r#"token\s*[=:]\s*["']?([a-zA-Z0-9_\-\.]{20,})["']?"#, "Token", "{{auth_token}}"),
-        
-        // AWS Keys
-        (r"AKIA[0-9A-Z]{16}", "AWS Access Key", "{{aws_access_key}}"),
-        (r#"aws[_-]?secret[_-]?access[_-]?key\s*[=:]\s*["']?([a-zA-Z0-9/\+]{40})["']?"#, "AWS Secret Key", "{{aws_secret_key}}"),
-        
-        // Private Keys
-        (r"-----BEGIN\s+(?:RSA\s+)?PRIVATE\s+KEY-----", "Private Key", "{{private_key}}"),
-        
-        // Passwords (exclure les variables {{...}})
-        (r"password=(?!{{)[a-zA-Z0-9]{3,}", "Password", "{{password}}"),
-        (r"pwd=(?!{{)[a-zA-Z0-9]{3,}", "Password", "{{password}}"),
-        
-        // Generic secrets
-        (r#"secret\s*[=:]\s*["']([^"'\s]{8,})["']"#, "Secret", "{{secret}}"),
-        (r#"client[_-]?secret\s*[=:]\s*["']?([a-zA-Z0-9_\-]{20,})["']?"#, "Client Secret", "{{client_secret}}"),
-        
-        // Database credentials
-        (r"jdbc:.*password=([^&\s]+)", "Database Password", "{{db_password}}"),
-        (r"mongodb(?:\+srv)?://[^:]+:([^@]+)@", "MongoDB Password", "{{mongo_password}}"),
-        
-        // OAuth
-        (r#"client_id\s*[=:]\s*["']?([a-zA-Z0-9_\-]{20,})["']?"#, "OAuth Client ID", "{{client_id}}"),
-        
-        // Slack tokens
-        (r"xox[baprs]-[0-9]{10,13}-[0-9]{10,13}-[a-zA-Z0-9]{24,}", "Slack Token", "{{slack_token}}"),
-        
-        // GitHub tokens
-        (r"gh[pousr]_[A-Za-z0-9_]{36,}", "GitHub Token", "{{github_token}}"),
-        
-        // Stripe keys
-        (r"sk_live_[a-zA-Z0-9]{24,}", "Stripe Secret Key", "{{stripe_secret_key}}"),
-        (r"pk_live_[a-zA-Z0-9]{24,}", "Stripe Publishable Key", "{{stripe_public_key}}"),
-    ];
-    
-    // Compiler les regex
-    let compiled_patterns: Vec<(Regex, &str, &str)> = secret_patterns
-        .iter()
-        .filter_map(|(pattern, type_name, suggestion)| {
-            Regex::new(pattern).ok().map(|r| (r, *type_name, *suggestion))
-        })
-        .collect();
-    
-    if let Some(items) = collection["item"].as_array() {
-        check_items(items, &compiled_patterns, &mut issues, "");
-    }
-    
-    issues
+    HardcodedSecrets.check(collection, &LintContext::new(collection), &LintConfig::default())
 }
 
-fn check_items(
-    items: &[Value],
-    patterns: &[(Regex, &str, &str)],
-    issues: &mut Vec<LintIssue>,
-    parent_path: &str,
-) {
-    for (index, item) in items.iter().enumerate() {
-        let item_name = item["name"].as_str().unwrap_or("unknown");
-        let current_path = if parent_path.is_empty() {
-            format!("/item[{}]", index)
-        } else {
-            format!("{}/item[{}]", parent_path, index)
-        };
-        
-        // Vérifier la requête
-        if let Some(request) = item.get("request") {
-            check_request_for_secrets(request, patterns, issues, &current_path, item_name);
-        }
-        
-        // Récursion pour les sous-dossiers
-        if let Some(sub_items) = item["item"].as_array() {
-            check_items(sub_items, patterns, issues, &current_path);
-        }
-    }
-}
-
-fn check_request_for_secrets(
-    request: &Value,
-    patterns: &[(Regex, &str, &str)],
-    issues: &mut Vec<LintIssue>,
-    path: &str,
-    item_name: &str,
-) {
+fn check_request_for_secrets(request: &Value, issues: &mut Vec<LintIssue>, path: &str, item_name: &str) {
     // Convertir la requête en string pour chercher les secrets
     let request_str = serde_json::to_string(request).unwrap_or_default();
     
-    for (regex, secret_type, suggestion) in patterns {
+    for (regex, secret_type, suggestion) in SECRET_REGEXES.iter() {
         if let Some(captures) = regex.captures(&request_str) {
             if let Some(matched) = captures.get(0) {
                 let matched_str = matched.as_str();
@@ -126,17 +96,15 @@ fn check_request_for_secrets(
                         matched_str.to_string()
                     };
                     
-                    issues.push(LintIssue {
-                        rule_id: "hardcoded-secrets".to_string(),
-                        severity: "error".to_string(),
-                        message: format!(
-                            "🔒 {} hardcodé détecté \"{}\" dans '{}' - Utilisez des variables d'environnement ({})",
-                            secret_type, preview, item_name, suggestion
-                        ),
-                        path: format!("{}/request", path),
-                        line: None,
-                        fix: None,
-                    });
+                    issues.push(LintIssue::builder("hardcoded-secrets")
+                        .severity("error")
+                        .message_id("hardcoded-secrets.found")
+                        .param("secret_type", *secret_type)
+                        .param("preview", preview)
+                        .param("request", item_name)
+                        .param("suggestion", *suggestion)
+                        .path(format!("{}/request", path))
+                        .render());
                     
                     // Ne rapporter qu'une seule fois par type de secret par requête
                     break;
@@ -146,6 +114,44 @@ fn check_request_for_secrets(
     }
 }
 
+/// Déclaration de la règle dans le registre
+pub struct HardcodedSecrets;
+
+impl Rule for HardcodedSecrets {
+    fn id(&self) -> &'static str {
+        "hardcoded-secrets"
+    }
+
+    fn category(&self) -> Category {
+        Category::Security
+    }
+
+    fn severity(&self) -> &'static str {
+        "error"
+    }
+
+    fn description(&self) -> &'static str {
+        "Detect hardcoded secrets (API keys, tokens, passwords)"
+    }
+
+    fn inputs(&self) -> &'static [RuleInput] {
+        &[RuleInput::RequestContent]
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, _config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        Some(Box::new(HardcodedSecrets))
+    }
+}
+
+impl ItemVisitor for HardcodedSecrets {
+    fn visit(&mut self, item: &ItemContext, _typed: &TypedItem, _ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        if let Some(request) = item.item.get("request") {
+            let item_name = item.item["name"].as_str().unwrap_or("unknown");
+            check_request_for_secrets(request, issues, &item.path, item_name);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -168,14 +174,13 @@ mod tests {
         });
         
         let issues = check(&collection);
-        assert!(issues.len() > 0);
+        assert!(!issues.is_empty());
         assert_eq!(issues[0].rule_id, "hardcoded-secrets");
         assert_eq!(issues[0].severity, "error");
         assert!(issues[0].message.contains("API Key"));
     }
 
     #[test]
-    #[ignore] // TODO: Fix password pattern detection
     fn test_password_detected() {
         let collection = json!({
             "info": { "name": "Test" },
@@ -192,7 +197,7 @@ mod tests {
         });
         
         let issues = check(&collection);
-        assert!(issues.len() > 0, "Should detect password in URL or body");
+        assert!(!issues.is_empty(), "Should detect password in URL or body");
         assert!(issues[0].message.contains("Password"));
     }
 
@@ -233,7 +238,38 @@ mod tests {
         });
         
         let issues = check(&collection);
-        assert!(issues.len() > 0);
+        assert!(!issues.is_empty());
         assert!(issues[0].message.contains("AWS Access Key"));
     }
+
+    #[test]
+    fn test_adversarial_inputs() {
+        // Requête de plusieurs Mo avec des préfixes de patterns jamais complétés
+        let body = format!(
+            "{}{}{}",
+            "jdbc:postgresql://db?user=".repeat(20_000),
+            "mongodb://user".repeat(20_000),
+            "a".repeat(1_000_000)
+        );
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "Huge Request",
+                "request": {
+                    "url": "https://api.example.com",
+                    "body": { "mode": "raw", "raw": body }
+                }
+            }]
+        });
+
+        let issues = check(&collection);
+        assert_eq!(issues.len(), 0);
+    }
+
+    #[test]
+    fn test_all_patterns_compile() {
+        for (pattern, _, _) in SECRET_PATTERNS {
+            assert!(utils::build_regex(pattern).is_ok(), "pattern failed to compile: {}", pattern);
+        }
+    }
 }
@@ -0,0 +1,203 @@
+use crate::context::LintContext;
+use crate::{LintConfig, LintIssue};
+use crate::model::TypedItem;
+use crate::rules::{Category, ItemVisitor, Rule};
+use crate::utils::{self, ItemContext};
+use crate::i18n::Render;
+use regex::Regex;
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::LazyLock;
+
+/// Variable de path (`{{id}}`, `:id`), normalisée en `{}`
+static PATH_PARAM: LazyLock<Regex> = LazyLock::new(|| utils::build_regex(r"\{\{[^}]+\}\}|^:.+$").unwrap());
+
+/// Règle : inconsistent-auth-for-same-endpoint
+///
+/// Détecte un même endpoint (méthode + path) appelé par plusieurs requêtes avec
+/// des authentifications effectives différentes (héritée, basic, aucune...).
+/// Source fréquente d'appels non authentifiés par erreur.
+///
+/// L'auth effective d'une requête est son bloc `auth`, sinon celui du folder parent
+/// le plus proche, sinon celui de la collection. Les variables de path (`{{id}}`, `:id`)
+/// sont normalisées pour regrouper les appels au même endpoint.
+///
+/// Sévérité : WARNING (-8%)
+pub fn check(collection: &Value) -> Vec<LintIssue> {
+    InconsistentAuthForSameEndpoint.check(collection, &LintContext::new(collection), &LintConfig::default())
+}
+
+/// Authentification effective d'une requête
+struct EffectiveAuth {
+    /// Type d'auth Postman ("bearer", "basic", "apikey"...) ou "none"
+    auth_type: String,
+    inherited: bool,
+}
+
+impl EffectiveAuth {
+    fn describe(&self) -> String {
+        if self.inherited {
+            format!("'{}' (inherited)", self.auth_type)
+        } else {
+            format!("'{}'", self.auth_type)
+        }
+    }
+}
+
+struct EndpointCall {
+    endpoint: String,
+    name: String,
+    path: String,
+    auth: EffectiveAuth,
+}
+
+/// Résout le type du bloc `auth` d'un item, ou retourne l'auth héritée s'il n'en déclare pas
+fn resolve_auth(auth_type: Option<&str>, inherited: Option<&EffectiveAuth>) -> EffectiveAuth {
+    match auth_type {
+        Some("noauth") => EffectiveAuth { auth_type: "none".to_string(), inherited: false },
+        Some("inherit") | None => inherited
+            .map(|parent| EffectiveAuth { auth_type: parent.auth_type.clone(), inherited: true })
+            .unwrap_or(EffectiveAuth { auth_type: "none".to_string(), inherited: false }),
+        Some(auth_type) => EffectiveAuth { auth_type: auth_type.to_string(), inherited: false },
+    }
+}
+
+/// Collecte les appels pendant le parcours, compare les groupes à la fin
+#[derive(Default)]
+struct EndpointVisitor {
+    calls: Vec<EndpointCall>,
+}
+
+impl ItemVisitor for EndpointVisitor {
+    fn visit(&mut self, item: &ItemContext, typed: &TypedItem, ctx: &LintContext, _issues: &mut Vec<LintIssue>) {
+        let Some(request) = &typed.item.request else {
+            return;
+        };
+        let url_path = request.url.path();
+        let normalized: Vec<String> = url_path
+            .trim_end_matches('/')
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| PATH_PARAM.replace_all(segment, "{}").to_lowercase())
+            .collect();
+
+        // Auth de la collection, puis des folders parents du plus haut au plus proche
+        let parent_auth = typed
+            .folders
+            .iter()
+            .fold(resolve_auth(ctx.model.auth_type(), None), |parent, folder| resolve_auth(folder.auth_type(), Some(&parent)));
+
+        self.calls.push(EndpointCall {
+            endpoint: format!("{} /{}", request.method(), normalized.join("/")),
+            name: typed.item.name.clone().unwrap_or_else(|| "unknown".to_string()),
+            path: item.path.clone(),
+            auth: resolve_auth(typed.item.auth_type(), Some(&parent_auth)),
+        });
+    }
+
+    fn finish(&mut self, _ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        // Regrouper par endpoint
+        let mut endpoints: BTreeMap<&str, Vec<&EndpointCall>> = BTreeMap::new();
+        for call in &self.calls {
+            endpoints.entry(call.endpoint.as_str()).or_default().push(call);
+        }
+
+        for (endpoint, group) in endpoints {
+            let auth_types: BTreeSet<&str> = group.iter().map(|c| c.auth.auth_type.as_str()).collect();
+            if auth_types.len() < 2 {
+                continue;
+            }
+
+            for call in &group {
+                let others: Vec<String> = group
+                    .iter()
+                    .filter(|other| other.auth.auth_type != call.auth.auth_type)
+                    .map(|other| format!("\"{}\" ({})", other.name, other.auth.describe()))
+                    .collect();
+
+                issues.push(LintIssue::builder("inconsistent-auth-for-same-endpoint")
+                    .message_id("inconsistent-auth-for-same-endpoint.mismatch")
+                    .param("request", call.name.as_str())
+                    .param("endpoint", endpoint)
+                    .param("auth", call.auth.describe())
+                    .param("others", others.join(", "))
+                    .path(call.path.clone())
+                    .render());
+            }
+        }
+    }
+}
+
+/// Déclaration de la règle dans le registre
+pub struct InconsistentAuthForSameEndpoint;
+
+impl Rule for InconsistentAuthForSameEndpoint {
+    fn id(&self) -> &'static str {
+        "inconsistent-auth-for-same-endpoint"
+    }
+
+    fn category(&self) -> Category {
+        Category::Security
+    }
+
+    fn severity(&self) -> &'static str {
+        "warning"
+    }
+
+    fn description(&self) -> &'static str {
+        "Same method+path called with different auth configurations"
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, _config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        Some(Box::<EndpointVisitor>::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_same_endpoint_with_different_auth() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "auth": { "type": "bearer" },
+            "item": [
+                {
+                    "name": "GET User",
+                    "request": { "method": "GET", "url": "{{base_url}}/users/{{userId}}" }
+                },
+                {
+                    "name": "GET User (debug)",
+                    "request": { "method": "GET", "url": "{{base_url}}/users/{{id}}", "auth": { "type": "noauth" } }
+                }
+            ]
+        });
+
+        let issues = check(&collection);
+        assert_eq!(issues.len(), 2);
+        assert!(issues[0].message.contains("GET /users/{}"));
+        assert!(issues[0].message.contains("'bearer' (inherited)"));
+        assert!(issues[1].message.contains("auth 'none'"));
+    }
+
+    #[test]
+    fn test_consistent_auth_is_ignored() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "Orders",
+                "auth": { "type": "basic" },
+                "item": [
+                    { "name": "GET Orders", "request": { "method": "GET", "url": "{{base_url}}/orders" } },
+                    { "name": "GET Orders (explicit)", "request": { "method": "GET", "url": "{{base_url}}/orders/", "auth": { "type": "basic" } } },
+                    { "name": "POST Order", "request": { "method": "POST", "url": "{{base_url}}/orders", "auth": { "type": "noauth" } } }
+                ]
+            }]
+        });
+
+        let issues = check(&collection);
+        assert_eq!(issues.len(), 0);
+    }
+}
@@ -0,0 +1,224 @@
+use crate::context::LintContext;
+use crate::{LintConfig, LintIssue};
+use crate::model::TypedItem;
+use crate::rules::{self, Category, ItemVisitor, Rule, RuleInput};
+use crate::utils::{self, ItemContext};
+use crate::i18n::Render;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::LazyLock;
+
+/// Configuration de la règle internal-hosts-disclosure
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct InternalHostsDisclosureConfig {
+    /// La collection est destinée à une publication externe
+    pub public: bool,
+    /// Suffixes de domaines internes (ex: ".corp", ".internal")
+    pub internal_suffixes: Vec<String>,
+}
+
+impl Default for InternalHostsDisclosureConfig {
+    fn default() -> Self {
+        InternalHostsDisclosureConfig {
+            public: false,
+            internal_suffixes: [".corp", ".internal", ".intranet", ".lan", ".local"]
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+        }
+    }
+}
+
+/// Règle : internal-hosts-disclosure
+///
+/// Dans une collection publique, signale les adresses IP privées
+/// (10.x, 172.16-31.x, 192.168.x) et les noms de domaines internes (.corp, .internal...)
+/// présents dans les URLs des requêtes ou dans les exemples de réponses.
+///
+/// Inactive tant que la collection n'est pas déclarée publique (`public: true`).
+///
+/// Sévérité : WARNING (-8%)
+pub fn check(collection: &Value) -> Vec<LintIssue> {
+    check_with_config(collection, &InternalHostsDisclosureConfig::default())
+}
+
+/// Version avec configuration personnalisable
+pub fn check_with_config(collection: &Value, config: &InternalHostsDisclosureConfig) -> Vec<LintIssue> {
+    if !config.public {
+        return Vec::new();
+    }
+    rules::visit(collection, &LintContext::new(collection), &mut HostDetector::new(&config.internal_suffixes))
+}
+
+/// Adresses IPv4 privées (RFC 1918)
+static PRIVATE_IP: LazyLock<Regex> = LazyLock::new(|| {
+    utils::build_regex(
+        r"\b(?:10\.\d{1,3}\.\d{1,3}\.\d{1,3}|172\.(?:1[6-9]|2\d|3[01])\.\d{1,3}\.\d{1,3}|192\.168\.\d{1,3}\.\d{1,3})\b",
+    )
+    .unwrap()
+});
+/// Noms d'hôtes (au moins un point)
+static HOSTNAME: LazyLock<Regex> = LazyLock::new(|| {
+    utils::build_regex(r"(?i)[a-z0-9](?:[a-z0-9-]*[a-z0-9])?(?:\.[a-z0-9](?:[a-z0-9-]*[a-z0-9])?)+").unwrap()
+});
+
+/// Détecteur d'IP privées et de domaines internes
+pub struct HostDetector {
+    suffixes: Vec<String>,
+}
+
+impl HostDetector {
+    pub fn new(suffixes: &[String]) -> Self {
+        HostDetector { suffixes: suffixes.iter().map(|s| s.to_lowercase()).collect() }
+    }
+
+    /// Hôtes internes trouvés dans un texte (sans doublons, dans l'ordre d'apparition)
+    pub fn find(&self, text: &str) -> Vec<String> {
+        let mut found: Vec<String> = Vec::new();
+
+        for ip in PRIVATE_IP.find_iter(text) {
+            if !found.iter().any(|f| f == ip.as_str()) {
+                found.push(ip.as_str().to_string());
+            }
+        }
+
+        for host in HOSTNAME.find_iter(text) {
+            let lower = host.as_str().to_lowercase();
+            if self.suffixes.iter().any(|suffix| lower.ends_with(suffix.as_str())) && !found.contains(&lower) {
+                found.push(lower);
+            }
+        }
+
+        found
+    }
+}
+
+impl ItemVisitor for HostDetector {
+    fn visit(&mut self, item: &ItemContext, typed: &TypedItem, _ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        // URL de la requête
+        if let Some(request) = &typed.item.request {
+            let hosts = self.find(request.url.raw());
+            if !hosts.is_empty() {
+                issues.push(disclosure_issue(&item.name, "url", &hosts, format!("{}/request/url", item.path)));
+            }
+        }
+
+        // Exemples de réponses (body + URL de la requête d'origine)
+        for (response_index, response) in typed.item.response.iter().enumerate() {
+            let original_url = response.original_request.as_ref().map_or("", |request| request.url.raw());
+            let hosts = self.find(&format!("{}\n{}", response.body, original_url));
+            if !hosts.is_empty() {
+                issues.push(disclosure_issue(
+                    &item.name,
+                    "example",
+                    &hosts,
+                    format!("{}/response[{}]", item.path, response_index),
+                ));
+            }
+        }
+    }
+}
+
+/// `location` : "url" ou "example"
+fn disclosure_issue(item_name: &str, location: &str, hosts: &[String], path: String) -> LintIssue {
+    LintIssue::builder("internal-hosts-disclosure")
+        .message_id(&format!("internal-hosts-disclosure.{}", location))
+        .param("request", item_name)
+        .param("hosts", hosts.join(", "))
+        .path(path)
+        .render()
+}
+
+/// Déclaration de la règle dans le registre
+pub struct InternalHostsDisclosure;
+
+impl Rule for InternalHostsDisclosure {
+    fn id(&self) -> &'static str {
+        "internal-hosts-disclosure"
+    }
+
+    fn category(&self) -> Category {
+        Category::Security
+    }
+
+    fn severity(&self) -> &'static str {
+        "warning"
+    }
+
+    fn description(&self) -> &'static str {
+        "No private IPs or internal domains in public collections"
+    }
+
+    fn default_options(&self) -> Option<Value> {
+        serde_json::to_value(InternalHostsDisclosureConfig::default()).ok()
+    }
+
+    fn inputs(&self) -> &'static [RuleInput] {
+        &[RuleInput::RequestContent]
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        let options: InternalHostsDisclosureConfig = config.options_for(self.id());
+        // Le profil de visibilité "public" active la règle en mode public
+        let public = options.public || config.visibility.unwrap_or_default().is_public();
+        public.then(|| Box::new(HostDetector::new(&options.internal_suffixes)) as Box<dyn ItemVisitor>)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn public_config() -> InternalHostsDisclosureConfig {
+        InternalHostsDisclosureConfig { public: true, ..Default::default() }
+    }
+
+    #[test]
+    fn test_private_ip_and_internal_domain() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "GET Users",
+                "request": { "method": "GET", "url": "http://10.0.12.4:8080/users" },
+                "response": [{
+                    "name": "200 OK",
+                    "code": 200,
+                    "body": "{\"avatar\": \"https://cdn.build.corp/u/1.png\", \"ip\": \"172.20.1.9\"}"
+                }]
+            }]
+        });
+
+        let issues = check_with_config(&collection, &public_config());
+        assert_eq!(issues.len(), 2);
+        assert!(issues[0].message.contains("10.0.12.4"));
+        assert_eq!(issues[1].path, "/item[0]/response[0]");
+        assert!(issues[1].message.contains("172.20.1.9"));
+        assert!(issues[1].message.contains("cdn.build.corp"));
+    }
+
+    #[test]
+    fn test_public_addresses_ignored() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "GET Users",
+                "request": { "method": "GET", "url": "https://172.32.0.1/users?host=api.example.com" }
+            }]
+        });
+
+        assert_eq!(check_with_config(&collection, &public_config()).len(), 0);
+    }
+
+    #[test]
+    fn test_inactive_for_non_public_collections() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [{ "name": "GET Users", "request": { "method": "GET", "url": "http://192.168.1.10/users" } }]
+        });
+
+        assert_eq!(check(&collection).len(), 0);
+    }
+}
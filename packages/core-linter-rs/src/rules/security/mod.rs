@@ -1 +1,20 @@
+use super::Rule;
+
 pub mod hardcoded_secrets;
+pub mod inconsistent_auth_for_same_endpoint;
+pub mod auth_override_awareness;
+pub mod auth_required_for_hosts;
+pub mod debug_headers;
+pub mod test_payload_residue;
+pub mod internal_hosts_disclosure;
+
+/// Règles de la catégorie, dans l'ordre d'exécution
+pub const RULES: &[&dyn Rule] = &[
+    &hardcoded_secrets::HardcodedSecrets,
+    &inconsistent_auth_for_same_endpoint::InconsistentAuthForSameEndpoint,
+    &auth_override_awareness::AuthOverrideAwareness,
+    &auth_required_for_hosts::AuthRequiredForHosts,
+    &debug_headers::DebugHeaders,
+    &test_payload_residue::TestPayloadResidue,
+    &internal_hosts_disclosure::InternalHostsDisclosure,
+];
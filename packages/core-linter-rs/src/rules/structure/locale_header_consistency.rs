@@ -0,0 +1,189 @@
+use crate::context::LintContext;
+use crate::{LintConfig, LintIssue};
+use crate::model::TypedItem;
+use crate::rules::{Category, ItemVisitor, Rule};
+use crate::utils::{self, ItemContext};
+use crate::i18n::Render;
+use regex::Regex;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+/// Tag de langue BCP 47, avec un poids `q` optionnel
+static LANGUAGE_TAG: LazyLock<Regex> = LazyLock::new(|| {
+    utils::build_regex(
+        r"^(?:\*|[A-Za-z]{2,3}(?:-[A-Za-z]{4})?(?:-(?:[A-Za-z]{2}|\d{3}))?(?:-(?:[A-Za-z0-9]{5,8}|\d[A-Za-z0-9]{3}))*)(?:\s*;\s*q=(?:0(?:\.\d{1,3})?|1(?:\.0{1,3})?))?$",
+    )
+    .unwrap()
+});
+
+/// Règle : locale-header-consistency
+///
+/// Vérifie les headers `Accept-Language` :
+/// - chaque langue est un tag BCP-47 valide (`fr`, `fr-FR`, `zh-Hant-TW`, `*`),
+///   avec un poids `;q=` optionnel (`en_US` ou `english` sont signalés)
+/// - la même valeur est utilisée dans toute la collection, idéalement pilotée
+///   par une variable (`{{accept_language}}`)
+///
+/// Sévérité : WARNING (-8%)
+pub fn check(collection: &Value) -> Vec<LintIssue> {
+    LocaleHeaderConsistency.check(collection, &LintContext::new(collection), &LintConfig::default())
+}
+
+struct LocaleHeader {
+    name: String,
+    path: String,
+    value: String,
+}
+
+fn first_index(headers: &[LocaleHeader], value: &str) -> usize {
+    headers.iter().position(|h| h.value == value).unwrap_or(0)
+}
+
+/// Valeur entièrement pilotée par une variable Postman
+fn is_variable(value: &str) -> bool {
+    let value = value.trim();
+    value.starts_with("{{") && value.ends_with("}}") && value.matches("{{").count() == 1
+}
+
+/// Relève les headers pendant le parcours ; la valeur dominante n'est connue qu'à la fin
+#[derive(Default)]
+struct LocaleVisitor {
+    headers: Vec<LocaleHeader>,
+}
+
+impl ItemVisitor for LocaleVisitor {
+    fn visit(&mut self, item: &ItemContext, typed: &TypedItem, _ctx: &LintContext, _issues: &mut Vec<LintIssue>) {
+        let Some(request) = &typed.item.request else {
+            return;
+        };
+        for (header_index, header) in request.header.iter().enumerate() {
+            if header.key.eq_ignore_ascii_case("accept-language") && !header.disabled {
+                self.headers.push(LocaleHeader {
+                    name: typed.item.name.clone().unwrap_or_else(|| "unknown".to_string()),
+                    path: format!("{}/request/header[{}]", item.path, header_index),
+                    value: header.value.trim().to_string(),
+                });
+            }
+        }
+    }
+
+    fn finish(&mut self, _ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        let headers = &self.headers;
+
+        // Valeur la plus répandue (à égalité, la première rencontrée)
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for header in headers {
+            *counts.entry(header.value.as_str()).or_default() += 1;
+        }
+        let dominant = headers
+            .iter()
+            .map(|h| h.value.as_str())
+            .max_by_key(|value| (counts[value], std::cmp::Reverse(first_index(headers, value))));
+
+        for header in headers {
+            let invalid: Vec<&str> = if is_variable(&header.value) {
+                Vec::new()
+            } else {
+                header
+                    .value
+                    .split(',')
+                    .map(|range| range.trim())
+                    .filter(|range| !LANGUAGE_TAG.is_match(range))
+                    .collect()
+            };
+
+            let issue = LintIssue::builder("locale-header-consistency").param("request", header.name.as_str());
+            let issue = if !invalid.is_empty() {
+                issue.message_id("locale-header-consistency.invalid").param("values", invalid.join(", "))
+            } else if counts.len() > 1 && Some(header.value.as_str()) != dominant {
+                issue
+                    .message_id("locale-header-consistency.inconsistent")
+                    .param("value", header.value.as_str())
+                    .param("dominant", dominant.unwrap_or_default())
+            } else {
+                continue;
+            };
+
+            issues.push(issue.path(header.path.clone()).render());
+        }
+    }
+}
+
+/// Déclaration de la règle dans le registre
+pub struct LocaleHeaderConsistency;
+
+impl Rule for LocaleHeaderConsistency {
+    fn id(&self) -> &'static str {
+        "locale-header-consistency"
+    }
+
+    fn category(&self) -> Category {
+        Category::Structure
+    }
+
+    fn severity(&self) -> &'static str {
+        "warning"
+    }
+
+    fn description(&self) -> &'static str {
+        "`Accept-Language` headers use valid BCP-47 tags and a consistent value (or a variable)"
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, _config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        Some(Box::<LocaleVisitor>::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn request(name: &str, value: &str) -> Value {
+        json!({
+            "name": name,
+            "request": { "method": "GET", "url": "{{base_url}}/users", "header": [{ "key": "Accept-Language", "value": value }] }
+        })
+    }
+
+    #[test]
+    fn test_invalid_tags() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [request("GET Users", "en_US, fr;q=0.8"), request("GET Orders", "fr-FR, en;q=0.5, *;q=0.1")]
+        });
+
+        let issues = check(&collection);
+        assert_eq!(issues.len(), 2);
+        assert!(issues[0].message.contains("invalid Accept-Language value (en_US)"));
+        assert_eq!(issues[0].path, "/item[0]/request/header[0]");
+        assert!(issues[1].message.contains("while most requests send \"en_US, fr;q=0.8\""));
+    }
+
+    #[test]
+    fn test_inconsistent_values() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [
+                request("GET Users", "{{accept_language}}"),
+                request("GET Orders", "{{accept_language}}"),
+                request("GET Products", "de-DE")
+            ]
+        });
+
+        let issues = check(&collection);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("\"GET Products\" sends Accept-Language \"de-DE\""));
+    }
+
+    #[test]
+    fn test_consistent_values() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [request("GET Users", "fr-FR"), request("GET Orders", "fr-FR")]
+        });
+
+        assert_eq!(check(&collection).len(), 0);
+    }
+}
@@ -0,0 +1,196 @@
+use crate::context::LintContext;
+use crate::{LintConfig, LintIssue};
+use crate::model::{Request, TypedItem};
+use crate::rules::{self, Category, ItemVisitor, Rule};
+use crate::utils::ItemContext;
+use crate::i18n::Render;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Configuration de la règle method-url-semantics (listes de mots par équipe)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct MethodUrlSemanticsConfig {
+    /// Verbes d'action qui ne devraient pas terminer le path d'un GET
+    pub action_verbs: Vec<String>,
+    /// Segments de lecture seule qui ne devraient pas être appelés en POST sans body
+    pub read_only_segments: Vec<String>,
+}
+
+impl Default for MethodUrlSemanticsConfig {
+    fn default() -> Self {
+        MethodUrlSemanticsConfig {
+            action_verbs: ["create", "delete", "update", "remove", "add", "edit", "save"]
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+            read_only_segments: ["search", "list", "find"]
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+        }
+    }
+}
+
+/// Règle : method-url-semantics
+///
+/// Vérifie la cohérence sémantique entre la méthode HTTP et l'URL :
+/// - GET dont le path se termine par un verbe d'action (/create, /delete, /update...)
+/// - POST sans body vers un path de lecture seule (/search...)
+///
+/// Sévérité : INFO (-3%)
+pub fn check(collection: &Value) -> Vec<LintIssue> {
+    check_with_config(collection, &MethodUrlSemanticsConfig::default())
+}
+
+/// Version avec configuration personnalisable
+pub fn check_with_config(collection: &Value, config: &MethodUrlSemanticsConfig) -> Vec<LintIssue> {
+    rules::visit(collection, &LintContext::new(collection), &mut SemanticsVisitor { config: config.clone() })
+}
+
+struct SemanticsVisitor {
+    config: MethodUrlSemanticsConfig,
+}
+
+impl ItemVisitor for SemanticsVisitor {
+    fn visit(&mut self, item: &ItemContext, typed: &TypedItem, _ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        if let Some(request) = &typed.item.request {
+            check_request(request, &self.config, issues, &item.path, &item.name);
+        }
+    }
+}
+
+fn check_request(
+    request: &Request,
+    config: &MethodUrlSemanticsConfig,
+    issues: &mut Vec<LintIssue>,
+    path: &str,
+    item_name: &str,
+) {
+    let method = request.method();
+    let url_path = request.url.path();
+    let last_segment = url_path
+        .trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .unwrap_or("")
+        .to_lowercase();
+
+    if last_segment.is_empty() {
+        return;
+    }
+
+    let matches = |words: &[String]| words.iter().any(|w| w.to_lowercase() == last_segment);
+
+    if method == "GET" && matches(&config.action_verbs) {
+        issues.push(LintIssue::builder("method-url-semantics")
+            .severity("info")
+            .message_id("method-url-semantics.get-on-action")
+            .param("request", item_name)
+            .param("url_path", url_path)
+            .path(path)
+            .render());
+    } else if method == "POST" && matches(&config.read_only_segments) && !request.has_body() {
+        issues.push(LintIssue::builder("method-url-semantics")
+            .severity("info")
+            .message_id("method-url-semantics.post-without-body")
+            .param("request", item_name)
+            .param("url_path", url_path)
+            .path(path)
+            .render());
+    }
+}
+
+/// Déclaration de la règle dans le registre
+pub struct MethodUrlSemantics;
+
+impl Rule for MethodUrlSemantics {
+    fn id(&self) -> &'static str {
+        "method-url-semantics"
+    }
+
+    fn category(&self) -> Category {
+        Category::Structure
+    }
+
+    fn severity(&self) -> &'static str {
+        "info"
+    }
+
+    fn description(&self) -> &'static str {
+        "GET on action paths / POST without body on read-only paths"
+    }
+
+    fn default_options(&self) -> Option<Value> {
+        serde_json::to_value(MethodUrlSemanticsConfig::default()).ok()
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        Some(Box::new(SemanticsVisitor { config: config.options_for(self.id()) }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_get_with_action_verb() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "GET Create User",
+                "request": { "method": "GET", "url": "{{base_url}}/users/create" }
+            }]
+        });
+
+        let issues = check(&collection);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].rule_id, "method-url-semantics");
+        assert_eq!(issues[0].severity, "info");
+    }
+
+    #[test]
+    fn test_post_search_without_body() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [
+                {
+                    "name": "POST Search Empty",
+                    "request": { "method": "POST", "url": "{{base_url}}/users/search" }
+                },
+                {
+                    "name": "POST Search Filtered",
+                    "request": {
+                        "method": "POST",
+                        "url": "{{base_url}}/users/search",
+                        "body": { "mode": "raw", "raw": "{\"name\": \"john\"}" }
+                    }
+                }
+            ]
+        });
+
+        let issues = check(&collection);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].path, "/item[0]");
+    }
+
+    #[test]
+    fn test_custom_word_lists() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "GET Archive Order",
+                "request": { "method": "GET", "url": "{{base_url}}/orders/1/archive" }
+            }]
+        });
+
+        let config = MethodUrlSemanticsConfig {
+            action_verbs: vec!["archive".to_string()],
+            read_only_segments: vec![],
+        };
+        assert_eq!(check(&collection).len(), 0);
+        assert_eq!(check_with_config(&collection, &config).len(), 1);
+    }
+}
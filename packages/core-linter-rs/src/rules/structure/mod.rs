@@ -1 +1,28 @@
+use super::Rule;
+
 pub mod request_naming_convention;
+pub mod method_url_semantics;
+pub mod no_body_on_get;
+pub mod body_mode_matches_content_type;
+pub mod no_personal_markers;
+pub mod locale_header_consistency;
+pub mod disabled_items;
+pub mod name_matches_resource;
+pub mod allowed_methods;
+pub mod required_folders;
+pub mod schema_version_current;
+
+/// Règles de la catégorie, dans l'ordre d'exécution
+pub const RULES: &[&dyn Rule] = &[
+    &request_naming_convention::RequestNamingConvention,
+    &method_url_semantics::MethodUrlSemantics,
+    &no_body_on_get::NoBodyOnGet,
+    &body_mode_matches_content_type::BodyModeMatchesContentType,
+    &no_personal_markers::NoPersonalMarkers,
+    &disabled_items::DisabledItems,
+    &locale_header_consistency::LocaleHeaderConsistency,
+    &name_matches_resource::NameMatchesResource,
+    &allowed_methods::AllowedMethods,
+    &required_folders::RequiredFolders,
+    &schema_version_current::SchemaVersionCurrent,
+];
@@ -0,0 +1,212 @@
+use crate::context::LintContext;
+use crate::{LintConfig, LintIssue};
+use crate::model::TypedItem;
+use crate::rules::{self, Category, ItemVisitor, Rule};
+use crate::utils::ItemContext;
+use crate::i18n::Render;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Configuration de la règle name-matches-resource
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct NameMatchesResourceConfig {
+    /// Mots du nom à ignorer en plus de la liste intégrée (vocabulaire d'équipe)
+    pub ignored_words: Vec<String>,
+}
+
+/// Mots d'un nom de requête qui ne désignent pas une ressource
+/// (méthodes, verbes d'action, articles et prépositions, en anglais et en français)
+const NON_RESOURCE_WORDS: &[&str] = &[
+    "get", "post", "put", "patch", "delete", "head", "options",
+    "all", "list", "create", "update", "remove", "add", "new", "edit", "save", "fetch", "retrieve",
+    "search", "find", "read", "set", "check", "single", "one", "many", "details", "detail", "info",
+    "the", "and", "for", "from", "with", "into", "without", "by", "of", "to", "in", "on", "per",
+    "login", "logout", "signin", "signup", "register", "refresh", "health", "ping", "status",
+    "liste", "créer", "creer", "modifier", "supprimer", "ajouter", "récupérer", "recuperer",
+    "rechercher", "lire", "les", "des", "une", "par", "pour", "avec", "sans", "dans",
+];
+
+/// Règle : name-matches-resource
+///
+/// Signale les requêtes dont le nom désigne une ressource absente du path de
+/// l'URL (ex: "GET Orders" sur `/users/{{id}}/invoices`) : erreur typique d'une
+/// requête dupliquée puis modifiée sans être renommée.
+///
+/// Un mot du nom correspond à un segment du path au singulier/pluriel près, ou
+/// s'il en est le préfixe (`Invoice` ↔ `invoice-lines`). Les noms sans mot de
+/// ressource ("GET by id") et les paths sans segment fixe ne sont pas vérifiés.
+///
+/// Sévérité : INFO (-3%)
+pub fn check(collection: &Value) -> Vec<LintIssue> {
+    check_with_config(collection, &NameMatchesResourceConfig::default())
+}
+
+/// Version avec configuration personnalisable
+pub fn check_with_config(collection: &Value, config: &NameMatchesResourceConfig) -> Vec<LintIssue> {
+    rules::visit(collection, &LintContext::new(collection), &mut ResourceVisitor::new(config))
+}
+
+struct ResourceVisitor {
+    /// Mots ignorés de la configuration, en minuscules
+    ignored: Vec<String>,
+}
+
+impl ResourceVisitor {
+    fn new(config: &NameMatchesResourceConfig) -> Self {
+        ResourceVisitor { ignored: config.ignored_words.iter().map(|word| word.to_lowercase()).collect() }
+    }
+}
+
+impl ItemVisitor for ResourceVisitor {
+    fn visit(&mut self, item: &ItemContext, typed: &TypedItem, _ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        let (Some(request), Some(name)) = (&typed.item.request, &typed.item.name) else {
+            return;
+        };
+
+        let name_words: Vec<String> = words(name)
+            .into_iter()
+            .filter(|word| word.len() >= 3 && !word.chars().all(|c| c.is_ascii_digit()))
+            .filter(|word| !NON_RESOURCE_WORDS.contains(&word.as_str()) && !self.ignored.contains(word))
+            .collect();
+
+        let url_path = request.url.path();
+        let path_words: Vec<String> = url_path
+            .split('/')
+            .filter(|segment| !segment.is_empty() && !segment.starts_with(':') && !segment.contains('{'))
+            .flat_map(words)
+            .collect();
+
+        if name_words.is_empty() || path_words.is_empty() {
+            return;
+        }
+
+        let matches_path = name_words
+            .iter()
+            .any(|name_word| path_words.iter().any(|path_word| same_resource(name_word, path_word)));
+
+        if !matches_path {
+            issues.push(LintIssue::builder("name-matches-resource")
+                .severity("info")
+                .message_id("name-matches-resource.mismatch")
+                .param("request", name.as_str())
+                .param("words", name_words.iter().map(|word| format!("\"{}\"", word)).collect::<Vec<_>>().join(", "))
+                .param("url_path", url_path)
+                .path(item.path.clone())
+                .render());
+        }
+    }
+}
+
+/// Mots en minuscules : séparateurs non alphanumériques et frontières camelCase
+fn words(text: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut previous_lower = false;
+
+    for c in text.chars() {
+        if !c.is_alphanumeric() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            previous_lower = false;
+            continue;
+        }
+        if c.is_uppercase() && previous_lower && !current.is_empty() {
+            words.push(std::mem::take(&mut current));
+        }
+        previous_lower = c.is_lowercase();
+        current.extend(c.to_lowercase());
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+
+    words
+}
+
+/// Même ressource au pluriel près, ou l'un préfixe de l'autre (4 lettres minimum)
+fn same_resource(a: &str, b: &str) -> bool {
+    let (a, b) = (singular(a), singular(b));
+    a == b || (a.len().min(b.len()) >= 4 && (a.starts_with(b.as_str()) || b.starts_with(a.as_str())))
+}
+
+fn singular(word: &str) -> String {
+    if let Some(stem) = word.strip_suffix("ies") {
+        format!("{}y", stem)
+    } else if word.ends_with("ss") {
+        word.to_string()
+    } else {
+        word.strip_suffix('s').unwrap_or(word).to_string()
+    }
+}
+
+/// Déclaration de la règle dans le registre
+pub struct NameMatchesResource;
+
+impl Rule for NameMatchesResource {
+    fn id(&self) -> &'static str {
+        "name-matches-resource"
+    }
+
+    fn category(&self) -> Category {
+        Category::Structure
+    }
+
+    fn severity(&self) -> &'static str {
+        "info"
+    }
+
+    fn description(&self) -> &'static str {
+        "Request names refer to a resource present in the URL path"
+    }
+
+    fn default_options(&self) -> Option<Value> {
+        serde_json::to_value(NameMatchesResourceConfig::default()).ok()
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        Some(Box::new(ResourceVisitor::new(&config.options_for(self.id()))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn request(name: &str, url: &str) -> Value {
+        json!({ "name": name, "request": { "method": "GET", "url": url } })
+    }
+
+    #[test]
+    fn test_copy_paste_name_is_reported() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [
+                request("GET Orders", "{{base_url}}/users/{{id}}/invoices"),
+                request("GET User invoices", "{{base_url}}/users/:id/invoices"),
+                request("List categories", "https://api.example.com/v1/category-tree"),
+                request("getInvoiceLines", "{{base_url}}/invoice-lines"),
+                request("GET by id", "{{base_url}}/users/{{id}}")
+            ]
+        });
+
+        let issues = check(&collection);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].path, "/item[0]");
+        assert!(issues[0].message.contains("refers to \"orders\" but its URL path \"/users/{{id}}/invoices\""));
+    }
+
+    #[test]
+    fn test_ignored_words() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [request("Legacy Orders", "{{base_url}}/orders"), request("Legacy", "{{base_url}}/users")]
+        });
+        let config = NameMatchesResourceConfig { ignored_words: vec!["Legacy".to_string()] };
+
+        assert_eq!(check(&collection).len(), 1);
+        assert!(check_with_config(&collection, &config).is_empty());
+    }
+}
@@ -0,0 +1,127 @@
+use crate::context::LintContext;
+use crate::{LintConfig, LintIssue};
+use crate::model::TypedItem;
+use crate::rules::{self, Category, ItemVisitor, Rule};
+use crate::utils::ItemContext;
+use crate::i18n::Render;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Configuration de la règle no-body-on-get
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct NoBodyOnGetConfig {
+    /// Autorise un body sur les GET (APIs de recherche "GET-with-body").
+    /// Les requêtes HEAD restent toujours signalées.
+    pub allow_get_body: bool,
+}
+
+/// Règle : no-body-on-get
+///
+/// Signale les requêtes GET/HEAD qui portent un body : certaines gateways et
+/// proxies le suppriment silencieusement, ce qui rend les tests trompeurs.
+///
+/// Sévérité : WARNING (-8%)
+pub fn check(collection: &Value) -> Vec<LintIssue> {
+    check_with_config(collection, &NoBodyOnGetConfig::default())
+}
+
+/// Version avec configuration personnalisable
+pub fn check_with_config(collection: &Value, config: &NoBodyOnGetConfig) -> Vec<LintIssue> {
+    rules::visit(collection, &LintContext::new(collection), &mut BodyVisitor { allow_get_body: config.allow_get_body })
+}
+
+struct BodyVisitor {
+    allow_get_body: bool,
+}
+
+impl ItemVisitor for BodyVisitor {
+    fn visit(&mut self, item: &ItemContext, typed: &TypedItem, _ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        let Some(request) = &typed.item.request else {
+            return;
+        };
+        let method = request.method();
+        let forbidden = method == "HEAD" || (method == "GET" && !self.allow_get_body);
+
+        if forbidden && request.has_body() {
+            issues.push(LintIssue::builder("no-body-on-get")
+                .message_id("no-body-on-get.body")
+                .param("request", item.name.as_str())
+                .param("method", method)
+                .path(format!("{}/request/body", item.path))
+                .render());
+        }
+    }
+}
+
+/// Déclaration de la règle dans le registre
+pub struct NoBodyOnGet;
+
+impl Rule for NoBodyOnGet {
+    fn id(&self) -> &'static str {
+        "no-body-on-get"
+    }
+
+    fn category(&self) -> Category {
+        Category::Structure
+    }
+
+    fn severity(&self) -> &'static str {
+        "warning"
+    }
+
+    fn description(&self) -> &'static str {
+        "No body on GET/HEAD requests"
+    }
+
+    fn default_options(&self) -> Option<Value> {
+        serde_json::to_value(NoBodyOnGetConfig::default()).ok()
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        let config: NoBodyOnGetConfig = config.options_for(self.id());
+        Some(Box::new(BodyVisitor { allow_get_body: config.allow_get_body }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn collection_with(method: &str) -> Value {
+        json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "Search Users",
+                "request": {
+                    "method": method,
+                    "url": "{{base_url}}/users",
+                    "body": { "mode": "raw", "raw": "{\"query\": \"john\"}" }
+                }
+            }]
+        })
+    }
+
+    #[test]
+    fn test_get_with_body() {
+        let issues = check(&collection_with("GET"));
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].rule_id, "no-body-on-get");
+        assert_eq!(issues[0].path, "/item[0]/request/body");
+    }
+
+    #[test]
+    fn test_post_with_body_allowed() {
+        let issues = check(&collection_with("POST"));
+        assert_eq!(issues.len(), 0);
+    }
+
+    #[test]
+    fn test_allow_get_body_option() {
+        let config = NoBodyOnGetConfig { allow_get_body: true };
+
+        assert_eq!(check_with_config(&collection_with("GET"), &config).len(), 0);
+        assert_eq!(check_with_config(&collection_with("HEAD"), &config).len(), 1);
+    }
+}
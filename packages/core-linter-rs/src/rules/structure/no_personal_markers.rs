@@ -0,0 +1,191 @@
+use crate::context::LintContext;
+use crate::{LintConfig, LintIssue};
+use crate::model::TypedItem;
+use crate::rules::{self, Category, ItemVisitor, Rule};
+use crate::utils::{self, ItemContext};
+use crate::i18n::Render;
+use crate::rule_api::IssueBuilder;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::LazyLock;
+
+/// Configuration de la règle no-personal-markers
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct NoPersonalMarkersConfig {
+    /// Prénoms/noms à signaler dans les noms d'items (ex: membres de l'équipe)
+    pub personal_names: Vec<String>,
+    /// Signaler les dates (2024-01-15, 15/01/2024, 20240115)
+    pub check_dates: bool,
+    /// Nom composé uniquement d'un ticket (ex: "JIRA-1234"), accepté si l'item a une description
+    pub ticket_pattern: String,
+}
+
+impl Default for NoPersonalMarkersConfig {
+    fn default() -> Self {
+        NoPersonalMarkersConfig {
+            personal_names: Vec::new(),
+            check_dates: true,
+            ticket_pattern: r"^\s*[\[(]?[A-Z][A-Z0-9]+-\d+[\])]?\s*$".to_string(),
+        }
+    }
+}
+
+/// Possessif anglais (`John's`)
+static POSSESSIVE: LazyLock<Regex> = LazyLock::new(|| utils::build_regex(r"(?i)\b[a-z]+'s\b").unwrap());
+/// Date ISO, jour/mois/année ou compacte
+static DATE: LazyLock<Regex> = LazyLock::new(|| {
+    utils::build_regex(r"\b(?:\d{4}-\d{2}-\d{2}|\d{1,2}/\d{1,2}/\d{2,4}|20\d{2}[01]\d[0-3]\d)\b").unwrap()
+});
+
+/// Règle : no-personal-markers
+///
+/// Signale les noms de requêtes/folders contenant des marqueurs personnels :
+/// - noms de personnes (`personal_names`) ou possessifs ("Bob's test")
+/// - dates ("Login 2024-01-15")
+/// - un simple numéro de ticket ("JIRA-1234") sans description
+///
+/// Ces noms perdent leur sens dès que la collection est partagée entre équipes.
+///
+/// Sévérité : INFO (-3%)
+pub fn check(collection: &Value) -> Vec<LintIssue> {
+    check_with_config(collection, &NoPersonalMarkersConfig::default())
+}
+
+/// Version avec configuration personnalisable
+pub fn check_with_config(collection: &Value, config: &NoPersonalMarkersConfig) -> Vec<LintIssue> {
+    rules::visit(collection, &LintContext::new(collection), &mut MarkerVisitor::new(config))
+}
+
+/// Marqueurs compilés une seule fois depuis la configuration
+struct MarkerVisitor {
+    markers: Vec<(&'static str, Regex)>,
+    ticket: Option<Regex>,
+}
+
+impl MarkerVisitor {
+    fn new(config: &NoPersonalMarkersConfig) -> Self {
+        // Cloner une `Regex` partage son automate compilé
+        let mut markers = vec![("possessive", POSSESSIVE.clone())];
+        if config.check_dates {
+            markers.push(("date", DATE.clone()));
+        }
+        let names: Vec<String> = config.personal_names.iter().map(|n| regex::escape(n.trim())).filter(|n| !n.is_empty()).collect();
+        if !names.is_empty() {
+            markers.push(("personal name", utils::build_regex(&format!(r"(?i)\b(?:{})\b", names.join("|"))).unwrap()));
+        }
+        MarkerVisitor { markers, ticket: utils::build_regex(&config.ticket_pattern).ok() }
+    }
+}
+
+impl ItemVisitor for MarkerVisitor {
+    fn visit(&mut self, item: &ItemContext, typed: &TypedItem, _ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        let Some(name) = typed.item.name.as_deref() else {
+            return;
+        };
+        let kind = if typed.item.is_folder() { "folder" } else { "request" };
+
+        let found: Vec<String> = self
+            .markers
+            .iter()
+            .filter_map(|(marker, regex)| regex.find(name).map(|m| format!("{} \"{}\"", marker, m.as_str())))
+            .collect();
+        if !found.is_empty() {
+            issues.push(marker_issue(
+                LintIssue::builder("no-personal-markers")
+                    .message_id(&format!("no-personal-markers.{}", kind))
+                    .param("markers", found.join(", ")),
+                name,
+                &item.path,
+            ));
+        }
+
+        let has_description = !typed.item.description_text().trim().is_empty();
+        if self.ticket.as_ref().is_some_and(|re| re.is_match(name)) && !has_description {
+            issues.push(marker_issue(
+                LintIssue::builder("no-personal-markers").message_id(&format!("no-personal-markers.{}-ticket-only", kind)),
+                name,
+                &item.path,
+            ));
+        }
+    }
+}
+
+fn marker_issue(issue: IssueBuilder, name: &str, path: &str) -> LintIssue {
+    issue.severity("info").param("name", name).path(path).render()
+}
+
+/// Déclaration de la règle dans le registre
+pub struct NoPersonalMarkers;
+
+impl Rule for NoPersonalMarkers {
+    fn id(&self) -> &'static str {
+        "no-personal-markers"
+    }
+
+    fn category(&self) -> Category {
+        Category::Structure
+    }
+
+    fn severity(&self) -> &'static str {
+        "info"
+    }
+
+    fn description(&self) -> &'static str {
+        "Request/folder names free of personal names, dates and ticket-only names"
+    }
+
+    fn default_options(&self) -> Option<Value> {
+        serde_json::to_value(NoPersonalMarkersConfig::default()).ok()
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        Some(Box::new(MarkerVisitor::new(&config.options_for(self.id()))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_dates_possessives_and_names() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "Bob's tests",
+                "item": [
+                    { "name": "GET Users 2024-01-15", "request": { "method": "GET", "url": "{{base_url}}/users" } },
+                    { "name": "POST Order (Marie)", "request": { "method": "POST", "url": "{{base_url}}/orders" } },
+                    { "name": "GET Orders", "request": { "method": "GET", "url": "{{base_url}}/orders" } }
+                ]
+            }]
+        });
+        let config = NoPersonalMarkersConfig { personal_names: vec!["marie".to_string()], ..Default::default() };
+
+        let issues = check_with_config(&collection, &config);
+        assert_eq!(issues.len(), 3);
+        assert!(issues[0].message.contains("Folder \"Bob's tests\""));
+        assert!(issues[1].message.contains("date \"2024-01-15\""));
+        assert!(issues[2].message.contains("personal name \"Marie\""));
+    }
+
+    #[test]
+    fn test_ticket_only_name_without_description() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [
+                { "name": "JIRA-1234", "request": { "method": "GET", "url": "{{base_url}}/users" } },
+                { "name": "[API-42]", "request": { "method": "GET", "url": "{{base_url}}/users", "description": "Regression for pagination bug" } },
+                { "name": "GET Users (JIRA-1234)", "request": { "method": "GET", "url": "{{base_url}}/users" } }
+            ]
+        });
+
+        let issues = check(&collection);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].path, "/item[0]");
+        assert!(issues[0].message.contains("only named after a ticket"));
+    }
+}
@@ -1,6 +1,16 @@
-use crate::LintIssue;
+use crate::context::LintContext;
+use crate::{FixAction, LintConfig, LintIssue};
+use crate::model::TypedItem;
+use crate::rules::{Category, ItemVisitor, Rule};
+use crate::utils::{self, ItemContext};
+use crate::i18n::Render;
 use regex::Regex;
 use serde_json::Value;
+use std::sync::LazyLock;
+
+/// Nom préfixé par la méthode HTTP
+static METHOD_PREFIX: LazyLock<Regex> =
+    LazyLock::new(|| utils::build_regex(r"^(GET|POST|PUT|PATCH|DELETE|HEAD|OPTIONS)\s+").unwrap());
 
 /// Règle : request-naming-convention
 /// 
@@ -12,53 +22,56 @@ use serde_json::Value;
 /// 
 /// Sévérité : WARNING (-8%)
 pub fn check(collection: &Value) -> Vec<LintIssue> {
-    let mut issues = Vec::new();
-    
-    if let Some(items) = collection["item"].as_array() {
-        check_items(items, &mut issues, "");
+    RequestNamingConvention.check(collection, &LintContext::new(collection), &LintConfig::default())
+}
+
+/// Déclaration de la règle dans le registre
+pub struct RequestNamingConvention;
+
+impl Rule for RequestNamingConvention {
+    fn id(&self) -> &'static str {
+        "request-naming-convention"
+    }
+
+    fn category(&self) -> Category {
+        Category::Structure
+    }
+
+    fn severity(&self) -> &'static str {
+        "warning"
+    }
+
+    fn description(&self) -> &'static str {
+        "Follow naming conventions"
+    }
+
+    fn fix_types(&self) -> &'static [&'static str] {
+        &["rename_request"]
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, _config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        Some(Box::new(RequestNamingConvention))
     }
-    
-    issues
 }
 
-fn check_items(items: &[Value], issues: &mut Vec<LintIssue>, parent_path: &str) {
-    for (index, item) in items.iter().enumerate() {
-        let default_name = format!("Item-{}", index + 1);
-        let item_name = item["name"].as_str().unwrap_or(&default_name);
-        let current_path = if parent_path.is_empty() {
-            format!("/item[{}]", index)
-        } else {
-            format!("{}/item[{}]", parent_path, index)
+impl ItemVisitor for RequestNamingConvention {
+    fn visit(&mut self, item: &ItemContext, typed: &TypedItem, _ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        let Some(request) = &typed.item.request else {
+            return;
         };
-        
-        // Si c'est une requête
-        if item.get("request").is_some() {
-            let method = item["request"]["method"].as_str().unwrap_or("");
-            
-            // Vérifier si le nom commence par la méthode HTTP
-            let naming_pattern = Regex::new(r"^(GET|POST|PUT|PATCH|DELETE|HEAD|OPTIONS)\s+").unwrap();
-            
-            if !naming_pattern.is_match(item_name) && !method.is_empty() {
-                issues.push(LintIssue {
-                    rule_id: "request-naming-convention".to_string(),
-                    severity: "warning".to_string(),
-                    message: format!(
-                        "📝 Request \"{}\" should start with the HTTP method (ex: \"{} {}\")",
-                        item_name, method, item_name
-                    ),
-                    path: current_path.clone(),
-                    line: None,
-                    fix: Some(serde_json::json!({
-                        "type": "rename_request",
-                        "suggested_name": format!("{} {}", method, item_name),
-                    })),
-                });
-            }
-        }
-        
-        // Si c'est un folder, récurser
-        if let Some(sub_items) = item["item"].as_array() {
-            check_items(sub_items, issues, &current_path);
+        let method = request.method.as_deref().unwrap_or("");
+
+        // Vérifier si le nom commence par la méthode HTTP
+        if !METHOD_PREFIX.is_match(&item.name) && !method.is_empty() {
+            issues.push(LintIssue::builder("request-naming-convention")
+                .message_id("request-naming-convention.method-prefix")
+                .param("request", item.name.as_str())
+                .param("method", method)
+                .path(item.path.clone())
+                .fix(FixAction::RenameRequest {
+                    suggested_name: format!("{} {}", method, item.name),
+                })
+                .render());
         }
     }
 }
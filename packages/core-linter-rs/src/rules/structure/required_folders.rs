@@ -0,0 +1,153 @@
+use crate::context::LintContext;
+use crate::{LintConfig, LintIssue};
+use crate::rules::{Category, Rule};
+use crate::model::{Collection, Item};
+use crate::i18n::Render;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Folder obligatoire à la racine de la collection
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct RequiredFolder {
+    /// Nom du folder (comparaison insensible à la casse)
+    pub name: String,
+    /// Nombre minimal de requêtes dans le folder, sous-folders compris
+    pub min_requests: usize,
+}
+
+/// Configuration de la règle required-folders
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct RequiredFoldersConfig {
+    /// Folders attendus à la racine (ex: `{ "name": "Smoke", "min_requests": 5 }`)
+    pub folders: Vec<RequiredFolder>,
+}
+
+/// Règle : required-folders
+///
+/// Signale les folders imposés par le standard de l'équipe qui manquent à la racine
+/// de la collection (ex: un folder "Smoke" avec une requête par endpoint critique),
+/// ou qui contiennent moins de requêtes que le minimum configuré.
+///
+/// Inactive tant qu'aucun folder n'est configuré.
+///
+/// Sévérité : ERROR (-15%)
+pub fn check(collection: &Value) -> Vec<LintIssue> {
+    check_with_config(collection, &RequiredFoldersConfig::default())
+}
+
+/// Version avec configuration personnalisable
+pub fn check_with_config(collection: &Value, config: &RequiredFoldersConfig) -> Vec<LintIssue> {
+    check_model(&Collection::from_value(collection), config)
+}
+
+/// Vérification sur le modèle typé (partagé via `LintContext::model`)
+pub fn check_model(collection: &Collection, config: &RequiredFoldersConfig) -> Vec<LintIssue> {
+    let mut issues = Vec::new();
+
+    for required in &config.folders {
+        let folder = collection.item.iter().enumerate().find(|(_, item)| {
+            item.is_folder() && item.name.as_deref().is_some_and(|name| name.trim().eq_ignore_ascii_case(required.name.trim()))
+        });
+
+        let issue = LintIssue::builder("required-folders").severity("error").param("folder", required.name.as_str());
+        let issue = match folder {
+            None => issue.message_id("required-folders.missing"),
+            Some((index, item)) => {
+                let requests = count_requests(item);
+                if requests >= required.min_requests {
+                    continue;
+                }
+                issue
+                    .message_id("required-folders.too-few-requests")
+                    .param("count", requests)
+                    .param("min", required.min_requests)
+                    .path(format!("/item[{}]", index))
+            }
+        };
+
+        issues.push(issue.render());
+    }
+
+    issues
+}
+
+fn count_requests(item: &Item) -> usize {
+    item.children()
+        .iter()
+        .map(|child| if child.is_request() { 1 } else { count_requests(child) })
+        .sum()
+}
+
+/// Déclaration de la règle dans le registre
+pub struct RequiredFolders;
+
+impl Rule for RequiredFolders {
+    fn id(&self) -> &'static str {
+        "required-folders"
+    }
+
+    fn category(&self) -> Category {
+        Category::Structure
+    }
+
+    fn severity(&self) -> &'static str {
+        "error"
+    }
+
+    fn description(&self) -> &'static str {
+        "Mandatory top-level folders exist and contain enough requests"
+    }
+
+    fn default_options(&self) -> Option<Value> {
+        serde_json::to_value(RequiredFoldersConfig::default()).ok()
+    }
+
+    // Pas de visiteur : seuls les folders racine sont comparés à la liste configurée,
+    // et le décompte porte sur leur sous-arbre du modèle plutôt que sur chaque item
+    fn check(&self, _collection: &Value, ctx: &LintContext, config: &LintConfig) -> Vec<LintIssue> {
+        check_model(&ctx.model, &config.options_for(self.id()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn collection() -> Value {
+        json!({
+            "info": { "name": "Test" },
+            "item": [
+                { "name": "smoke", "item": [
+                    { "name": "Health", "request": { "method": "GET", "url": "{{base_url}}/health" } },
+                    { "name": "Auth", "item": [
+                        { "name": "Login", "request": { "method": "POST", "url": "{{base_url}}/login" } }
+                    ] }
+                ] },
+                { "name": "Users", "item": [] }
+            ]
+        })
+    }
+
+    #[test]
+    fn test_inactive_without_configuration() {
+        assert!(check(&collection()).is_empty());
+    }
+
+    #[test]
+    fn test_missing_folder_and_minimum_requests() {
+        let config: RequiredFoldersConfig = serde_json::from_value(json!({
+            "folders": [{ "name": "Smoke", "min_requests": 3 }, { "name": "Regression" }, { "name": "Users" }]
+        }))
+        .unwrap();
+
+        let issues = check_with_config(&collection(), &config);
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].path, "/item[0]");
+        assert!(issues[0].message.contains("contains 2 requests (at least 3 required)"));
+        assert_eq!(issues[1].path, "");
+        assert!(issues[1].message.contains("\"Regression\" is missing"));
+    }
+}
@@ -0,0 +1,129 @@
+use crate::context::LintContext;
+use crate::{FixAction, LintConfig, LintIssue};
+use crate::rules::{Category, Rule};
+use crate::utils::walk_items;
+use crate::i18n::Render;
+use serde_json::Value;
+
+/// Schéma de collection courant
+pub const CURRENT_SCHEMA: &str = "https://schema.getpostman.com/json/collection/v2.1.0/collection.json";
+
+/// Règle : schema-version-current
+///
+/// Signale les collections déclarant le schéma v2.0.0 dans `info.schema`, ou une URL
+/// de schéma en http. Le fix remplace l'URL par celle du schéma v2.1.0, sauf si la
+/// collection utilise encore des constructions propres à la v2.0 (paramètres d'auth
+/// en objet au lieu d'une liste clé/valeur) : ils doivent être convertis d'abord,
+/// sans quoi Postman ignorerait ces auths à l'import.
+///
+/// Sévérité : WARNING (-8%)
+pub fn check(collection: &Value) -> Vec<LintIssue> {
+    let schema = collection["info"]["schema"].as_str().unwrap_or("").trim();
+    let outdated = schema.contains("/v2.0.0/");
+    if !outdated && !schema.starts_with("http://") {
+        return Vec::new();
+    }
+
+    let legacy_auths = count_legacy_auths(collection);
+    let issue = LintIssue::builder("schema-version-current").param("schema", schema);
+    let issue = if !outdated {
+        issue
+            .message_id("schema-version-current.insecure-url")
+            .fix(FixAction::UpgradeSchema { schema: schema.replacen("http://", "https://", 1) })
+    } else if legacy_auths > 0 {
+        issue.message_id("schema-version-current.legacy-auth").param("count", legacy_auths)
+    } else {
+        issue
+            .message_id("schema-version-current.outdated")
+            .fix(FixAction::UpgradeSchema { schema: CURRENT_SCHEMA.to_string() })
+    };
+
+    vec![issue.render()]
+}
+
+/// Auths au format v2.0 : paramètres du type en objet (`"bearer": { "token": ... }`)
+/// là où la v2.1 attend une liste (`"bearer": [{ "key": "token", ... }]`)
+fn count_legacy_auths(collection: &Value) -> usize {
+    let is_legacy = |auth: &Value| {
+        auth["type"]
+            .as_str()
+            .is_some_and(|auth_type| auth.get(auth_type).is_some_and(Value::is_object))
+    };
+
+    let mut count = usize::from(is_legacy(&collection["auth"]));
+    walk_items(collection, |item| {
+        count += usize::from(is_legacy(&item.item["auth"])) + usize::from(is_legacy(&item.item["request"]["auth"]));
+    });
+    count
+}
+
+/// Déclaration de la règle dans le registre
+pub struct SchemaVersionCurrent;
+
+impl Rule for SchemaVersionCurrent {
+    fn id(&self) -> &'static str {
+        "schema-version-current"
+    }
+
+    fn category(&self) -> Category {
+        Category::Structure
+    }
+
+    fn severity(&self) -> &'static str {
+        "warning"
+    }
+
+    fn description(&self) -> &'static str {
+        "info.schema references the current v2.1.0 collection schema over https"
+    }
+
+    // Pas de visiteur : la règle porte sur `info.schema`, et les items ne sont parcourus
+    // (pour les auths v2.0) que si le schéma est déjà signalé comme obsolète
+    fn check(&self, collection: &Value, _ctx: &LintContext, _config: &LintConfig) -> Vec<LintIssue> {
+        check(collection)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn collection(schema: &str, auth: Value) -> Value {
+        json!({
+            "info": { "name": "Test", "schema": schema },
+            "item": [{ "name": "GET Users", "request": { "method": "GET", "url": "{{base_url}}/users", "auth": auth } }]
+        })
+    }
+
+    #[test]
+    fn test_current_schema_passes() {
+        assert!(check(&collection(CURRENT_SCHEMA, Value::Null)).is_empty());
+    }
+
+    #[test]
+    fn test_old_and_insecure_schemas() {
+        let issues = check(&collection("https://schema.getpostman.com/json/collection/v2.0.0/collection.json", Value::Null));
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].fix, Some(FixAction::UpgradeSchema { schema: CURRENT_SCHEMA.to_string() }));
+
+        let issues = check(&collection("http://schema.getpostman.com/json/collection/v2.1.0/collection.json", Value::Null));
+        assert!(issues[0].message.contains("uses http"));
+        assert_eq!(issues[0].fix, Some(FixAction::UpgradeSchema { schema: CURRENT_SCHEMA.to_string() }));
+    }
+
+    #[test]
+    fn test_legacy_auth_blocks_upgrade() {
+        let auth = json!({ "type": "bearer", "bearer": { "token": "{{token}}" } });
+        let issues = check(&collection("http://schema.getpostman.com/json/collection/v2.0.0/collection.json", auth));
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("1 auth blocks in the v2.0 layout"));
+        assert!(issues[0].fix.is_none());
+        assert_eq!(issues[0].message_id.as_deref(), Some("schema-version-current.legacy-auth"));
+
+        // Format v2.1 : pas de blocage
+        let auth = json!({ "type": "bearer", "bearer": [{ "key": "token", "value": "{{token}}", "type": "string" }] });
+        let issues = check(&collection("https://schema.getpostman.com/json/collection/v2.0.0/collection.json", auth));
+        assert!(issues[0].fix.is_some());
+    }
+}
@@ -1,5 +1,18 @@
+use super::Rule;
+
 pub mod test_http_status_mandatory;
 pub mod test_description_with_uri;
 pub mod test_response_time_mandatory;
 pub mod test_body_content_validation;
 pub mod test_schema_validation_recommended;
+pub mod undefined_helper_calls;
+
+/// Règles de la catégorie, dans l'ordre d'exécution
+pub const RULES: &[&dyn Rule] = &[
+    &test_http_status_mandatory::TestHttpStatusMandatory,
+    &test_description_with_uri::TestDescriptionWithUri,
+    &test_response_time_mandatory::TestResponseTimeMandatory,
+    &test_body_content_validation::TestBodyContentValidation,
+    &test_schema_validation_recommended::TestSchemaValidationRecommended,
+    &undefined_helper_calls::UndefinedHelperCalls,
+];
@@ -1,7 +1,32 @@
-use crate::LintIssue;
-use crate::utils;
+use crate::context::LintContext;
+use crate::{LintConfig, LintIssue};
+use crate::model::TypedItem;
+use crate::rules::{Category, ItemVisitor, Rule, RuleInput};
+use crate::utils::{self, ItemContext};
+use crate::i18n::Render;
 use regex::Regex;
 use serde_json::Value;
+use std::sync::LazyLock;
+
+/// Patterns pour les tests de contenu du body
+pub const BODY_PATTERNS: &[&str] = &[
+    r"pm\.response\.json\(\)",
+    r"pm\.response\.to\.have\.jsonSchema",
+    r"responseJson",
+    r"jsonData",
+    r"pm\.response\.text\(\)",
+    r"\.to\.have\.property\(",
+    r"\.to\.include\(",
+    r"\.to\.eql\(",
+    r"\.to\.equal\(",
+    r"\.to\.be\.",
+];
+
+/// Patterns d'une requête probablement sans body
+const NO_BODY_PATTERNS: &[&str] = &[r"204", r"(?i)no.*content", r"(?i)delete"];
+
+static BODY_REGEXES: LazyLock<Vec<Regex>> = LazyLock::new(|| utils::build_regexes(BODY_PATTERNS));
+static NO_BODY_REGEXES: LazyLock<Vec<Regex>> = LazyLock::new(|| utils::build_regexes(NO_BODY_PATTERNS));
 
 /// Règle : test-body-content-validation
 /// 
@@ -16,69 +41,24 @@ use serde_json::Value;
 /// 
 /// Sévérité : WARNING (-8%)
 pub fn check(collection: &Value) -> Vec<LintIssue> {
-    let mut issues = Vec::new();
-    
-    if let Some(items) = collection["item"].as_array() {
-        check_items(items, &mut issues, "", &[]);
-    }
-    
-    issues
+    check_with_context(collection, &LintContext::new(collection))
 }
 
-fn check_items(
-    items: &[Value],
-    issues: &mut Vec<LintIssue>,
-    parent_path: &str,
-    parent_scripts: &[String],
-) {
-    for (index, item) in items.iter().enumerate() {
-        let default_name = format!("Item-{}", index + 1);
-        let item_name = item["name"].as_str().unwrap_or(&default_name);
-        let current_path = if parent_path.is_empty() {
-            format!("/item[{}]", index)
-        } else {
-            format!("{}/item[{}]", parent_path, index)
-        };
-        
-        // Si c'est une requête
-        if item.get("request").is_some() {
-            check_request_body_validation(item, issues, &current_path, item_name, parent_scripts);
-        }
-        
-        // Si c'est un folder, récurser avec les scripts du folder
-        if let Some(sub_items) = item["item"].as_array() {
-            let mut updated_scripts = parent_scripts.to_vec();
-            
-            // Ajouter les scripts de test du folder actuel
-            if let Some(events) = item["event"].as_array() {
-                for event in events {
-                    if event["listen"] == "test" {
-                        if let Some(exec) = event["script"]["exec"].as_array() {
-                            let script = exec
-                                .iter()
-                                .filter_map(|line| line.as_str())
-                                .collect::<Vec<&str>>()
-                                .join("\n");
-                            updated_scripts.push(script);
-                        }
-                    }
-                }
-            }
-            
-            check_items(sub_items, issues, &current_path, &updated_scripts);
-        }
-    }
+/// Version utilisant le contexte partagé (index des scripts)
+pub fn check_with_context(collection: &Value, ctx: &LintContext) -> Vec<LintIssue> {
+    TestBodyContentValidation.check(collection, ctx, &LintConfig::default())
 }
 
 fn check_request_body_validation(
-    item: &Value,
+    item: &ItemContext,
+    ctx: &LintContext,
     issues: &mut Vec<LintIssue>,
     path: &str,
     item_name: &str,
     parent_scripts: &[String],
 ) {
-    // Extraire le script de test
-    let test_script = utils::extract_test_scripts(item).join("\n");
+    // Script de test de la requête (index partagé)
+    let test_script = &ctx.scripts.get(path).test;
     
     // Vérifier si des tests existent (dans la requête ou les parents)
     let has_test_in_parent = !parent_scripts.is_empty() && 
@@ -89,76 +69,65 @@ fn check_request_body_validation(
         return;
     }
     
-    // Patterns pour les tests de contenu du body
-    let body_patterns = vec![
-        r"pm\.response\.json\(\)",
-        r"pm\.response\.to\.have\.jsonSchema",
-        r"responseJson",
-        r"jsonData",
-        r"pm\.response\.text\(\)",
-        r"\.to\.have\.property\(",
-        r"\.to\.include\(",
-        r"\.to\.eql\(",
-        r"\.to\.equal\(",
-        r"\.to\.be\.",
-    ];
-    
-    // Vérifier dans le script de la requête
-    let has_body_test = body_patterns.iter().any(|pattern| {
-        if let Ok(re) = Regex::new(pattern) {
-            re.is_match(&test_script)
-        } else {
-            false
-        }
-    });
-    
-    // Si pas trouvé, vérifier dans les scripts parents
-    let has_test_in_parents = if !has_body_test {
-        parent_scripts.iter().any(|parent_script| {
-            body_patterns.iter().any(|pattern| {
-                if let Ok(re) = Regex::new(pattern) {
-                    re.is_match(parent_script)
-                } else {
-                    false
-                }
-            })
-        })
-    } else {
-        false
-    };
-    
-    // Patterns pour détecter si c'est probablement une requête sans body
-    let no_body_patterns = vec![
-        r"204",
-        r"(?i)no.*content",
-        r"(?i)delete",
-    ];
+    // Vérifier dans le script de la requête, puis dans les scripts parents
+    let has_body_test = BODY_REGEXES.iter().any(|re| re.is_match(test_script));
+    let has_test_in_parents =
+        !has_body_test && parent_scripts.iter().any(|script| BODY_REGEXES.iter().any(|re| re.is_match(script)));
     
-    let method = item["request"]["method"].as_str().unwrap_or("");
-    let probably_no_body = no_body_patterns.iter().any(|pattern| {
-        if let Ok(re) = Regex::new(pattern) {
-            re.is_match(&test_script) ||
-            re.is_match(method) ||
-            re.is_match(item_name) ||
-            parent_scripts.iter().any(|s| re.is_match(s))
-        } else {
-            false
-        }
+    // Probablement une requête sans body (204, DELETE...)
+    let method = item.method.as_deref().unwrap_or("");
+    let probably_no_body = NO_BODY_REGEXES.iter().any(|re| {
+        re.is_match(test_script) ||
+        re.is_match(method) ||
+        re.is_match(item_name) ||
+        parent_scripts.iter().any(|s| re.is_match(s))
     });
     
     // Avertissement seulement si pas de test de body ET probablement pas un endpoint sans body
     if !has_body_test && !has_test_in_parents && !probably_no_body {
-        issues.push(LintIssue {
-            rule_id: "test-body-content-validation".to_string(),
-            severity: "warning".to_string(),
-            message: format!(
-                "⚠️ Request \"{}\" should validate response content (body, properties, schema)",
-                item_name
-            ),
-            path: path.to_string(),
-            line: None,
-            fix: None,
-        });
+        issues.push(LintIssue::builder("test-body-content-validation")
+            .message_id("test-body-content-validation.missing")
+            .param("request", item_name)
+            .path(path)
+            .render());
+    }
+}
+
+/// Déclaration de la règle dans le registre
+pub struct TestBodyContentValidation;
+
+impl Rule for TestBodyContentValidation {
+    fn id(&self) -> &'static str {
+        "test-body-content-validation"
+    }
+
+    fn category(&self) -> Category {
+        Category::Testing
+    }
+
+    fn severity(&self) -> &'static str {
+        "warning"
+    }
+
+    fn description(&self) -> &'static str {
+        "Body content validation recommended"
+    }
+
+    fn inputs(&self) -> &'static [RuleInput] {
+        &[RuleInput::Scripts]
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, _config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        Some(Box::new(TestBodyContentValidation))
+    }
+}
+
+impl ItemVisitor for TestBodyContentValidation {
+    fn visit(&mut self, item: &ItemContext, _typed: &TypedItem, ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        // Les scripts des folders parents s'appliquent à la requête
+        if item.is_request() {
+            check_request_body_validation(item, ctx, issues, &item.path, &item.name, item.parent_test_scripts);
+        }
     }
 }
 
@@ -1,6 +1,29 @@
-use crate::LintIssue;
+use crate::context::LintContext;
+use crate::{FixAction, LintConfig, LintIssue};
+use crate::model::TypedItem;
+use crate::rules::{Category, ItemVisitor, Rule, RuleInput};
+use crate::utils::{self, ItemContext};
+use crate::i18n::Render;
 use regex::Regex;
 use serde_json::Value;
+use std::sync::LazyLock;
+
+/// Patterns des variables contenant le chemin de la requête
+const PATH_VARIABLE_PATTERNS: &[&str] = &[
+    r#"pm\.environment\.set\s*\(\s*["']([^"']+)["']\s*,\s*[^)]*(?:path|location|uri|url)"#,
+    r#"pm\.variables\.set\s*\(\s*["']([^"']+)["']\s*,\s*[^)]*(?:path|location|uri|url)"#,
+    r#"let\s+(\w+)\s*=\s*[^;]*(?:path|location|uri|url)"#,
+    r#"const\s+(\w+)\s*=\s*[^;]*(?:path|location|uri|url)"#,
+];
+
+static PATH_VARIABLE_REGEXES: LazyLock<Vec<Regex>> = LazyLock::new(|| utils::build_regexes(PATH_VARIABLE_PATTERNS));
+/// Appel `pm.test(`
+static TEST_CALL: LazyLock<Regex> = LazyLock::new(|| utils::build_regex(r"pm\.test\s*\(").unwrap());
+/// Description d'un `pm.test` (premier argument)
+static TEST_DESCRIPTION: LazyLock<Regex> =
+    LazyLock::new(|| utils::build_regex(r#"pm\.test\s*\(\s*([^,]+?)(?:,|\))"#).unwrap());
+/// Texte entre guillemets d'une description
+static QUOTED_TEXT: LazyLock<Regex> = LazyLock::new(|| utils::build_regex(r#"["']([^"']+)["']"#).unwrap());
 
 /// Règle : test-description-with-uri
 /// 
@@ -14,85 +37,23 @@ use serde_json::Value;
 /// 
 /// Sévérité : ERROR (-15%)
 pub fn check(collection: &Value) -> Vec<LintIssue> {
-    let mut issues = Vec::new();
-    
-    if let Some(items) = collection["item"].as_array() {
-        check_items(items, &mut issues, "", &[]);
-    }
-    
-    issues
+    check_with_context(collection, &LintContext::new(collection))
 }
 
-fn check_items(
-    items: &[Value],
-    issues: &mut Vec<LintIssue>,
-    parent_path: &str,
-    parent_scripts: &[String],
-) {
-    for (index, item) in items.iter().enumerate() {
-        let default_name = format!("Item-{}", index + 1);
-        let item_name = item["name"].as_str().unwrap_or(&default_name);
-        let current_path = if parent_path.is_empty() {
-            format!("/item[{}]", index)
-        } else {
-            format!("{}/item[{}]", parent_path, index)
-        };
-        
-        // Si c'est une requête
-        if item.get("request").is_some() {
-            // Vérifier si des tests existent dans les folders parents
-            let has_tests_in_parent = parent_scripts.iter().any(|script| {
-                Regex::new(r"pm\.test\s*\(").unwrap().is_match(script)
-            });
-            
-            if has_tests_in_parent {
-                // Skip : les tests au niveau folder ne peuvent pas inclure l'URI spécifique
-                continue;
-            }
-            
-            check_request_tests(item, issues, &current_path, item_name);
-        }
-        
-        // Si c'est un folder, récurser avec les scripts du folder
-        if let Some(sub_items) = item["item"].as_array() {
-            let mut updated_scripts = parent_scripts.to_vec();
-            
-            // Ajouter les scripts de test du folder actuel
-            if let Some(events) = item["event"].as_array() {
-                for event in events {
-                    if event["listen"] == "test" {
-                        if let Some(exec) = event["script"]["exec"].as_array() {
-                            let script = exec
-                                .iter()
-                                .filter_map(|line| line.as_str())
-                                .collect::<Vec<&str>>()
-                                .join("\n");
-                            updated_scripts.push(script);
-                        }
-                    }
-                }
-            }
-            
-            check_items(sub_items, issues, &current_path, &updated_scripts);
-        }
-    }
+/// Version utilisant le contexte partagé (index des scripts et des URLs)
+pub fn check_with_context(collection: &Value, ctx: &LintContext) -> Vec<LintIssue> {
+    TestDescriptionWithUri.check(collection, ctx, &LintConfig::default())
 }
 
-fn check_request_tests(item: &Value, issues: &mut Vec<LintIssue>, path: &str, item_name: &str) {
-    // Extraire le script de test
-    let test_script = extract_test_script(item);
+fn check_request_tests(ctx: &LintContext, issues: &mut Vec<LintIssue>, path: &str, item_name: &str) {
+    // Scripts et URL de la requête (index partagé)
+    let scripts = ctx.scripts.get(path);
+    let test_script = &scripts.test;
     if test_script.is_empty() {
         return;
     }
-    
-    // Extraire le script pre-request pour détecter les variables
-    let prerequest_script = extract_prerequest_script(item);
-    
-    // Extraire l'URI path
-    let uri_path = extract_uri_path(item);
-    if uri_path == "/unknown" {
-        return;
-    }
+    let prerequest_script = &scripts.prerequest;
+    let uri_path = scripts.url.as_ref().map(|url| url.path.as_str()).unwrap_or("");
     
     // Extraire les segments du path
     let path_segments: Vec<&str> = uri_path
@@ -105,12 +66,10 @@ fn check_request_tests(item: &Value, issues: &mut Vec<LintIssue>, path: &str, it
     }
     
     // Détecter les variables de chemin définies
-    let path_variables = extract_path_variables(&prerequest_script, &test_script);
+    let path_variables = extract_path_variables(prerequest_script, test_script);
     
     // Analyser chaque test pm.test (avec description simple ou concaténation)
-    let test_pattern = Regex::new(r#"pm\.test\s*\(\s*([^,]+?)(?:,|\))"#).unwrap();
-    
-    for caps in test_pattern.captures_iter(&test_script) {
+    for caps in TEST_DESCRIPTION.captures_iter(test_script) {
         if let Some(desc_match) = caps.get(1) {
             let raw_description = desc_match.as_str().trim();
             
@@ -126,8 +85,7 @@ fn check_request_tests(item: &Value, issues: &mut Vec<LintIssue>, path: &str, it
             }
             
             // Extraire la description textuelle (entre guillemets)
-            let simple_desc_pattern = Regex::new(r#"["']([^"']+)["']"#).unwrap();
-            if let Some(simple_caps) = simple_desc_pattern.captures(raw_description) {
+            if let Some(simple_caps) = QUOTED_TEXT.captures(raw_description) {
                 if let Some(text_match) = simple_caps.get(1) {
                     let test_description = text_match.as_str();
                     let test_desc_lower = test_description.to_lowercase();
@@ -146,37 +104,29 @@ fn check_request_tests(item: &Value, issues: &mut Vec<LintIssue>, path: &str, it
                         let suggested_segments = &path_segments[path_segments.len() - max_segments..];
                         let suggested_path = format!("/{}", suggested_segments.join("/"));
                         
-                        let suggestion = if path_variables.is_empty() {
-                            format!(
-                                "include a path segment (ex: \"{}\") or use the location/requestName variable",
-                                suggested_path
-                            )
+                        let issue = LintIssue::builder("test-description-with-uri")
+                            .severity("error")
+                            .param("test", test_description)
+                            .param("request", item_name)
+                            .param("segment", suggested_path);
+                        let issue = if path_variables.is_empty() {
+                            issue.message_id("test-description-with-uri.location-variable")
                         } else {
-                            format!(
-                                "include a path segment (ex: \"{}\") or use the {} variable",
-                                suggested_path,
-                                path_variables.join(" or ")
-                            )
+                            issue
+                                .message_id("test-description-with-uri.path-variable")
+                                .param("variables", path_variables.join(" or "))
                         };
                         
                         // Générer la nouvelle description avec location
                         let new_description = format!("location + ' - {}'", test_description);
                         
-                        issues.push(LintIssue {
-                            rule_id: "test-description-with-uri".to_string(),
-                            severity: "error".to_string(),
-                            message: format!(
-                                "🎯 Test \"{}\" in \"{}\" should {}",
-                                test_description, item_name, suggestion
-                            ),
-                            path: path.to_string(),
-                            line: None,
-                            fix: Some(serde_json::json!({
-                                "type": "update_test_description",
-                                "old_description": test_description,
-                                "new_description": new_description,
-                            })),
-                        });
+                        issues.push(issue
+                            .path(path)
+                            .fix(FixAction::UpdateTestDescription {
+                                old_description: test_description.to_string(),
+                                new_description,
+                            })
+                            .render());
                     }
                 }
             }
@@ -184,106 +134,62 @@ fn check_request_tests(item: &Value, issues: &mut Vec<LintIssue>, path: &str, it
     }
 }
 
-fn extract_test_script(item: &Value) -> String {
-    if let Some(events) = item["event"].as_array() {
-        for event in events {
-            if event["listen"] == "test" {
-                if let Some(exec) = event["script"]["exec"].as_array() {
-                    return exec
-                        .iter()
-                        .filter_map(|line| line.as_str())
-                        .collect::<Vec<&str>>()
-                        .join("\n");
-                }
-            }
+fn extract_path_variables(prerequest_script: &str, test_script: &str) -> Vec<String> {
+    let mut variables = Vec::new();
+    
+    for re in PATH_VARIABLE_REGEXES.iter() {
+        for script in [prerequest_script, test_script] {
+            variables.extend(re.captures_iter(script).map(|caps| caps[1].to_string()));
         }
     }
-    String::new()
+    
+    // Dédupliquer
+    variables.sort();
+    variables.dedup();
+    variables
 }
 
-fn extract_prerequest_script(item: &Value) -> String {
-    if let Some(events) = item["event"].as_array() {
-        for event in events {
-            if event["listen"] == "prerequest" {
-                if let Some(exec) = event["script"]["exec"].as_array() {
-                    return exec
-                        .iter()
-                        .filter_map(|line| line.as_str())
-                        .collect::<Vec<&str>>()
-                        .join("\n");
-                }
-            }
-        }
+/// Déclaration de la règle dans le registre
+pub struct TestDescriptionWithUri;
+
+impl Rule for TestDescriptionWithUri {
+    fn id(&self) -> &'static str {
+        "test-description-with-uri"
     }
-    String::new()
-}
 
-fn extract_uri_path(item: &Value) -> String {
-    let url = if let Some(url_str) = item["request"]["url"].as_str() {
-        url_str.to_string()
-    } else if let Some(url_obj) = item["request"]["url"].as_object() {
-        url_obj.get("raw")
-            .and_then(|v| v.as_str())
-            .unwrap_or("")
-            .to_string()
-    } else {
-        return "/unknown".to_string();
-    };
-    
-    // Nettoyer l'URL des variables {{base_url}}
-    let clean_url = Regex::new(r"\{\{[^}]+\}\}")
-        .unwrap()
-        .replace_all(&url, "http://example.com");
-    
-    // Extraire le path
-    if let Ok(parsed_url) = url::Url::parse(&clean_url) {
-        let path = parsed_url.path().to_string();
-        // Supprimer les query params et fragments
-        path.split('?').next()
-            .unwrap_or(&path)
-            .split('#').next()
-            .unwrap_or(&path)
-            .to_string()
-    } else {
-        // Fallback : extraire manuellement
-        if let Some(path_match) = Regex::new(r"/[^?#]*").unwrap().find(&url) {
-            path_match.as_str().to_string()
-        } else {
-            "/unknown".to_string()
-        }
+    fn category(&self) -> Category {
+        Category::Testing
+    }
+
+    fn severity(&self) -> &'static str {
+        "error"
+    }
+
+    fn description(&self) -> &'static str {
+        "Test descriptions must include URIs"
+    }
+
+    fn fix_types(&self) -> &'static [&'static str] {
+        &["update_test_description"]
+    }
+
+    fn inputs(&self) -> &'static [RuleInput] {
+        &[RuleInput::Scripts]
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, _config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        Some(Box::new(TestDescriptionWithUri))
     }
 }
 
-fn extract_path_variables(prerequest_script: &str, test_script: &str) -> Vec<String> {
-    let mut variables = Vec::new();
-    
-    // Patterns pour détecter les variables de chemin
-    let patterns = vec![
-        r#"pm\.environment\.set\s*\(\s*["']([^"']+)["']\s*,\s*[^)]*(?:path|location|uri|url)"#,
-        r#"pm\.variables\.set\s*\(\s*["']([^"']+)["']\s*,\s*[^)]*(?:path|location|uri|url)"#,
-        r#"let\s+(\w+)\s*=\s*[^;]*(?:path|location|uri|url)"#,
-        r#"const\s+(\w+)\s*=\s*[^;]*(?:path|location|uri|url)"#,
-    ];
-    
-    for pattern in patterns {
-        if let Ok(re) = Regex::new(pattern) {
-            for caps in re.captures_iter(prerequest_script) {
-                if let Some(var_match) = caps.get(1) {
-                    variables.push(var_match.as_str().to_string());
-                }
-            }
-            for caps in re.captures_iter(test_script) {
-                if let Some(var_match) = caps.get(1) {
-                    variables.push(var_match.as_str().to_string());
-                }
-            }
+impl ItemVisitor for TestDescriptionWithUri {
+    fn visit(&mut self, item: &ItemContext, _typed: &TypedItem, ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        // Les tests définis au niveau folder ne peuvent pas inclure l'URI de chaque requête
+        let has_tests_in_parent = item.parent_test_scripts.iter().any(|script| TEST_CALL.is_match(script));
+        if item.is_request() && !has_tests_in_parent {
+            check_request_tests(ctx, issues, &item.path, &item.name);
         }
     }
-    
-    // Dédupliquer
-    variables.sort();
-    variables.dedup();
-    variables
 }
 
 #[cfg(test)]
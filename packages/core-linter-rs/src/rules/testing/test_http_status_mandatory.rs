@@ -1,6 +1,22 @@
-use crate::LintIssue;
+use crate::context::LintContext;
+use crate::snippets;
+use crate::{FixAction, LintConfig, LintIssue};
+use crate::model::TypedItem;
+use crate::rules::{Category, ItemVisitor, Rule, RuleInput};
+use crate::utils::{self, ItemContext};
+use crate::i18n::Render;
 use regex::Regex;
 use serde_json::Value;
+use std::sync::LazyLock;
+
+/// Patterns regex pour détecter les tests de statut HTTP
+pub const STATUS_PATTERNS: &[&str] = &[
+    r"pm\.response\.to\.have\.status\(",
+    r"pm\.response\.to\.be\.success",
+    r"pm\.expect\(pm\.response\.code\)",
+    r"pm\.response\.code\s*===",
+    r"responseCode\.code\s*===",
+];
 
 /// Règle : test-http-status-mandatory
 /// 
@@ -12,86 +28,67 @@ use serde_json::Value;
 /// - pm.response.code === 200
 /// - responseCode.code === 200
 pub fn check(collection: &Value) -> Vec<LintIssue> {
-    let mut issues = Vec::new();
-    
-    // Patterns regex pour détecter les tests de statut HTTP
-    let status_patterns = vec![
-        r"pm\.response\.to\.have\.status\(",
-        r"pm\.response\.to\.be\.success",
-        r"pm\.expect\(pm\.response\.code\)",
-        r"pm\.response\.code\s*===",
-        r"responseCode\.code\s*===",
-    ];
-    
-    let combined_pattern = status_patterns.join("|");
-    let regex = Regex::new(&combined_pattern).unwrap();
-    
-    if let Some(items) = collection["item"].as_array() {
-        check_items(items, &regex, &mut issues, "");
-    }
-    
-    issues
+    check_with_context(collection, &LintContext::new(collection))
 }
 
-fn check_items(items: &[Value], regex: &Regex, issues: &mut Vec<LintIssue>, parent_path: &str) {
-    for (index, item) in items.iter().enumerate() {
-        let item_name = item["name"].as_str().unwrap_or("unknown");
-        let current_path = if parent_path.is_empty() {
-            format!("/item[{}]", index)
-        } else {
-            format!("{}/item[{}]", parent_path, index)
-        };
-        
-        // Si c'est une requête
-        if item.get("request").is_some() {
-            let has_status_test = check_request_for_status_test(item, regex);
-            
-            if !has_status_test {
-                // Générer le code de test à ajouter avec la variable location
-                let test_code = "pm.test(location + ' - Status code is 2xx', function() {\n    pm.response.to.be.success;\n});".to_string();
-                
-                issues.push(LintIssue {
-                    rule_id: "test-http-status-mandatory".to_string(),
-                    severity: "error".to_string(),
-                    message: format!("Request '{}' does not test the HTTP status code", item_name),
-                    path: current_path.clone(),
-                    line: None,
-                    fix: Some(serde_json::json!({
-                        "type": "add_test",
-                        "test_code": test_code,
-                    })),
-                });
-            }
-        }
-        
-        // Récursion pour les sous-dossiers
-        if let Some(sub_items) = item["item"].as_array() {
-            check_items(sub_items, regex, issues, &current_path);
-        }
+/// Version utilisant le contexte partagé (index des scripts, snippets configurés)
+pub fn check_with_context(collection: &Value, ctx: &LintContext) -> Vec<LintIssue> {
+    TestHttpStatusMandatory.check(collection, ctx, &LintConfig::default())
+}
+
+/// Regex combinée des patterns de statut, compilée une seule fois
+static STATUS_REGEX: LazyLock<Regex> = LazyLock::new(|| utils::build_regex(&STATUS_PATTERNS.join("|")).unwrap());
+
+/// Déclaration de la règle dans le registre
+pub struct TestHttpStatusMandatory;
+
+impl Rule for TestHttpStatusMandatory {
+    fn id(&self) -> &'static str {
+        "test-http-status-mandatory"
+    }
+
+    fn category(&self) -> Category {
+        Category::Testing
+    }
+
+    fn severity(&self) -> &'static str {
+        "error"
+    }
+
+    fn description(&self) -> &'static str {
+        "HTTP status tests required"
+    }
+
+    fn fix_types(&self) -> &'static [&'static str] {
+        &["add_test"]
+    }
+
+    fn inputs(&self) -> &'static [RuleInput] {
+        &[RuleInput::Scripts]
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, _config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        Some(Box::new(TestHttpStatusMandatory))
     }
 }
 
-fn check_request_for_status_test(item: &Value, regex: &Regex) -> bool {
-    // Extraire le script de test
-    if let Some(events) = item["event"].as_array() {
-        for event in events {
-            if event["listen"] == "test" {
-                if let Some(script) = event["script"]["exec"].as_array() {
-                    let test_script = script
-                        .iter()
-                        .filter_map(|line| line.as_str())
-                        .collect::<Vec<&str>>()
-                        .join("\n");
-                    
-                    if regex.is_match(&test_script) {
-                        return true;
-                    }
-                }
-            }
+impl ItemVisitor for TestHttpStatusMandatory {
+    fn visit(&mut self, item: &ItemContext, _typed: &TypedItem, ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        if !item.is_request() || STATUS_REGEX.is_match(&ctx.scripts.get(&item.path).test) {
+            return;
         }
+
+        // Générer le code de test à ajouter avec la variable location
+        let test_code = ctx.snippets.render(snippets::STATUS_TEST, &[]);
+
+        issues.push(LintIssue::builder("test-http-status-mandatory")
+            .severity("error")
+            .message_id("test-http-status-mandatory.missing")
+            .param("request", item.name.as_str())
+            .path(item.path.clone())
+            .fix(FixAction::AddTest { test_code })
+            .render());
     }
-    
-    false
 }
 
 #[cfg(test)]
@@ -148,4 +145,27 @@ mod tests {
         assert_eq!(issues[0].rule_id, "test-http-status-mandatory");
         assert_eq!(issues[0].severity, "error");
     }
+
+    #[test]
+    fn test_custom_status_snippet() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "GET Users",
+                "request": { "url": "https://api.example.com/users" }
+            }]
+        });
+        
+        let mut custom = std::collections::HashMap::new();
+        custom.insert("status_test".to_string(), "checkStatus({{location_expr}}, 200);".to_string());
+        let ctx = LintContext::new(&collection)
+            .with_snippets(crate::snippets::SnippetLibrary::new(Default::default(), Some(&custom)));
+        
+        let issues = check_with_context(&collection, &ctx);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].fix,
+            Some(FixAction::AddTest { test_code: "checkStatus(location, 200);".to_string() })
+        );
+    }
 }
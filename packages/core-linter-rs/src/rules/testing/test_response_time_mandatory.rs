@@ -1,7 +1,27 @@
-use crate::LintIssue;
-use crate::utils;
+use crate::context::LintContext;
+use crate::snippets;
+use crate::{FixAction, LintConfig, LintIssue};
+use crate::model::TypedItem;
+use crate::rules::{Category, ItemVisitor, Rule, RuleInput};
+use crate::utils::{self, ItemContext};
+use crate::i18n::Render;
 use regex::Regex;
 use serde_json::Value;
+use std::sync::LazyLock;
+
+/// Patterns pour détecter les tests de temps de réponse
+pub const RESPONSE_TIME_PATTERNS: &[&str] = &[
+    r"responseTime",
+    r"response_time",
+    r"pm\.response\.responseTime",
+    r"pm\.expect\(.*responseTime.*\)",
+    r"responseTime.*\.to\.be\.below",
+    r"responseTime.*\.to\.be\.lessThan",
+    r"(?i)temps de réponse",
+    r"(?i)response time",
+];
+
+static RESPONSE_TIME_REGEXES: LazyLock<Vec<Regex>> = LazyLock::new(|| utils::build_regexes(RESPONSE_TIME_PATTERNS));
 
 /// Règle : test-response-time-mandatory
 /// 
@@ -14,118 +34,80 @@ use serde_json::Value;
 /// 
 /// Sévérité : WARNING (-8%)
 pub fn check(collection: &Value) -> Vec<LintIssue> {
-    let mut issues = Vec::new();
-    
-    if let Some(items) = collection["item"].as_array() {
-        check_items(items, &mut issues, "", &[]);
-    }
-    
-    issues
+    check_with_context(collection, &LintContext::new(collection))
 }
 
-fn check_items(
-    items: &[Value],
-    issues: &mut Vec<LintIssue>,
-    parent_path: &str,
-    parent_scripts: &[String],
-) {
-    for (index, item) in items.iter().enumerate() {
-        let default_name = format!("Item-{}", index + 1);
-        let item_name = item["name"].as_str().unwrap_or(&default_name);
-        let current_path = if parent_path.is_empty() {
-            format!("/item[{}]", index)
-        } else {
-            format!("{}/item[{}]", parent_path, index)
-        };
-        
-        // Si c'est une requête
-        if item.get("request").is_some() {
-            check_request_response_time(item, issues, &current_path, item_name, parent_scripts);
-        }
-        
-        // Si c'est un folder, récurser avec les scripts du folder
-        if let Some(sub_items) = item["item"].as_array() {
-            let mut updated_scripts = parent_scripts.to_vec();
-            
-            // Ajouter les scripts de test du folder actuel
-            if let Some(events) = item["event"].as_array() {
-                for event in events {
-                    if event["listen"] == "test" {
-                        if let Some(exec) = event["script"]["exec"].as_array() {
-                            let script = exec
-                                .iter()
-                                .filter_map(|line| line.as_str())
-                                .collect::<Vec<&str>>()
-                                .join("\n");
-                            updated_scripts.push(script);
-                        }
-                    }
-                }
-            }
-            
-            check_items(sub_items, issues, &current_path, &updated_scripts);
-        }
-    }
+/// Version utilisant le contexte partagé (index des scripts, snippets configurés)
+pub fn check_with_context(collection: &Value, ctx: &LintContext) -> Vec<LintIssue> {
+    TestResponseTimeMandatory.check(collection, ctx, &LintConfig::default())
 }
 
 fn check_request_response_time(
-    item: &Value,
+    ctx: &LintContext,
     issues: &mut Vec<LintIssue>,
     path: &str,
     item_name: &str,
     parent_scripts: &[String],
 ) {
-    // Extraire le script de test
-    let test_script = utils::extract_test_scripts(item).join("\n");
-    
-    // Patterns pour détecter les tests de temps de réponse
-    let response_time_patterns = vec![
-        r"responseTime",
-        r"response_time",
-        r"pm\.response\.responseTime",
-        r"pm\.expect\(.*responseTime.*\)",
-        r"responseTime.*\.to\.be\.below",
-        r"responseTime.*\.to\.be\.lessThan",
-        r"(?i)temps de réponse",
-        r"(?i)response time",
-    ];
+    // Script de test de la requête (index partagé)
+    let test_script = &ctx.scripts.get(path).test;
     
-    // Vérifier dans le script de la requête
-    let has_response_time_test = response_time_patterns.iter().any(|pattern| {
-        if let Ok(re) = Regex::new(pattern) {
-            re.is_match(&test_script)
-        } else {
-            false
-        }
-    });
-    
-    // Si pas trouvé, vérifier dans les scripts parents
-    let has_test_in_parents = if !has_response_time_test {
-        parent_scripts.iter().any(|parent_script| {
-            response_time_patterns.iter().any(|pattern| {
-                if let Ok(re) = Regex::new(pattern) {
-                    re.is_match(parent_script)
-                } else {
-                    false
-                }
-            })
-        })
-    } else {
-        false
-    };
+    // Vérifier dans le script de la requête, puis dans les scripts parents
+    let has_response_time_test = RESPONSE_TIME_REGEXES.iter().any(|re| re.is_match(test_script));
+    let has_test_in_parents = !has_response_time_test
+        && parent_scripts.iter().any(|script| RESPONSE_TIME_REGEXES.iter().any(|re| re.is_match(script)));
     
     if !has_response_time_test && !has_test_in_parents {
-        issues.push(LintIssue {
-            rule_id: "test-response-time-mandatory".to_string(),
-            severity: "warning".to_string(),
-            message: format!("⏱️ Request \"{}\" is missing response time test", item_name),
-            path: path.to_string(),
-            line: None,
-            fix: Some(serde_json::json!({
-                "type": "add_response_time_test",
-                "suggested_code": "pm.test(location + \" - Response time is less than 200ms\", function () {\n    pm.expect(pm.response.responseTime).to.be.below(200);\n});",
-            })),
-        });
+        issues.push(LintIssue::builder("test-response-time-mandatory")
+            .message_id("test-response-time-mandatory.missing")
+            .param("request", item_name)
+            .path(path)
+            .fix(FixAction::AddResponseTimeTest {
+                suggested_code: ctx.snippets.render(snippets::RESPONSE_TIME_TEST, &[("threshold", "200")]),
+            })
+            .render());
+    }
+}
+
+/// Déclaration de la règle dans le registre
+pub struct TestResponseTimeMandatory;
+
+impl Rule for TestResponseTimeMandatory {
+    fn id(&self) -> &'static str {
+        "test-response-time-mandatory"
+    }
+
+    fn category(&self) -> Category {
+        Category::Testing
+    }
+
+    fn severity(&self) -> &'static str {
+        "warning"
+    }
+
+    fn description(&self) -> &'static str {
+        "Response time tests recommended"
+    }
+
+    fn fix_types(&self) -> &'static [&'static str] {
+        &["add_response_time_test"]
+    }
+
+    fn inputs(&self) -> &'static [RuleInput] {
+        &[RuleInput::Scripts]
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, _config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        Some(Box::new(TestResponseTimeMandatory))
+    }
+}
+
+impl ItemVisitor for TestResponseTimeMandatory {
+    fn visit(&mut self, item: &ItemContext, _typed: &TypedItem, ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        // Les scripts des folders parents s'appliquent à la requête
+        if item.is_request() {
+            check_request_response_time(ctx, issues, &item.path, &item.name, item.parent_test_scripts);
+        }
     }
 }
 
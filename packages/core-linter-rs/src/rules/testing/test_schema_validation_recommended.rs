@@ -1,7 +1,22 @@
-use crate::LintIssue;
-use crate::utils;
+use crate::context::LintContext;
+use crate::snippets;
+use crate::{FixAction, LintConfig, LintIssue};
+use crate::model::TypedItem;
+use crate::rules::{Category, ItemVisitor, Rule, RuleInput};
+use crate::utils::{self, ItemContext};
+use crate::i18n::Render;
 use regex::Regex;
 use serde_json::Value;
+use std::sync::LazyLock;
+
+/// Patterns pour détecter la validation de schéma
+pub const SCHEMA_PATTERNS: &[&str] = &[
+    r"pm\.response\.to\.have\.jsonSchema\s*\(",
+    r"jsonSchema",
+    r"Schema_Validation",
+];
+
+static SCHEMA_REGEXES: LazyLock<Vec<Regex>> = LazyLock::new(|| utils::build_regexes(SCHEMA_PATTERNS));
 
 /// Règle : test-schema-validation-recommended
 /// 
@@ -13,135 +28,91 @@ use serde_json::Value;
 /// 
 /// Sévérité : WARNING (-8%)
 pub fn check(collection: &Value) -> Vec<LintIssue> {
-    let mut issues = Vec::new();
-    
-    if let Some(items) = collection["item"].as_array() {
-        check_items(items, &mut issues, "", &[]);
-    }
-    
-    issues
+    check_with_context(collection, &LintContext::new(collection))
 }
 
-fn check_items(
-    items: &[Value],
-    issues: &mut Vec<LintIssue>,
-    parent_path: &str,
-    parent_scripts: &[String],
-) {
-    for (index, item) in items.iter().enumerate() {
-        let default_name = format!("Item-{}", index + 1);
-        let item_name = item["name"].as_str().unwrap_or(&default_name);
-        let current_path = if parent_path.is_empty() {
-            format!("/item[{}]", index)
-        } else {
-            format!("{}/item[{}]", parent_path, index)
-        };
-        
-        // Si c'est une requête
-        if item.get("request").is_some() {
-            check_request_schema_validation(item, issues, &current_path, item_name, parent_scripts);
-        }
-        
-        // Si c'est un folder, récurser avec les scripts du folder
-        if let Some(sub_items) = item["item"].as_array() {
-            let mut updated_scripts = parent_scripts.to_vec();
-            
-            // Ajouter les scripts de test du folder actuel
-            if let Some(events) = item["event"].as_array() {
-                for event in events {
-                    if event["listen"] == "test" {
-                        if let Some(exec) = event["script"]["exec"].as_array() {
-                            let script = exec
-                                .iter()
-                                .filter_map(|line| line.as_str())
-                                .collect::<Vec<&str>>()
-                                .join("\n");
-                            updated_scripts.push(script);
-                        }
-                    }
-                }
-            }
-            
-            check_items(sub_items, issues, &current_path, &updated_scripts);
-        }
-    }
+/// Version utilisant le contexte partagé (index des scripts, snippets configurés)
+pub fn check_with_context(collection: &Value, ctx: &LintContext) -> Vec<LintIssue> {
+    TestSchemaValidationRecommended.check(collection, ctx, &LintConfig::default())
 }
 
 fn check_request_schema_validation(
-    item: &Value,
+    item: &ItemContext,
+    ctx: &LintContext,
     issues: &mut Vec<LintIssue>,
     path: &str,
     item_name: &str,
     parent_scripts: &[String],
 ) {
-    // Extraire le script de test
-    let test_script = utils::extract_test_scripts(item).join("\n");
-    
-    // Patterns pour détecter la validation de schéma
-    let schema_patterns = vec![
-        r"pm\.response\.to\.have\.jsonSchema\s*\(",
-        r"jsonSchema",
-        r"Schema_Validation",
-    ];
+    // Script de test de la requête (index partagé)
+    let test_script = &ctx.scripts.get(path).test;
     
-    // Vérifier dans le script de la requête
-    let has_schema_validation = schema_patterns.iter().any(|pattern| {
-        if let Ok(re) = Regex::new(pattern) {
-            re.is_match(&test_script)
-        } else {
-            false
-        }
-    });
-    
-    // Si pas trouvé, vérifier dans les scripts parents
-    let has_schema_in_parents = if !has_schema_validation {
-        parent_scripts.iter().any(|parent_script| {
-            schema_patterns.iter().any(|pattern| {
-                if let Ok(re) = Regex::new(pattern) {
-                    re.is_match(parent_script)
-                } else {
-                    false
-                }
-            })
-        })
-    } else {
-        false
-    };
+    // Vérifier dans le script de la requête, puis dans les scripts parents
+    let has_schema_validation = SCHEMA_REGEXES.iter().any(|re| re.is_match(test_script));
+    let has_schema_in_parents = !has_schema_validation
+        && parent_scripts.iter().any(|script| SCHEMA_REGEXES.iter().any(|re| re.is_match(script)));
     
     let is_covered = has_schema_validation || has_schema_in_parents;
     
     // Vérifier si la requête retourne probablement du JSON
-    let method = item["request"]["method"].as_str().unwrap_or("");
-    let url = if let Some(url_str) = item["request"]["url"].as_str() {
-        url_str.to_string()
-    } else if let Some(url_obj) = item["request"]["url"].as_object() {
-        url_obj.get("raw")
-            .and_then(|v| v.as_str())
-            .unwrap_or("")
-            .to_string()
-    } else {
-        String::new()
-    };
+    let method = item.method.as_deref().unwrap_or("");
+    let url = ctx.scripts.get(path).url.as_ref().map(|url| url.raw.as_str()).unwrap_or("");
     
     let likely_json_response = (method == "GET" || method == "POST") &&
         !url.contains("/download") &&
         !url.contains("/file");
     
     if likely_json_response && !is_covered {
-        issues.push(LintIssue {
-            rule_id: "test-schema-validation-recommended".to_string(),
-            severity: "warning".to_string(),
-            message: format!(
-                "🛡️ Request \"{}\" should use JSON schema validation to improve test robustness",
-                item_name
-            ),
-            path: path.to_string(),
-            line: None,
-            fix: Some(serde_json::json!({
-                "type": "add_schema_validation",
-                "suggested_code": "// Définir le schéma JSON attendu\nconst schema = {\n    \"type\": \"object\",\n    \"properties\": {\n        // Définir les propriétés attendues\n    },\n    \"required\": []\n};\n\n// Test de validation de schéma\nif (pm.response.code === 200) {\n    pm.test(requestName + \" - Schema_Validation\", () => {\n        pm.response.to.have.jsonSchema(schema);\n    });\n}",
-            })),
-        });
+        issues.push(LintIssue::builder("test-schema-validation-recommended")
+            .message_id("test-schema-validation-recommended.missing")
+            .param("request", item_name)
+            .path(path)
+            .fix(FixAction::AddSchemaValidation {
+                suggested_code: ctx.snippets.render(snippets::SCHEMA_TEST, &[]),
+            })
+            .render());
+    }
+}
+
+/// Déclaration de la règle dans le registre
+pub struct TestSchemaValidationRecommended;
+
+impl Rule for TestSchemaValidationRecommended {
+    fn id(&self) -> &'static str {
+        "test-schema-validation-recommended"
+    }
+
+    fn category(&self) -> Category {
+        Category::Testing
+    }
+
+    fn severity(&self) -> &'static str {
+        "warning"
+    }
+
+    fn description(&self) -> &'static str {
+        "JSON responses validated against a schema"
+    }
+
+    fn fix_types(&self) -> &'static [&'static str] {
+        &["add_schema_validation"]
+    }
+
+    fn inputs(&self) -> &'static [RuleInput] {
+        &[RuleInput::Scripts]
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, _config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        Some(Box::new(TestSchemaValidationRecommended))
+    }
+}
+
+impl ItemVisitor for TestSchemaValidationRecommended {
+    fn visit(&mut self, item: &ItemContext, _typed: &TypedItem, ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        // Les scripts des folders parents s'appliquent à la requête
+        if item.is_request() {
+            check_request_schema_validation(item, ctx, issues, &item.path, &item.name, item.parent_test_scripts);
+        }
     }
 }
 
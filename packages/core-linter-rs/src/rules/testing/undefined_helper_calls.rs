@@ -0,0 +1,248 @@
+use crate::context::LintContext;
+use crate::{LintConfig, LintIssue};
+use crate::model::TypedItem;
+use crate::rules::{Category, ItemVisitor, Rule, RuleInput};
+use crate::utils::{self, ItemContext};
+use crate::i18n::Render;
+use regex::Regex;
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::sync::LazyLock;
+
+/// Fonctions globales du sandbox Postman et du JavaScript, jamais définies par les scripts
+const SANDBOX_GLOBALS: &[&str] = &[
+    "require", "eval", "xml2Json", "atob", "btoa", "setTimeout", "clearTimeout", "setInterval",
+    "clearInterval", "parseInt", "parseFloat", "isNaN", "isFinite", "encodeURI", "encodeURIComponent",
+    "decodeURI", "decodeURIComponent", "String", "Number", "Boolean", "Array", "Object", "Date",
+    "RegExp", "Error", "Symbol", "BigInt", "Promise", "Map", "Set",
+];
+
+/// Mots-clés suivis d'une parenthèse, qui ne sont pas des appels
+const KEYWORDS: &[&str] = &[
+    "if", "for", "while", "switch", "catch", "return", "typeof", "function", "with", "await", "async",
+    "void", "delete", "in", "of", "instanceof", "yield", "super",
+];
+
+/// Règle : undefined-helper-calls
+///
+/// Les scripts des folders (ou de la collection) définissent souvent des helpers
+/// (`validateSchema()`, `checkPagination()`) appelés par les scripts des requêtes.
+/// Signale les appels de fonctions qui ne sont définies ni dans les scripts de l'item,
+/// ni dans ceux de ses folders parents ou de la collection : le script échouerait
+/// avec une `ReferenceError`. Seuls les appels directs sont vérifiés (pas les
+/// méthodes `objet.methode()`) ; un script chargeant du code avec `eval` n'est pas
+/// analysable et n'est pas signalé.
+///
+/// Sévérité : WARNING (-8%)
+pub fn check(collection: &Value) -> Vec<LintIssue> {
+    UndefinedHelperCalls.check(collection, &LintContext::new(collection), &LintConfig::default())
+}
+
+/// Déclarations de fonctions et de variables (une variable peut contenir une fonction)
+const DEFINITION_PATTERNS: &[&str] = &[
+    r"\bfunction\s*\*?\s*([A-Za-z_$][\w$]*)\s*\(",
+    r"\b(?:const|let|var)\s+([A-Za-z_$][\w$]*)",
+    r"(?:^|[^\w$.])([A-Za-z_$][\w$]*)\s*=\s*(?:async\s+)?(?:function\b|\([^()]*\)\s*=>|[A-Za-z_$][\w$]*\s*=>)",
+    // Méthode d'un objet littéral ou d'une classe : `helper(args) {`
+    r"(?:^|[^\w$.])([A-Za-z_$][\w$]*)\s*\([^()]*\)\s*\{",
+];
+
+static DEFINITIONS: LazyLock<Vec<Regex>> = LazyLock::new(|| utils::build_regexes(DEFINITION_PATTERNS));
+/// Appel direct `nom(`
+static CALL: LazyLock<Regex> = LazyLock::new(|| utils::build_regex(r"(?:^|[^\w$.])([A-Za-z_$][\w$]*)\s*\(").unwrap());
+/// `new` précédant un appel (constructeur)
+static NEW: LazyLock<Regex> = LazyLock::new(|| utils::build_regex(r"\bnew\s+$").unwrap());
+/// Code chargé dynamiquement
+static EVAL: LazyLock<Regex> = LazyLock::new(|| utils::build_regex(r"(?:^|[^\w$.])eval\s*\(").unwrap());
+
+/// Fonctions et variables déclarées par un script
+fn definitions(code: &str) -> BTreeSet<String> {
+    DEFINITIONS
+        .iter()
+        .flat_map(|re| re.captures_iter(code).map(|caps| caps[1].to_string()).collect::<Vec<_>>())
+        .collect()
+}
+
+/// Appels directs de fonctions (hors méthodes, constructeurs et mots-clés)
+fn calls(code: &str) -> BTreeSet<String> {
+    CALL.captures_iter(code)
+        .filter(|caps| !NEW.is_match(&code[..caps.get(1).unwrap().start()]))
+        .map(|caps| caps[1].to_string())
+        .filter(|name| !KEYWORDS.contains(&name.as_str()) && !SANDBOX_GLOBALS.contains(&name.as_str()))
+        .collect()
+}
+
+/// Script sans commentaires ni contenu des chaînes littérales (remplacées par `""`), pour ne
+/// pas confondre `"Status (200)"` ou `// voir helper()` avec un appel
+fn code_only(script: &str) -> String {
+    let chars: Vec<char> = script.chars().collect();
+    let mut code = String::with_capacity(script.len());
+    let mut i = 0;
+    while i < chars.len() {
+        match (chars[i], chars.get(i + 1)) {
+            ('/', Some('/')) => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+            }
+            ('/', Some('*')) => {
+                i += 2;
+                while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                    i += 1;
+                }
+                i += 2;
+                code.push(' ');
+            }
+            (quote @ ('"' | '\'' | '`'), _) => {
+                i += 1;
+                while i < chars.len() && chars[i] != quote {
+                    i += if chars[i] == '\\' { 2 } else { 1 };
+                }
+                i += 1;
+                code.push_str("\"\"");
+            }
+            (c, _) => {
+                code.push(c);
+                i += 1;
+            }
+        }
+    }
+    code
+}
+
+/// Déclaration de la règle dans le registre
+pub struct UndefinedHelperCalls;
+
+impl Rule for UndefinedHelperCalls {
+    fn id(&self) -> &'static str {
+        "undefined-helper-calls"
+    }
+
+    fn category(&self) -> Category {
+        Category::Testing
+    }
+
+    fn severity(&self) -> &'static str {
+        "warning"
+    }
+
+    fn description(&self) -> &'static str {
+        "Scripts only call helper functions defined in the item or its parent folder/collection scripts"
+    }
+
+    fn inputs(&self) -> &'static [RuleInput] {
+        &[RuleInput::Scripts]
+    }
+
+    fn visitor<'a>(&'a self, _ctx: &'a LintContext, _config: &'a LintConfig) -> Option<Box<dyn ItemVisitor + 'a>> {
+        Some(Box::new(UndefinedHelperCalls))
+    }
+}
+
+impl ItemVisitor for UndefinedHelperCalls {
+    fn visit(&mut self, item: &ItemContext, _typed: &TypedItem, ctx: &LintContext, issues: &mut Vec<LintIssue>) {
+        let own = ctx.scripts.get(&item.path);
+        let own_code = code_only(&format!("{}\n{}", own.prerequest, own.test));
+        let used = calls(&own_code);
+        if used.is_empty() {
+            return;
+        }
+
+        // Chaîne d'héritage : collection, folders parents, puis l'item lui-même
+        let collection = ctx.scripts.get("");
+        let upstream: Vec<String> = [&collection.prerequest, &collection.test]
+            .into_iter()
+            .chain(item.parent_prerequest_scripts)
+            .chain(item.parent_test_scripts)
+            .map(|script| code_only(script))
+            .chain(std::iter::once(own_code))
+            .collect();
+        if upstream.iter().any(|code| EVAL.is_match(code)) {
+            return;
+        }
+        let defined: BTreeSet<String> = upstream.iter().flat_map(|code| definitions(code)).collect();
+
+        issues.extend(used.difference(&defined).map(|helper| LintIssue::builder("undefined-helper-calls")
+            .message_id("undefined-helper-calls.undefined")
+            .param("item", item.name.as_str())
+            .param("helper", helper.as_str())
+            .path(item.path.clone())
+            .render()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn script(listen: &str, lines: &[&str]) -> Value {
+        json!([{ "listen": listen, "script": { "exec": lines } }])
+    }
+
+    #[test]
+    fn test_helpers_resolved_through_parents() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "event": script("prerequest", &["checkPagination = function(body) { pm.expect(body.page).to.exist; };"]),
+            "item": [{
+                "name": "Users",
+                "event": script("test", &["function validateSchema(schema) { pm.response.to.have.jsonSchema(schema); }"]),
+                "item": [{
+                    "name": "GET Users",
+                    "request": { "method": "GET", "url": "{{base_url}}/users" },
+                    "event": script("test", &[
+                        "const body = pm.response.json();",
+                        "pm.test(\"Status (200) - see helper()\", () => validateSchema({}));",
+                        "// legacyCheck() n'est plus appelé",
+                        "checkPagination(body);",
+                        "const date = new Date(parseInt(body.ts));"
+                    ])
+                }]
+            }]
+        });
+        assert!(check(&collection).is_empty());
+    }
+
+    #[test]
+    fn test_undefined_and_sibling_helpers() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [
+                {
+                    "name": "Orders",
+                    "event": script("test", &["const helpers = { checkOrder(order) { pm.expect(order.id).to.exist; } };"]),
+                    "item": []
+                },
+                {
+                    "name": "GET Users",
+                    "request": { "method": "GET", "url": "{{base_url}}/users" },
+                    "event": script("test", &["validateSchema(schema);", "checkOrder(pm.response.json());"])
+                }
+            ]
+        });
+        let issues = check(&collection);
+        let helpers: Vec<bool> = ["checkOrder()", "validateSchema()"]
+            .iter()
+            .map(|helper| issues.iter().any(|issue| issue.message.contains(helper)))
+            .collect();
+        assert_eq!(issues.len(), 2);
+        assert_eq!(helpers, vec![true, true]);
+        assert_eq!(issues[0].path, "/item[1]");
+        assert_eq!(issues[0].message_id.as_deref(), Some("undefined-helper-calls.undefined"));
+    }
+
+    #[test]
+    fn test_eval_loaded_helpers_are_skipped() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "event": script("prerequest", &["eval(pm.globals.get('helpers'));"]),
+            "item": [{
+                "name": "GET Users",
+                "request": { "method": "GET", "url": "{{base_url}}/users" },
+                "event": script("test", &["validateSchema(schema);"])
+            }]
+        });
+        assert!(check(&collection).is_empty());
+    }
+}
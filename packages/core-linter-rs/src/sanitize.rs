@@ -0,0 +1,218 @@
+//! Anonymisation d'une collection avant partage
+//!
+//! Réutilise les détecteurs des règles de sécurité pour produire une copie partageable :
+//! secrets remplacés par des variables, emails / téléphones masqués, hôtes internes
+//! remplacés par `example.com`. Chaque remplacement est consigné dans un rapport
+//! (sans la valeur d'origine).
+
+use crate::rules::security::hardcoded_secrets::SECRET_PATTERNS;
+use crate::rules::security::internal_hosts_disclosure::HostDetector;
+use crate::utils;
+use regex::{Captures, Regex};
+use serde::Serialize;
+use serde_json::Value;
+use std::sync::LazyLock;
+
+/// Hôte de remplacement des hôtes internes
+const PLACEHOLDER_HOST: &str = "example.com";
+const PLACEHOLDER_EMAIL: &str = "user@example.com";
+const PLACEHOLDER_PHONE: &str = "+10000000000";
+
+/// Un remplacement effectué
+#[derive(Serialize, Debug, Clone)]
+pub struct Redaction {
+    /// Chemin de la valeur modifiée (ex: /item[0]/request/header[1]/value)
+    pub path: String,
+    /// Type de donnée masquée ("API Key", "email", "internal host"...)
+    pub kind: String,
+    pub replacement: String,
+}
+
+#[derive(Serialize, Debug, Default)]
+pub struct SanitizeReport {
+    pub redactions: Vec<Redaction>,
+}
+
+/// Motifs fixes des données masquées
+struct Patterns {
+    secrets: Vec<(Regex, &'static str, &'static str)>,
+    email: Regex,
+    phone: Regex,
+}
+
+static PATTERNS: LazyLock<Patterns> = LazyLock::new(|| Patterns {
+    secrets: SECRET_PATTERNS
+        .iter()
+        .filter_map(|(pattern, kind, variable)| utils::build_regex(pattern).ok().map(|r| (r, *kind, *variable)))
+        .collect(),
+    email: utils::build_regex(r"[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}").unwrap(),
+    phone: utils::build_regex(r"\+\d{1,3}[\s.-]?\(?\d{1,4}\)?(?:[\s.-]?\d{2,4}){2,4}").unwrap(),
+});
+
+/// Motifs fixes et hôtes internes configurés
+struct Sanitizer {
+    patterns: &'static Patterns,
+    hosts: HostDetector,
+}
+
+/// Anonymise la collection en place et retourne le rapport des remplacements
+pub fn sanitize(collection: &mut Value, internal_suffixes: &[String]) -> SanitizeReport {
+    let sanitizer = Sanitizer { patterns: &PATTERNS, hosts: HostDetector::new(internal_suffixes) };
+
+    let mut report = SanitizeReport::default();
+    sanitize_value(collection, &sanitizer, &mut report, "");
+    report
+}
+
+fn sanitize_value(value: &mut Value, sanitizer: &Sanitizer, report: &mut SanitizeReport, path: &str) {
+    match value {
+        Value::String(text) => {
+            if let Some(sanitized) = sanitize_text(text, sanitizer, report, path) {
+                *text = sanitized;
+            }
+        }
+        Value::Array(values) => {
+            for (index, child) in values.iter_mut().enumerate() {
+                sanitize_value(child, sanitizer, report, &format!("{}[{}]", path, index));
+            }
+        }
+        Value::Object(map) => {
+            for (key, child) in map.iter_mut() {
+                sanitize_value(child, sanitizer, report, &format!("{}/{}", path, key));
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Retourne le texte anonymisé, ou None s'il n'y avait rien à masquer
+fn sanitize_text(text: &str, sanitizer: &Sanitizer, report: &mut SanitizeReport, path: &str) -> Option<String> {
+    let mut result = text.to_string();
+    let mut redact = |kind: &str, replacement: &str| {
+        report.redactions.push(Redaction {
+            path: path.to_string(),
+            kind: kind.to_string(),
+            replacement: replacement.to_string(),
+        });
+    };
+
+    // 1. Secrets → variables
+    for (regex, kind, variable) in &sanitizer.patterns.secrets {
+        // Une clé privée occupe toute la valeur : on la remplace entièrement
+        if *kind == "Private Key" && regex.is_match(&result) {
+            redact(kind, variable);
+            result = variable.to_string();
+            continue;
+        }
+
+        let mut replaced = false;
+        let updated = regex.replace_all(&result, |caps: &Captures| {
+            let whole = caps.get(0).unwrap();
+            if whole.as_str().contains("{{") {
+                return whole.as_str().to_string();
+            }
+            replaced = true;
+            // Ne remplacer que la valeur capturée (garder "api_key=" ou "Bearer ")
+            match caps.get(1) {
+                Some(secret) => format!(
+                    "{}{}{}",
+                    &whole.as_str()[..secret.start() - whole.start()],
+                    variable,
+                    &whole.as_str()[secret.end() - whole.start()..]
+                ),
+                None => variable.to_string(),
+            }
+        });
+        if replaced {
+            result = updated.into_owned();
+            redact(kind, variable);
+        }
+    }
+
+    // 2. Données personnelles
+    if sanitizer.patterns.email.find_iter(&result).any(|m| !m.as_str().ends_with(PLACEHOLDER_HOST)) {
+        result = sanitizer
+            .patterns
+            .email
+            .replace_all(&result, |caps: &Captures| {
+                if caps[0].ends_with(PLACEHOLDER_HOST) { caps[0].to_string() } else { PLACEHOLDER_EMAIL.to_string() }
+            })
+            .into_owned();
+        redact("email", PLACEHOLDER_EMAIL);
+    }
+    if sanitizer.patterns.phone.is_match(&result) {
+        result = sanitizer.patterns.phone.replace_all(&result, PLACEHOLDER_PHONE).into_owned();
+        redact("phone number", PLACEHOLDER_PHONE);
+    }
+
+    // 3. Hôtes internes → example.com
+    for host in sanitizer.hosts.find(&result) {
+        result = result.replace(&host, PLACEHOLDER_HOST);
+        redact("internal host", PLACEHOLDER_HOST);
+    }
+
+    if result != text {
+        Some(result)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn suffixes() -> Vec<String> {
+        vec![".corp".to_string(), ".internal".to_string()]
+    }
+
+    #[test]
+    fn test_sanitize_secrets_pii_and_hosts() {
+        let mut collection = json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "POST Invite",
+                "request": {
+                    "method": "POST",
+                    "url": "http://api.build.corp/invites",
+                    "header": [{ "key": "X-Api-Key", "value": "api_key=abcdef1234567890abcdef1234567890" }],
+                    "body": { "mode": "raw", "raw": "{\"email\": \"jane.doe@acme.io\", \"phone\": \"+33 6 12 34 56 78\"}" }
+                }
+            }]
+        });
+
+        let report = sanitize(&mut collection, &suffixes());
+
+        let request = &collection["item"][0]["request"];
+        assert_eq!(request["url"], "http://example.com/invites");
+        assert_eq!(request["header"][0]["value"], "api_key={{api_key}}");
+        let body = request["body"]["raw"].as_str().unwrap();
+        assert!(body.contains("user@example.com"));
+        assert!(body.contains("+10000000000"));
+
+        assert_eq!(report.redactions.len(), 4);
+        assert!(report.redactions.iter().any(|r| r.path == "/item[0]/request/header[0]/value" && r.kind == "API Key"));
+    }
+
+    #[test]
+    fn test_clean_collection_untouched() {
+        let mut collection = json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "GET Users",
+                "request": {
+                    "method": "GET",
+                    "url": "{{base_url}}/users",
+                    "header": [{ "key": "Authorization", "value": "Bearer {{auth_token}}" }]
+                }
+            }]
+        });
+        let original = collection.clone();
+
+        let report = sanitize(&mut collection, &suffixes());
+
+        assert!(report.redactions.is_empty());
+        assert_eq!(collection, original);
+    }
+}
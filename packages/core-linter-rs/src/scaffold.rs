@@ -0,0 +1,165 @@
+//! Mode scaffold : génération des tests manquants sur toute la collection
+//!
+//! Indépendamment des issues remontées par le linter, injecte un lot de tests
+//! standard (statut HTTP, temps de réponse, stub de validation de schéma) dans chaque
+//! requête qui ne les possède pas encore. Les tests hérités des folders parents
+//! (et de la collection) sont pris en compte pour ne pas créer de doublons.
+
+use crate::fixer;
+use crate::FixAction;
+use crate::rules::testing::{test_http_status_mandatory, test_response_time_mandatory, test_schema_validation_recommended};
+use crate::snippets::{self, SnippetLibrary};
+use crate::utils;
+use regex::Regex;
+use serde::Serialize;
+use serde_json::Value;
+use std::sync::LazyLock;
+
+/// Seuil de temps de réponse utilisé par le snippet intégré
+const DEFAULT_RESPONSE_TIME_THRESHOLD: &str = "200";
+
+#[derive(Serialize, Debug, Default)]
+pub struct ScaffoldReport {
+    pub requests_updated: u32,
+    pub tests_added: u32,
+}
+
+struct TestDetectors {
+    status: Regex,
+    response_time: Regex,
+    schema: Regex,
+}
+
+static DETECTORS: LazyLock<TestDetectors> = LazyLock::new(|| TestDetectors {
+    status: utils::build_regex(&test_http_status_mandatory::STATUS_PATTERNS.join("|")).unwrap(),
+    response_time: utils::build_regex(&test_response_time_mandatory::RESPONSE_TIME_PATTERNS.join("|")).unwrap(),
+    schema: utils::build_regex(&test_schema_validation_recommended::SCHEMA_PATTERNS.join("|")).unwrap(),
+});
+
+/// Injecte les tests standard manquants dans toutes les requêtes de la collection
+pub fn scaffold_tests(collection: &mut Value, library: &SnippetLibrary) -> ScaffoldReport {
+
+    // 1. Planifier les ajouts (parcours en lecture seule)
+    let mut planned = Vec::new();
+    let collection_scripts = utils::extract_test_scripts(collection);
+    if let Some(items) = collection["item"].as_array() {
+        plan_items(items, library, &mut planned, "", &collection_scripts);
+    }
+
+    // 2. Appliquer les ajouts via le fixer (création des events, prerequest location...)
+    let mut report = ScaffoldReport::default();
+    let mut last_path = String::new();
+    for (path, test_code) in planned {
+        let fix = FixAction::AddTest { test_code };
+        if fixer::apply_single_fix(collection, &path, &fix, library.locale) {
+            report.tests_added += 1;
+            if path != last_path {
+                report.requests_updated += 1;
+                last_path = path;
+            }
+        }
+    }
+
+    report
+}
+
+fn plan_items(
+    items: &[Value],
+    library: &SnippetLibrary,
+    planned: &mut Vec<(String, String)>,
+    parent_path: &str,
+    parent_scripts: &[String],
+) {
+    for (index, item) in items.iter().enumerate() {
+        let current_path = if parent_path.is_empty() {
+            format!("/item[{}]", index)
+        } else {
+            format!("{}/item[{}]", parent_path, index)
+        };
+
+        // Scripts hérités + scripts propres à l'item
+        let mut scripts = parent_scripts.to_vec();
+        scripts.extend(utils::extract_test_scripts(item));
+
+        if item.get("request").is_some() {
+            let covered = |regex: &Regex| scripts.iter().any(|script| regex.is_match(script));
+
+            if !covered(&DETECTORS.status) {
+                planned.push((current_path.clone(), library.render(snippets::STATUS_TEST, &[])));
+            }
+            if !covered(&DETECTORS.response_time) {
+                planned.push((
+                    current_path.clone(),
+                    library.render(snippets::RESPONSE_TIME_TEST, &[("threshold", DEFAULT_RESPONSE_TIME_THRESHOLD)]),
+                ));
+            }
+
+            // Le stub de schéma n'a de sens que pour les réponses probablement JSON
+            let method = item["request"]["method"].as_str().unwrap_or("GET");
+            let expects_body = method == "GET" || method == "POST";
+            if expects_body && !covered(&DETECTORS.schema) {
+                planned.push((current_path.clone(), library.render(snippets::SCHEMA_TEST, &[])));
+            }
+        }
+
+        // Si c'est un folder, récurser avec ses scripts
+        if let Some(sub_items) = item["item"].as_array() {
+            plan_items(sub_items, library, planned, &current_path, &scripts);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_scaffold_adds_missing_bundle() {
+        let mut collection = json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "GET Users",
+                "request": { "method": "GET", "url": "{{base_url}}/users" }
+            }]
+        });
+
+        let report = scaffold_tests(&mut collection, &SnippetLibrary::default());
+
+        assert_eq!(report.requests_updated, 1);
+        assert_eq!(report.tests_added, 3);
+        let events = collection["item"][0]["event"].as_array().unwrap();
+        let test_event = events.iter().find(|e| e["listen"] == "test").unwrap();
+        assert_eq!(test_event["script"]["exec"].as_array().unwrap().len(), 3);
+        // Les snippets utilisent la variable location : le prerequest est ajouté
+        assert!(events.iter().any(|e| e["listen"] == "prerequest"));
+    }
+
+    #[test]
+    fn test_scaffold_honors_folder_inheritance() {
+        let mut collection = json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "Users",
+                "event": [{
+                    "listen": "test",
+                    "script": {
+                        "exec": [
+                            "pm.test('Status', () => pm.response.to.have.status(200));",
+                            "pm.test('Response time', () => pm.expect(pm.response.responseTime).to.be.below(500));"
+                        ]
+                    }
+                }],
+                "item": [{
+                    "name": "DELETE User",
+                    "request": { "method": "DELETE", "url": "{{base_url}}/users/1" }
+                }]
+            }]
+        });
+
+        let report = scaffold_tests(&mut collection, &SnippetLibrary::default());
+
+        assert_eq!(report.tests_added, 0);
+        assert!(collection["item"][0]["item"][0].get("event").is_none());
+    }
+}
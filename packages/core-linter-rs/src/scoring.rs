@@ -0,0 +1,183 @@
+//! Calcul du score
+//!
+//! Chaque sévérité retire jusqu'à 15 (error), 8 (warning) ou 3 (info) points selon
+//! la part de requêtes concernées. Ces pénalités sont réparties entre les catégories
+//! au prorata de leurs issues, puis plafonnées par catégorie : quelques erreurs de
+//! documentation ne pèsent plus autant qu'une fuite de secrets. Les plafonds par
+//! défaut se remplacent via `LintConfig::category_caps`.
+
+use crate::rules::{self, Category};
+use crate::{LintConfig, LintIssue};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Catégorie des issues de règles externes (sans plafond effectif)
+pub const EXTERNAL: &str = "external";
+
+/// Pénalités maximales par sévérité, atteintes quand toutes les requêtes sont concernées
+const ERROR_WEIGHT: f64 = 15.0;
+const WARNING_WEIGHT: f64 = 8.0;
+const INFO_WEIGHT: f64 = 3.0;
+
+/// Issues d'une catégorie et points retirés au score
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct CategoryScore {
+    pub errors: u32,
+    pub warnings: u32,
+    pub infos: u32,
+    /// Points retirés au score, plafond appliqué
+    pub penalty: f64,
+    /// Plafond de pénalité de la catégorie
+    pub max_penalty: f64,
+}
+
+/// Pénalité totale maximale, toutes sévérités confondues
+const MAX_PENALTY: f64 = ERROR_WEIGHT + WARNING_WEIGHT + INFO_WEIGHT;
+
+/// Plafond par défaut : la sécurité peut seule faire chuter le score, la
+/// documentation et la performance beaucoup moins
+pub fn default_cap(category: Category) -> f64 {
+    match category {
+        Category::Security => MAX_PENALTY,
+        Category::Testing => 20.0,
+        Category::Structure | Category::BestPractices => 12.0,
+        Category::Performance | Category::Flow | Category::Documentation => 10.0,
+    }
+}
+
+/// Catégorie d'une issue (`external` pour les règles hors catalogue intégré)
+pub fn category_of(rule_id: &str) -> String {
+    rules::find(rule_id)
+        .and_then(|rule| serde_json::to_value(rule.category()).ok())
+        .and_then(|value| value.as_str().map(str::to_string))
+        .unwrap_or_else(|| EXTERNAL.to_string())
+}
+
+/// Plafond d'une catégorie selon la configuration (pénalité totale maximale pour
+/// les règles externes)
+pub fn cap_for(config: &LintConfig, category: &str) -> f64 {
+    let Ok(category) = serde_json::from_value::<Category>(serde_json::json!(category)) else {
+        return MAX_PENALTY;
+    };
+    config
+        .category_caps
+        .as_ref()
+        .and_then(|caps| caps.get(&category))
+        .copied()
+        .unwrap_or_else(|| default_cap(category))
+}
+
+/// Issues par catégorie et par sévérité (les hints ne comptent pas), avec le
+/// plafond retourné par `cap` ; les pénalités sont calculées par `score`
+pub fn tally(issues: &[LintIssue], cap: impl Fn(&str) -> f64) -> BTreeMap<String, CategoryScore> {
+    let mut categories: BTreeMap<String, CategoryScore> = BTreeMap::new();
+    for issue in issues {
+        if !matches!(issue.severity.as_str(), "error" | "warning" | "info") {
+            continue;
+        }
+        let category = category_of(&issue.rule_id);
+        let entry = categories.entry(category.clone()).or_insert_with(|| CategoryScore {
+            max_penalty: cap(&category),
+            ..Default::default()
+        });
+        match issue.severity.as_str() {
+            "error" => entry.errors += 1,
+            "warning" => entry.warnings += 1,
+            _ => entry.infos += 1,
+        }
+    }
+    categories
+}
+
+/// Ajoute les issues de `other` à `categories` (résultats de shards ou de collections)
+pub fn merge(categories: &mut BTreeMap<String, CategoryScore>, other: &BTreeMap<String, CategoryScore>) {
+    for (category, score) in other {
+        let entry = categories.entry(category.clone()).or_insert_with(|| CategoryScore {
+            max_penalty: score.max_penalty,
+            ..Default::default()
+        });
+        entry.errors += score.errors;
+        entry.warnings += score.warnings;
+        entry.infos += score.infos;
+    }
+}
+
+/// Renseigne la pénalité de chaque catégorie et retourne le score non arrondi (0-100)
+pub fn score(categories: &mut BTreeMap<String, CategoryScore>, total_requests: u32) -> f64 {
+    let total = |count: fn(&CategoryScore) -> u32| categories.values().map(count).sum::<u32>() as f64;
+    let (errors, warnings, infos) = (total(|c| c.errors), total(|c| c.warnings), total(|c| c.infos));
+
+    // Pénalité de chaque sévérité selon le ratio de requêtes concernées (pas le nombre
+    // absolu) : 100% des requêtes en erreur = -15 points, 50% = -7.5 points
+    let total_requests = total_requests.max(1) as f64;
+    let severity_penalty = |count: f64, weight: f64| (count / total_requests).min(1.0) * weight;
+    let (error_penalty, warning_penalty, info_penalty) = (
+        severity_penalty(errors, ERROR_WEIGHT),
+        severity_penalty(warnings, WARNING_WEIGHT),
+        severity_penalty(infos, INFO_WEIGHT),
+    );
+    let share = |count: u32, total: f64, penalty: f64| if total > 0.0 { penalty * count as f64 / total } else { 0.0 };
+
+    let mut score = 100.0;
+    for category in categories.values_mut() {
+        let penalty = share(category.errors, errors, error_penalty)
+            + share(category.warnings, warnings, warning_penalty)
+            + share(category.infos, infos, info_penalty);
+        category.penalty = (penalty.min(category.max_penalty) * 100.0).round() / 100.0;
+        score -= penalty.min(category.max_penalty);
+    }
+
+    // Bonus: +5% si 0 erreurs ET ≤2 warnings (comme dans le projet source)
+    if errors == 0.0 && warnings <= 2.0 {
+        score += 5.0;
+    }
+
+    score.clamp(0.0, 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issues(rule_id: &str, count: usize) -> Vec<LintIssue> {
+        (0..count)
+            .map(|i| LintIssue::builder(rule_id).severity("error").path(format!("/item[{}]", i)).build())
+            .collect()
+    }
+
+    #[test]
+    fn test_category_caps() {
+        let config = LintConfig::default();
+        let cap = |category: &str| cap_for(&config, category);
+
+        // 3 requêtes sur 3 en erreur : -15 points pour les secrets, plafonné à -10
+        // pour la documentation
+        let mut secrets = tally(&issues("hardcoded-secrets", 3), cap);
+        assert_eq!(score(&mut secrets, 3), 85.0);
+        let mut documentation = tally(&issues("request-examples-required", 3), cap);
+        assert_eq!(score(&mut documentation, 3), 90.0);
+        assert_eq!(documentation["documentation"].penalty, 10.0);
+        assert_eq!(documentation["documentation"].max_penalty, 10.0);
+
+        // Règle externe : pas de plafond effectif
+        assert_eq!(score(&mut tally(&issues("acme-rule", 3), cap), 3), 85.0);
+        assert_eq!(cap(EXTERNAL), 26.0);
+    }
+
+    #[test]
+    fn test_configured_caps_and_shares() {
+        let config: LintConfig = serde_json::from_value(serde_json::json!({
+            "local_only": true,
+            "category_caps": { "security": 4, "documentation": 30 }
+        }))
+        .unwrap();
+        let mut all = issues("hardcoded-secrets", 2);
+        all.extend(issues("request-examples-required", 2));
+
+        // 4 erreurs sur 4 requêtes : -15 points partagés à parts égales, puis plafonnés
+        let mut categories = tally(&all, |category| cap_for(&config, category));
+        assert_eq!(score(&mut categories, 4), 88.5);
+        assert_eq!(categories["security"].penalty, 4.0);
+        assert_eq!(categories["documentation"].penalty, 7.5);
+    }
+}
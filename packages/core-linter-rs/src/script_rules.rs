@@ -0,0 +1,200 @@
+//! Règles scriptées en Rhai (feature `rhai-rules`, `--script-rule` sur la CLI)
+//!
+//! Pour les besoins trop spécifiques pour les options des règles intégrées, sans
+//! écrire de crate : un fichier `.rhai` définit une règle dont l'identifiant est le
+//! nom du fichier (`acme-owner-tag.rhai` → `acme-owner-tag`). Le script définit
+//! `check_item(item, options)`, appelée pour chaque item avec le même objet que les
+//! règles JavaScript (path, name, folders, method, url, scripts...), ou
+//! `check(collection, options)`, appelée une fois avec la collection entière. Il peut
+//! aussi définir `severity()` et `description()`.
+//!
+//! Le résultat suit les règles JavaScript : `()`, une issue ou un tableau d'issues
+//! `#{ message, path?, severity?, fix? }`. Les scripts n'accèdent ni aux fichiers ni
+//! au réseau, et leur nombre d'opérations est borné.
+
+use crate::registry::{issues_from_value, item_object, RuleRegistry};
+use crate::rule_api::{walk, ItemContext, LintIssue, Rule};
+use rhai::{CallFnOptions, Dynamic, Engine, Scope, AST};
+use serde_json::Value;
+use std::path::Path;
+
+/// Opérations autorisées par appel (évite qu'une boucle infinie bloque l'analyse)
+const MAX_OPERATIONS: u64 = 1_000_000;
+
+pub struct ScriptRule {
+    id: String,
+    severity: String,
+    description: String,
+    engine: Engine,
+    ast: AST,
+    /// `check_item` défini (sinon `check`)
+    per_item: bool,
+}
+
+impl ScriptRule {
+    /// Compile le script d'une règle
+    pub fn compile(id: &str, source: &str) -> Result<Self, String> {
+        let mut engine = Engine::new();
+        engine.set_max_operations(MAX_OPERATIONS);
+        engine.set_max_call_levels(32);
+        engine.set_module_resolver(rhai::module_resolvers::DummyModuleResolver::new());
+
+        let ast = engine.compile(source).map_err(|e| format!("Script rule '{}': {}", id, e))?;
+        let defines = |name: &str, arity: usize| ast.iter_functions().any(|f| f.name == name && f.params.len() == arity);
+        let per_item = defines("check_item", 2);
+        let (has_severity, has_description) = (defines("severity", 0), defines("description", 0));
+        if !per_item && !defines("check", 2) {
+            return Err(format!(
+                "Script rule '{}' must define check_item(item, options) or check(collection, options)",
+                id
+            ));
+        }
+
+        let mut rule = ScriptRule {
+            id: id.to_string(),
+            severity: "warning".to_string(),
+            description: String::new(),
+            engine,
+            ast,
+            per_item,
+        };
+        if has_severity {
+            rule.severity = rule.call("severity", ())?.as_str().unwrap_or_default().to_string();
+            if !crate::rules::ISSUE_SEVERITIES.contains(&rule.severity.as_str()) {
+                return Err(format!("Script rule '{}' has an invalid severity '{}'", id, rule.severity));
+            }
+        }
+        if has_description {
+            rule.description = rule.call("description", ())?.as_str().unwrap_or_default().to_string();
+        }
+        Ok(rule)
+    }
+
+    fn call(&self, name: &str, args: impl rhai::FuncArgs) -> Result<Value, String> {
+        let options = CallFnOptions::new().eval_ast(false);
+        let returned: Dynamic = self
+            .engine
+            .call_fn_with_options(options, &mut Scope::new(), &self.ast, name, args)
+            .map_err(|e| e.to_string())?;
+        if returned.is_unit() {
+            return Ok(Value::Null);
+        }
+        rhai::serde::from_dynamic(&returned).map_err(|e| e.to_string())
+    }
+
+    /// Appelle `name` avec des arguments JSON et convertit le résultat en issues
+    fn run(&self, name: &str, input: &Value, options: &Value, subject: &str, default_path: &str) -> Vec<LintIssue> {
+        let args = rhai::serde::to_dynamic(input).and_then(|input| Ok((input, rhai::serde::to_dynamic(options)?)));
+        match args.map_err(|e| e.to_string()).and_then(|args| self.call(name, args)) {
+            Ok(returned) => issues_from_value(&self.id, &self.severity, default_path, &returned),
+            Err(error) => vec![LintIssue::builder(&self.id)
+                .severity("info")
+                .message(format!("⚠️ Custom rule '{}' failed on {}: {}", self.id, subject, error))
+                .path(default_path)
+                .build()],
+        }
+    }
+}
+
+impl Rule for ScriptRule {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn severity(&self) -> &str {
+        &self.severity
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn check_item(&self, item: &ItemContext, options: &Value) -> Vec<LintIssue> {
+        self.run("check_item", &item_object(item, options), options, &format!("\"{}\"", item.name), &item.path)
+    }
+
+    fn check(&self, collection: &Value, options: &Value) -> Vec<LintIssue> {
+        if !self.per_item {
+            return self.run("check", collection, options, "the collection", "");
+        }
+        let mut issues = Vec::new();
+        walk(collection, &mut |item| issues.extend(self.check_item(item, options)));
+        issues
+    }
+}
+
+/// Charge un fichier `.rhai` et enregistre sa règle ; retourne son identifiant
+pub fn load(path: &Path, registry: &mut RuleRegistry) -> Result<String, String> {
+    let source =
+        std::fs::read_to_string(path).map_err(|e| format!("Cannot read script rule '{}': {}", path.display(), e))?;
+    let id = path.file_stem().map(|stem| stem.to_string_lossy().into_owned()).unwrap_or_default();
+    if crate::rules::find(&id).is_some() {
+        return Err(format!("Script rule '{}' is already registered", id));
+    }
+    registry.register(ScriptRule::compile(&id, &source)?).map_err(|e| format!("Script rule: {}", e))?;
+    Ok(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{run_linter_with_registry, LintConfig};
+    use serde_json::json;
+
+    fn collection() -> Value {
+        json!({
+            "info": { "name": "Test" },
+            "item": [
+                { "name": "GET Users [owner:core]", "request": { "method": "GET", "url": "/users" } },
+                { "name": "GET Orders", "request": { "method": "GET", "url": "/orders" } }
+            ]
+        })
+    }
+
+    #[test]
+    fn test_item_and_collection_scripts() {
+        let mut registry = RuleRegistry::new();
+        let owner_tag = r#"
+            fn severity() { "error" }
+            fn check_item(item, options) {
+                if item.is_request && !item.name.contains(options.tag) {
+                    #{ message: `Request "${item.name}" has no ${options.tag} tag` }
+                }
+            }
+        "#;
+        registry.register(ScriptRule::compile("acme-owner-tag", owner_tag).unwrap()).unwrap();
+        let max_requests = r#"
+            fn check(collection, options) {
+                if collection.item.len() > 1 { [#{ message: "Too many requests" }] } else { [] }
+            }
+        "#;
+        registry.register(ScriptRule::compile("acme-max-requests", max_requests).unwrap()).unwrap();
+
+        let config = LintConfig {
+            rules: Some(vec!["acme-owner-tag".to_string(), "acme-max-requests".to_string()]),
+            rule_options: Some([("acme-owner-tag".to_string(), json!({ "tag": "[owner:" }))].into()),
+            ..Default::default()
+        };
+        let result = run_linter_with_registry(&collection(), &config, &registry);
+
+        let issues: Vec<(&str, &str, &str)> = result
+            .issues
+            .iter()
+            .map(|issue| (issue.rule_id.as_str(), issue.severity.as_str(), issue.path.as_str()))
+            .collect();
+        assert_eq!(issues.len(), 2);
+        assert!(issues.contains(&("acme-owner-tag", "error", "/item[1]")));
+        assert!(issues.contains(&("acme-max-requests", "warning", "")));
+    }
+
+    #[test]
+    fn test_invalid_and_runaway_scripts() {
+        assert!(ScriptRule::compile("acme-empty", "let x = 1;").err().unwrap().contains("must define"));
+        assert!(ScriptRule::compile("acme-bad", "fn severity() { \"fatal\" } fn check(c, o) {}").is_err());
+
+        let runaway = ScriptRule::compile("acme-loop", "fn check(collection, options) { loop {} }").unwrap();
+        let issues = runaway.check(&collection(), &Value::Null);
+        assert_eq!(issues[0].severity, "info");
+        assert!(issues[0].message.starts_with("⚠️ Custom rule 'acme-loop' failed on the collection"));
+    }
+}
@@ -0,0 +1,258 @@
+//! Session d'analyse d'une collection
+//!
+//! Conserve la collection parsée, son index de scripts et son modèle typé, ainsi que
+//! les issues brutes de chaque règle intégrée. Cocher ou décocher une règle dans
+//! l'IHM ne ré-exécute que cette règle (`lint_subset`) : sévérités, suppressions,
+//! stats et score sont recalculés à partir du cache.
+//!
+//! Le cache est invalidé dès que les réglages lus par les règles changent (options,
+//! locale, snippets, rapport Newman, profil de visibilité, limites). Les règles
+//! externes (crates ou JavaScript) sont toujours ré-exécutées : leur registre peut
+//! changer entre deux appels.
+//!
+//! `SessionStore` associe un handle à chaque session ouverte : l'IHM parse une seule
+//! fois une collection volumineuse puis l'analyse et la corrige via son handle.
+
+use crate::context::ScriptIndex;
+use crate::model::Collection;
+use crate::registry::RuleRegistry;
+use crate::{environment, fixer, finish, guards, lint_context, rules, run_rules, LintConfig, LintError, LintIssue, LintResult};
+use serde_json::Value;
+use std::collections::HashMap;
+
+pub struct LintSession {
+    collection: Value,
+    scripts: ScriptIndex,
+    model: Collection,
+    /// Réglages ayant produit les issues en cache
+    settings_key: String,
+    /// Issues brutes par règle intégrée (avant sévérités et suppressions)
+    rule_issues: HashMap<String, Vec<LintIssue>>,
+}
+
+impl LintSession {
+    pub fn new(collection: Value) -> Self {
+        LintSession {
+            scripts: ScriptIndex::build(&collection),
+            model: Collection::from_value(&collection),
+            collection,
+            settings_key: String::new(),
+            rule_issues: HashMap::new(),
+        }
+    }
+
+    pub fn collection(&self) -> &Value {
+        &self.collection
+    }
+
+    /// Analyse complète ; les règles déjà exécutées avec les mêmes réglages ne sont
+    /// pas relancées
+    pub fn lint(&mut self, config: &LintConfig, registry: &RuleRegistry) -> LintResult {
+        self.lint_subset(config, registry, &[])
+    }
+
+    /// Analyse complète en ré-exécutant uniquement `only_rules` (et les règles absentes
+    /// du cache)
+    pub fn lint_subset(&mut self, config: &LintConfig, registry: &RuleRegistry, only_rules: &[String]) -> LintResult {
+        let settings_key = settings_key(config);
+        if settings_key != self.settings_key {
+            self.rule_issues.clear();
+            self.settings_key = settings_key;
+        }
+
+        let ctx = lint_context(config, self.scripts.clone(), self.model.clone());
+        let guard = guards::ResourceGuard::new(&self.collection, &config.limits.clone().unwrap_or_default());
+        let cached = &self.rule_issues;
+        let (rule_issues, skipped_rules, profile) = run_rules(&self.collection, config, registry, &ctx, &guard, |rule_id| {
+            only_rules.iter().any(|id| id == rule_id) || !cached.contains_key(rule_id) || rules::find(rule_id).is_none()
+        });
+
+        let mut issues = Vec::new();
+        for (rule_id, computed) in rule_issues {
+            match computed {
+                Some(computed) => {
+                    if rules::find(&rule_id).is_some() {
+                        self.rule_issues.insert(rule_id, computed.clone());
+                    }
+                    issues.extend(computed);
+                }
+                None => issues.extend(self.rule_issues[&rule_id].iter().cloned()),
+            }
+        }
+
+        let mut result = finish(&self.collection, config, registry, &guard, issues, skipped_rules);
+        result.profile = profile;
+        result
+    }
+
+    /// Oublie les issues en cache (la prochaine analyse relance toutes les règles)
+    pub fn invalidate(&mut self) {
+        self.rule_issues.clear();
+    }
+
+    /// Applique les corrections automatiques à la collection de la session, puis la
+    /// ré-analyse. En mode strict (`strict_fix`), un fix de type inconnu est une erreur
+    /// et la collection reste inchangée.
+    pub fn fix(&mut self, config: &LintConfig, registry: &RuleRegistry) -> Result<FixOutcome, LintError> {
+        let before = self.lint(config, registry);
+        let mut fixed = self.collection.clone();
+        let report = fixer::apply_fixes_checked(&mut fixed, &before.issues, config.locale.unwrap_or_default());
+        if config.strict_fix.unwrap_or(false) && !report.unknown.is_empty() {
+            let details: Vec<String> = report.unknown.iter().map(|unknown| unknown.to_string()).collect();
+            return Err(LintError::FixFailed(format!("Strict fix mode: {}", details.join("; "))));
+        }
+
+        // Environnement déclarant les variables introduites par les fixes
+        let introduced = environment::introduced_variables(&self.collection, &fixed);
+        let environment_template = if introduced.is_empty() {
+            Value::Null
+        } else {
+            let collection_name = fixed["info"]["name"].as_str().unwrap_or("Collection");
+            environment::environment_template(collection_name, &introduced)
+        };
+
+        *self = LintSession::new(fixed);
+        let after = self.lint(config, registry);
+        Ok(FixOutcome {
+            fixes_applied: report.applied,
+            before,
+            after,
+            environment_template,
+        })
+    }
+}
+
+/// Résultat de `LintSession::fix`
+pub struct FixOutcome {
+    pub fixes_applied: usize,
+    /// Analyse avant corrections
+    pub before: LintResult,
+    /// Analyse de la collection corrigée
+    pub after: LintResult,
+    /// Environnement des variables introduites par les fixes (`null` si aucune)
+    pub environment_template: Value,
+}
+
+/// Sessions ouvertes, indexées par handle
+#[derive(Default)]
+pub struct SessionStore {
+    next_handle: u32,
+    sessions: HashMap<u32, LintSession>,
+}
+
+impl SessionStore {
+    /// Ouvre une session et retourne son handle (jamais réutilisé)
+    pub fn open(&mut self, collection: Value) -> u32 {
+        self.next_handle += 1;
+        self.sessions.insert(self.next_handle, LintSession::new(collection));
+        self.next_handle
+    }
+
+    /// Ferme une session ; `false` si le handle est inconnu
+    pub fn close(&mut self, handle: u32) -> bool {
+        self.sessions.remove(&handle).is_some()
+    }
+
+    pub fn get_mut(&mut self, handle: u32) -> Result<&mut LintSession, LintError> {
+        self.sessions.get_mut(&handle).ok_or(LintError::UnknownHandle(handle))
+    }
+}
+
+/// Réglages lus par les règles : hors liste des règles activées et post-traitements
+/// (sévérités imposées, items ignorés, suppressions, quality gate, troncature)
+fn settings_key(config: &LintConfig) -> String {
+    let mut settings = config.clone();
+    settings.local_only = false;
+    settings.rules = None;
+    settings.disabled_rules = None;
+    settings.fix = None;
+    settings.strict_fix = None;
+    settings.severity_overrides = None;
+    settings.suppressions = None;
+    settings.ignore = None;
+    settings.maturity_target = None;
+    settings.normalize = None;
+    settings.quality_gate = None;
+    settings.max_issues_per_rule = None;
+    serde_json::to_string(&settings).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::run_linter;
+    use serde_json::json;
+
+    fn collection() -> Value {
+        json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "GET Users",
+                "request": {
+                    "method": "GET",
+                    "url": "https://api.example.com/users",
+                    "header": [{ "key": "X-Debug", "value": "1" }]
+                }
+            }]
+        })
+    }
+
+    fn config(rules: &[&str]) -> LintConfig {
+        LintConfig {
+            rules: Some(rules.iter().map(|rule| rule.to_string()).collect()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_toggled_rule_matches_full_run() {
+        let mut session = LintSession::new(collection());
+        let registry = RuleRegistry::new();
+
+        session.lint(&config(&["test-http-status-mandatory"]), &registry);
+        let toggled = config(&["test-http-status-mandatory", "debug-headers"]);
+        let result = session.lint_subset(&toggled, &registry, &["debug-headers".to_string()]);
+        let expected = run_linter(&collection(), &toggled);
+
+        assert_eq!(result.score, expected.score);
+        assert_eq!(
+            result.issues.iter().map(|issue| &issue.rule_id).collect::<Vec<_>>(),
+            expected.issues.iter().map(|issue| &issue.rule_id).collect::<Vec<_>>()
+        );
+        assert_eq!(session.rule_issues.len(), 2);
+    }
+
+    #[test]
+    fn test_store_handles_and_fix() {
+        let mut store = SessionStore::default();
+        let handle = store.open(json!({
+            "info": { "name": "Test" },
+            "item": [{ "name": "GET Users", "request": { "method": "GET", "url": "https://api.example.com/users" } }]
+        }));
+        let config = config(&["test-http-status-mandatory"]);
+
+        let outcome = store.get_mut(handle).unwrap().fix(&config, &RuleRegistry::new()).unwrap();
+        assert_eq!(outcome.fixes_applied, 1);
+        assert!(outcome.after.issues.is_empty());
+        assert!(store.get_mut(handle).unwrap().collection()["item"][0]["event"].is_array());
+
+        assert!(store.close(handle));
+        assert!(!store.close(handle));
+        assert!(matches!(store.get_mut(handle), Err(LintError::UnknownHandle(h)) if h == handle));
+        assert_ne!(store.open(collection()), handle);
+    }
+
+    #[test]
+    fn test_settings_change_invalidates_cache() {
+        let mut session = LintSession::new(collection());
+        let registry = RuleRegistry::new();
+        session.lint(&config(&["debug-headers"]), &registry);
+
+        let mut configured = config(&["debug-headers"]);
+        configured.rule_options = Some(HashMap::from([("debug-headers".to_string(), json!({ "denylist": [] }))]));
+        let result = session.lint(&configured, &registry);
+
+        assert_eq!(result.issues.len(), run_linter(&collection(), &configured).issues.len());
+        assert_eq!(session.rule_issues.len(), 1);
+    }
+}
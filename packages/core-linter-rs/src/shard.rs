@@ -0,0 +1,278 @@
+//! Analyse d'une collection découpée en shards
+//!
+//! `--shard 2/5` répartit les items de premier niveau (folders et requêtes) entre
+//! les shards de façon déterministe (item `i` → shard `i % 5 + 1`) et n'analyse que
+//! la partition du shard. Les paths des issues sont ceux de la collection complète,
+//! si bien que `merge-results` peut recombiner les sorties des jobs de CI.
+//!
+//! Les éléments de niveau collection (issues au path "/", tests de la collection)
+//! ne sont comptés que par le shard 1. Les règles qui agrègent toute la collection
+//! (ex: test-coverage-minimum) ne voient que la partition de ce shard.
+
+use crate::registry::RuleRegistry;
+use crate::{coverage, maturity, profile, remediation, run_linter_with_registry, scoring, LintConfig, LintIssue, LintResult, LintStats};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Shard `index` (à partir de 1) sur `count`
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Shard {
+    pub index: usize,
+    pub count: usize,
+}
+
+impl Shard {
+    /// Parse une spécification "2/5"
+    pub fn parse(spec: &str) -> Result<Shard, String> {
+        let invalid = || format!("Invalid shard '{}' (expected INDEX/COUNT, e.g. 2/5)", spec);
+        let (index, count) = spec.split_once('/').ok_or_else(invalid)?;
+        let index: usize = index.trim().parse().map_err(|_| invalid())?;
+        let count: usize = count.trim().parse().map_err(|_| invalid())?;
+        if count == 0 || index == 0 || index > count {
+            return Err(invalid());
+        }
+        Ok(Shard { index, count })
+    }
+
+    /// L'item de premier niveau à cet indice appartient-il au shard ?
+    pub fn owns(&self, top_level_index: usize) -> bool {
+        top_level_index % self.count == self.index - 1
+    }
+}
+
+/// Analyse la partition du shard ; les paths restent ceux de la collection complète
+pub fn lint_shard(collection: &Value, config: &LintConfig, registry: &RuleRegistry, shard: Shard) -> LintResult {
+    let items = collection["item"].as_array().cloned().unwrap_or_default();
+    let owned: Vec<usize> = (0..items.len()).filter(|&index| shard.owns(index)).collect();
+
+    let mut partition = collection.clone();
+    partition["item"] = Value::Array(owned.iter().map(|&index| items[index].clone()).collect());
+
+    let mut result = run_linter_with_registry(&partition, config, registry);
+
+    // Revenir aux indices de la collection complète
+    for issue in result.issues.iter_mut().chain(result.suppressed.iter_mut().map(|s| &mut s.issue)) {
+        issue.path = remap_path(&issue.path, &owned);
+        for related in &mut issue.related {
+            related.path = remap_path(&related.path, &owned);
+        }
+    }
+    for request in &mut result.coverage.requests {
+        request.path = remap_path(&request.path, &owned);
+    }
+
+    // Le niveau collection n'est compté qu'une fois, par le shard 1
+    if shard.index > 1 {
+        result.issues.retain(|issue| issue.path.starts_with("/item["));
+        result.suppressed.retain(|suppressed| suppressed.issue.path.starts_with("/item["));
+        let collection_tests = crate::utils::extract_test_scripts(collection).len() as u32;
+        result.stats.total_tests = result.stats.total_tests.saturating_sub(collection_tests);
+        recount(&mut result.stats, &result.issues);
+        result.score = scoring::score(&mut result.stats.categories, result.stats.total_requests) as u32;
+    }
+
+    result.shard = Some(shard);
+    result.regroup();
+    result
+}
+
+/// Recombine les résultats de tous les shards d'une analyse
+pub fn merge_results(results: &[LintResult]) -> Result<LintResult, String> {
+    let mut ordered: Vec<(Shard, &LintResult)> = Vec::new();
+    for result in results {
+        let shard = result.shard.ok_or("Result is not a shard output (missing 'shard')")?;
+        ordered.push((shard, result));
+    }
+    ordered.sort_by_key(|(shard, _)| shard.index);
+
+    let count = ordered.first().map(|(shard, _)| shard.count).ok_or("No shard results to merge")?;
+    let indices: Vec<usize> = ordered.iter().map(|(shard, _)| shard.index).collect();
+    if ordered.iter().any(|(shard, _)| shard.count != count) || indices != (1..=count).collect::<Vec<_>>() {
+        return Err(format!("Expected shards 1..{} exactly once, got {:?}", count, indices));
+    }
+
+    // Des shards produits par des moteurs ou configurations différents ne sont pas fusionnables
+    let (engine_version, ruleset_hash) = (&ordered[0].1.engine_version, &ordered[0].1.ruleset_hash);
+    if ordered
+        .iter()
+        .any(|(_, result)| &result.engine_version != engine_version || &result.ruleset_hash != ruleset_hash)
+    {
+        return Err("Shards were linted with different engine versions or rule configurations".to_string());
+    }
+
+    let mut stats = LintStats {
+        total_requests: 0,
+        total_tests: 0,
+        total_folders: 0,
+        errors: 0,
+        warnings: 0,
+        infos: 0,
+        hints: 0,
+        test_coverage_percent: 0.0,
+        methods: Default::default(),
+        categories: Default::default(),
+    };
+    let mut issues: Vec<LintIssue> = Vec::new();
+    let mut requests = Vec::new();
+    let mut skipped_rules: Vec<crate::guards::SkippedRule> = Vec::new();
+    let mut suppressed = Vec::new();
+    let mut truncated: BTreeMap<String, usize> = BTreeMap::new();
+    let mut config_warnings: Vec<String> = Vec::new();
+    let mut tested_requests = 0.0;
+
+    for (_, result) in &ordered {
+        stats.total_requests += result.stats.total_requests;
+        stats.total_tests += result.stats.total_tests;
+        stats.total_folders += result.stats.total_folders;
+        stats.errors += result.stats.errors;
+        stats.warnings += result.stats.warnings;
+        stats.infos += result.stats.infos;
+        stats.hints += result.stats.hints;
+        for (method, count) in &result.stats.methods {
+            *stats.methods.entry(method.clone()).or_default() += count;
+        }
+        scoring::merge(&mut stats.categories, &result.stats.categories);
+        tested_requests += result.stats.test_coverage_percent * result.stats.total_requests as f64 / 100.0;
+
+        issues.extend(result.issues.iter().cloned());
+        requests.extend(result.coverage.requests.iter().cloned());
+        suppressed.extend(result.suppressed.iter().cloned());
+        for skipped in &result.skipped_rules {
+            if !skipped_rules.iter().any(|s| s.rule_id == skipped.rule_id) {
+                skipped_rules.push(skipped.clone());
+            }
+        }
+        for warning in &result.config_warnings {
+            if !config_warnings.contains(warning) {
+                config_warnings.push(warning.clone());
+            }
+        }
+        for (rule_id, omitted) in &result.truncated {
+            *truncated.entry(rule_id.clone()).or_default() += omitted;
+        }
+    }
+
+    if stats.total_requests > 0 {
+        stats.test_coverage_percent = (tested_requests / stats.total_requests as f64 * 1000.0).round() / 10.0;
+    }
+    // Les stats des shards gardent les vrais totaux, même si les issues ont été tronquées
+    let score = scoring::score(&mut stats.categories, stats.total_requests) as u32;
+    remediation::annotate(&mut issues, &stats);
+    // Le niveau global est celui du shard le moins avancé ; chaque shard liste toutes
+    // ses règles manquantes jusqu'à son niveau suivant, qui couvre le niveau suivant global
+    let maturity = maturity::evaluate(
+        ordered.iter().flat_map(|(_, result)| result.maturity.missing_rules.iter().cloned()),
+        ordered.first().and_then(|(_, result)| result.maturity.target),
+    );
+
+    let mut merged = LintResult {
+        score,
+        issues,
+        stats,
+        coverage: coverage::from_requests(requests),
+        skipped_rules,
+        suppressed,
+        truncated,
+        config_warnings,
+        shard: None,
+        engine_version: engine_version.clone(),
+        ruleset_hash: ruleset_hash.clone(),
+        maturity,
+        groups: ordered[0].1.groups.clone(),
+        profile: profile::merge(ordered.iter().filter_map(|(_, result)| result.profile.as_ref())),
+    };
+    merged.regroup();
+    Ok(merged)
+}
+
+/// `/item[1]/item[3]` avec owned = [2, 7] → `/item[7]/item[3]`
+fn remap_path(path: &str, owned: &[usize]) -> String {
+    let Some(rest) = path.strip_prefix("/item[") else {
+        return path.to_string();
+    };
+    let Some((index, tail)) = rest.split_once(']') else {
+        return path.to_string();
+    };
+    match index.parse::<usize>().ok().and_then(|index| owned.get(index)) {
+        Some(original) => format!("/item[{}]{}", original, tail),
+        None => path.to_string(),
+    }
+}
+
+fn recount(stats: &mut LintStats, issues: &[LintIssue]) {
+    stats.errors = issues.iter().filter(|i| i.severity == "error").count() as u32;
+    stats.warnings = issues.iter().filter(|i| i.severity == "warning").count() as u32;
+    stats.infos = issues.iter().filter(|i| i.severity == "info").count() as u32;
+    stats.hints = issues.iter().filter(|i| i.severity == "hint").count() as u32;
+    // Les plafonds déjà appliqués (configuration) sont conservés
+    let caps = std::mem::take(&mut stats.categories);
+    stats.categories = scoring::tally(issues, |category| {
+        caps.get(category).map_or_else(|| scoring::cap_for(&LintConfig::default(), category), |score| score.max_penalty)
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::run_linter;
+    use serde_json::json;
+
+    fn collection() -> Value {
+        let folders: Vec<Value> = (0..5)
+            .map(|i| json!({
+                "name": format!("Folder {}", i),
+                "item": [{ "name": format!("GET Item {}", i), "request": { "method": "GET", "url": "{{base_url}}/items" } }]
+            }))
+            .collect();
+        json!({
+            "info": { "name": "Test" },
+            "event": [{ "listen": "test", "script": { "exec": ["pm.test('Status code is 200', () => pm.response.to.have.status(200));"] } }],
+            "item": folders
+        })
+    }
+
+    #[test]
+    fn test_parse_shard() {
+        assert_eq!(Shard::parse("2/5"), Ok(Shard { index: 2, count: 5 }));
+        assert!(Shard::parse("0/5").is_err());
+        assert!(Shard::parse("6/5").is_err());
+        assert!(Shard::parse("abc").is_err());
+    }
+
+    #[test]
+    fn test_shard_paths_refer_to_full_collection() {
+        let config = LintConfig::default();
+        let shard = lint_shard(&collection(), &config, &RuleRegistry::default(), Shard::parse("2/2").unwrap());
+
+        assert_eq!(shard.stats.total_requests, 2);
+        assert!(shard.issues.iter().all(|issue| issue.path.starts_with("/item[1]") || issue.path.starts_with("/item[3]")));
+    }
+
+    #[test]
+    fn test_merged_shards_match_full_run() {
+        let config = LintConfig {
+            rules: Some(vec!["test-response-time-mandatory".to_string(), "request-naming-convention".to_string()]),
+            ..Default::default()
+        };
+        let shards: Vec<LintResult> = (1..=3)
+            .map(|index| lint_shard(&collection(), &config, &RuleRegistry::default(), Shard { index, count: 3 }))
+            .collect();
+
+        let merged = merge_results(&shards).unwrap();
+        let full = run_linter(&collection(), &config);
+
+        assert_eq!(merged.score, full.score);
+        assert_eq!(merged.stats.total_requests, full.stats.total_requests);
+        assert_eq!(merged.stats.total_tests, full.stats.total_tests);
+        assert_eq!(merged.stats.test_coverage_percent, full.stats.test_coverage_percent);
+        assert_eq!(merged.stats.methods, full.stats.methods);
+        let mut merged_paths: Vec<&str> = merged.issues.iter().map(|i| i.path.as_str()).collect();
+        let mut full_paths: Vec<&str> = full.issues.iter().map(|i| i.path.as_str()).collect();
+        merged_paths.sort();
+        full_paths.sort();
+        assert_eq!(merged_paths, full_paths);
+
+        assert!(merge_results(&shards[..2]).is_err());
+    }
+}
@@ -0,0 +1,184 @@
+//! Simulation de configurations candidates
+//!
+//! Permet de prévisualiser l'effet d'une configuration ("et si on activait le preset
+//! sécurité ?") sans lancer une analyse complète par candidat : les scripts sont
+//! indexés une seule fois, et les configurations qui ne diffèrent que par la liste
+//! des règles activées partagent la même exécution, filtrée ensuite par config.
+
+use crate::registry::RuleRegistry;
+use crate::{calculate_score, context, run_with_scripts, LintConfig, LintIssue, LintResult, LintStats};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+
+#[derive(Serialize, Debug)]
+pub struct SimulationResult {
+    pub score: u32,
+    pub errors: u32,
+    pub warnings: u32,
+    pub infos: u32,
+    pub hints: u32,
+    /// Nombre total d'issues
+    pub issues: usize,
+    /// Nombre d'issues par règle
+    pub issues_by_rule: BTreeMap<String, usize>,
+}
+
+/// Score et nombres d'issues pour chaque configuration, dans l'ordre donné
+pub fn simulate(collection: &Value, configs: &[LintConfig]) -> Vec<SimulationResult> {
+    let scripts = context::ScriptIndex::build(collection);
+    let registry = RuleRegistry::default();
+
+    // Regrouper les configs identiques hors liste de règles
+    let mut groups: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+    for (index, config) in configs.iter().enumerate() {
+        groups.entry(settings_key(config)).or_default().push(index);
+    }
+
+    let mut results: Vec<Option<SimulationResult>> = configs.iter().map(|_| None).collect();
+    for members in groups.values() {
+        // Une exécution par groupe, avec l'union des règles activées
+        let mut union_config = configs[members[0]].clone();
+        union_config.rules = union_rules(members.iter().map(|&index| &configs[index]));
+        union_config.disabled_rules = common_disabled_rules(members.iter().map(|&index| &configs[index]));
+        // Compter toutes les issues, même si les configs limitent le résultat
+        union_config.max_issues_per_rule = None;
+        let group_result = run_with_scripts(collection, &union_config, &registry, scripts.clone());
+
+        for &index in members {
+            results[index] = Some(filter_result(&group_result, &configs[index]));
+        }
+    }
+
+    results.into_iter().flatten().collect()
+}
+
+/// Réglages influant sur les issues produites, hors liste de règles
+fn settings_key(config: &LintConfig) -> String {
+    let mut settings = config.clone();
+    settings.rules = None;
+    settings.disabled_rules = None;
+    settings.fix = None;
+    settings.local_only = false;
+    settings.max_issues_per_rule = None;
+    serde_json::to_string(&settings).unwrap_or_default()
+}
+
+/// Union des règles activées ; `None` (toutes les règles) l'emporte
+fn union_rules<'a>(configs: impl Iterator<Item = &'a LintConfig>) -> Option<Vec<String>> {
+    let mut union = BTreeSet::new();
+    for config in configs {
+        union.extend(config.rules.as_ref()?.iter().cloned());
+    }
+    Some(union.into_iter().collect())
+}
+
+/// Règles exclues par toutes les configs du groupe
+fn common_disabled_rules<'a>(mut configs: impl Iterator<Item = &'a LintConfig>) -> Option<Vec<String>> {
+    let first = configs.next()?.disabled_rules.clone()?;
+    let others: Vec<&LintConfig> = configs.collect();
+    Some(
+        first
+            .into_iter()
+            .filter(|rule_id| others.iter().all(|config| config.disabled_rules.iter().flatten().any(|id| id == rule_id)))
+            .collect(),
+    )
+}
+
+fn filter_result(result: &LintResult, config: &LintConfig) -> SimulationResult {
+    let issues: Vec<&LintIssue> = result
+        .issues
+        .iter()
+        .filter(|issue| config.is_rule_enabled(&issue.rule_id))
+        .collect();
+
+    let count = |severity: &str| issues.iter().filter(|i| i.severity == severity).count() as u32;
+    let stats = LintStats {
+        errors: count("error"),
+        warnings: count("warning"),
+        infos: count("info"),
+        hints: count("hint"),
+        ..result.stats.clone()
+    };
+
+    let mut issues_by_rule = BTreeMap::new();
+    for issue in &issues {
+        *issues_by_rule.entry(issue.rule_id.clone()).or_default() += 1;
+    }
+
+    let owned: Vec<LintIssue> = issues.iter().map(|issue| (*issue).clone()).collect();
+    SimulationResult {
+        score: calculate_score(&owned, &stats),
+        errors: stats.errors,
+        warnings: stats.warnings,
+        infos: stats.infos,
+        hints: stats.hints,
+        issues: issues.len(),
+        issues_by_rule,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::run_linter;
+    use serde_json::json;
+
+    fn collection() -> Value {
+        json!({
+            "info": { "name": "Test" },
+            "item": [{
+                "name": "GET Users",
+                "request": {
+                    "method": "GET",
+                    "url": "https://api.example.com/users",
+                    "header": [{ "key": "Authorization", "value": "Bearer abcdefghijklmnopqrstuvwxyz123456" }]
+                }
+            }]
+        })
+    }
+
+    fn config(rules: &[&str]) -> LintConfig {
+        LintConfig {
+            rules: Some(rules.iter().map(|r| r.to_string()).collect()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_simulation_matches_individual_runs() {
+        let configs = vec![
+            config(&["test-http-status-mandatory"]),
+            config(&["test-http-status-mandatory", "hardcoded-secrets"]),
+            LintConfig::default(),
+            LintConfig { disabled_rules: Some(vec!["hardcoded-secrets".to_string()]), ..Default::default() },
+        ];
+
+        let results = simulate(&collection(), &configs);
+
+        assert_eq!(results.len(), 4);
+        for (config, simulated) in configs.iter().zip(&results) {
+            let actual = run_linter(&collection(), config);
+            assert_eq!(simulated.score, actual.score);
+            assert_eq!(simulated.issues, actual.issues.len());
+        }
+        assert_eq!(results[0].issues_by_rule.keys().collect::<Vec<_>>(), vec!["test-http-status-mandatory"]);
+        assert!(results[1].issues_by_rule.contains_key("hardcoded-secrets"));
+        assert!(!results[3].issues_by_rule.contains_key("hardcoded-secrets"));
+    }
+
+    #[test]
+    fn test_configs_with_different_options_run_separately() {
+        let mut public = config(&["internal-hosts-disclosure"]);
+        public.visibility = Some(crate::visibility::Visibility::Public);
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [{ "name": "GET Users", "request": { "method": "GET", "url": "http://10.0.0.1/users" } }]
+        });
+
+        let results = simulate(&collection, &[config(&["internal-hosts-disclosure"]), public]);
+
+        assert_eq!(results[0].issues, 0);
+        assert_eq!(results[1].issues, 1);
+    }
+}
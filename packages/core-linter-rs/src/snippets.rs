@@ -0,0 +1,110 @@
+//! Snippets de code Postman suggérés par les règles et injectés par le fixer
+//!
+//! Les commentaires des snippets passent par le catalogue `i18n` pour suivre
+//! la langue configurée. Les équipes peuvent remplacer les snippets intégrés
+//! par leurs propres modèles (voir `SnippetLibrary`).
+
+use crate::i18n::{t, Locale};
+use std::collections::HashMap;
+
+/// Nom du snippet de test de statut HTTP
+pub const STATUS_TEST: &str = "status_test";
+/// Nom du snippet de test de temps de réponse (placeholder `{{threshold}}`)
+pub const RESPONSE_TIME_TEST: &str = "response_time_test";
+/// Nom du snippet de validation de schéma JSON
+pub const SCHEMA_TEST: &str = "schema_test";
+
+/// Bibliothèque de snippets : modèles intégrés + modèles personnalisés issus de la config
+///
+/// Placeholders disponibles dans les modèles :
+/// - `{{location_expr}}` : expression JS identifiant la requête dans la description du test
+/// - `{{threshold}}` : seuil de temps de réponse en ms
+#[derive(Debug, Clone, Default)]
+pub struct SnippetLibrary {
+    pub locale: Locale,
+    pub custom: HashMap<String, String>,
+}
+
+impl SnippetLibrary {
+    pub fn new(locale: Locale, custom: Option<&HashMap<String, String>>) -> Self {
+        SnippetLibrary {
+            locale,
+            custom: custom.cloned().unwrap_or_default(),
+        }
+    }
+
+    /// Génère le code d'un snippet en substituant les placeholders fournis
+    pub fn render(&self, name: &str, params: &[(&str, &str)]) -> String {
+        let template = match self.custom.get(name) {
+            Some(custom) => custom.clone(),
+            None => self.builtin(name),
+        };
+
+        let mut code = template.replace("{{location_expr}}", "location");
+        for (key, value) in params {
+            code = code.replace(&format!("{{{{{}}}}}", key), value);
+        }
+        code
+    }
+
+    fn builtin(&self, name: &str) -> String {
+        match name {
+            STATUS_TEST => "pm.test({{location_expr}} + ' - Status code is 2xx', function() {\n    pm.response.to.be.success;\n});".to_string(),
+            RESPONSE_TIME_TEST => "pm.test({{location_expr}} + \" - Response time is less than {{threshold}}ms\", function () {\n    pm.expect(pm.response.responseTime).to.be.below({{threshold}});\n});".to_string(),
+            SCHEMA_TEST => schema_validation(self.locale),
+            _ => String::new(),
+        }
+    }
+}
+
+/// Script pre-request définissant la variable `location` utilisée par les descriptions de tests
+pub fn location_prerequest(locale: Locale) -> Vec<String> {
+    vec![
+        t(locale, "snippet.location_comment").to_string(),
+        "pm.environment.set('location', pm.request.url.getPath());".to_string(),
+    ]
+}
+
+/// Squelette de test de validation de schéma JSON
+pub fn schema_validation(locale: Locale) -> String {
+    format!(
+        "{}\nconst schema = {{\n    \"type\": \"object\",\n    \"properties\": {{\n        {}\n    }},\n    \"required\": []\n}};\n\n{}\nif (pm.response.code === 200) {{\n    pm.test(requestName + \" - Schema_Validation\", () => {{\n        pm.response.to.have.jsonSchema(schema);\n    }});\n}}",
+        t(locale, "snippet.schema_comment"),
+        t(locale, "snippet.schema_properties_comment"),
+        t(locale, "snippet.schema_test_comment"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snippets_follow_locale() {
+        assert!(location_prerequest(Locale::En)[0].contains("Define the location variable"));
+        assert!(schema_validation(Locale::En).contains("// Schema validation test"));
+        assert!(schema_validation(Locale::Fr).contains("// Définir le schéma JSON attendu"));
+    }
+
+    #[test]
+    fn test_builtin_placeholders() {
+        let library = SnippetLibrary::default();
+
+        let code = library.render(RESPONSE_TIME_TEST, &[("threshold", "500")]);
+        assert!(code.starts_with("pm.test(location + "));
+        assert!(code.contains(".to.be.below(500)"));
+    }
+
+    #[test]
+    fn test_custom_snippet_overrides_builtin() {
+        let mut custom = HashMap::new();
+        custom.insert(
+            STATUS_TEST.to_string(),
+            "utils.expectStatus({{location_expr}}, {{base_url}});".to_string(),
+        );
+        let library = SnippetLibrary::new(Locale::En, Some(&custom));
+
+        // Les variables Postman non connues ({{base_url}}) sont conservées telles quelles
+        assert_eq!(library.render(STATUS_TEST, &[]), "utils.expectStatus(location, {{base_url}});");
+    }
+}
@@ -0,0 +1,298 @@
+//! Positions des issues dans le JSON d'origine
+//!
+//! `serde_json::Value` ne garde pas la position des nœuds : les règles ne voient que
+//! des paths. Quand le texte de la collection est disponible (CLI, `Linter::lint_str`,
+//! `lint` en WASM) et que `LintConfig::source_map` est actif, ce module relit le
+//! texte en notant l'offset de chaque nœud, indexé par JSON Pointer, puis renseigne
+//! `line` et `column` des issues à partir de leur `pointer`. Un membre d'objet est
+//! positionné sur sa clé, un élément de tableau sur sa valeur ; une issue portant sur
+//! un nœud absent (test manquant...) est positionnée sur son plus proche ancêtre.
+
+use crate::pointer;
+use crate::{LintIssue, LintResult};
+use std::collections::HashMap;
+
+/// Position d'un nœud : offset en octets, ligne et colonne (en caractères) à partir de 1
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    pub offset: usize,
+    pub line: u32,
+    pub column: u32,
+}
+
+/// Positions des nœuds d'un document JSON, par JSON Pointer
+#[derive(Debug, Clone, Default)]
+pub struct SourceMap {
+    positions: HashMap<String, Position>,
+}
+
+impl SourceMap {
+    /// Relit le texte d'un document JSON ; `None` s'il n'est pas valide
+    pub fn parse(text: &str) -> Option<SourceMap> {
+        let mut scanner = Scanner { bytes: text.as_bytes(), offset: 0, line: 1, column: 1, positions: HashMap::new() };
+        let mut pointer = String::new();
+        scanner.skip_whitespace();
+        let root = scanner.position();
+        scanner.positions.insert(String::new(), root);
+        scanner.value(&mut pointer)?;
+        scanner.skip_whitespace();
+        (scanner.offset == scanner.bytes.len()).then_some(SourceMap { positions: scanner.positions })
+    }
+
+    /// Position d'un nœud, ou de son plus proche ancêtre présent dans le document
+    pub fn locate(&self, pointer: &str) -> Option<Position> {
+        let mut pointer = pointer;
+        loop {
+            if let Some(position) = self.positions.get(pointer) {
+                return Some(*position);
+            }
+            pointer = &pointer[..pointer.rfind('/')?];
+        }
+    }
+
+    /// Renseigne `line` et `column` des issues (affichées et masquées) qui n'en ont pas
+    pub fn annotate(&self, result: &mut LintResult) {
+        let suppressed = result.suppressed.iter_mut().map(|suppressed| &mut suppressed.issue);
+        for issue in result.issues.iter_mut().chain(suppressed) {
+            self.annotate_issue(issue);
+        }
+    }
+
+    fn annotate_issue(&self, issue: &mut LintIssue) {
+        if issue.line.is_some() {
+            return;
+        }
+        let pointer = issue.pointer.clone().unwrap_or_else(|| pointer::from_path(&issue.path));
+        if let Some(position) = self.locate(&pointer) {
+            issue.line = Some(position.line);
+            issue.column = Some(position.column);
+        }
+    }
+}
+
+/// Renseigne les positions des issues d'un résultat à partir du texte analysé
+pub fn annotate(result: &mut LintResult, source: &str) {
+    if let Some(map) = SourceMap::parse(source) {
+        map.annotate(result);
+    }
+}
+
+/// Lecture du JSON octet par octet, en suivant ligne et colonne
+struct Scanner<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+    line: u32,
+    column: u32,
+    positions: HashMap<String, Position>,
+}
+
+impl Scanner<'_> {
+    fn position(&self) -> Position {
+        Position { offset: self.offset, line: self.line, column: self.column }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.offset).copied()
+    }
+
+    fn bump(&mut self) -> Option<u8> {
+        let byte = self.peek()?;
+        self.offset += 1;
+        if byte == b'\n' {
+            self.line += 1;
+            self.column = 1;
+        } else if byte & 0xC0 != 0x80 {
+            // Les octets de continuation UTF-8 ne comptent pas comme des caractères
+            self.column += 1;
+        }
+        Some(byte)
+    }
+
+    fn expect(&mut self, expected: u8) -> Option<()> {
+        (self.bump()? == expected).then_some(())
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.bump();
+        }
+    }
+
+    /// Valeur à la position courante ; les positions de ses descendants sont
+    /// enregistrées sous `pointer`
+    fn value(&mut self, pointer: &mut String) -> Option<()> {
+        match self.peek()? {
+            b'{' => self.object(pointer),
+            b'[' => self.array(pointer),
+            b'"' => self.string().map(drop),
+            _ => {
+                // Nombre ou littéral : validé par le parse de `serde_json` en amont
+                let start = self.offset;
+                while matches!(self.peek(), Some(b'-' | b'+' | b'.' | b'0'..=b'9' | b'a'..=b'z' | b'A'..=b'Z')) {
+                    self.bump();
+                }
+                (self.offset > start).then_some(())
+            }
+        }
+    }
+
+    fn object(&mut self, pointer: &mut String) -> Option<()> {
+        self.expect(b'{')?;
+        self.skip_whitespace();
+        if self.peek()? == b'}' {
+            return self.expect(b'}');
+        }
+        loop {
+            let key_position = self.position();
+            let key = self.string()?;
+            let parent_len = pointer.len();
+            pointer.push('/');
+            pointer.push_str(&pointer::escape(&key));
+            self.positions.insert(pointer.clone(), key_position);
+
+            self.skip_whitespace();
+            self.expect(b':')?;
+            self.skip_whitespace();
+            self.value(pointer)?;
+            pointer.truncate(parent_len);
+
+            self.skip_whitespace();
+            match self.bump()? {
+                b',' => self.skip_whitespace(),
+                b'}' => return Some(()),
+                _ => return None,
+            }
+        }
+    }
+
+    fn array(&mut self, pointer: &mut String) -> Option<()> {
+        self.expect(b'[')?;
+        self.skip_whitespace();
+        if self.peek()? == b']' {
+            return self.expect(b']');
+        }
+        for index in 0.. {
+            let parent_len = pointer.len();
+            pointer.push('/');
+            pointer.push_str(&index.to_string());
+            self.positions.insert(pointer.clone(), self.position());
+            self.value(pointer)?;
+            pointer.truncate(parent_len);
+
+            self.skip_whitespace();
+            match self.bump()? {
+                b',' => self.skip_whitespace(),
+                b']' => return Some(()),
+                _ => return None,
+            }
+        }
+        None
+    }
+
+    /// Chaîne JSON décodée (nécessaire pour les clés, qui forment le pointer)
+    fn string(&mut self) -> Option<String> {
+        self.expect(b'"')?;
+        let mut decoded: Vec<u8> = Vec::new();
+        loop {
+            match self.bump()? {
+                b'"' => return String::from_utf8(decoded).ok(),
+                b'\\' => {
+                    let unescaped = match self.bump()? {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => self.unicode_escape()?,
+                        _ => return None,
+                    };
+                    decoded.extend_from_slice(unescaped.encode_utf8(&mut [0; 4]).as_bytes());
+                }
+                byte => decoded.push(byte),
+            }
+        }
+    }
+
+    /// Séquence `\uXXXX` (après `\u`), paire de surrogates comprise
+    fn unicode_escape(&mut self) -> Option<char> {
+        let high = self.hex4()?;
+        if !(0xD800..0xDC00).contains(&high) {
+            return char::from_u32(high as u32);
+        }
+        self.expect(b'\\')?;
+        self.expect(b'u')?;
+        let low = self.hex4()?;
+        char::decode_utf16([high, low]).next()?.ok()
+    }
+
+    fn hex4(&mut self) -> Option<u16> {
+        let mut value = 0u16;
+        for _ in 0..4 {
+            let digit = (self.bump()? as char).to_digit(16)?;
+            value = value * 16 + digit as u16;
+        }
+        Some(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{run_linter, LintConfig};
+
+    const COLLECTION: &str = r#"{
+  "info": { "name": "Test" },
+  "item": [
+    {
+      "name": "Users \"é\"",
+      "request": { "method": "GET", "url": "https://api.example.com/users" }
+    },
+    {"name": "a/b~c", "item": []}
+  ]
+}"#;
+
+    #[test]
+    fn test_positions_follow_keys_and_array_elements() {
+        let map = SourceMap::parse(COLLECTION).unwrap();
+        let position = |pointer: &str| map.locate(pointer).map(|p| (p.line, p.column));
+
+        assert_eq!(position(""), Some((1, 1)));
+        assert_eq!(position("/info/name"), Some((2, 13)));
+        assert_eq!(position("/item/0"), Some((4, 5)));
+        assert_eq!(position("/item/0/request/url"), Some((6, 37)));
+        assert_eq!(position("/item/1/item"), Some((8, 23)));
+        // Nœud absent : plus proche ancêtre
+        assert_eq!(position("/item/0/event/0/script"), Some((4, 5)));
+
+        let url = map.locate("/item/0/request/url").unwrap();
+        assert_eq!(&COLLECTION[url.offset..url.offset + 5], "\"url\"");
+        assert!(SourceMap::parse("{\"a\": [1, 2}").is_none());
+    }
+
+    #[test]
+    fn test_escaped_keys_and_unicode() {
+        let text = r#"{"a/b": {"m~n": 1}, "é😀": [true, null, -1.5e3]}"#;
+        let map = SourceMap::parse(text).unwrap();
+        assert_eq!(map.locate("/a~1b/m~0n").map(|p| p.column), Some(10));
+        // Colonnes en caractères, offsets en octets (« é😀 » : deux caractères, six octets)
+        let number = map.locate("/é😀/2").unwrap();
+        assert_eq!(number.column, 40);
+        assert_eq!(number.offset, 43);
+        assert!(text[number.offset..].starts_with("-1.5e3"));
+    }
+
+    #[test]
+    fn test_annotate_sets_issue_line_and_column() {
+        let collection: serde_json::Value = serde_json::from_str(COLLECTION).unwrap();
+        let mut result = run_linter(&collection, &LintConfig { local_only: true, ..Default::default() });
+        assert!(result.issues.iter().all(|issue| issue.line.is_none()));
+
+        annotate(&mut result, COLLECTION);
+        let issue = result.issues.iter().find(|issue| issue.path == "/item[0]").unwrap();
+        assert_eq!((issue.line, issue.column), (Some(4), Some(5)));
+        assert!(result.issues.iter().all(|issue| issue.line.is_some() && issue.column.is_some()));
+    }
+}
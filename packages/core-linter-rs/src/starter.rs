@@ -0,0 +1,99 @@
+//! Commande `new` : collection de départ sans issue
+//!
+//! Génère une collection minimale (Overview, folder d'exemple avec une requête
+//! documentée, script pre-request `location` au niveau de la collection) puis lui
+//! applique les corrections automatiques des règles activées : l'Overview suit le
+//! template configuré (`collection-overview-template`) et les tests standard,
+//! exigés requête par requête, suivent les snippets de l'équipe. Avec les règles
+//! par défaut, la collection produite obtient un score de 100.
+
+use crate::snippets::{self, SnippetLibrary};
+use crate::{fixer, run_linter, LintConfig};
+use serde_json::{json, Value};
+
+/// Nombre maximal de passes de corrections (un fix peut en appeler un autre)
+const MAX_FIX_PASSES: usize = 3;
+
+/// Collection de départ nommée `name`, corrigée selon `config`
+pub fn new_collection(name: &str, config: &LintConfig) -> Value {
+    let locale = config.locale.unwrap_or_default();
+    // La validation de schéma n'est qu'une suggestion manuelle : ajoutée d'emblée
+    let schema_test = SnippetLibrary::new(locale, config.snippets.as_ref()).render(snippets::SCHEMA_TEST, &[]);
+
+    let mut collection = json!({
+        "info": {
+            "name": name,
+            "version": "1.0.0",
+            "description": "",
+            "schema": "https://schema.getpostman.com/json/collection/v2.1.0/collection.json"
+        },
+        // Variable `location` utilisée par les descriptions des tests standard
+        "event": [
+            { "listen": "prerequest", "script": { "type": "text/javascript", "exec": snippets::location_prerequest(locale) } }
+        ],
+        "variable": [{ "key": "base_url", "value": "https://api.example.com" }],
+        "item": [{
+            "name": "Examples",
+            "description": "Example requests: duplicate this folder for each resource of the API.",
+            "item": [{
+                "name": "GET List Examples",
+                "event": [{ "listen": "test", "script": { "type": "text/javascript", "exec": [schema_test] } }],
+                "request": {
+                    "method": "GET",
+                    "header": [{ "key": "Accept", "value": "application/json" }],
+                    "url": "{{base_url}}/examples"
+                },
+                "response": [{
+                    "name": "200 OK",
+                    "status": "OK",
+                    "code": 200,
+                    "header": [{ "key": "Content-Type", "value": "application/json" }],
+                    "body": "[\n    {\n        \"id\": 1,\n        \"name\": \"Example\"\n    }\n]"
+                }]
+            }]
+        }]
+    });
+
+    // Corrections des règles : Overview, table des endpoints, tests de statut et de temps de réponse
+    for _ in 0..MAX_FIX_PASSES {
+        let result = run_linter(&collection, config);
+        if fixer::apply_fixes_with_locale(&mut collection, &result.issues, locale) == 0 {
+            break;
+        }
+    }
+    collection
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_rules_score_100() {
+        let collection = new_collection("Orders API", &LintConfig::default());
+        let result = run_linter(&collection, &LintConfig::default());
+
+        assert_eq!(result.issues.iter().map(|issue| &issue.message).collect::<Vec<_>>(), Vec::<&String>::new());
+        assert_eq!(result.score, 100);
+        assert_eq!(collection["info"]["name"], "Orders API");
+        assert!(collection["info"]["description"].as_str().unwrap().starts_with("# Orders API"));
+    }
+
+    #[test]
+    fn test_custom_snippets_used() {
+        let config = LintConfig {
+            snippets: Some(
+                [(
+                    snippets::RESPONSE_TIME_TEST.to_string(),
+                    "pm.test('fast', () => pm.expect(pm.response.responseTime).to.be.below({{threshold}}));".to_string(),
+                )]
+                .into(),
+            ),
+            ..Default::default()
+        };
+        let collection = new_collection("Orders API", &config);
+
+        let request_tests = &collection["item"][0]["item"][0]["event"];
+        assert!(request_tests.to_string().contains("' - fast'"));
+    }
+}
@@ -0,0 +1,209 @@
+//! Résumé d'analyse et quality gate
+//!
+//! Pour les audits de masse (des centaines de collections chaque nuit), seul le
+//! score, les stats, le nombre d'issues par règle et le verdict du quality gate
+//! sont conservés : la liste des issues n'est pas sérialisée.
+
+use crate::{LintResult, LintStats};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Seuils du quality gate ; un seuil absent de la configuration n'est pas vérifié
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct QualityGate {
+    /// Score minimal exigé
+    pub min_score: Option<u32>,
+    /// Nombre maximal d'issues de sévérité error
+    pub max_errors: Option<u32>,
+    /// Nombre maximal d'issues de sévérité warning
+    pub max_warnings: Option<u32>,
+    /// Sévérité à partir de laquelle une seule issue fait échouer le gate
+    pub fail_on: Option<FailOn>,
+}
+
+/// Seuil de sévérité de `fail_on`
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum FailOn {
+    Error,
+    Warning,
+    Info,
+}
+
+impl FailOn {
+    pub fn parse(severity: &str) -> Option<Self> {
+        match severity {
+            "error" => Some(FailOn::Error),
+            "warning" => Some(FailOn::Warning),
+            "info" => Some(FailOn::Info),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            FailOn::Error => "error",
+            FailOn::Warning => "warning",
+            FailOn::Info => "info",
+        }
+    }
+
+    /// Issues de cette sévérité ou plus grave
+    fn count(self, stats: &LintStats) -> u32 {
+        match self {
+            FailOn::Error => stats.errors,
+            FailOn::Warning => stats.errors + stats.warnings,
+            FailOn::Info => stats.errors + stats.warnings + stats.infos,
+        }
+    }
+}
+
+impl Default for QualityGate {
+    fn default() -> Self {
+        QualityGate {
+            min_score: None,
+            max_errors: Some(0),
+            max_warnings: None,
+            fail_on: None,
+        }
+    }
+}
+
+/// Verdict du quality gate
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct GateVerdict {
+    pub passed: bool,
+    /// Conditions non respectées (ex: "score 62 < 80")
+    pub failures: Vec<String>,
+}
+
+impl QualityGate {
+    /// Gate sans aucun seuil, complété par les overrides de la CLI
+    pub fn unset() -> Self {
+        QualityGate {
+            min_score: None,
+            max_errors: None,
+            max_warnings: None,
+            fail_on: None,
+        }
+    }
+
+    pub fn evaluate(&self, score: u32, stats: &LintStats) -> GateVerdict {
+        let mut failures = Vec::new();
+        if let Some(min_score) = self.min_score.filter(|min| score < *min) {
+            failures.push(format!("score {} < {}", score, min_score));
+        }
+        if let Some(max_errors) = self.max_errors.filter(|max| stats.errors > *max) {
+            failures.push(format!("errors {} > {}", stats.errors, max_errors));
+        }
+        if let Some(max_warnings) = self.max_warnings.filter(|max| stats.warnings > *max) {
+            failures.push(format!("warnings {} > {}", stats.warnings, max_warnings));
+        }
+        if let Some(fail_on) = self.fail_on {
+            let count = fail_on.count(stats);
+            if count > 0 {
+                failures.push(format!("{} issues at {} level or above", count, fail_on.as_str()));
+            }
+        }
+        GateVerdict {
+            passed: failures.is_empty(),
+            failures,
+        }
+    }
+}
+
+/// Résultat réduit : sans la liste des issues
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LintSummary {
+    pub score: u32,
+    pub stats: LintStats,
+    /// Nombre d'issues par règle, y compris celles omises par `max_issues_per_rule`
+    pub issues_by_rule: BTreeMap<String, usize>,
+    pub quality_gate: GateVerdict,
+}
+
+pub fn summarize(result: &LintResult, gate: &QualityGate) -> LintSummary {
+    let mut issues_by_rule = BTreeMap::new();
+    for issue in &result.issues {
+        *issues_by_rule.entry(issue.rule_id.clone()).or_default() += 1;
+    }
+    for (rule_id, omitted) in &result.truncated {
+        *issues_by_rule.entry(rule_id.clone()).or_default() += omitted;
+    }
+
+    LintSummary {
+        score: result.score,
+        stats: result.stats.clone(),
+        issues_by_rule,
+        quality_gate: gate.evaluate(result.score, &result.stats),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{run_linter, LintConfig};
+    use serde_json::json;
+
+    #[test]
+    fn test_summary_counts_issues_by_rule() {
+        let collection = json!({
+            "info": { "name": "Test" },
+            "item": [
+                { "name": "GET Users", "request": { "method": "GET", "url": "https://api.example.com/users" } },
+                { "name": "GET Orders", "request": { "method": "GET", "url": "https://api.example.com/orders" } }
+            ]
+        });
+        let config = LintConfig {
+            rules: Some(vec!["test-http-status-mandatory".to_string()]),
+            ..Default::default()
+        };
+
+        let summary = summarize(&run_linter(&collection, &config), &QualityGate::default());
+
+        assert_eq!(summary.issues_by_rule["test-http-status-mandatory"], 2);
+        assert!(!summary.quality_gate.passed);
+        assert_eq!(summary.quality_gate.failures, vec!["errors 2 > 0"]);
+
+        // Issues omises de la sortie : toujours comptées
+        let truncated = LintConfig { max_issues_per_rule: Some(1), ..config };
+        let summary = summarize(&run_linter(&collection, &truncated), &QualityGate::default());
+        assert_eq!(summary.issues_by_rule["test-http-status-mandatory"], 2);
+    }
+
+    #[test]
+    fn test_gate_thresholds() {
+        let stats = LintStats {
+            total_requests: 10,
+            total_tests: 10,
+            total_folders: 0,
+            errors: 0,
+            warnings: 4,
+            infos: 0,
+            hints: 0,
+            test_coverage_percent: 100.0,
+            methods: Default::default(),
+            categories: Default::default(),
+        };
+        let gate = QualityGate {
+            min_score: Some(80),
+            max_errors: Some(0),
+            max_warnings: Some(5),
+            fail_on: None,
+        };
+
+        assert!(gate.evaluate(85, &stats).passed);
+        assert_eq!(gate.evaluate(70, &stats).failures, vec!["score 70 < 80"]);
+
+        let strict = QualityGate { fail_on: FailOn::parse("warning"), ..gate.clone() };
+        assert_eq!(strict.evaluate(85, &stats).failures, vec!["4 issues at warning level or above"]);
+        let lenient = QualityGate { fail_on: FailOn::parse("error"), ..gate };
+        assert!(lenient.evaluate(85, &stats).passed);
+
+        // Seuils absents de la configuration : non vérifiés
+        let partial: QualityGate = serde_json::from_value(json!({ "min_score": 80 })).unwrap();
+        assert_eq!(partial, QualityGate { min_score: Some(80), ..QualityGate::unset() });
+        let with_errors = LintStats { errors: 3, ..stats };
+        assert!(partial.evaluate(85, &with_errors).passed);
+    }
+}
@@ -0,0 +1,167 @@
+//! Suppressions d'issues et rapport des issues masquées
+//!
+//! Deux sources peuvent masquer une issue :
+//! - une suppression inline `linterman-disable <règle>[, <règle>] -- <raison>` dans la
+//!   description ou un script d'un item (s'applique à l'item et à ses enfants) ;
+//! - une entrée de la liste `suppressions` de la configuration (règle + préfixe de path).
+//!
+//! Les issues masquées ne comptent ni dans les stats ni dans le score, mais restent
+//! listées dans `LintResult::suppressed` pour que les audits puissent les vérifier.
+
+use crate::{utils, LintIssue};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Suppression déclarée dans la configuration
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct Suppression {
+    /// Règle masquée ("*" pour toutes les règles)
+    pub rule_id: String,
+    /// Préfixe de path concerné (ex: "/item[2]"), toute la collection si vide
+    pub path: String,
+    /// Justification, reprise dans le rapport
+    pub reason: Option<String>,
+}
+
+/// Origine d'une suppression
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum SuppressionSource {
+    Inline,
+    Config,
+}
+
+/// Issue masquée, avec l'origine et la justification de la suppression
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SuppressedIssue {
+    #[serde(flatten)]
+    pub issue: LintIssue,
+    pub source: SuppressionSource,
+    /// Path de l'item portant la suppression inline, ou préfixe de la config
+    pub suppressed_at: String,
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+/// Sépare les issues actives des issues masquées par une suppression
+pub fn apply(collection: &Value, issues: Vec<LintIssue>, configured: &[Suppression]) -> (Vec<LintIssue>, Vec<SuppressedIssue>) {
+    let mut rules = Vec::new();
+    collect_inline(collection, "", &mut rules);
+    rules.extend(configured.iter().map(|suppression| (suppression.clone(), SuppressionSource::Config)));
+
+    if rules.is_empty() {
+        return (issues, Vec::new());
+    }
+
+    let mut kept = Vec::new();
+    let mut suppressed = Vec::new();
+    for issue in issues {
+        let matching = rules.iter().find(|(suppression, _)| {
+            (suppression.rule_id == "*" || suppression.rule_id == issue.rule_id) && within(&issue.path, &suppression.path)
+        });
+        match matching {
+            Some((suppression, source)) => suppressed.push(SuppressedIssue {
+                issue,
+                source: *source,
+                suppressed_at: suppression.path.clone(),
+                reason: suppression.reason.clone(),
+            }),
+            None => kept.push(issue),
+        }
+    }
+    (kept, suppressed)
+}
+
+/// Vérifie si `path` désigne l'item `prefix` ou l'un de ses descendants
+fn within(path: &str, prefix: &str) -> bool {
+    let prefix = prefix.trim_end_matches('/');
+    prefix.is_empty()
+        || path == prefix
+        || path.strip_prefix(prefix).is_some_and(|rest| rest.starts_with('/'))
+}
+
+/// Collecte les suppressions inline de la collection et de tous ses items
+fn collect_inline(item: &Value, path: &str, rules: &mut Vec<(Suppression, SuppressionSource)>) {
+    let mut sources = vec![utils::get_item_description(item)];
+    sources.extend(utils::extract_prerequest_scripts(item));
+    sources.extend(utils::extract_test_scripts(item));
+
+    for line in sources.iter().flat_map(|source| source.lines()) {
+        if let Some(directive) = line.split("linterman-disable").nth(1) {
+            let (rule_list, reason) = match directive.split_once("--") {
+                Some((rule_list, reason)) => (rule_list, Some(reason.trim().to_string()).filter(|r| !r.is_empty())),
+                None => (directive, None),
+            };
+            for rule_id in rule_list.split(',').map(str::trim).filter(|r| !r.is_empty()) {
+                rules.push((
+                    Suppression {
+                        rule_id: rule_id.to_string(),
+                        path: path.to_string(),
+                        reason: reason.clone(),
+                    },
+                    SuppressionSource::Inline,
+                ));
+            }
+        }
+    }
+
+    if let Some(items) = item["item"].as_array() {
+        for (index, child) in items.iter().enumerate() {
+            collect_inline(child, &format!("{}/item[{}]", path, index), rules);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn issue(rule_id: &str, path: &str) -> LintIssue {
+        LintIssue::builder(rule_id).path(path).build()
+    }
+
+    #[test]
+    fn test_inline_suppression_applies_to_item_and_children() {
+        let collection = json!({
+            "item": [
+                {
+                    "name": "Legacy",
+                    "description": "Ancienne API\nlinterman-disable hardcoded-secrets, debug-headers -- jeton de démo public",
+                    "item": [{ "name": "GET Users", "request": { "method": "GET", "url": "/users" } }]
+                },
+                { "name": "GET Orders", "request": { "method": "GET", "url": "/orders" } }
+            ]
+        });
+        let issues = vec![
+            issue("hardcoded-secrets", "/item[0]/item[0]/request/header[0]"),
+            issue("debug-headers", "/item[0]"),
+            issue("hardcoded-secrets", "/item[1]"),
+            issue("test-http-status-mandatory", "/item[0]/item[0]"),
+        ];
+
+        let (kept, suppressed) = apply(&collection, issues, &[]);
+
+        assert_eq!(kept.len(), 2);
+        assert_eq!(suppressed.len(), 2);
+        assert_eq!(suppressed[0].source, SuppressionSource::Inline);
+        assert_eq!(suppressed[0].suppressed_at, "/item[0]");
+        assert_eq!(suppressed[0].reason.as_deref(), Some("jeton de démo public"));
+    }
+
+    #[test]
+    fn test_configured_suppression() {
+        let configured = vec![Suppression {
+            rule_id: "*".to_string(),
+            path: "/item[1]".to_string(),
+            reason: None,
+        }];
+        let issues = vec![issue("hardcoded-secrets", "/item[1]/request/url"), issue("debug-headers", "/item[10]")];
+
+        let (kept, suppressed) = apply(&json!({ "item": [] }), issues, &configured);
+
+        assert_eq!(kept[0].path, "/item[10]");
+        assert_eq!(suppressed[0].source, SuppressionSource::Config);
+    }
+}
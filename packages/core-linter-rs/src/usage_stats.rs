@@ -0,0 +1,134 @@
+//! Statistiques d'usage locales des règles
+//!
+//! Avec `--stats-file`, la CLI cumule d'une analyse à l'autre, dans un fichier JSON
+//! local, le nombre d'analyses où chaque règle a signalé des issues. Les équipes
+//! plateforme peuvent demander ce fichier aux utilisateurs pour ajuster les jeux de
+//! règles par défaut (règles qui se déclenchent partout, règles souvent masquées).
+//! Rien n'est envoyé sur le réseau, et seuls des identifiants de règles et des
+//! compteurs sont enregistrés : ni nom de collection, ni path, ni message.
+
+use crate::LintResult;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Version du format de fichier
+pub const USAGE_STATS_VERSION: u32 = 1;
+
+/// Déclenchements cumulés d'une règle
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct RuleUsage {
+    /// Analyses où la règle a signalé au moins une issue (masquées comprises)
+    pub runs: u64,
+    /// Issues signalées, y compris celles omises par `max_issues_per_rule`
+    pub issues: u64,
+    /// Issues masquées par une suppression
+    pub suppressed: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct UsageStats {
+    pub version: u32,
+    /// Version du moteur de la dernière analyse enregistrée
+    #[serde(default)]
+    pub engine_version: String,
+    /// Analyses enregistrées
+    pub runs: u64,
+    /// Déclenchements par règle
+    #[serde(default)]
+    pub rules: BTreeMap<String, RuleUsage>,
+}
+
+impl Default for UsageStats {
+    fn default() -> Self {
+        UsageStats { version: USAGE_STATS_VERSION, engine_version: String::new(), runs: 0, rules: BTreeMap::new() }
+    }
+}
+
+impl UsageStats {
+    /// Ajoute une analyse (à enregistrer avant tout filtrage par baseline)
+    pub fn record(&mut self, result: &LintResult) {
+        let mut fired: BTreeMap<&str, RuleUsage> = BTreeMap::new();
+        for issue in &result.issues {
+            fired.entry(&issue.rule_id).or_default().issues += 1;
+        }
+        for (rule_id, omitted) in &result.truncated {
+            fired.entry(rule_id).or_default().issues += *omitted as u64;
+        }
+        for suppressed in &result.suppressed {
+            fired.entry(&suppressed.issue.rule_id).or_default().suppressed += 1;
+        }
+
+        for (rule_id, usage) in fired {
+            let total = self.rules.entry(rule_id.to_string()).or_default();
+            total.runs += 1;
+            total.issues += usage.issues;
+            total.suppressed += usage.suppressed;
+        }
+        self.runs += 1;
+        self.engine_version = result.engine_version.clone();
+    }
+
+    /// Règles déclenchées dans le plus d'analyses (puis le plus d'issues)
+    pub fn most_frequent(&self) -> Vec<(&str, &RuleUsage)> {
+        let mut rules: Vec<(&str, &RuleUsage)> = self.rules.iter().map(|(id, usage)| (id.as_str(), usage)).collect();
+        rules.sort_by(|a, b| b.1.runs.cmp(&a.1.runs).then(b.1.issues.cmp(&a.1.issues)).then(a.0.cmp(b.0)));
+        rules
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::suppression::Suppression;
+    use crate::{run_linter, LintConfig};
+    use serde_json::json;
+
+    fn collection(requests: usize) -> serde_json::Value {
+        json!({
+            "info": { "name": "Test" },
+            "item": (0..requests)
+                .map(|i| json!({ "name": format!("GET Item {}", i), "request": { "method": "GET", "url": format!("https://api.example.com/items/{}", i) } }))
+                .collect::<Vec<_>>()
+        })
+    }
+
+    fn config() -> LintConfig {
+        LintConfig {
+            local_only: true,
+            rules: Some(vec!["test-http-status-mandatory".to_string(), "environment-variables-usage".to_string()]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_record_accumulates_runs() {
+        let mut stats = UsageStats::default();
+        stats.record(&run_linter(&collection(3), &config()));
+        let config = LintConfig { max_issues_per_rule: Some(1), ..config() };
+        stats.record(&run_linter(&collection(2), &config));
+
+        assert_eq!(stats.runs, 2);
+        let status = &stats.rules["test-http-status-mandatory"];
+        assert_eq!((status.runs, status.issues, status.suppressed), (2, 5, 0));
+        assert_eq!(stats.most_frequent()[0].1.runs, 2);
+        assert!(!stats.engine_version.is_empty());
+
+        // Aller-retour par le fichier JSON
+        let saved = serde_json::to_string(&stats).unwrap();
+        assert_eq!(serde_json::from_str::<UsageStats>(&saved).unwrap(), stats);
+    }
+
+    #[test]
+    fn test_suppressed_issues_are_counted() {
+        let suppression =
+            Suppression { rule_id: "environment-variables-usage".to_string(), path: String::new(), reason: None };
+        let config = LintConfig { suppressions: Some(vec![suppression]), ..config() };
+        let mut stats = UsageStats::default();
+        stats.record(&run_linter(&collection(2), &config));
+
+        let usage = &stats.rules["environment-variables-usage"];
+        assert_eq!((usage.runs, usage.issues, usage.suppressed), (1, 0, 2));
+        let order: Vec<&str> = stats.most_frequent().iter().map(|(id, _)| *id).collect();
+        assert_eq!(order, vec!["test-http-status-mandatory", "environment-variables-usage"]);
+    }
+}
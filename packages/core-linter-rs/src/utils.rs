@@ -1,7 +1,9 @@
-use serde_json::Value;
+//! Utilitaires pour analyser les collections Postman
+//! Inspiré de folderScriptHelpers.js du projet source
 
-/// Utilitaires pour analyser les collections Postman
-/// Inspiré de folderScriptHelpers.js du projet source
+use crate::rule_api;
+use serde_json::Value;
+use std::sync::LazyLock;
 
 /// Extrait les scripts de test d'un item (folder ou request)
 pub fn extract_test_scripts(item: &Value) -> Vec<String> {
@@ -101,7 +103,7 @@ impl InheritedScripts {
     /// Vérifie si une variable est définie dans les pre-request scripts
     pub fn has_variable(&self, var_name: &str) -> bool {
         let set_pattern = format!(r#"pm\.environment\.set\s*\(\s*['"]{}['"]"#, var_name);
-        let regex = regex::Regex::new(&set_pattern).unwrap();
+        let regex = build_regex(&set_pattern).unwrap();
         
         self.prerequest_scripts.iter().any(|script| regex.is_match(script))
     }
@@ -131,6 +133,110 @@ pub fn get_request_name(item: &Value) -> String {
     item["name"].as_str().unwrap_or("unknown").to_string()
 }
 
+/// Extrait l'URL brute d'une requête (format string ou objet `url.raw`)
+pub fn get_request_url(item: &Value) -> String {
+    let url = &item["request"]["url"];
+    if let Some(url_str) = url.as_str() {
+        url_str.to_string()
+    } else {
+        url["raw"].as_str().unwrap_or("").to_string()
+    }
+}
+
+/// Extrait l'hôte d'une requête, sans schéma ni port (ex: "api.example.com" ou "{{base_url}}")
+pub fn get_url_host(item: &Value) -> String {
+    let url = &item["request"]["url"];
+    if let Some(host_parts) = url["host"].as_array() {
+        let host = host_parts
+            .iter()
+            .filter_map(|part| part.as_str())
+            .collect::<Vec<&str>>()
+            .join(".");
+        return host.split(':').next().unwrap_or("").to_string();
+    }
+    
+    let raw = get_request_url(item);
+    let without_scheme = raw.split_once("://").map(|(_, rest)| rest).unwrap_or(&raw);
+    let authority = without_scheme.split(['/', '?', '#']).next().unwrap_or("");
+    // Les variables {{...}} peuvent contenir ':' : ne retirer le port que hors variables
+    if authority.starts_with("{{") {
+        authority.to_string()
+    } else {
+        authority.split(':').next().unwrap_or("").to_string()
+    }
+}
+
+/// Extrait le path d'une requête, sans schéma, hôte (ou variable d'hôte `{{base_url}}`),
+/// query string ni fragment
+pub fn get_url_path(item: &Value) -> String {
+    static HOST: LazyLock<regex::Regex> =
+        LazyLock::new(|| build_regex(r"^(?:[a-zA-Z][a-zA-Z0-9+.-]*://[^/?#]*|\{\{[^}]+\}\})").unwrap());
+    let url = get_request_url(item);
+    let without_host = HOST.replace(&url, "");
+    
+    without_host
+        .split(['?', '#'])
+        .next()
+        .unwrap_or("")
+        .to_string()
+}
+
+/// Vérifie si une requête porte un body non vide (raw, urlencoded, formdata, graphql...)
+pub fn request_has_body(item: &Value) -> bool {
+    let body = &item["request"]["body"];
+    match body["mode"].as_str() {
+        Some("raw") => !body["raw"].as_str().unwrap_or("").trim().is_empty(),
+        Some(mode) => body[mode].as_array().map(|a| !a.is_empty()).unwrap_or(!body[mode].is_null()),
+        None => false,
+    }
+}
+
+/// Description d'un item (folder ou requête), chaîne ou objet `{content}`
+pub fn get_item_description(item: &Value) -> String {
+    [&item["description"], &item["request"]["description"]]
+        .iter()
+        .filter_map(|d| d.as_str().or_else(|| d["content"].as_str()))
+        .collect::<Vec<&str>>()
+        .join("\n")
+}
+
+/// Hash FNV-1a 64 bits en hexadécimal (16 caractères) : stable d'une version de
+/// Rust et d'une plateforme à l'autre, contrairement à `DefaultHasher`
+pub fn stable_hash(bytes: impl IntoIterator<Item = u8>) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in bytes {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    format!("{:016x}", hash)
+}
+
+/// Taille maximale d'une regex compilée (protège contre les motifs configurés démesurés)
+pub const REGEX_SIZE_LIMIT: usize = 1024 * 1024;
+/// Taille maximale du cache du DFA paresseux, par regex
+pub const REGEX_DFA_SIZE_LIMIT: usize = 2 * 1024 * 1024;
+
+/// Compile une regex avec des limites explicites de taille et de DFA.
+/// Toutes les regex du moteur (y compris celles issues de la configuration) passent par ici.
+pub fn build_regex(pattern: &str) -> Result<regex::Regex, regex::Error> {
+    regex::RegexBuilder::new(pattern)
+        .size_limit(REGEX_SIZE_LIMIT)
+        .dfa_size_limit(REGEX_DFA_SIZE_LIMIT)
+        .build()
+}
+
+/// Compile une liste de motifs fixes (voir [`build_regex`]) ; les règles la compilent
+/// une seule fois dans une `LazyLock` statique plutôt qu'à chaque item
+pub fn build_regexes(patterns: &[&str]) -> Vec<regex::Regex> {
+    patterns.iter().filter_map(|pattern| build_regex(pattern).ok()).collect()
+}
+
+/// Compile un motif à jokers `*` (insensible à la casse) en regex ancrée
+pub fn wildcard_regex(pattern: &str) -> Option<regex::Regex> {
+    let escaped = regex::escape(pattern).replace(r"\*", ".*");
+    build_regex(&format!("(?i)^{}$", escaped)).ok()
+}
+
 /// Vérifie si un item est une requête (vs un folder)
 pub fn is_request(item: &Value) -> bool {
     item.get("request").is_some()
@@ -141,6 +247,100 @@ pub fn is_folder(item: &Value) -> bool {
     item.get("request").is_none() && item.get("item").is_some()
 }
 
+/// Contexte d'un item pendant le parcours unique de la collection (voir [`walk_items`])
+pub struct ItemContext<'a> {
+    pub item: &'a Value,
+    /// Path de l'item (`/item[0]/item[1]`)
+    pub path: String,
+    /// Position de l'item dans son parent
+    pub index: usize,
+    /// Profondeur (0 pour les items de premier niveau)
+    pub depth: usize,
+    /// Nom de l'item ("Item-N" s'il n'est pas nommé)
+    pub name: String,
+    /// Méthode HTTP en majuscules (requêtes uniquement)
+    pub method: Option<String>,
+    /// Scripts de test des folders parents, un par folder (du plus haut au plus proche)
+    pub parent_test_scripts: &'a [String],
+    /// Scripts pre-request des folders parents, un par folder
+    pub parent_prerequest_scripts: &'a [String],
+}
+
+impl ItemContext<'_> {
+    pub fn is_request(&self) -> bool {
+        is_request(self.item)
+    }
+
+    /// Le parcours descend dans ses sous-items (`item` est un tableau) : les items
+    /// suivants de profondeur supérieure sont ses descendants
+    pub fn has_children(&self) -> bool {
+        self.item["item"].is_array()
+    }
+}
+
+/// Parcourt une seule fois tous les items de la collection (profondeur d'abord,
+/// un folder avant ses enfants) et appelle `visit` pour chacun
+pub fn walk_items(collection: &Value, visit: impl FnMut(&ItemContext)) {
+    walk_items_with(
+        collection,
+        |folder, _| (extract_test_scripts(folder).join("\n"), extract_prerequest_scripts(folder).join("\n")),
+        visit,
+    );
+}
+
+/// Comme [`walk_items`], les scripts (test, pre-request) de chaque folder étant fournis
+/// par `folder_scripts(folder, path)` (ex: `context::ScriptIndex::walk`, sans ré-extraction)
+pub fn walk_items_with(
+    collection: &Value,
+    folder_scripts: impl FnMut(&Value, &str) -> (String, String),
+    visit: impl FnMut(&ItemContext),
+) {
+    let mut walker = ItemWalker { parent_tests: Vec::new(), parent_prerequests: Vec::new(), folder_scripts, visit };
+    rule_api::traverse(collection, &mut walker);
+}
+
+/// Visiteur de `rule_api::traverse` : piles des scripts des folders parents
+struct ItemWalker<S, V> {
+    parent_tests: Vec<String>,
+    parent_prerequests: Vec<String>,
+    folder_scripts: S,
+    visit: V,
+}
+
+impl<'a, S, V> rule_api::Visitor<'a> for ItemWalker<S, V>
+where
+    S: FnMut(&Value, &str) -> (String, String),
+    V: FnMut(&ItemContext),
+{
+    fn visit(&mut self, item: &'a Value, path: &str, index: usize, depth: usize) {
+        let context = ItemContext {
+            item,
+            path: path.to_string(),
+            index,
+            depth,
+            name: rule_api::item_name(item, index),
+            method: item
+                .get("request")
+                .map(|request| request["method"].as_str().unwrap_or("GET").to_uppercase()),
+            parent_test_scripts: &self.parent_tests,
+            parent_prerequest_scripts: &self.parent_prerequests,
+        };
+        (self.visit)(&context);
+    }
+
+    // Folder : ses scripts s'appliquent à tous ses descendants
+    fn enter_folder(&mut self, folder: &'a Value, path: &str, _index: usize) {
+        let (test, prerequest) = (self.folder_scripts)(folder, path);
+        self.parent_tests.push(test);
+        self.parent_prerequests.push(prerequest);
+    }
+
+    fn leave_folder(&mut self) {
+        self.parent_tests.pop();
+        self.parent_prerequests.pop();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -201,4 +401,59 @@ mod tests {
         assert!(is_request(&request));
         assert!(!is_request(&folder));
     }
+
+    #[test]
+    fn test_get_url_path() {
+        let with_variable = json!({ "request": { "url": "{{base_url}}/users/{{id}}?expand=true" } });
+        let with_host = json!({ "request": { "url": { "raw": "https://api.example.com/orders#top" } } });
+        
+        assert_eq!(get_url_path(&with_variable), "/users/{{id}}");
+        assert_eq!(get_url_path(&with_host), "/orders");
+    }
+
+    #[test]
+    fn test_get_url_host() {
+        let with_variable = json!({ "request": { "url": "{{base_url}}/users" } });
+        let with_port = json!({ "request": { "url": "https://api.example.com:8443/orders" } });
+        let with_parts = json!({ "request": { "url": { "raw": "https://api.example.com/x", "host": ["api", "example", "com"] } } });
+        
+        assert_eq!(get_url_host(&with_variable), "{{base_url}}");
+        assert_eq!(get_url_host(&with_port), "api.example.com");
+        assert_eq!(get_url_host(&with_parts), "api.example.com");
+    }
+
+    #[test]
+    fn test_build_regex_size_limit() {
+        assert!(build_regex(r"api[_-]?key\s*=").is_ok());
+        // Motif démesuré (ex: issu d'une config) : refusé au lieu d'épuiser la mémoire
+        assert!(build_regex(r"(?:\w{100}){100}").is_err());
+    }
+
+    #[test]
+    fn test_walk_items_single_pass_context() {
+        let collection = json!({
+            "item": [{
+                "name": "Users",
+                "event": [{ "listen": "test", "script": { "exec": ["pm.response.to.have.status(200);"] } }],
+                "item": [
+                    { "name": "List", "request": { "method": "get", "url": "{{base_url}}/users" } },
+                    { "item": [{ "request": { "url": "{{base_url}}/users/1" } }] }
+                ]
+            }, {
+                "name": "Health", "request": { "method": "GET", "url": "{{base_url}}/health" }
+            }]
+        });
+
+        let mut visited = Vec::new();
+        walk_items(&collection, |item| {
+            visited.push((item.path.clone(), item.name.clone(), item.method.clone(), item.depth, item.parent_test_scripts.len()));
+        });
+
+        assert_eq!(visited.len(), 5);
+        assert_eq!(visited[0], ("/item[0]".to_string(), "Users".to_string(), None, 0, 0));
+        assert_eq!(visited[1], ("/item[0]/item[0]".to_string(), "List".to_string(), Some("GET".to_string()), 1, 1));
+        assert_eq!(visited[2].1, "Item-2");
+        assert_eq!(visited[3], ("/item[0]/item[1]/item[0]".to_string(), "Item-1".to_string(), Some("GET".to_string()), 2, 2));
+        assert_eq!(visited[4].4, 0);
+    }
 }
@@ -0,0 +1,88 @@
+//! Profils de visibilité des collections
+//!
+//! Une collection destinée à une publication externe (`public`) est analysée plus
+//! strictement qu'une collection interne : les règles d'exposition (hôtes internes,
+//! headers de debug, payloads de test...) sont activées et leurs issues escaladées.
+
+use crate::LintIssue;
+use serde::{Deserialize, Serialize};
+
+/// Visibilité de la collection analysée
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Visibility {
+    /// Collection interne à l'équipe / l'entreprise
+    #[default]
+    Internal,
+    /// Collection destinée à une publication externe
+    Public,
+}
+
+/// Règles dont les issues deviennent des erreurs en profil public
+const PUBLIC_ESCALATED_RULES: &[&str] = &[
+    "internal-hosts-disclosure",
+    "debug-headers",
+    "test-payload-residue",
+    "hardcoded-secrets",
+    "auth-required-for-hosts",
+];
+
+impl Visibility {
+    /// Parse une visibilité ("public", "internal")
+    pub fn parse(value: &str) -> Option<Visibility> {
+        match value.to_lowercase().as_str() {
+            "public" => Some(Visibility::Public),
+            "internal" | "private" => Some(Visibility::Internal),
+            _ => None,
+        }
+    }
+
+    pub fn is_public(self) -> bool {
+        self == Visibility::Public
+    }
+
+    /// Ajuste la sévérité des issues selon le profil
+    pub fn adjust_severities(self, issues: &mut [LintIssue]) {
+        if !self.is_public() {
+            return;
+        }
+
+        for issue in issues.iter_mut() {
+            if PUBLIC_ESCALATED_RULES.contains(&issue.rule_id.as_str()) {
+                issue.severity = "error".to_string();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(rule_id: &str) -> LintIssue {
+        LintIssue::builder(rule_id)
+            .message("Test")
+            .path("/item[0]")
+            .build()
+    }
+
+    #[test]
+    fn test_public_profile_escalates_exposure_rules() {
+        let mut issues = vec![issue("debug-headers"), issue("request-naming-convention")];
+
+        Visibility::Public.adjust_severities(&mut issues);
+
+        assert_eq!(issues[0].severity, "error");
+        assert_eq!(issues[1].severity, "warning");
+    }
+
+    #[test]
+    fn test_internal_profile_keeps_severities() {
+        let mut issues = vec![issue("debug-headers")];
+
+        Visibility::Internal.adjust_severities(&mut issues);
+
+        assert_eq!(issues[0].severity, "warning");
+        assert_eq!(Visibility::parse("PUBLIC"), Some(Visibility::Public));
+    }
+}
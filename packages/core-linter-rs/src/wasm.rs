@@ -0,0 +1,314 @@
+//! Bindings WASM (feature `wasm`, active par défaut)
+//!
+//! Fonctions exportées vers JavaScript par `wasm-pack` : elles prennent et
+//! retournent du JSON sous forme de chaînes et lèvent des `WasmError`. Sans la
+//! feature, le crate se compile comme une bibliothèque Rust ordinaire, sans
+//! `wasm-bindgen` (`default-features = false`).
+
+use crate::wasm_error::{ErrorCode, WasmError};
+use crate::{
+    compare, graph, hashing, impact, incremental, js_rules, normalize, rules, run_linter_with_registry, scaffold,
+    session, snippets, source_map, utils, LintConfig, LintResult,
+};
+use serde_json::Value;
+use wasm_bindgen::prelude::*;
+
+
+#[wasm_bindgen]
+pub fn lint(collection_json: &str, config_json: &str) -> Result<String, JsValue> {
+    let collection: Value = serde_json::from_str(collection_json)
+        .map_err(|e| WasmError::parse(ErrorCode::ParseCollection, "collection", &e))?;
+    
+    let config = LintConfig::from_json(config_json).map_err(WasmError::from)?;
+    
+    let mut result = run_linter_with_registry(&collection, &config, &js_rules::registry());
+    if config.source_map.unwrap_or(false) {
+        source_map::annotate(&mut result, collection_json);
+    }
+    
+    serde_json::to_string(&result)
+        .map_err(|e| WasmError::serialize(&e).into())
+}
+
+/// Validation stricte de la configuration (règles JavaScript enregistrées comprises) :
+/// lève `UNKNOWN_RULE` ou `INVALID_CONFIG` là où `lint` se contente de `config_warnings`
+#[wasm_bindgen]
+pub fn validate_config(config_json: &str) -> Result<(), JsValue> {
+    let config = LintConfig::from_json(config_json).map_err(WasmError::from)?;
+    config.validate(&js_rules::registry()).map_err(|e| WasmError::from(e).into())
+}
+
+thread_local! {
+    /// Session de la dernière collection passée à `lint_subset`, avec l'empreinte de son JSON
+    static SUBSET_SESSION: std::cell::RefCell<Option<(String, session::LintSession)>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+/// Analyse en ne ré-exécutant que les règles `only_rules` (case cochée ou décochée dans
+/// l'IHM) : tant que le JSON de la collection est inchangé, sa version parsée, son
+/// index de scripts et les issues des autres règles sont réutilisés
+#[wasm_bindgen]
+pub fn lint_subset(collection_json: &str, config_json: &str, only_rules_json: &str) -> Result<String, JsValue> {
+    let config: LintConfig = serde_json::from_str(config_json)
+        .map_err(|e| WasmError::parse(ErrorCode::ParseConfig, "config", &e))?;
+    
+    let only_rules: Vec<String> = serde_json::from_str(only_rules_json)
+        .map_err(|e| WasmError::parse(ErrorCode::ParseInput, "rule list", &e))?;
+    
+    let fingerprint = utils::stable_hash(collection_json.bytes());
+    let result = SUBSET_SESSION.with(|cached| {
+        let mut cached = cached.borrow_mut();
+        if cached.as_ref().is_none_or(|(cached_fingerprint, _)| *cached_fingerprint != fingerprint) {
+            let collection: Value = serde_json::from_str(collection_json)
+                .map_err(|e| WasmError::parse(ErrorCode::ParseCollection, "collection", &e))?;
+            *cached = Some((fingerprint, session::LintSession::new(collection)));
+        }
+        let (_, session) = cached.as_mut().expect("session initialisée ci-dessus");
+        Ok::<_, JsValue>(session.lint_subset(&config, &js_rules::registry(), &only_rules))
+    })?;
+    
+    serde_json::to_string(&result)
+        .map_err(|e| WasmError::serialize(&e).into())
+}
+
+/// Applique les corrections automatiques et retourne la collection corrigée + le nombre de fixes appliqués
+#[wasm_bindgen]
+pub fn lint_and_fix(collection_json: &str, config_json: &str) -> Result<String, JsValue> {
+    let collection: Value = serde_json::from_str(collection_json)
+        .map_err(|e| WasmError::parse(ErrorCode::ParseCollection, "collection", &e))?;
+    
+    let config: LintConfig = serde_json::from_str(config_json)
+        .map_err(|e| WasmError::parse(ErrorCode::ParseConfig, "config", &e))?;
+    
+    // Lancer le linter, appliquer les corrections (règles JS enregistrées incluses) et ré-analyser
+    let mut session = session::LintSession::new(collection);
+    let outcome = session
+        .fix(&config, &js_rules::registry())
+        .map_err(WasmError::from)?;
+    
+    let mut response = fix_response(&outcome);
+    response["fixed_collection"] = session.collection().clone();
+    
+    serde_json::to_string(&response)
+        .map_err(|e| WasmError::serialize(&e).into())
+}
+
+/// Stats avant / après corrections et issues restantes
+fn fix_response(outcome: &session::FixOutcome) -> Value {
+    serde_json::json!({
+        "environment_template": outcome.environment_template,
+        "fixes_applied": outcome.fixes_applied,
+        "before": {
+            "score": outcome.before.score,
+            "issues": outcome.before.issues.len(),
+        },
+        "after": {
+            "score": outcome.after.score,
+            "issues": outcome.after.issues.len(),
+        },
+        "remaining_issues": outcome.after.issues,
+    })
+}
+
+thread_local! {
+    /// Collections ouvertes par `open_collection`
+    static SESSIONS: std::cell::RefCell<session::SessionStore> = std::cell::RefCell::new(session::SessionStore::default());
+}
+
+/// Parse une collection une seule fois et la conserve en mémoire WASM ; retourne un
+/// handle à passer à `lint_collection` / `fix_collection` puis à `close_collection`
+#[wasm_bindgen]
+pub fn open_collection(collection_json: &str) -> Result<u32, JsValue> {
+    let collection: Value = serde_json::from_str(collection_json)
+        .map_err(|e| WasmError::parse(ErrorCode::ParseCollection, "collection", &e))?;
+    
+    Ok(SESSIONS.with(|sessions| sessions.borrow_mut().open(collection)))
+}
+
+/// Libère la collection d'un handle ; `false` si le handle est inconnu
+#[wasm_bindgen]
+pub fn close_collection(handle: u32) -> bool {
+    SESSIONS.with(|sessions| sessions.borrow_mut().close(handle))
+}
+
+/// Analyse la collection d'un handle ; les règles déjà exécutées avec les mêmes
+/// réglages ne sont pas relancées (cocher une règle n'exécute que celle-ci)
+#[wasm_bindgen]
+pub fn lint_collection(handle: u32, config_json: &str) -> Result<String, JsValue> {
+    let config: LintConfig = serde_json::from_str(config_json)
+        .map_err(|e| WasmError::parse(ErrorCode::ParseConfig, "config", &e))?;
+    
+    let result = SESSIONS.with(|sessions| {
+        let mut sessions = sessions.borrow_mut();
+        let session = sessions.get_mut(handle).map_err(WasmError::from)?;
+        Ok::<_, JsValue>(session.lint(&config, &js_rules::registry()))
+    })?;
+    
+    serde_json::to_string(&result)
+        .map_err(|e| WasmError::serialize(&e).into())
+}
+
+/// Corrige la collection d'un handle en place ; même réponse que `lint_and_fix`,
+/// sans la collection corrigée (voir `export_collection`)
+#[wasm_bindgen]
+pub fn fix_collection(handle: u32, config_json: &str) -> Result<String, JsValue> {
+    let config: LintConfig = serde_json::from_str(config_json)
+        .map_err(|e| WasmError::parse(ErrorCode::ParseConfig, "config", &e))?;
+    
+    let outcome = SESSIONS.with(|sessions| {
+        let mut sessions = sessions.borrow_mut();
+        let session = sessions.get_mut(handle).map_err(WasmError::from)?;
+        session.fix(&config, &js_rules::registry()).map_err(|e| JsValue::from(WasmError::from(e)))
+    })?;
+    
+    serde_json::to_string(&fix_response(&outcome))
+        .map_err(|e| WasmError::serialize(&e).into())
+}
+
+/// JSON de la collection d'un handle (après corrections éventuelles)
+#[wasm_bindgen]
+pub fn export_collection(handle: u32) -> Result<String, JsValue> {
+    SESSIONS.with(|sessions| {
+        let mut sessions = sessions.borrow_mut();
+        let session = sessions.get_mut(handle).map_err(WasmError::from)?;
+        serde_json::to_string(session.collection())
+            .map_err(|e| WasmError::serialize(&e).into())
+    })
+}
+
+/// Estime, pour chaque règle, les points gagnés si toutes ses issues étaient corrigées
+#[wasm_bindgen]
+pub fn impact_analysis(collection_json: &str, config_json: &str) -> Result<String, JsValue> {
+    let collection: Value = serde_json::from_str(collection_json)
+        .map_err(|e| WasmError::parse(ErrorCode::ParseCollection, "collection", &e))?;
+    
+    let config: LintConfig = serde_json::from_str(config_json)
+        .map_err(|e| WasmError::parse(ErrorCode::ParseConfig, "config", &e))?;
+    
+    let analysis = impact::impact_analysis(&collection, &config);
+    
+    serde_json::to_string(&analysis)
+        .map_err(|e| WasmError::serialize(&e).into())
+}
+
+/// Compare plusieurs configurations candidates (scores et nombres d'issues) en une analyse
+#[wasm_bindgen]
+pub fn simulate(collection_json: &str, configs_json: &str) -> Result<String, JsValue> {
+    let collection: Value = serde_json::from_str(collection_json)
+        .map_err(|e| WasmError::parse(ErrorCode::ParseCollection, "collection", &e))?;
+    
+    let configs: Vec<LintConfig> = serde_json::from_str(configs_json)
+        .map_err(|e| WasmError::parse(ErrorCode::ParseInput, "configs", &e))?;
+    
+    let results = crate::simulate::simulate(&collection, &configs);
+    
+    serde_json::to_string(&results)
+        .map_err(|e| WasmError::serialize(&e).into())
+}
+
+/// Analyse la nouvelle version d'une collection en ne retenant que les issues
+/// introduites par les items ajoutés ou modifiés depuis l'ancienne version
+#[wasm_bindgen]
+pub fn lint_diff(old_collection_json: &str, new_collection_json: &str, config_json: &str) -> Result<String, JsValue> {
+    let old_collection: Value = serde_json::from_str(old_collection_json)
+        .map_err(|e| WasmError::parse(ErrorCode::ParseCollection, "previous collection", &e))?;
+    
+    let new_collection: Value = serde_json::from_str(new_collection_json)
+        .map_err(|e| WasmError::parse(ErrorCode::ParseCollection, "collection", &e))?;
+    
+    let config: LintConfig = serde_json::from_str(config_json)
+        .map_err(|e| WasmError::parse(ErrorCode::ParseConfig, "config", &e))?;
+    
+    let diff = incremental::lint_diff(&old_collection, &new_collection, &config, &js_rules::registry());
+    
+    serde_json::to_string(&diff)
+        .map_err(|e| WasmError::serialize(&e).into())
+}
+
+/// Compare deux résultats JSON (issues nouvelles / résolues / persistantes, évolution du score)
+#[wasm_bindgen]
+pub fn compare_results(before_json: &str, after_json: &str) -> Result<String, JsValue> {
+    let before: LintResult = serde_json::from_str(before_json)
+        .map_err(|e| WasmError::parse(ErrorCode::ParseInput, "previous result", &e))?;
+    
+    let after: LintResult = serde_json::from_str(after_json)
+        .map_err(|e| WasmError::parse(ErrorCode::ParseInput, "current result", &e))?;
+    
+    let comparison = compare::compare_results(&before, &after);
+    
+    serde_json::to_string(&comparison)
+        .map_err(|e| WasmError::serialize(&e).into())
+}
+
+/// Métadonnées de toutes les règles intégrées (id, catégorie, sévérité, description, options)
+#[wasm_bindgen]
+pub fn list_rules() -> Result<String, JsValue> {
+    serde_json::to_string(&rules::list_rules())
+        .map_err(|e| WasmError::serialize(&e).into())
+}
+
+/// Hash stable du contenu de chaque item, indexé par path (détection des requêtes modifiées)
+#[wasm_bindgen]
+pub fn hash_items(collection_json: &str) -> Result<String, JsValue> {
+    let collection: Value = serde_json::from_str(collection_json)
+        .map_err(|e| WasmError::parse(ErrorCode::ParseCollection, "collection", &e))?;
+    
+    serde_json::to_string(&hashing::hash_items(&collection))
+        .map_err(|e| WasmError::serialize(&e).into())
+}
+
+/// Injecte les tests standard manquants (statut, temps de réponse, schéma) dans toute la collection
+#[wasm_bindgen]
+pub fn scaffold_tests(collection_json: &str, config_json: &str) -> Result<String, JsValue> {
+    let mut collection: Value = serde_json::from_str(collection_json)
+        .map_err(|e| WasmError::parse(ErrorCode::ParseCollection, "collection", &e))?;
+    
+    let config: LintConfig = serde_json::from_str(config_json)
+        .map_err(|e| WasmError::parse(ErrorCode::ParseConfig, "config", &e))?;
+    
+    let library = snippets::SnippetLibrary::new(config.locale.unwrap_or_default(), config.snippets.as_ref());
+    let report = scaffold::scaffold_tests(&mut collection, &library);
+    
+    let response = serde_json::json!({
+        "scaffolded_collection": collection,
+        "requests_updated": report.requests_updated,
+        "tests_added": report.tests_added,
+    });
+    
+    serde_json::to_string(&response)
+        .map_err(|e| WasmError::serialize(&e).into())
+}
+
+/// Normalise la collection pour git (tris, champs volatils) selon `config.normalize`
+#[wasm_bindgen]
+pub fn normalize_collection(collection_json: &str, config_json: &str) -> Result<String, JsValue> {
+    let mut collection: Value = serde_json::from_str(collection_json)
+        .map_err(|e| WasmError::parse(ErrorCode::ParseCollection, "collection", &e))?;
+    
+    let config: LintConfig = serde_json::from_str(config_json)
+        .map_err(|e| WasmError::parse(ErrorCode::ParseConfig, "config", &e))?;
+    
+    let report = normalize::normalize(&mut collection, &config.normalize.unwrap_or_default());
+    
+    let response = serde_json::json!({
+        "normalized_collection": collection,
+        "arrays_sorted": report.arrays_sorted,
+        "fields_stripped": report.fields_stripped,
+    });
+    
+    serde_json::to_string(&response)
+        .map_err(|e| WasmError::serialize(&e).into())
+}
+
+/// Graphe de la collection au format `mermaid` ou `dot` (arbre, flux des variables en option)
+#[wasm_bindgen]
+pub fn collection_graph(collection_json: &str, format: &str, include_variables: bool) -> Result<String, JsValue> {
+    let collection: Value = serde_json::from_str(collection_json)
+        .map_err(|e| WasmError::parse(ErrorCode::ParseCollection, "collection", &e))?;
+    
+    let format = graph::GraphFormat::parse(format).ok_or_else(|| {
+        WasmError::new(ErrorCode::ParseInput, format!("Unsupported graph format '{}' (expected mermaid or dot)", format))
+    })?;
+    
+    Ok(graph::render(&collection, format, include_variables))
+}
@@ -0,0 +1,131 @@
+//! Erreurs structurées des bindings WASM
+//!
+//! Chaque échec est levé côté JavaScript sous la forme d'un objet
+//! `{ code, detail, line, column }` plutôt que d'une chaîne : l'IHM choisit son
+//! message selon `code` et peut pointer l'endroit d'un export tronqué ou invalide
+//! (`line` / `column`, renseignés pour les erreurs de parsing JSON).
+
+use crate::LintError;
+use serde::Serialize;
+use std::fmt;
+use wasm_bindgen::JsValue;
+
+/// Catégorie d'erreur, sérialisée en SCREAMING_SNAKE_CASE (`PARSE_COLLECTION`)
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ErrorCode {
+    /// JSON de la collection invalide ou tronqué
+    ParseCollection,
+    /// JSON de la configuration invalide ou de forme inattendue
+    ParseConfig,
+    /// Autre entrée invalide (liste de règles, résultats à comparer, configurations)
+    ParseInput,
+    /// Règle JavaScript refusée (id vide, sévérité inconnue)
+    InvalidRule,
+    /// Handle de collection fermé ou jamais ouvert
+    UnknownHandle,
+    /// Corrections automatiques refusées (mode strict)
+    FixFailed,
+    /// Résultat non sérialisable
+    SerializeResult,
+    /// Configuration incohérente (validation stricte)
+    InvalidConfig,
+    /// Règle inconnue référencée par la configuration (validation stricte)
+    UnknownRule,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct WasmError {
+    pub code: ErrorCode,
+    pub detail: String,
+    /// Position de l'erreur dans le JSON (à partir de 1)
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+}
+
+impl WasmError {
+    pub fn new(code: ErrorCode, detail: impl Into<String>) -> Self {
+        WasmError { code, detail: detail.into(), line: None, column: None }
+    }
+
+    /// Erreur de parsing JSON de l'entrée `input` ("collection", "config"...), avec sa position
+    pub fn parse(code: ErrorCode, input: &str, error: &serde_json::Error) -> Self {
+        WasmError { code, ..WasmError::from(LintError::parse(input, error)) }
+    }
+
+    pub fn serialize(error: &serde_json::Error) -> Self {
+        WasmError::new(ErrorCode::SerializeResult, format!("Failed to serialize result: {}", error))
+    }
+}
+
+impl From<LintError> for WasmError {
+    fn from(error: LintError) -> Self {
+        match error {
+            LintError::Parse { input, message, line, column } => WasmError {
+                code: match input.as_str() {
+                    "collection" => ErrorCode::ParseCollection,
+                    "config" => ErrorCode::ParseConfig,
+                    _ => ErrorCode::ParseInput,
+                },
+                detail: format!("Failed to parse {}: {}", input, message),
+                line,
+                column,
+            },
+            LintError::InvalidConfig(_) => WasmError::new(ErrorCode::InvalidConfig, error.to_string()),
+            LintError::UnknownRule(_) => WasmError::new(ErrorCode::UnknownRule, error.to_string()),
+            LintError::FixFailed(detail) => WasmError::new(ErrorCode::FixFailed, detail),
+            LintError::UnknownHandle(_) => WasmError::new(ErrorCode::UnknownHandle, error.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for WasmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.detail)?;
+        if let (Some(line), Some(column)) = (self.line, self.column) {
+            write!(f, " (line {}, column {})", line, column)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<WasmError> for JsValue {
+    fn from(error: WasmError) -> Self {
+        serde_json::to_string(&error)
+            .ok()
+            .and_then(|json| js_sys::JSON::parse(&json).ok())
+            .unwrap_or_else(|| JsValue::from_str(&error.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn test_truncated_json_position() {
+        let error = serde_json::from_str::<Value>("{\n  \"info\": {\n    \"name\": \"Te").unwrap_err();
+        let wasm_error = WasmError::parse(ErrorCode::ParseCollection, "collection", &error);
+
+        assert_eq!(wasm_error.line, Some(3));
+        assert_eq!(wasm_error.detail, "Failed to parse collection: EOF while parsing a string");
+        assert_eq!(
+            serde_json::to_value(&wasm_error).unwrap(),
+            json!({ "code": "PARSE_COLLECTION", "detail": wasm_error.detail, "line": 3, "column": wasm_error.column })
+        );
+    }
+
+    #[test]
+    fn test_error_without_position() {
+        let wasm_error = WasmError::from(LintError::UnknownHandle(4));
+        assert_eq!(wasm_error.code, ErrorCode::UnknownHandle);
+
+        assert_eq!(wasm_error.to_string(), "Unknown collection handle 4 (closed or never opened)");
+        assert_eq!(serde_json::to_value(&wasm_error).unwrap()["line"], Value::Null);
+
+        let unknown = WasmError::from(LintError::UnknownRule("test-http-statuss".to_string()));
+        assert_eq!(unknown.code, ErrorCode::UnknownRule);
+        assert_eq!(unknown.detail, "Unknown rule 'test-http-statuss'");
+    }
+}
@@ -0,0 +1,421 @@
+//! Analyse d'un ensemble de collections (mode workspace)
+//!
+//! Un monorepo contient souvent plusieurs collections : chacune est analysée
+//! séparément, puis les résultats sont regroupés dans un seul `WorkspaceResult`
+//! avec les issues qui concernent plusieurs collections à la fois et un score
+//! global pondéré par le nombre de requêtes de chaque collection.
+//!
+//! Les exports de workspace / backups Postman (un seul JSON avec `collections` et
+//! `environments`) sont découpés automatiquement : chaque collection est analysée
+//! avec l'environnement qui lui correspond (voir [`WorkspaceExport`]).
+
+use crate::environment;
+use crate::registry::RuleRegistry;
+use crate::summary::{summarize, GateVerdict, LintSummary, QualityGate};
+use crate::utils;
+use crate::{run_linter_with_registry, scoring, LintConfig, LintIssue, LintResult, LintStats};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::LazyLock;
+
+/// Résultat d'une collection du workspace
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CollectionResult {
+    /// Nom de la collection (`info.name`)
+    pub name: String,
+    /// Origine de la collection (fichier, entrée d'un export...)
+    pub source: String,
+    /// Environnement associé (export de workspace)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub environment: Option<String>,
+    pub result: LintResult,
+}
+
+/// Résultat agrégé du workspace
+#[derive(Serialize, Deserialize, Debug)]
+pub struct WorkspaceResult {
+    /// Score global, moyenne des scores pondérée par le nombre de requêtes
+    pub score: u32,
+    /// Somme des stats des collections
+    pub stats: LintStats,
+    pub collections: Vec<CollectionResult>,
+    /// Issues concernant plusieurs fichiers (collections dupliquées, variables absentes
+    /// de l'environnement associé...)
+    #[serde(default)]
+    pub issues: Vec<LintIssue>,
+}
+
+/// Analyse chaque collection `(source, collection)` puis les vérifications inter-collections
+pub fn lint_workspace(collections: &[(String, Value)], config: &LintConfig, registry: &RuleRegistry) -> WorkspaceResult {
+    let results: Vec<CollectionResult> = collections
+        .iter()
+        .map(|(source, collection)| CollectionResult {
+            name: collection["info"]["name"].as_str().unwrap_or("unnamed").to_string(),
+            source: source.clone(),
+            environment: None,
+            result: run_linter_with_registry(collection, config, registry),
+        })
+        .collect();
+
+    let mut stats = LintStats {
+        total_requests: 0,
+        total_tests: 0,
+        total_folders: 0,
+        errors: 0,
+        warnings: 0,
+        infos: 0,
+        hints: 0,
+        test_coverage_percent: 0.0,
+        methods: Default::default(),
+        categories: Default::default(),
+    };
+    let mut tested_requests = 0.0;
+    let mut weighted_score = 0.0;
+    let mut total_weight = 0.0;
+
+    for collection in &results {
+        let collection_stats = &collection.result.stats;
+        stats.total_requests += collection_stats.total_requests;
+        stats.total_tests += collection_stats.total_tests;
+        stats.total_folders += collection_stats.total_folders;
+        stats.errors += collection_stats.errors;
+        stats.warnings += collection_stats.warnings;
+        stats.infos += collection_stats.infos;
+        stats.hints += collection_stats.hints;
+        for (method, count) in &collection_stats.methods {
+            *stats.methods.entry(method.clone()).or_default() += count;
+        }
+        scoring::merge(&mut stats.categories, &collection_stats.categories);
+        tested_requests += collection_stats.test_coverage_percent * collection_stats.total_requests as f64 / 100.0;
+
+        // Une collection vide compte comme une requête pour ne pas disparaître du score
+        let weight = collection_stats.total_requests.max(1) as f64;
+        weighted_score += collection.result.score as f64 * weight;
+        total_weight += weight;
+    }
+
+    if stats.total_requests > 0 {
+        stats.test_coverage_percent = (tested_requests / stats.total_requests as f64 * 1000.0).round() / 10.0;
+    }
+    // Pénalités par catégorie sur l'ensemble des requêtes (le score reste la moyenne
+    // pondérée des collections)
+    scoring::score(&mut stats.categories, stats.total_requests);
+
+    WorkspaceResult {
+        score: if total_weight > 0.0 { (weighted_score / total_weight).round() as u32 } else { 100 },
+        stats,
+        issues: cross_collection_issues(collections),
+        collections: results,
+    }
+}
+
+/// Collections portant le même nom ou le même `_postman_id` (conflit à l'import)
+fn cross_collection_issues(collections: &[(String, Value)]) -> Vec<LintIssue> {
+    let mut issues = Vec::new();
+
+    for (field, rule_id, severity) in [
+        ("name", "workspace-duplicate-collection-name", "warning"),
+        ("_postman_id", "workspace-duplicate-collection-id", "error"),
+    ] {
+        let mut sources: HashMap<&str, Vec<&str>> = HashMap::new();
+        let mut order = Vec::new();
+        for (source, collection) in collections {
+            if let Some(value) = collection["info"][field].as_str().filter(|v| !v.is_empty()) {
+                let entry = sources.entry(value).or_default();
+                if entry.is_empty() {
+                    order.push(value);
+                }
+                entry.push(source);
+            }
+        }
+
+        for value in order {
+            let duplicates = &sources[value];
+            if duplicates.len() > 1 {
+                issues.push(LintIssue::builder(rule_id)
+                    .severity(severity)
+                    .message(format!(
+                        "🗂️ Collections {} share the same {} \"{}\"",
+                        duplicates.join(", "),
+                        if field == "name" { "name" } else { "id" },
+                        value
+                    ))
+                    .path(format!("/info/{}", field))
+                    .build());
+            }
+        }
+    }
+
+    issues
+}
+
+/// Collections et environnements d'un workspace, extraits des exports le cas échéant
+#[derive(Debug, Default)]
+pub struct WorkspaceExport {
+    /// Collections v2 `(source, collection)`
+    pub collections: Vec<(String, Value)>,
+    pub environments: Vec<Value>,
+    /// Collections ignorées (format v1 des anciens backups), par source
+    pub unsupported: Vec<String>,
+}
+
+impl WorkspaceExport {
+    /// Ajoute un fichier : un export de workspace est découpé en collections et
+    /// environnements, une collection seule est ajoutée telle quelle.
+    /// Retourne `true` si le fichier était un export.
+    pub fn add(&mut self, source: &str, value: Value) -> bool {
+        if !is_export(&value) {
+            self.collections.push((source.to_string(), value));
+            return false;
+        }
+
+        let mut value = value;
+        if let Some(collections) = value["collections"].as_array_mut() {
+            for (index, entry) in collections.iter_mut().enumerate() {
+                let entry_source = format!("{}#collections[{}]", source, index);
+                // Réponses de l'API Postman : collection enveloppée dans `collection`
+                let collection = match entry.get_mut("collection") {
+                    Some(wrapped) if wrapped.get("info").is_some() => wrapped.take(),
+                    _ => entry.take(),
+                };
+                if collection.get("info").is_some() {
+                    self.collections.push((entry_source, collection));
+                } else {
+                    self.unsupported.push(entry_source);
+                }
+            }
+        }
+        if let Some(environments) = value["environments"].as_array_mut() {
+            self.environments.extend(environments.iter_mut().map(Value::take));
+        }
+        true
+    }
+
+    /// Environnement d'une collection : même nom, ou nom préfixé par celui de la
+    /// collection ("Orders - Staging") ; à défaut, l'unique environnement de l'export
+    pub fn matching_environment(&self, collection_name: &str) -> Option<&Value> {
+        let collection_name = collection_name.to_lowercase();
+        let name_of = |environment: &Value| environment["name"].as_str().unwrap_or("").to_lowercase();
+
+        self.environments
+            .iter()
+            .find(|environment| name_of(environment) == collection_name)
+            .or_else(|| {
+                self.environments
+                    .iter()
+                    .find(|environment| !collection_name.is_empty() && name_of(environment).starts_with(&collection_name))
+            })
+            .or(match self.environments.as_slice() {
+                [only] => Some(only),
+                _ => None,
+            })
+    }
+}
+
+/// Export de workspace / backup : liste `collections` à la racine, pas de bloc `info`
+pub fn is_export(value: &Value) -> bool {
+    value["collections"].is_array() && value.get("info").is_none()
+}
+
+/// Analyse un workspace : chaque collection, puis les vérifications inter-collections
+/// et les variables absentes de l'environnement associé
+pub fn lint_export(export: &WorkspaceExport, config: &LintConfig, registry: &RuleRegistry) -> WorkspaceResult {
+    let mut workspace = lint_workspace(&export.collections, config, registry);
+
+    for (result, (source, collection)) in workspace.collections.iter_mut().zip(&export.collections) {
+        let Some(environment) = export.matching_environment(&result.name) else {
+            continue;
+        };
+        let environment_name = environment["name"].as_str().unwrap_or("unnamed").to_string();
+        let undefined = undefined_variables(collection, environment);
+        if !undefined.is_empty() {
+            workspace.issues.push(LintIssue::builder("workspace-undefined-variable")
+                .message(format!(
+                    "🌍 Collection {} uses variables not defined in environment \"{}\": {}",
+                    source,
+                    environment_name,
+                    undefined.into_iter().collect::<Vec<_>>().join(", ")
+                ))
+                .path("/variable")
+                .build());
+        }
+        result.environment = Some(environment_name);
+    }
+
+    workspace
+}
+
+/// Variable écrite par un script (`pm.environment.set('token', ...)`)
+static SET_VARIABLE: LazyLock<Regex> = LazyLock::new(|| {
+    utils::build_regex(r#"pm\.(?:environment|collectionVariables|globals|variables)\.set\(\s*['"]([^'"]+)['"]"#).unwrap()
+});
+
+/// Variables référencées par la collection mais définies ni par la collection
+/// (`variable`, scripts), ni par l'environnement (valeurs actives)
+fn undefined_variables(collection: &Value, environment: &Value) -> BTreeSet<String> {
+    let mut defined: BTreeSet<String> = collection["variable"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .chain(
+            environment["values"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter(|value| value["enabled"].as_bool() != Some(false)),
+        )
+        .filter_map(|variable| variable["key"].as_str().map(str::to_string))
+        .collect();
+
+    // Variables écrites par les scripts (pm.environment.set('token', ...))
+    let content = serde_json::to_string(collection).unwrap_or_default().replace("\\\"", "\"");
+    defined.extend(SET_VARIABLE.captures_iter(&content).map(|caps| caps[1].to_string()));
+
+    environment::referenced_variables(collection)
+        .into_iter()
+        .filter(|name| !defined.contains(name))
+        .collect()
+}
+
+/// Score d'une collection dans le résumé du workspace
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CollectionScore {
+    pub name: String,
+    pub source: String,
+    pub score: u32,
+}
+
+/// Résumé du workspace (`--format summary`)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct WorkspaceSummary {
+    pub score: u32,
+    pub stats: LintStats,
+    /// Nombre d'issues par règle, toutes collections confondues
+    pub issues_by_rule: BTreeMap<String, usize>,
+    pub quality_gate: GateVerdict,
+    pub collections: Vec<CollectionScore>,
+}
+
+pub fn summarize_workspace(workspace: &WorkspaceResult, gate: &QualityGate) -> WorkspaceSummary {
+    let mut issues_by_rule = BTreeMap::new();
+    for collection in &workspace.collections {
+        let LintSummary { issues_by_rule: counts, .. } = summarize(&collection.result, gate);
+        for (rule_id, count) in counts {
+            *issues_by_rule.entry(rule_id).or_default() += count;
+        }
+    }
+    for issue in &workspace.issues {
+        *issues_by_rule.entry(issue.rule_id.clone()).or_default() += 1;
+    }
+
+    WorkspaceSummary {
+        score: workspace.score,
+        stats: workspace.stats.clone(),
+        issues_by_rule,
+        quality_gate: gate.evaluate(workspace.score, &workspace.stats),
+        collections: workspace
+            .collections
+            .iter()
+            .map(|collection| CollectionScore {
+                name: collection.name.clone(),
+                source: collection.source.clone(),
+                score: collection.result.score,
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn collection(name: &str, id: &str, requests: usize) -> Value {
+        let items: Vec<Value> = (0..requests)
+            .map(|i| json!({ "name": format!("GET Item {}", i), "request": { "method": "GET", "url": "{{base_url}}/items" } }))
+            .collect();
+        json!({ "info": { "name": name, "_postman_id": id }, "item": items })
+    }
+
+    #[test]
+    fn test_weighted_score_and_stats() {
+        let collections = vec![
+            ("orders.json".to_string(), collection("Orders", "a", 3)),
+            ("users.json".to_string(), collection("Users", "b", 1)),
+        ];
+        let config = LintConfig {
+            rules: Some(vec!["test-http-status-mandatory".to_string()]),
+            ..Default::default()
+        };
+
+        let workspace = lint_workspace(&collections, &config, &RuleRegistry::default());
+
+        assert_eq!(workspace.collections.len(), 2);
+        assert_eq!(workspace.collections[0].name, "Orders");
+        assert_eq!(workspace.stats.total_requests, 4);
+        assert_eq!(workspace.stats.errors, 4);
+        let expected = (workspace.collections[0].result.score as f64 * 3.0 + workspace.collections[1].result.score as f64) / 4.0;
+        assert_eq!(workspace.score, expected.round() as u32);
+        assert!(workspace.issues.is_empty());
+
+        let summary = summarize_workspace(&workspace, &QualityGate::default());
+        assert_eq!(summary.issues_by_rule["test-http-status-mandatory"], 4);
+        assert!(!summary.quality_gate.passed);
+    }
+
+    #[test]
+    fn test_duplicate_collections_reported() {
+        let collections = vec![
+            ("main/orders.json".to_string(), collection("Orders", "same-id", 1)),
+            ("feature/orders.json".to_string(), collection("Orders", "same-id", 1)),
+            ("users.json".to_string(), collection("Users", "other", 1)),
+        ];
+
+        let workspace = lint_workspace(&collections, &LintConfig::default(), &RuleRegistry::default());
+
+        assert_eq!(workspace.issues.len(), 2);
+        assert_eq!(workspace.issues[0].rule_id, "workspace-duplicate-collection-name");
+        assert!(workspace.issues[0].message.contains("main/orders.json, feature/orders.json"));
+        assert_eq!(workspace.issues[1].severity, "error");
+    }
+
+    #[test]
+    fn test_export_split_and_matched_with_environment() {
+        let export = json!({
+            "version": 1,
+            "collections": [
+                { "collection": {
+                    "info": { "name": "Orders" },
+                    "item": [{ "name": "GET Orders", "request": { "method": "GET", "url": "{{base_url}}/orders?key={{api_key}}" },
+                               "event": [{ "listen": "prerequest", "script": { "exec": ["pm.environment.set('token', 'x');"] } }] }]
+                } },
+                { "name": "Legacy", "requests": [] }
+            ],
+            "environments": [
+                { "name": "Users - Staging", "values": [] },
+                { "name": "Orders - Staging", "values": [
+                    { "key": "base_url", "value": "https://staging.example.com", "enabled": true },
+                    { "key": "api_key", "value": "", "enabled": false }
+                ] }
+            ]
+        });
+
+        let mut workspace_export = WorkspaceExport::default();
+        assert!(workspace_export.add("backup.json", export));
+        assert!(!workspace_export.add("users.json", collection("Users", "u", 1)));
+        assert_eq!(workspace_export.collections.len(), 2);
+        assert_eq!(workspace_export.unsupported, vec!["backup.json#collections[1]".to_string()]);
+
+        let workspace = lint_export(&workspace_export, &LintConfig::default(), &RuleRegistry::default());
+
+        assert_eq!(workspace.collections[0].source, "backup.json#collections[0]");
+        assert_eq!(workspace.collections[0].environment.as_deref(), Some("Orders - Staging"));
+        assert_eq!(workspace.collections[1].environment.as_deref(), Some("Users - Staging"));
+        let undefined: Vec<&LintIssue> = workspace.issues.iter().filter(|issue| issue.rule_id == "workspace-undefined-variable").collect();
+        assert_eq!(undefined.len(), 2);
+        assert!(undefined[0].message.ends_with("\"Orders - Staging\": api_key"));
+        assert!(undefined[1].message.ends_with("\"Users - Staging\": base_url"));
+    }
+}
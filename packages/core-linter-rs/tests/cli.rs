@@ -0,0 +1,103 @@
+//! Tests de bout en bout de la CLI (`postman-linter`)
+//!
+//! Chaque test écrit ses fichiers dans un répertoire temporaire propre, d'où la CLI
+//! est lancée (aucun `.linterman.toml` du dépôt n'est découvert).
+
+use serde_json::{json, Value};
+use std::fs;
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+/// Répertoire temporaire vide, propre au test
+fn workdir(test: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("linterman-cli-{}-{}", test, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Collection de `requests` requêtes sans test de statut
+fn collection(requests: usize) -> Value {
+    json!({
+        "info": { "name": "Orders" },
+        "item": (0..requests)
+            .map(|i| json!({ "name": format!("GET Order {}", i), "request": { "method": "GET", "url": format!("https://api.example.com/orders/{}", i) } }))
+            .collect::<Vec<_>>()
+    })
+}
+
+fn run(dir: &PathBuf, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_postman-linter")).current_dir(dir).args(args).output().unwrap()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+#[test]
+fn test_baseline_sees_issues_omitted_by_max_issues_per_rule() {
+    let dir = workdir("baseline-truncation");
+    fs::write(dir.join("before.json"), collection(3).to_string()).unwrap();
+    fs::write(dir.join("after.json"), collection(6).to_string()).unwrap();
+
+    let baseline = run(&dir, &["baseline", "--rules", "test-http-status-mandatory", "before.json"]);
+    assert!(baseline.status.success(), "{}", stderr(&baseline));
+    fs::write(dir.join("baseline.json"), &baseline.stdout).unwrap();
+
+    let output = run(
+        &dir,
+        &["--rules", "test-http-status-mandatory", "--baseline", "baseline.json", "--max-issues-per-rule", "1", "after.json"],
+    );
+    let log = stderr(&output);
+    assert!(log.contains("3 new issues, 3 known issues hidden"), "{}", log);
+    assert!(!log.contains("baseline issues are fixed"), "{}", log);
+
+    let report: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["result"]["issues"].as_array().unwrap().len(), 1);
+    assert_eq!(report["result"]["truncated"]["test-http-status-mandatory"], 2);
+}
+
+#[test]
+fn test_gate_with_baseline_fails_only_on_new_issues() {
+    let dir = workdir("baseline-gate");
+    fs::write(dir.join("before.json"), collection(2).to_string()).unwrap();
+    fs::write(dir.join("after.json"), collection(3).to_string()).unwrap();
+
+    let baseline = run(&dir, &["baseline", "--rules", "test-http-status-mandatory", "before.json"]);
+    assert!(baseline.status.success(), "{}", stderr(&baseline));
+    fs::write(dir.join("baseline.json"), &baseline.stdout).unwrap();
+
+    let args = ["--rules", "test-http-status-mandatory", "--baseline", "baseline.json", "--fail-on", "error"];
+    let unchanged = run(&dir, &[&args[..], &["before.json"]].concat());
+    assert!(unchanged.status.success(), "{}", stderr(&unchanged));
+    let report: Value = serde_json::from_slice(&unchanged.stdout).unwrap();
+    assert_eq!(report["result"]["stats"]["errors"], 0);
+
+    let regressed = run(&dir, &[&args[..], &["after.json"]].concat());
+    assert_eq!(regressed.status.code(), Some(1));
+    assert!(stderr(&regressed).contains("1 issues at error level or above"), "{}", stderr(&regressed));
+}
+
+#[test]
+fn test_gate_override_checks_only_the_given_threshold() {
+    let dir = workdir("gate-override");
+    fs::write(dir.join("collection.json"), collection(2).to_string()).unwrap();
+
+    let output = run(&dir, &["--rules", "test-http-status-mandatory", "--format", "summary", "--min-score", "0", "collection.json"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    let summary: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(summary["result"]["stats"]["errors"], 2);
+    assert_eq!(summary["result"]["quality_gate"]["passed"], true);
+}
+
+#[test]
+fn test_summary_verdict_matches_exit_code_without_gate() {
+    let dir = workdir("summary-no-gate");
+    fs::write(dir.join("collection.json"), collection(2).to_string()).unwrap();
+
+    let output = run(&dir, &["--rules", "test-http-status-mandatory", "--format", "summary", "collection.json"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    let summary: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(summary["result"]["stats"]["errors"], 2);
+    assert_eq!(summary["result"]["quality_gate"]["passed"], true);
+}
@@ -10,6 +10,104 @@ export type { LintConfig, LintResult, LintIssue, LintStats } from '@linterman/sh
 interface WasmModule {
   lint(collection_json: string, config_json: string): string;
   lint_and_fix(collection_json: string, config_json: string): string;
+  lint_subset(collection_json: string, config_json: string, only_rules_json: string): string;
+  lint_diff(old_collection_json: string, new_collection_json: string, config_json: string): string;
+  open_collection(collection_json: string): number;
+  close_collection(handle: number): boolean;
+  lint_collection(handle: number, config_json: string): string;
+  fix_collection(handle: number, config_json: string): string;
+  export_collection(handle: number): string;
+  register_js_rule(id: string, severity: string, callback: (item: JsRuleItem) => unknown): void;
+  register_js_collection_rule(
+    id: string,
+    severity: string,
+    callback: (collection: unknown, options: unknown) => unknown
+  ): void;
+  clear_js_rules(): void;
+  list_rules(): string;
+  validate_config(config_json: string): void;
+}
+
+/**
+ * Item transmis à une règle JavaScript personnalisée
+ */
+export interface JsRuleItem {
+  path: string;
+  name: string;
+  folders: string[];
+  is_request: boolean;
+  is_folder: boolean;
+  method: string | null;
+  url: string | null;
+  test_script: string;
+  prerequest_script: string;
+  inherited_test_scripts: string[];
+  item: unknown;
+  options: unknown;
+}
+
+/**
+ * Correction proposée par une issue (`LintIssue.fix`), discriminée par `type`.
+ * Les types non standard (règles externes ou JS) sont conservés tels quels.
+ */
+export type FixAction =
+  | { type: 'rename_request'; suggested_name: string }
+  | { type: 'add_test'; test_code: string }
+  | { type: 'add_response_time_test'; suggested_code: string }
+  | { type: 'add_schema_validation'; suggested_code: string }
+  | { type: 'update_test_description'; old_description: string; new_description: string }
+  | { type: 'adjust_threshold'; current_threshold: number; suggested_threshold: number; observed_p95?: number }
+  | { type: 'prune_examples'; keep_indices: number[] }
+  | { type: 'set_header'; header: string; value: string }
+  | { type: 'disable_header'; header: string }
+  | { type: 'normalize_placeholder'; original: string; normalized: string }
+  | { type: 'regenerate_endpoint_table'; table: string }
+  | { type: 'generate_overview'; content: string }
+  | { type: 'upgrade_schema'; schema: string }
+  | { type: 'use_environment_variable'; field: string; suggested_variable: string }
+  | { type: 'replace_secret'; secret_type: string; suggested_variable: string }
+  | { type: string; [key: string]: unknown };
+
+/**
+ * Issue retournée par une règle JavaScript (path et severity optionnels)
+ */
+export interface JsRuleIssue {
+  message: string;
+  path?: string;
+  severity?: 'error' | 'warning' | 'info' | 'hint';
+  fix?: FixAction;
+}
+
+/**
+ * Code d'une erreur levée par le module WASM
+ */
+export type WasmErrorCode =
+  | 'PARSE_COLLECTION'
+  | 'PARSE_CONFIG'
+  | 'PARSE_INPUT'
+  | 'INVALID_RULE'
+  | 'UNKNOWN_HANDLE'
+  | 'FIX_FAILED'
+  | 'SERIALIZE_RESULT'
+  | 'INVALID_CONFIG'
+  | 'UNKNOWN_RULE';
+
+/**
+ * Erreur structurée levée par le module WASM (objet, pas une instance d'Error) ;
+ * `line` / `column` localisent les erreurs de parsing JSON
+ */
+export interface WasmError {
+  code: WasmErrorCode;
+  detail: string;
+  line: number | null;
+  column: number | null;
+}
+
+/**
+ * Vérifie qu'une erreur capturée provient du module WASM
+ */
+export function isWasmError(error: unknown): error is WasmError {
+  return typeof error === 'object' && error !== null && 'code' in error && 'detail' in error;
 }
 
 // ============================================================================
@@ -64,7 +162,8 @@ export function isWasmInitialized(): boolean {
 /**
  * Analyse une collection Postman avec le linter
  * 
- * @param collection - Collection Postman (objet JSON)
+ * @param collection - Collection Postman (objet JSON, ou texte du fichier d'origine
+ *   pour obtenir `line`/`column` des issues avec `source_map: true`)
  * @param config - Configuration du linter (optionnel)
  * @returns Résultat de l'analyse avec score, issues et stats
  * 
@@ -109,7 +208,7 @@ export async function lint(
 
   try {
     // Convertir en JSON strings
-    const collectionJson = JSON.stringify(collection);
+    const collectionJson = typeof collection === 'string' ? collection : JSON.stringify(collection);
     const configJson = JSON.stringify(validatedConfig);
 
     // Appeler le WASM
@@ -162,6 +261,48 @@ export function lintSync(
   }
 }
 
+/**
+ * Ré-analyse une collection en n'exécutant que les règles `onlyRules`
+ *
+ * Pour les cases à cocher de l'IHM : tant que la collection est inchangée, le WASM
+ * réutilise sa version parsée, son index de scripts et les issues des autres règles.
+ * Le résultat est identique à celui de `lint` avec la même configuration.
+ *
+ * ⚠️ Cette fonction nécessite que initWasm() ait été appelé au préalable
+ *
+ * @param collection - Collection Postman (objet JSON)
+ * @param config - Configuration du linter (règles activées après le changement)
+ * @param onlyRules - Règles à ré-exécuter (celle qui vient d'être cochée)
+ */
+export function lintSubset(
+  collection: unknown,
+  config: Partial<LintConfig>,
+  onlyRules: string[]
+): LintResult {
+  if (!wasmModule) {
+    throw new Error('WASM module not initialized. Call initWasm() first.');
+  }
+
+  const validatedConfig = LintConfigSchema.parse({
+    local_only: true,
+    ...config,
+  });
+
+  try {
+    const resultJson = wasmModule.lint_subset(
+      JSON.stringify(collection),
+      JSON.stringify(validatedConfig),
+      JSON.stringify(onlyRules)
+    );
+    return LintResultSchema.parse(JSON.parse(resultJson));
+  } catch (error) {
+    if (error instanceof Error) {
+      throw new Error(`Linting failed: ${error.message}`);
+    }
+    throw error;
+  }
+}
+
 /**
  * Analyse et corrige automatiquement une collection Postman
  * 
@@ -242,48 +383,303 @@ export function lintAndFixSync(
   }
 }
 
+/**
+ * Résultat de `lintDiff`
+ */
+export interface DiffLintResult {
+  /** Items ajoutés ou modifiés (paths dans la nouvelle version) */
+  changed_items: string[];
+  /** Issues introduites par les changements */
+  issues: LintResult['issues'];
+  score_before: number;
+  score_after: number;
+  /** Analyse complète de la nouvelle version */
+  result: LintResult;
+}
+
+/**
+ * Analyse la nouvelle version d'une collection en ne retenant que les issues
+ * introduites par les items ajoutés ou modifiés (CI des merge requests)
+ *
+ * ⚠️ Cette fonction nécessite que initWasm() ait été appelé au préalable
+ *
+ * @param oldCollection - Version de référence (branche cible)
+ * @param newCollection - Version à analyser
+ * @param config - Configuration du linter
+ */
+export function lintDiff(
+  oldCollection: unknown,
+  newCollection: unknown,
+  config: Partial<LintConfig> = {}
+): DiffLintResult {
+  const wasm = requireWasm();
+  const validatedConfig = LintConfigSchema.parse({
+    local_only: true,
+    ...config,
+  });
+
+  const diff = JSON.parse(
+    wasm.lint_diff(JSON.stringify(oldCollection), JSON.stringify(newCollection), JSON.stringify(validatedConfig))
+  );
+  return { ...diff, result: LintResultSchema.parse(diff.result) };
+}
+
+// ============================================================================
+// Sessions (collection parsée une seule fois)
+// ============================================================================
+
+/**
+ * Handle d'une collection conservée en mémoire WASM (voir `openCollection`)
+ */
+export type CollectionHandle = number;
+
+function requireWasm(): WasmModule {
+  if (!wasmModule) {
+    throw new Error('WASM module not initialized. Call initWasm() first.');
+  }
+  return wasmModule;
+}
+
+/**
+ * Parse une collection une seule fois et la conserve en mémoire WASM. Les analyses et
+ * corrections suivantes passent par le handle, sans re-sérialiser la collection ;
+ * les règles déjà exécutées ne sont pas relancées tant que leurs options ne changent pas.
+ *
+ * @example
+ * ```typescript
+ * const handle = openCollection(collection);
+ * const result = lintCollection(handle, { rules });
+ * const fixed = fixCollection(handle, { rules });
+ * const json = exportCollection(handle);
+ * closeCollection(handle);
+ * ```
+ */
+export function openCollection(collection: unknown): CollectionHandle {
+  return requireWasm().open_collection(JSON.stringify(collection));
+}
+
+/**
+ * Libère la collection d'un handle (`false` si le handle est inconnu)
+ */
+export function closeCollection(handle: CollectionHandle): boolean {
+  return requireWasm().close_collection(handle);
+}
+
+/**
+ * Analyse la collection d'un handle
+ */
+export function lintCollection(handle: CollectionHandle, config: Partial<LintConfig> = {}): LintResult {
+  const validatedConfig = LintConfigSchema.parse({ local_only: true, ...config });
+  const resultJson = requireWasm().lint_collection(handle, JSON.stringify(validatedConfig));
+  return LintResultSchema.parse(JSON.parse(resultJson));
+}
+
+/**
+ * Corrige la collection d'un handle en place ; même réponse que `lintAndFix`, sans
+ * `fixed_collection` (voir `exportCollection`)
+ */
+export function fixCollection(handle: CollectionHandle, config: Partial<LintConfig> = {}): any {
+  const validatedConfig = LintConfigSchema.parse({ local_only: true, fix: true, ...config });
+  return JSON.parse(requireWasm().fix_collection(handle, JSON.stringify(validatedConfig)));
+}
+
+/**
+ * Collection d'un handle, corrections comprises
+ */
+export function exportCollection(handle: CollectionHandle): unknown {
+  return JSON.parse(requireWasm().export_collection(handle));
+}
+
+// ============================================================================
+// Règles JavaScript personnalisées
+// ============================================================================
+
+/**
+ * Enregistre une règle personnalisée appelée pour chaque item de la collection
+ * (remplace une règle existante de même id). Nécessite que `initWasm()` ait été appelé.
+ *
+ * @example
+ * ```typescript
+ * registerJsRule('team-owner-tag', 'warning', (item) =>
+ *   item.is_request && !item.name.includes('[owner:')
+ *     ? { message: `Request "${item.name}" has no owner tag` }
+ *     : null
+ * );
+ * ```
+ */
+export function registerJsRule(
+  id: string,
+  severity: 'error' | 'warning' | 'info' | 'hint',
+  callback: (item: JsRuleItem) => JsRuleIssue | JsRuleIssue[] | null | undefined
+): void {
+  if (!wasmModule) {
+    throw new Error('WASM module not initialized. Call initWasm() first.');
+  }
+  wasmModule.register_js_rule(id, severity, callback);
+}
+
+/**
+ * Supprime toutes les règles JavaScript enregistrées (y compris celles des plugins)
+ */
+export function clearJsRules(): void {
+  wasmModule?.clear_js_rules();
+}
+
+// ============================================================================
+// Plugins WASM
+// ============================================================================
+
+/**
+ * Version de l'interface des plugins supportée (`PLUGIN_ABI_VERSION` de linterman-rule-api)
+ */
+export const PLUGIN_ABI_VERSION = 1;
+
+/**
+ * Règle déclarée par un plugin
+ */
+export interface PluginRuleInfo {
+  id: string;
+  severity: 'error' | 'warning' | 'info' | 'hint';
+  description: string;
+}
+
+interface PluginExports {
+  memory: WebAssembly.Memory;
+  linterman_plugin_abi_version(): number;
+  linterman_plugin_rules(): number;
+  linterman_plugin_check(ruleId: number, collection: number, options: number): number;
+  linterman_plugin_alloc(len: number): number;
+  linterman_plugin_free(ptr: number): void;
+}
+
+/** Lit puis libère une chaîne terminée par un zéro retournée par le plugin */
+function takePluginString(plugin: PluginExports, ptr: number): string {
+  const memory = new Uint8Array(plugin.memory.buffer);
+  const end = memory.indexOf(0, ptr);
+  const text = new TextDecoder().decode(memory.subarray(ptr, end));
+  plugin.linterman_plugin_free(ptr);
+  return text;
+}
+
+/** Copie une chaîne dans la mémoire du plugin (à libérer avec `linterman_plugin_free`) */
+function writePluginString(plugin: PluginExports, text: string): number {
+  const bytes = new TextEncoder().encode(text);
+  const ptr = plugin.linterman_plugin_alloc(bytes.length + 1);
+  // Lue après l'allocation : la mémoire a pu grandir
+  new Uint8Array(plugin.memory.buffer).set(bytes, ptr);
+  return ptr;
+}
+
+/**
+ * Charge un plugin de règles compilé en WASM (crate `cdylib` utilisant
+ * `linterman_rule_api::export_plugin!`, cible `wasm32-unknown-unknown`). Ses règles
+ * sont enregistrées comme règles JavaScript et leurs issues rejoignent celles des
+ * règles intégrées. Retourne les identifiants des règles chargées.
+ *
+ * @example
+ * ```typescript
+ * await initWasm();
+ * const ids = await loadPlugin(fetch('/plugins/acme_rules.wasm'));
+ * const result = await lint(collection, { rules: [...builtinRules, ...ids] });
+ * ```
+ */
+export async function loadPlugin(source: BufferSource | Response | Promise<Response>): Promise<string[]> {
+  const wasm = requireWasm();
+  const response = await source;
+  const { instance } =
+    response instanceof Response
+      ? await WebAssembly.instantiate(await response.arrayBuffer())
+      : await WebAssembly.instantiate(response);
+  const plugin = instance.exports as unknown as PluginExports;
+
+  if (typeof plugin.linterman_plugin_abi_version !== 'function') {
+    throw new Error('Not a linterman plugin: linterman_plugin_abi_version is not exported');
+  }
+  const abiVersion = plugin.linterman_plugin_abi_version();
+  if (abiVersion !== PLUGIN_ABI_VERSION) {
+    throw new Error(`Plugin targets plugin ABI ${abiVersion} (this linter supports ${PLUGIN_ABI_VERSION})`);
+  }
+
+  const rules = JSON.parse(takePluginString(plugin, plugin.linterman_plugin_rules())) as PluginRuleInfo[];
+  for (const rule of rules) {
+    wasm.register_js_collection_rule(rule.id, rule.severity, (collection, options) => {
+      const args = [rule.id, JSON.stringify(collection), JSON.stringify(options ?? null)].map((text) =>
+        writePluginString(plugin, text)
+      );
+      try {
+        const output = JSON.parse(takePluginString(plugin, plugin.linterman_plugin_check(args[0], args[1], args[2])));
+        if (!Array.isArray(output)) {
+          throw new Error(output?.error ?? 'invalid plugin output');
+        }
+        return output;
+      } finally {
+        args.forEach((ptr) => plugin.linterman_plugin_free(ptr));
+      }
+    });
+  }
+  return rules.map((rule) => rule.id);
+}
+
 // ============================================================================
 // Helpers
 // ============================================================================
 
+/**
+ * Métadonnées d'une règle intégrée
+ */
+export interface RuleMetadata {
+  id: string;
+  category: 'testing' | 'structure' | 'performance' | 'best_practices' | 'flow' | 'documentation' | 'security';
+  severity: 'error' | 'warning' | 'info';
+  description: string;
+  /** Niveau de maturité (parcours d'adoption L1 → L3) */
+  maturity: 'L1' | 'L2' | 'L3';
+  fixable: boolean;
+  fix_types: string[];
+  /** Options configurables (`rule_options[id]`) et leurs valeurs par défaut */
+  options: Record<string, unknown> | null;
+}
+
+/**
+ * Liste les règles intégrées, dans l'ordre d'exécution
+ */
+export function listRules(): RuleMetadata[] {
+  if (!wasmModule) {
+    throw new Error('WASM module not initialized. Call initWasm() first.');
+  }
+
+  return JSON.parse(wasmModule.list_rules());
+}
+
+/**
+ * Validation stricte d'une configuration (règles JavaScript enregistrées comprises) :
+ * lève une `WasmError` `UNKNOWN_RULE` ou `INVALID_CONFIG` là où `lint()` se contente
+ * de `config_warnings`
+ *
+ * @param config - Configuration à valider
+ */
+export function validateConfig(config: Partial<LintConfig> = {}): void {
+  const wasm = requireWasm();
+  const validatedConfig = LintConfigSchema.parse({
+    local_only: true,
+    ...config,
+  });
+
+  wasm.validate_config(JSON.stringify(validatedConfig));
+}
+
 /**
  * Obtient la liste des règles disponibles
  */
 export function getAvailableRules(): string[] {
-  return [
-    'test-http-status-mandatory',
-    'hardcoded-secrets',
-  ];
+  return listRules().map((rule) => rule.id);
 }
 
 /**
  * Obtient les métadonnées d'une règle
  */
-export function getRuleMetadata(ruleId: string): {
-  id: string;
-  name: string;
-  category: string;
-  severity: string;
-  description: string;
-} | null {
-  const rules: Record<string, any> = {
-    'test-http-status-mandatory': {
-      id: 'test-http-status-mandatory',
-      name: 'Test HTTP Status Mandatory',
-      category: 'testing',
-      severity: 'error',
-      description: 'Vérifie que chaque requête teste le code de statut HTTP',
-    },
-    'hardcoded-secrets': {
-      id: 'hardcoded-secrets',
-      name: 'Hardcoded Secrets',
-      category: 'security',
-      severity: 'error',
-      description: 'Détecte les secrets hardcodés (API keys, tokens, passwords)',
-    },
-  };
-
-  return rules[ruleId] || null;
+export function getRuleMetadata(ruleId: string): RuleMetadata | null {
+  return listRules().find((rule) => rule.id === ruleId) ?? null;
 }
 
 /**